@@ -17,18 +17,28 @@
 //!
 //! `$\text{asinh(x)}$` codec implementation for the [`numcodecs`] API.
 
-use ndarray::{Array, ArrayBase, ArrayView, ArrayViewMut, Data, Dimension, Zip};
+use std::borrow::Cow;
+
+use ndarray::{Array, ArrayBase, ArrayView, ArrayViewMut, Data, Dimension, IxDyn, Zip};
 use num_traits::{Float, Signed};
 use numcodecs::{
     AnyArray, AnyArrayAssignError, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray,
-    Codec, StaticCodec, StaticCodecConfig, StaticCodecVersion,
+    Codec, DynCodec, ErasedDynCodec, ErasedError, StaticCodec, StaticCodecConfig,
+    StaticCodecVersion,
+};
+use numcodecs_registry::GlobalRegistry;
+use numcodecs_utils::framing::{
+    FramingError, decode_framed, encode_framed, framed_array, framed_bytes,
 };
-use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 
+type AsinhCodecVersion = StaticCodecVersion<1, 1, 0>;
+
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
-#[serde(deny_unknown_fields)]
+// serde cannot deny unknown fields because of the flatten
+#[schemars(deny_unknown_fields)]
 /// Asinh codec, which applies a quasi-logarithmic transformation on encoding.
 ///
 /// For values close to zero that are within the codec's `linear_width`, the
@@ -42,61 +52,172 @@ use thiserror::Error;
 /// `$d = w \cdot \text{sinh}\left( \frac{c}{w} \right)$`
 /// on decoding, where `$w$` is the codec's `linear_width`.
 ///
+/// The `linear_width` can either be fixed in the config or be computed from
+/// the data during encoding. In the latter case, the transformed data is
+/// encoded with a wrapped codec, and the encoded array is a one-dimensional
+/// byte array that stores the `linear_width` next to the wrapped codec's
+/// encoding.
+///
 /// The codec only supports finite floating point numbers.
 pub struct AsinhCodec {
-    /// The width of the close-to-zero input value range where the transform is
-    /// nearly linear
-    pub linear_width: f64,
+    /// The mode in which the `linear_width` is determined
+    #[serde(flatten)]
+    pub mode: AsinhMode,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default, rename = "_version")]
-    pub version: StaticCodecVersion<1, 0, 0>,
+    pub version: AsinhCodecVersion,
+}
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "mode")]
+#[serde(deny_unknown_fields)]
+/// Mode in which the `linear_width` is determined
+pub enum AsinhMode {
+    /// The `linear_width` is the `quantile` of `$|x|$`, which is computed
+    /// during encoding
+    #[serde(rename = "quantile")]
+    Quantile {
+        /// The quantile, in `$[0, 1]$`, of the absolute data values that is
+        /// used as the `linear_width`
+        quantile: Quantile<f64>,
+        /// The configuration of the wrapped codec, which encodes the
+        /// transformed data
+        #[serde(serialize_with = "DynCodec::get_config")]
+        #[serde(deserialize_with = "GlobalRegistry::codec_from_config")]
+        #[schemars(schema_with = "ErasedDynCodec::codec_config_schema")]
+        codec: ErasedDynCodec,
+    },
+    /// The `linear_width` is fixed in the config
+    ///
+    /// This is the default mode, which is used if no `mode` is specified.
+    #[serde(untagged)]
+    Fixed {
+        /// The width of the close-to-zero input value range where the
+        /// transform is nearly linear
+        linear_width: f64,
+    },
 }
 
 impl Codec for AsinhCodec {
     type Error = AsinhCodecError;
 
     fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
-        match data {
-            #[expect(clippy::cast_possible_truncation)]
-            AnyCowArray::F32(data) => Ok(AnyArray::F32(asinh(data, self.linear_width as f32)?)),
-            AnyCowArray::F64(data) => Ok(AnyArray::F64(asinh(data, self.linear_width)?)),
-            encoded => Err(AsinhCodecError::UnsupportedDtype(encoded.dtype())),
-        }
+        let (quantile, codec) = match &self.mode {
+            AsinhMode::Fixed { linear_width } => {
+                return match data {
+                    #[expect(clippy::cast_possible_truncation)]
+                    AnyCowArray::F32(data) => Ok(AnyArray::F32(asinh(data, *linear_width as f32)?)),
+                    AnyCowArray::F64(data) => Ok(AnyArray::F64(asinh(data, *linear_width)?)),
+                    encoded => Err(AsinhCodecError::UnsupportedDtype(encoded.dtype())),
+                };
+            }
+            AsinhMode::Quantile { quantile, codec } => (*quantile, codec),
+        };
+
+        let (linear_width, transformed) = match data {
+            AnyCowArray::F32(data) => {
+                let linear_width = quantile_linear_width(&data, quantile)?;
+                (
+                    f64::from(linear_width),
+                    AnyArray::F32(asinh(data, linear_width)?),
+                )
+            }
+            AnyCowArray::F64(data) => {
+                let linear_width = quantile_linear_width(&data, quantile)?;
+                (linear_width, AnyArray::F64(asinh(data, linear_width)?))
+            }
+            encoded => return Err(AsinhCodecError::UnsupportedDtype(encoded.dtype())),
+        };
+
+        let encoded = codec
+            .encode(transformed.into_cow())
+            .map_err(|err| AsinhCodecError::InnerEncodeFailed { source: err })?;
+
+        let encoded = encode_framed(
+            &CompressionHeader {
+                linear_width,
+                version: StaticCodecVersion,
+            },
+            &encoded,
+        )?;
+
+        Ok(framed_array(encoded))
     }
 
     fn decode(&self, encoded: AnyCowArray) -> Result<AnyArray, Self::Error> {
-        match encoded {
-            #[expect(clippy::cast_possible_truncation)]
-            AnyCowArray::F32(encoded) => {
-                Ok(AnyArray::F32(sinh(encoded, self.linear_width as f32)?))
+        let codec = match &self.mode {
+            AsinhMode::Fixed { linear_width } => {
+                return match encoded {
+                    #[expect(clippy::cast_possible_truncation)]
+                    AnyCowArray::F32(encoded) => {
+                        Ok(AnyArray::F32(sinh(encoded, *linear_width as f32)?))
+                    }
+                    AnyCowArray::F64(encoded) => Ok(AnyArray::F64(sinh(encoded, *linear_width)?)),
+                    encoded => Err(AsinhCodecError::UnsupportedDtype(encoded.dtype())),
+                };
             }
-            AnyCowArray::F64(encoded) => Ok(AnyArray::F64(sinh(encoded, self.linear_width)?)),
-            encoded => Err(AsinhCodecError::UnsupportedDtype(encoded.dtype())),
+            AsinhMode::Quantile { codec, .. } => codec,
+        };
+
+        let encoded = encoded.view();
+        let (header, encoded, _) = decode_framed::<CompressionHeader>(&framed_bytes(&encoded)?)?;
+
+        let decoded = codec
+            .decode(encoded.into_cow())
+            .map_err(|err| AsinhCodecError::InnerDecodeFailed { source: err })?;
+
+        match decoded {
+            #[expect(clippy::cast_possible_truncation)]
+            AnyArray::F32(decoded) => Ok(AnyArray::F32(sinh(decoded, header.linear_width as f32)?)),
+            AnyArray::F64(decoded) => Ok(AnyArray::F64(sinh(decoded, header.linear_width)?)),
+            decoded => Err(AsinhCodecError::UnsupportedDtype(decoded.dtype())),
         }
     }
 
     fn decode_into(
         &self,
         encoded: AnyArrayView,
-        decoded: AnyArrayViewMut,
+        mut decoded: AnyArrayViewMut,
     ) -> Result<(), Self::Error> {
-        match (encoded, decoded) {
-            #[expect(clippy::cast_possible_truncation)]
-            (AnyArrayView::F32(encoded), AnyArrayViewMut::F32(decoded)) => {
-                sinh_into(encoded, decoded, self.linear_width as f32)
+        let codec = match &self.mode {
+            AsinhMode::Fixed { linear_width } => {
+                return match (encoded, decoded) {
+                    #[expect(clippy::cast_possible_truncation)]
+                    (AnyArrayView::F32(encoded), AnyArrayViewMut::F32(decoded)) => {
+                        sinh_into(encoded, decoded, *linear_width as f32)
+                    }
+                    (AnyArrayView::F64(encoded), AnyArrayViewMut::F64(decoded)) => {
+                        sinh_into(encoded, decoded, *linear_width)
+                    }
+                    (encoded @ (AnyArrayView::F32(_) | AnyArrayView::F64(_)), decoded) => {
+                        Err(AsinhCodecError::MismatchedDecodeIntoArray {
+                            source: AnyArrayAssignError::DTypeMismatch {
+                                src: encoded.dtype(),
+                                dst: decoded.dtype(),
+                            },
+                        })
+                    }
+                    (encoded, _decoded) => Err(AsinhCodecError::UnsupportedDtype(encoded.dtype())),
+                };
             }
-            (AnyArrayView::F64(encoded), AnyArrayViewMut::F64(decoded)) => {
-                sinh_into(encoded, decoded, self.linear_width)
-            }
-            (encoded @ (AnyArrayView::F32(_) | AnyArrayView::F64(_)), decoded) => {
-                Err(AsinhCodecError::MismatchedDecodeIntoArray {
-                    source: AnyArrayAssignError::DTypeMismatch {
-                        src: encoded.dtype(),
-                        dst: decoded.dtype(),
-                    },
-                })
-            }
-            (encoded, _decoded) => Err(AsinhCodecError::UnsupportedDtype(encoded.dtype())),
+            AsinhMode::Quantile { codec, .. } => codec,
+        };
+
+        if !matches!(decoded.dtype(), AnyArrayDType::F32 | AnyArrayDType::F64) {
+            return Err(AsinhCodecError::UnsupportedDtype(decoded.dtype()));
+        }
+
+        let (header, encoded, _) = decode_framed::<CompressionHeader>(&framed_bytes(&encoded)?)?;
+
+        codec
+            .decode_into(encoded.view(), decoded.view_mut())
+            .map_err(|err| AsinhCodecError::InnerDecodeFailed { source: err })?;
+
+        match decoded {
+            #[expect(clippy::cast_possible_truncation)]
+            AnyArrayViewMut::F32(decoded) => sinh_inplace(decoded, header.linear_width as f32),
+            AnyArrayViewMut::F64(decoded) => sinh_inplace(decoded, header.linear_width),
+            decoded => Err(AsinhCodecError::UnsupportedDtype(decoded.dtype())),
         }
     }
 }
@@ -115,6 +236,50 @@ impl StaticCodec for AsinhCodec {
     }
 }
 
+#[expect(clippy::derive_partial_eq_without_eq)] // floats are not Eq
+#[derive(Copy, Clone, PartialEq, PartialOrd, Hash)]
+/// Floating point quantile in `$[0, 1]$`
+pub struct Quantile<T: Float>(T);
+
+impl Serialize for Quantile<f64> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Quantile<f64> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let x = f64::deserialize(deserializer)?;
+
+        if (0.0..=1.0).contains(&x) {
+            Ok(Self(x))
+        } else {
+            Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Float(x),
+                &"a value in [0.0, 1.0]",
+            ))
+        }
+    }
+}
+
+impl JsonSchema for Quantile<f64> {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("QuantileF64")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed(concat!(module_path!(), "::", "Quantile<f64>"))
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "minimum": 0.0,
+            "maximum": 1.0
+        })
+    }
+}
+
 #[derive(Debug, Error)]
 /// Errors that may occur when applying the [`AsinhCodec`].
 pub enum AsinhCodecError {
@@ -125,6 +290,28 @@ pub enum AsinhCodecError {
     /// point data
     #[error("Asinh does not support non-finite (infinite or NaN) floating point data")]
     NonFiniteData,
+    /// [`AsinhCodec`] failed to encode the transformed data with the wrapped
+    /// codec
+    #[error("Asinh failed to encode the transformed data with the wrapped codec")]
+    InnerEncodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`AsinhCodec`] failed to decode the transformed data with the wrapped
+    /// codec
+    #[error("Asinh failed to decode the transformed data with the wrapped codec")]
+    InnerDecodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`AsinhCodec`] failed to frame or unframe the wrapped codec's encoding
+    /// together with the `linear_width`
+    #[error("Asinh failed to frame or unframe the wrapped codec's encoding")]
+    FramingFailed {
+        /// The source of the error
+        #[from]
+        source: FramingError,
+    },
     /// [`AsinhCodec`] cannot decode into the provided array
     #[error("Asinh cannot decode into the provided array")]
     MismatchedDecodeIntoArray {
@@ -215,10 +402,88 @@ pub fn sinh_into<T: Float, D: Dimension>(
     Ok(())
 }
 
+/// Compute the `linear_width` as the `quantile` of the absolute values of the
+/// input `data` array.
+///
+/// The quantile is computed using linear interpolation between the closest
+/// ranks. If the data is empty or the quantile is zero, e.g. for data that is
+/// mostly zero, the `linear_width` is one.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`AsinhCodecError::NonFiniteData`] if any data element is non-finite
+///   (infinite or NaN)
+pub fn quantile_linear_width<T: Float, S: Data<Elem = T>, D: Dimension>(
+    data: &ArrayBase<S, D>,
+    quantile: Quantile<f64>,
+) -> Result<T, AsinhCodecError> {
+    if !Zip::from(data).all(|x| x.is_finite()) {
+        return Err(AsinhCodecError::NonFiniteData);
+    }
+
+    let mut sorted = data.iter().map(|x| x.abs()).collect::<Vec<_>>();
+    sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let Some(last) = sorted.len().checked_sub(1) else {
+        return Ok(T::one());
+    };
+
+    #[expect(clippy::cast_precision_loss)]
+    let rank = quantile.0 * (last as f64);
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let lower_rank = (rank.floor() as usize).min(last);
+    let upper_rank = (lower_rank + 1).min(last);
+
+    let (Some(lower), Some(upper), Some(fraction)) = (
+        sorted.get(lower_rank),
+        sorted.get(upper_rank),
+        T::from(rank - rank.floor()),
+    ) else {
+        return Ok(T::one());
+    };
+
+    let linear_width = fraction.mul_add(*upper - *lower, *lower);
+
+    if linear_width.is_zero() {
+        return Ok(T::one());
+    }
+
+    Ok(linear_width)
+}
+
+/// Compute `$w \cdot \text{sinh}\left( \frac{x}{w} \right)$` in-place
+/// over the elements of the `data` array.
+fn sinh_inplace<T: Float>(
+    mut data: ArrayViewMut<T, IxDyn>,
+    linear_width: T,
+) -> Result<(), AsinhCodecError> {
+    if !Zip::from(&data).all(|x| x.is_finite()) {
+        return Err(AsinhCodecError::NonFiniteData);
+    }
+
+    data.mapv_inplace(|x| (x / linear_width).sinh() * linear_width);
+
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize)]
+struct CompressionHeader {
+    linear_width: f64,
+    version: AsinhCodecVersion,
+}
+
 #[cfg(test)]
 mod tests {
+    use numcodecs_identity::IdentityCodec;
+    use serde_json::json;
+
     use super::*;
 
+    numcodecs_registry::export_global! {
+        static REGISTRY: numcodecs_registry::EmptyRegistry = numcodecs_registry::EmptyRegistry;
+    }
+
     #[test]
     fn roundtrip() -> Result<(), AsinhCodecError> {
         let data = (-1000..1000).map(f64::from).collect::<Vec<_>>();
@@ -255,4 +520,87 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn quantile_roundtrip() -> Result<(), AsinhCodecError> {
+        let data = Array::from_shape_fn((10, 20), |(i, j)| {
+            (f64::from(u32::try_from(i * 20 + j).unwrap_or(0)) - 100.0) * 1.5
+        });
+
+        let codec = AsinhCodec {
+            mode: AsinhMode::Quantile {
+                quantile: Quantile(0.5),
+                codec: ErasedDynCodec::new(IdentityCodec {
+                    version: StaticCodecVersion,
+                }),
+            },
+            version: StaticCodecVersion,
+        };
+
+        let encoded = codec.encode(AnyCowArray::F64(data.view().into_dyn().into()))?;
+        assert_eq!(encoded.dtype(), AnyArrayDType::U8);
+        assert_eq!(encoded.shape().len(), 1);
+
+        let encoded_bytes = encoded.view();
+        let (_, transformed, _) =
+            decode_framed::<CompressionHeader>(&framed_bytes(&encoded_bytes)?)?;
+        assert_eq!(transformed.shape(), data.shape());
+
+        let decoded = codec.decode(encoded.cow())?;
+        let AnyArray::F64(decoded) = decoded else {
+            return Err(AsinhCodecError::UnsupportedDtype(decoded.dtype()));
+        };
+        assert_eq!(decoded.shape(), data.shape());
+        for (r, d) in data.iter().zip(decoded.iter()) {
+            assert!((r - d).abs() < 1e-10, "{r} {d}");
+        }
+
+        let mut decoded_into = Array::zeros(data.raw_dim()).into_dyn();
+        codec.decode_into(
+            encoded.view(),
+            AnyArrayViewMut::F64(decoded_into.view_mut()),
+        )?;
+        for (r, d) in data.iter().zip(decoded_into.iter()) {
+            assert!((r - d).abs() < 1e-10, "{r} {d}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn quantile_linear_widths() -> Result<(), AsinhCodecError> {
+        let data = Array::from_vec(vec![-4.0_f64, 3.0, -2.0, 1.0, 0.0]);
+
+        let median = quantile_linear_width(&data, Quantile(0.5))?;
+        assert_eq!(median.to_bits(), 2.0_f64.to_bits());
+
+        let max = quantile_linear_width(&data, Quantile(1.0))?;
+        assert_eq!(max.to_bits(), 4.0_f64.to_bits());
+
+        // a zero quantile falls back to a linear width of one
+        let min = quantile_linear_width(&data, Quantile(0.0))?;
+        assert_eq!(min.to_bits(), 1.0_f64.to_bits());
+
+        Ok(())
+    }
+
+    #[test]
+    #[expect(clippy::unwrap_used)]
+    fn fixed_mode_is_default() {
+        for config in [
+            json!({ "linear_width": 2.0 }),
+            json!({ "linear_width": 2.0, "_version": "1.0.0" }),
+        ] {
+            let codec: AsinhCodec = serde_json::from_value(config).unwrap();
+            assert!(matches!(
+                codec.mode,
+                AsinhMode::Fixed { linear_width } if linear_width.to_bits() == 2.0_f64.to_bits()
+            ));
+
+            assert_eq!(
+                serde_json::to_value(&codec).unwrap(),
+                json!({ "linear_width": 2.0, "_version": "1.1.0" })
+            );
+        }
+    }
 }
@@ -19,11 +19,12 @@
 
 use std::borrow::Cow;
 
-use ndarray::{Array, ArrayBase, Data, Dimension};
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension, Slice, Zip};
 use numcodecs::{
     AnyArray, AnyArrayAssignError, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray,
     Codec, StaticCodec, StaticCodecConfig, StaticCodecVersion,
 };
+use numcodecs_utils::regions::{self, ErrorBoundRegion, InvalidRegionError};
 use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
@@ -35,7 +36,7 @@ use thiserror::Error;
 /// Drops the specified number of bits from the floating point mantissa,
 /// leaving an array that is more amenable to compression. The number of
 /// bits to keep should be determined by information analysis of the data
-/// to be compressed.
+/// to be compressed, which the information mode performs automatically.
 ///
 /// The approach is based on the paper by Klöwer et al. 2021
 /// (<https://www.nature.com/articles/s43588-021-00156-2>).
@@ -43,9 +44,16 @@ pub struct BitRoundCodec {
     /// Bit rounding mode.
     #[serde(flatten)]
     pub mode: BitRoundMode,
+    /// Optional regions with spatially varying absolute error bounds.
+    ///
+    /// Every point inside a region is guaranteed to be within the region's
+    /// absolute error bound, in addition to the bound of the rounding mode.
+    /// Where regions overlap, the strictest bound applies.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub regions: Vec<ErrorBoundRegion>,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default, rename = "_version")]
-    pub version: StaticCodecVersion<2, 0, 0>,
+    pub version: StaticCodecVersion<2, 2, 0>,
 }
 
 #[derive(Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -82,6 +90,33 @@ pub enum BitRoundMode {
         /// `$|x - \hat{x}| \leq |x| \cdot \epsilon_{rel}$`.
         eb_rel: NonNegative<f64>,
     },
+    /// Preserve a fraction of the real bitwise information content.
+    ///
+    /// The number of bits of the mantissa to keep is chosen such that the
+    /// kept bits contain at least the requested fraction of the real
+    /// information content of the data, which is computed as the mutual
+    /// information between the bits of adjacent elements. Information that is
+    /// not significant at the 99% confidence level is treated as noise.
+    ///
+    /// The chosen number of keepbits is logged at the info level and can be
+    /// audited with the `information_keepbits` function.
+    #[serde(rename = "information")]
+    Information {
+        /// The fraction of the real information content to preserve, e.g.
+        /// `0.99` to preserve 99% of the information.
+        ///
+        /// A fraction of `1.0` keeps all bits of the mantissa.
+        information_level: UnitInterval<f64>,
+        /// The optional axis along which the data is split into slices, for
+        /// each of which the information content and number of keepbits are
+        /// computed separately.
+        ///
+        /// If no axis is provided, the number of keepbits is computed once
+        /// over the entire data, which is flattened in standard (row-major)
+        /// order.
+        #[serde(default)]
+        axis: Option<usize>,
+    },
 }
 
 impl Codec for BitRoundCodec {
@@ -89,8 +124,16 @@ impl Codec for BitRoundCodec {
 
     fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
         match data {
-            AnyCowArray::F32(data) => Ok(AnyArray::F32(bit_round(data, &self.mode)?)),
-            AnyCowArray::F64(data) => Ok(AnyArray::F64(bit_round(data, &self.mode)?)),
+            AnyCowArray::F32(data) => Ok(AnyArray::F32(bit_round_with_regions(
+                &data,
+                &self.mode,
+                &self.regions,
+            )?)),
+            AnyCowArray::F64(data) => Ok(AnyArray::F64(bit_round_with_regions(
+                &data,
+                &self.mode,
+                &self.regions,
+            )?)),
             encoded => Err(BitRoundCodecError::UnsupportedDtype(encoded.dtype())),
         }
     }
@@ -144,6 +187,25 @@ pub enum BitRoundCodecError {
         /// The `dtype` of the data to encode
         dtype: AnyArrayDType,
     },
+    /// [`BitRoundCodec`] cannot encode along an invalid `axis` for an array
+    /// with `ndim` dimensions
+    #[error(
+        "BitRound cannot encode along an invalid axis {axis} for an array with {ndim} dimensions"
+    )]
+    InvalidAxis {
+        /// The out-of-bounds axis index
+        axis: usize,
+        /// The number of dimensions of the array
+        ndim: usize,
+    },
+    /// [`BitRoundCodec`] cannot encode with an error bound region that does
+    /// not fit the array's shape
+    #[error("BitRound cannot encode with an error bound region that does not fit the array")]
+    InvalidRegion {
+        /// The source of the error
+        #[from]
+        source: InvalidRegionError,
+    },
     /// [`BitRoundCodec`] cannot decode into the provided array
     #[error("BitRound cannot decode into the provided array")]
     MismatchedDecodeIntoArray {
@@ -196,16 +258,66 @@ impl JsonSchema for NonNegative<f64> {
     }
 }
 
+#[expect(clippy::derive_partial_eq_without_eq)] // floats are not Eq
+#[derive(Copy, Clone, PartialEq, PartialOrd, Hash)]
+/// Floating point number in the closed unit interval `$[0, 1]$`
+pub struct UnitInterval<T: Float>(T);
+
+impl Serialize for UnitInterval<f64> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for UnitInterval<f64> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let x = f64::deserialize(deserializer)?;
+
+        if (0.0..=1.0).contains(&x) {
+            Ok(Self(x))
+        } else {
+            Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Float(x),
+                &"a value in [0.0, 1.0]",
+            ))
+        }
+    }
+}
+
+impl JsonSchema for UnitInterval<f64> {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("UnitIntervalF64")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed(concat!(module_path!(), "::", "UnitInterval<f64>"))
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "minimum": 0.0,
+            "maximum": 1.0
+        })
+    }
+}
+
 /// Floating-point bit rounding, which drops the specified number of bits from
 /// the floating point mantissa.
 ///
 /// See <https://github.com/milankl/BitInformation.jl> for the the original
 /// implementation in Julia.
 ///
+/// The number of keepbits that the information mode chooses can be computed
+/// separately with [`information_keepbits`].
+///
 /// # Errors
 ///
-/// Errors with [`BitRoundCodecError::ExcessiveKeepBits`] if `keepbits` exceeds
-/// [`T::MANITSSA_BITS`][`Float::MANITSSA_BITS`].
+/// Errors with
+/// - [`BitRoundCodecError::ExcessiveKeepBits`] if `keepbits` exceeds
+///   [`T::MANITSSA_BITS`][`Float::MANITSSA_BITS`]
+/// - [`BitRoundCodecError::InvalidAxis`] if the information mode's `axis` is
+///   out of bounds
 pub fn bit_round<T: Float, S: Data<Elem = T>, D: Dimension>(
     data: ArrayBase<S, D>,
     mode: &BitRoundMode,
@@ -242,6 +354,46 @@ pub fn bit_round<T: Float, S: Data<Elem = T>, D: Dimension>(
             return Ok(encoded);
         }
         BitRoundMode::RelativeError { eb_rel } => (BitRounder::keepbits_from_eb_rel(*eb_rel), true),
+        BitRoundMode::Information {
+            information_level,
+            axis,
+        } => {
+            let slice_keepbits = information_keepbits(&data, *information_level, *axis)?;
+
+            if let Some(axis) = axis {
+                log::info!(
+                    "BitRound chose {slice_keepbits:?} keepbits for the slices along axis {axis} \
+                     to preserve {} of the information",
+                    information_level.0
+                );
+            } else if let [keepbits] = slice_keepbits.as_slice() {
+                log::info!(
+                    "BitRound chose {keepbits} keepbits to preserve {} of the information",
+                    information_level.0
+                );
+            }
+
+            let mut encoded = data.into_owned();
+            let (mut encoded_slices, axis) = match axis {
+                Some(axis) => (encoded.view_mut().into_dyn(), *axis),
+                None => (encoded.view_mut().into_dyn().insert_axis(Axis(0)), 0),
+            };
+
+            for (mut slice, keepbits) in
+                encoded_slices.axis_iter_mut(Axis(axis)).zip(slice_keepbits)
+            {
+                // Skip if no bit rounding needs to happen
+                // - required since the ties to even impl does not work in this case
+                if keepbits == T::MANITSSA_BITS {
+                    continue;
+                }
+
+                let bit_round = BitRounder::new(keepbits);
+                slice.mapv_inplace(|x| bit_round.apply(x));
+            }
+
+            return Ok(encoded);
+        }
     };
 
     let mut encoded = data.into_owned();
@@ -266,6 +418,239 @@ pub fn bit_round<T: Float, S: Data<Elem = T>, D: Dimension>(
     Ok(encoded)
 }
 
+/// Floating-point bit rounding with additional spatially varying absolute
+/// error bound `regions`.
+///
+/// The `data` is first rounded with [`bit_round`] using the provided `mode`.
+/// Afterwards, every point inside a region whose rounding error exceeds the
+/// region's absolute error bound is rounded again, keeping enough bits of its
+/// mantissa to meet the local bound.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`BitRoundCodecError::InvalidRegion`] if any region does not fit the
+///   shape of the `data`
+/// - any error that [`bit_round`] may return
+pub fn bit_round_with_regions<T: Float, S: Data<Elem = T>, D: Dimension>(
+    data: &ArrayBase<S, D>,
+    mode: &BitRoundMode,
+    regions: &[ErrorBoundRegion],
+) -> Result<Array<T, D>, BitRoundCodecError> {
+    regions::check_regions_fit(regions, data.shape())?;
+
+    let mut encoded = bit_round(data.view(), mode)?;
+
+    for region in regions {
+        let eb_abs = T::from_f64(region.eb_abs.get());
+
+        let mut encoded_region = encoded.view_mut().into_dyn();
+        let mut data_region = data.view().into_dyn();
+        for (axis, (&start, &stop)) in region.start.iter().zip(&region.stop).enumerate() {
+            encoded_region.slice_axis_inplace(Axis(axis), Slice::from(start..stop));
+            data_region.slice_axis_inplace(Axis(axis), Slice::from(start..stop));
+        }
+
+        Zip::from(&mut encoded_region)
+            .and(&data_region)
+            .for_each(|encoded, &x| {
+                // subnormal, infinite, and NaN values are hard so just keep
+                // them as is
+                if !x.is_normal() {
+                    *encoded = x;
+                    return;
+                }
+
+                // the error is already within the local bound
+                if (x - *encoded).abs() <= eb_abs {
+                    return;
+                }
+
+                let keepbits = BitRounder::keepbits_from_eb_rel(NonNegative(eb_abs / x.abs()));
+
+                *encoded = if keepbits == T::MANITSSA_BITS {
+                    x
+                } else {
+                    BitRounder::new(keepbits).apply(x)
+                };
+            });
+    }
+
+    Ok(encoded)
+}
+
+/// Computes the real bitwise information content of the `data`.
+///
+/// The information content of each bit is computed as the mutual information
+/// between the bit of adjacent elements, where the data is flattened in
+/// standard (row-major) order. Information that is not significant at the
+/// 99% confidence level is set to zero.
+///
+/// The returned vector contains the information content of every bit, in
+/// bits, starting from the most significant (sign) bit.
+///
+/// See <https://github.com/milankl/BitInformation.jl> for the original
+/// implementation in Julia.
+pub fn bit_information<T: Float, S: Data<Elem = T>, D: Dimension>(
+    data: &ArrayBase<S, D>,
+) -> Vec<f64> {
+    // counts[bit][a][b] counts how often the bit is a in one element and b in
+    //  the next element, starting with the most significant bit
+    let mut counts = (0..T::BITS)
+        .rev()
+        .map(|shift| (shift, [[0_u64; 2]; 2]))
+        .collect::<Vec<_>>();
+    let mut num_pairs = 0_u64;
+
+    let mut iter = data.iter();
+    if let Some(first) = iter.next() {
+        let mut prev: u64 = first.to_binary().into();
+
+        for x in iter {
+            let x: u64 = x.to_binary().into();
+
+            for (shift, count) in &mut counts {
+                let a = ((prev >> *shift) & 1) == 1;
+                let b = ((x >> *shift) & 1) == 1;
+                #[expect(clippy::indexing_slicing)] // a and b are always 0 or 1
+                {
+                    count[usize::from(a)][usize::from(b)] += 1;
+                }
+            }
+
+            num_pairs += 1;
+            prev = x;
+        }
+    }
+
+    if num_pairs == 0 {
+        return vec![0.0; counts.len()];
+    }
+
+    #[expect(clippy::cast_precision_loss)]
+    let n = num_pairs as f64;
+    let free_entropy = binomial_free_entropy(n);
+
+    counts
+        .into_iter()
+        .map(|(_, [[c00, c01], [c10, c11]])| {
+            #[expect(clippy::cast_precision_loss)]
+            let [p00, p01, p10, p11] = [c00, c01, c10, c11].map(|c| (c as f64) / n);
+
+            let (pa0, pa1) = (p00 + p01, p10 + p11);
+            let (pb0, pb1) = (p00 + p10, p01 + p11);
+
+            let information = [
+                (p00, pa0, pb0),
+                (p01, pa0, pb1),
+                (p10, pa1, pb0),
+                (p11, pa1, pb1),
+            ]
+            .into_iter()
+            .filter(|(pab, _, _)| *pab > 0.0)
+            .map(|(pab, pa, pb)| pab * (pab / (pa * pb)).log2())
+            .sum::<f64>();
+
+            if information > free_entropy {
+                information
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// Computes the number of bits of the mantissa that the information mode of
+/// [`bit_round`] keeps to preserve the `information_level` fraction of the real
+/// bitwise information content of the `data`.
+///
+/// If an `axis` is provided, the returned vector contains the number of
+/// keepbits for each slice along the axis. Otherwise, it contains the single
+/// number of keepbits for the entire data.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`BitRoundCodecError::InvalidAxis`] if the `axis` is out of bounds
+pub fn information_keepbits<T: Float, S: Data<Elem = T>, D: Dimension>(
+    data: &ArrayBase<S, D>,
+    information_level: UnitInterval<f64>,
+    axis: Option<usize>,
+) -> Result<Vec<u32>, BitRoundCodecError> {
+    let (data, axis) = match axis {
+        Some(axis) if axis >= data.ndim() => {
+            return Err(BitRoundCodecError::InvalidAxis {
+                axis,
+                ndim: data.ndim(),
+            });
+        }
+        Some(axis) => (data.view().into_dyn(), axis),
+        None => (data.view().into_dyn().insert_axis(Axis(0)), 0),
+    };
+
+    Ok(data
+        .axis_iter(Axis(axis))
+        .map(|slice| keepbits_from_information::<T>(&bit_information(&slice), information_level))
+        .collect())
+}
+
+/// Computes the number of bits of the mantissa that need to be kept to
+/// preserve the `information_level` fraction of the real bitwise
+/// `information` content, as computed by [`bit_information`].
+///
+/// If the `information` contains no significant information at all, no bits
+/// of the mantissa are kept. If the `information_level` is `1.0`, all bits of
+/// the mantissa are kept.
+#[must_use]
+pub fn keepbits_from_information<T: Float>(
+    information: &[f64],
+    information_level: UnitInterval<f64>,
+) -> u32 {
+    // the sign and exponent bits are always kept
+    let sign_exponent_bits = T::BITS - T::MANITSSA_BITS;
+
+    if information_level.0 >= 1.0 {
+        return T::MANITSSA_BITS;
+    }
+
+    let total_information = information.iter().sum::<f64>();
+
+    if total_information <= 0.0 {
+        return 0;
+    }
+
+    let mut cumulative_information = 0.0;
+    let mut keepbits = T::BITS;
+
+    for (b, information) in (1..=T::BITS).zip(information) {
+        cumulative_information += information;
+
+        if (cumulative_information / total_information) > information_level.0 {
+            keepbits = b;
+            break;
+        }
+    }
+
+    keepbits
+        .saturating_sub(sign_exponent_bits)
+        .min(T::MANITSSA_BITS)
+}
+
+/// Computes the entropy, in bits, of a binomial distribution that is free,
+/// i.e. not significant at the 99% confidence level, for `n` samples.
+fn binomial_free_entropy(n: f64) -> f64 {
+    // quantile of the standard normal distribution at 1 - (1 - 0.99) / 2
+    const Z: f64 = 2.575_829_303_548_900_4;
+
+    let p = 0.5 + Z / (2.0 * n.sqrt());
+
+    if p >= 1.0 {
+        return 1.0;
+    }
+
+    p.mul_add(p.log2(), (1.0 - p).mul_add((1.0 - p).log2(), 1.0))
+}
+
 struct BitRounder<T: Float> {
     ulp_half: T::Binary,
     keep_mask: T::Binary,
@@ -313,7 +698,11 @@ impl<T: Float> BitRounder<T> {
 }
 
 /// Floating point types.
-pub trait Float: Sized + Copy + std::ops::Div<Self, Output = Self> {
+pub trait Float:
+    Sized + Copy + PartialOrd + std::ops::Sub<Self, Output = Self> + std::ops::Div<Self, Output = Self>
+{
+    /// Total number of bits, including the sign, exponent, and mantissa bits
+    const BITS: u32;
     /// Number of significant digits in base 2
     const MANITSSA_BITS: u32;
     /// Binary mask to extract only the mantissa bits
@@ -331,7 +720,8 @@ pub trait Float: Sized + Copy + std::ops::Div<Self, Output = Self> {
         + std::ops::Add<Self::Binary, Output = Self::Binary>
         + std::ops::AddAssign<Self::Binary>
         + std::ops::BitAnd<Self::Binary, Output = Self::Binary>
-        + std::ops::BitAndAssign<Self::Binary>;
+        + std::ops::BitAndAssign<Self::Binary>
+        + Into<u64>;
 
     /// Bit-cast the floating point value to its binary representation
     fn to_binary(self) -> Self::Binary;
@@ -356,6 +746,7 @@ impl Float for f32 {
     type Binary = u32;
 
     const BINARY_ONE: Self::Binary = 1;
+    const BITS: u32 = u32::BITS;
     const MANITSSA_BITS: u32 = Self::MANTISSA_DIGITS - 1;
     const MANTISSA_MASK: Self::Binary = (1 << Self::MANITSSA_BITS) - 1;
     const TY: AnyArrayDType = AnyArrayDType::F32;
@@ -390,6 +781,7 @@ impl Float for f64 {
     type Binary = u64;
 
     const BINARY_ONE: Self::Binary = 1;
+    const BITS: u32 = u64::BITS;
     const MANITSSA_BITS: u32 = Self::MANTISSA_DIGITS - 1;
     const MANTISSA_MASK: Self::Binary = (1 << Self::MANITSSA_BITS) - 1;
     const TY: AnyArrayDType = AnyArrayDType::F64;
@@ -699,4 +1091,196 @@ mod tests {
 
         assert_eq!(i32::from(0.0_f64.normal_log2_floor()), f64::MIN_EXP - 2);
     }
+
+    #[test]
+    fn information_smooth_data() {
+        #[expect(clippy::cast_precision_loss)]
+        let data = Array1::from_shape_fn(10_000, |i| ((i as f64) * 0.001).sin());
+
+        let information = bit_information(&data);
+        assert_eq!(information.len(), 64);
+
+        let keepbits = keepbits_from_information::<f64>(&information, UnitInterval(0.99));
+        assert!(keepbits > 0 && keepbits < f64::MANITSSA_BITS, "{keepbits}");
+
+        assert_eq!(
+            keepbits_from_information::<f64>(&information, UnitInterval(1.0)),
+            f64::MANITSSA_BITS
+        );
+
+        let encoded = bit_round(
+            data.view(),
+            &BitRoundMode::Information {
+                information_level: UnitInterval(0.99),
+                axis: None,
+            },
+        )
+        .unwrap();
+        let expected = bit_round(
+            data.view(),
+            &BitRoundMode::Keepbits {
+                keepbits: u8::try_from(keepbits).unwrap(),
+            },
+        )
+        .unwrap();
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn information_constant_data() {
+        let data = Array1::from_elem(1000, 42.0_f32);
+
+        assert!(bit_information(&data).iter().all(|i| *i == 0.0));
+        assert_eq!(
+            keepbits_from_information::<f32>(&bit_information(&data), UnitInterval(0.99)),
+            0
+        );
+
+        // too few elements to compute any information
+        assert!(
+            bit_information(&Array1::from_elem(1, 42.0_f32))
+                .iter()
+                .all(|i| *i == 0.0)
+        );
+    }
+
+    #[test]
+    fn information_along_axis() {
+        #[expect(clippy::cast_precision_loss)]
+        let data = ndarray::Array2::from_shape_fn((2, 1000), |(i, j)| {
+            if i == 0 {
+                1.5_f32
+            } else {
+                ((j as f32) * 0.01).sin()
+            }
+        });
+
+        let encoded = bit_round(
+            data.view(),
+            &BitRoundMode::Information {
+                information_level: UnitInterval(0.99),
+                axis: Some(0),
+            },
+        )
+        .unwrap();
+
+        // the constant slice contains no information and is rounded to
+        //  zero keepbits, while the smooth slice keeps some bits
+        assert!(encoded.row(0).iter().all(|x| *x == 2.0));
+        assert_ne!(encoded.row(1), ndarray::Array1::<f32>::zeros(1000));
+
+        assert!(matches!(
+            bit_round(
+                data.view(),
+                &BitRoundMode::Information {
+                    information_level: UnitInterval(0.99),
+                    axis: Some(2),
+                },
+            ),
+            Err(BitRoundCodecError::InvalidAxis { axis: 2, ndim: 2 })
+        ));
+    }
+
+    #[test]
+    fn audit_information_keepbits() {
+        #[expect(clippy::cast_precision_loss)]
+        let data = ndarray::Array2::from_shape_fn((3, 1000), |(i, j)| {
+            ((j as f64) * 0.001 * ((i + 1) as f64)).sin()
+        });
+
+        assert_eq!(
+            information_keepbits(&data, UnitInterval(0.99), None).unwrap(),
+            [keepbits_from_information::<f64>(
+                &bit_information(&data),
+                UnitInterval(0.99)
+            )]
+        );
+
+        assert_eq!(
+            information_keepbits(&data, UnitInterval(0.99), Some(0)).unwrap(),
+            data.rows()
+                .into_iter()
+                .map(|row| keepbits_from_information::<f64>(
+                    &bit_information(&row),
+                    UnitInterval(0.99)
+                ))
+                .collect::<Vec<_>>()
+        );
+
+        assert!(matches!(
+            information_keepbits(&data, UnitInterval(0.99), Some(2)),
+            Err(BitRoundCodecError::InvalidAxis { axis: 2, ndim: 2 })
+        ));
+    }
+
+    #[test]
+    fn error_bound_regions() {
+        #[expect(clippy::cast_precision_loss)]
+        let data = ndarray::Array2::from_shape_fn((16, 16), |(i, j)| {
+            ((i * 16 + j) as f64).mul_add(0.37, 1.0).sin() * 100.0
+        });
+
+        let regions = vec![
+            ErrorBoundRegion {
+                start: vec![0, 0],
+                stop: vec![8, 8],
+                eb_abs: regions::NonNegative::new(1e-6).unwrap(),
+            },
+            ErrorBoundRegion {
+                start: vec![4, 4],
+                stop: vec![16, 12],
+                eb_abs: regions::NonNegative::new(1e-3).unwrap(),
+            },
+        ];
+
+        let encoded =
+            bit_round_with_regions(&data, &BitRoundMode::Keepbits { keepbits: 2 }, &regions)
+                .unwrap();
+
+        for ((i, j), (x, y)) in data
+            .indexed_iter()
+            .zip(encoded.iter())
+            .map(|((i, x), y)| (i, (x, y)))
+        {
+            let error = (x - y).abs();
+
+            for region in &regions {
+                if (region.start[0]..region.stop[0]).contains(&i)
+                    && (region.start[1]..region.stop[1]).contains(&j)
+                {
+                    assert!(error <= region.eb_abs.get(), "{x} vs {y} at ({i}, {j})");
+                }
+            }
+        }
+
+        // points outside all regions are only rounded to two keepbits
+        assert_eq!(
+            encoded[(15, 15)],
+            bit_round(data.view(), &BitRoundMode::Keepbits { keepbits: 2 }).unwrap()[(15, 15)]
+        );
+    }
+
+    #[test]
+    fn invalid_error_bound_region() {
+        let data = Array1::<f32>::zeros(4);
+
+        for (start, stop) in [
+            (vec![0], vec![5]),
+            (vec![3], vec![2]),
+            (vec![0, 0], vec![1, 1]),
+        ] {
+            assert!(matches!(
+                bit_round_with_regions(
+                    &data,
+                    &BitRoundMode::Keepbits { keepbits: 2 },
+                    &[ErrorBoundRegion {
+                        start,
+                        stop,
+                        eb_abs: regions::NonNegative::new(0.0).unwrap(),
+                    }],
+                ),
+                Err(BitRoundCodecError::InvalidRegion { .. })
+            ));
+        }
+    }
 }
@@ -1,6 +1,6 @@
 #![expect(missing_docs)]
 
-use ::{ndarray as _, schemars as _, serde as _, thiserror as _};
+use ::{log as _, ndarray as _, numcodecs_utils as _, schemars as _, serde as _, thiserror as _};
 
 use numcodecs::{DynCodecType, StaticCodecType};
 use numcodecs_bit_round::BitRoundCodec;
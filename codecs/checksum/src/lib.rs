@@ -0,0 +1,495 @@
+//! [![CI Status]][workflow] [![MSRV]][repo] [![Latest Version]][crates.io] [![Rust Doc Crate]][docs.rs] [![Rust Doc Main]][docs]
+//!
+//! [CI Status]: https://img.shields.io/github/actions/workflow/status/juntyr/numcodecs-rs/ci.yml?branch=main
+//! [workflow]: https://github.com/juntyr/numcodecs-rs/actions/workflows/ci.yml?query=branch%3Amain
+//!
+//! [MSRV]: https://img.shields.io/badge/MSRV-1.87.0-blue
+//! [repo]: https://github.com/juntyr/numcodecs-rs
+//!
+//! [Latest Version]: https://img.shields.io/crates/v/numcodecs-checksum
+//! [crates.io]: https://crates.io/crates/numcodecs-checksum
+//!
+//! [Rust Doc Crate]: https://img.shields.io/docsrs/numcodecs-checksum
+//! [docs.rs]: https://docs.rs/numcodecs-checksum/
+//!
+//! [Rust Doc Main]: https://img.shields.io/badge/docs-main-blue
+//! [docs]: https://juntyr.github.io/numcodecs-rs/numcodecs_checksum
+//!
+//! Checksum meta-codec implementation for the [`numcodecs`] API.
+
+use ndarray::{Array1, ArrayView1};
+use numcodecs::{
+    AnyArray, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray, Codec, DynCodec,
+    ErasedDynCodec, ErasedError, StaticCodec, StaticCodecConfig, StaticCodecVersion,
+};
+use numcodecs_registry::GlobalRegistry;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+type ChecksumCodecVersion = StaticCodecVersion<1, 0, 0>;
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+/// Checksum meta-codec which wraps an existing codec and appends a checksum of
+/// its encoding, which is verified before decoding.
+///
+/// The wrapped codec must encode the data into a one-dimensional byte array.
+/// The checksum is appended to the encoded bytes in little-endian byte order,
+/// such that the encoding with a CRC32C checksum is compatible with Zarr v3's
+/// `crc32c` bytes codec.
+pub struct ChecksumCodec {
+    /// The configuration of the wrapped codec.
+    #[serde(serialize_with = "DynCodec::get_config")]
+    #[serde(deserialize_with = "GlobalRegistry::codec_from_config")]
+    #[schemars(schema_with = "ErasedDynCodec::codec_config_schema")]
+    pub codec: ErasedDynCodec,
+    /// The checksum algorithm
+    pub checksum: ChecksumAlgorithm,
+    /// The codec's encoding format version. Do not provide this parameter explicitly.
+    #[serde(default, rename = "_version")]
+    pub version: ChecksumCodecVersion,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+/// Checksum algorithm
+pub enum ChecksumAlgorithm {
+    /// 32-bit CRC32C (Castagnoli) checksum
+    #[serde(rename = "crc32c")]
+    Crc32c,
+    /// 32-bit Adler-32 checksum
+    #[serde(rename = "adler32")]
+    Adler32,
+    /// 64-bit XXH3 hash
+    #[serde(rename = "xxh3")]
+    Xxh3,
+}
+
+impl ChecksumAlgorithm {
+    /// Returns the size of the checksum, in bytes
+    #[must_use]
+    pub const fn size(self) -> usize {
+        match self {
+            Self::Crc32c | Self::Adler32 => 4,
+            Self::Xxh3 => 8,
+        }
+    }
+
+    /// Computes the checksum of the `bytes`
+    #[must_use]
+    pub fn checksum(self, bytes: &[u8]) -> u64 {
+        match self {
+            Self::Crc32c => u64::from(crc32c(bytes)),
+            Self::Adler32 => u64::from(adler32(bytes)),
+            Self::Xxh3 => xxhash_rust::xxh3::xxh3_64(bytes),
+        }
+    }
+}
+
+impl Codec for ChecksumCodec {
+    type Error = ChecksumCodecError;
+
+    fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        let encoded = self
+            .codec
+            .encode(data)
+            .map_err(|err| ChecksumCodecError::InnerEncodeFailed { source: err })?;
+
+        let AnyArray::U8(encoded) = encoded else {
+            return Err(ChecksumCodecError::InnerEncodingNotBytes {
+                dtype: encoded.dtype(),
+            });
+        };
+
+        if !matches!(encoded.shape(), [_]) {
+            return Err(ChecksumCodecError::InnerEncodingNotOneDimensional {
+                shape: encoded.shape().to_vec(),
+            });
+        }
+
+        let mut encoded_bytes = AnyArray::U8(encoded).as_bytes().into_owned();
+
+        let checksum = self.checksum.checksum(&encoded_bytes);
+        encoded_bytes.extend(checksum.to_le_bytes().iter().take(self.checksum.size()));
+
+        Ok(AnyArray::U8(Array1::from(encoded_bytes).into_dyn()))
+    }
+
+    fn decode(&self, encoded: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        let AnyCowArray::U8(encoded) = encoded else {
+            return Err(ChecksumCodecError::EncodedDataNotBytes {
+                dtype: encoded.dtype(),
+            });
+        };
+
+        if !matches!(encoded.shape(), [_]) {
+            return Err(ChecksumCodecError::EncodedDataNotOneDimensional {
+                shape: encoded.shape().to_vec(),
+            });
+        }
+
+        let encoded = AnyCowArray::U8(encoded);
+        let encoded = encoded.as_bytes();
+
+        let inner_encoded = verify_checksum(self.checksum, &encoded)?;
+
+        self.codec
+            .decode(AnyArrayView::U8(ArrayView1::from(inner_encoded).into_dyn()).cow())
+            .map_err(|err| ChecksumCodecError::InnerDecodeFailed { source: err })
+    }
+
+    fn decode_into(
+        &self,
+        encoded: AnyArrayView,
+        decoded: AnyArrayViewMut,
+    ) -> Result<(), Self::Error> {
+        let AnyArrayView::U8(encoded) = encoded else {
+            return Err(ChecksumCodecError::EncodedDataNotBytes {
+                dtype: encoded.dtype(),
+            });
+        };
+
+        if !matches!(encoded.shape(), [_]) {
+            return Err(ChecksumCodecError::EncodedDataNotOneDimensional {
+                shape: encoded.shape().to_vec(),
+            });
+        }
+
+        let encoded = AnyArrayView::U8(encoded);
+        let encoded = encoded.as_bytes();
+
+        let inner_encoded = verify_checksum(self.checksum, &encoded)?;
+
+        self.codec
+            .decode_into(
+                AnyArrayView::U8(ArrayView1::from(inner_encoded).into_dyn()),
+                decoded,
+            )
+            .map_err(|err| ChecksumCodecError::InnerDecodeFailed { source: err })
+    }
+}
+
+impl StaticCodec for ChecksumCodec {
+    const CODEC_ID: &'static str = "checksum.rs";
+
+    type Config<'de> = Self;
+
+    fn from_config(config: Self::Config<'_>) -> Self {
+        config
+    }
+
+    fn get_config(&self) -> StaticCodecConfig<'_, Self> {
+        StaticCodecConfig::from(self)
+    }
+}
+
+#[derive(Debug, Error)]
+/// Errors that may occur when applying the [`ChecksumCodec`].
+pub enum ChecksumCodecError {
+    /// [`ChecksumCodec`] failed to encode the data with the wrapped codec
+    #[error("Checksum failed to encode the data with the wrapped codec")]
+    InnerEncodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`ChecksumCodec`] failed to decode the data with the wrapped codec
+    #[error("Checksum failed to decode the data with the wrapped codec")]
+    InnerDecodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`ChecksumCodec`] requires the wrapped codec to encode into a
+    /// one-dimensional byte array but received an array of a different dtype
+    #[error(
+        "Checksum requires the wrapped codec to encode into a one-dimensional byte array but received an array of dtype {dtype}"
+    )]
+    InnerEncodingNotBytes {
+        /// The unexpected dtype of the wrapped codec's encoding
+        dtype: AnyArrayDType,
+    },
+    /// [`ChecksumCodec`] requires the wrapped codec to encode into a
+    /// one-dimensional byte array but received an array of a different shape
+    #[error(
+        "Checksum requires the wrapped codec to encode into a one-dimensional byte array but received a byte array of shape {shape:?}"
+    )]
+    InnerEncodingNotOneDimensional {
+        /// The unexpected shape of the wrapped codec's encoding
+        shape: Vec<usize>,
+    },
+    /// [`ChecksumCodec`] can only decode one-dimensional byte arrays but
+    /// received an array of a different dtype
+    #[error(
+        "Checksum can only decode one-dimensional byte arrays but received an array of dtype {dtype}"
+    )]
+    EncodedDataNotBytes {
+        /// The unexpected dtype of the encoded array
+        dtype: AnyArrayDType,
+    },
+    /// [`ChecksumCodec`] can only decode one-dimensional byte arrays but
+    /// received an array of a different shape
+    #[error(
+        "Checksum can only decode one-dimensional byte arrays but received a byte array of shape {shape:?}"
+    )]
+    EncodedDataNotOneDimensional {
+        /// The unexpected shape of the encoded array
+        shape: Vec<usize>,
+    },
+    /// [`ChecksumCodec`] decoded data that is too short to contain a checksum
+    #[error("Checksum decoded {len} bytes, which is too short to contain a {algorithm:?} checksum")]
+    DecodeMissingChecksum {
+        /// The checksum algorithm
+        algorithm: ChecksumAlgorithm,
+        /// The length of the encoded data, in bytes
+        len: usize,
+    },
+    /// [`ChecksumCodec`] decoded data whose checksum does not match the stored
+    /// checksum, i.e. the encoded data is corrupted
+    #[error(
+        "Checksum decoded data whose {algorithm:?} checksum {computed:#x} does not match the stored checksum {stored:#x}"
+    )]
+    ChecksumMismatch {
+        /// The checksum algorithm
+        algorithm: ChecksumAlgorithm,
+        /// The checksum that was stored in the encoded data
+        stored: u64,
+        /// The checksum that was computed for the encoded data
+        computed: u64,
+    },
+}
+
+/// Verifies the checksum at the end of the `encoded` bytes and returns the
+/// encoding of the wrapped codec that precedes it
+fn verify_checksum(
+    algorithm: ChecksumAlgorithm,
+    encoded: &[u8],
+) -> Result<&[u8], ChecksumCodecError> {
+    let (inner_encoded, stored) = encoded
+        .len()
+        .checked_sub(algorithm.size())
+        .and_then(|len| encoded.split_at_checked(len))
+        .ok_or(ChecksumCodecError::DecodeMissingChecksum {
+            algorithm,
+            len: encoded.len(),
+        })?;
+
+    let stored = stored
+        .iter()
+        .rev()
+        .fold(0, |checksum, byte| (checksum << 8) | u64::from(*byte));
+    let computed = algorithm.checksum(inner_encoded);
+
+    if stored != computed {
+        return Err(ChecksumCodecError::ChecksumMismatch {
+            algorithm,
+            stored,
+            computed,
+        });
+    }
+
+    Ok(inner_encoded)
+}
+
+/// Lookup table for the reflected CRC32C (Castagnoli) polynomial
+const CRC32C_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+
+    let mut i = 0;
+    while i < table.len() {
+        #[expect(clippy::cast_possible_truncation)] // i < 256
+        let mut crc = i as u32;
+
+        let mut k = 0;
+        while k < 8 {
+            crc = if (crc & 1) == 0 {
+                crc >> 1
+            } else {
+                (crc >> 1) ^ 0x82F6_3B78
+            };
+            k += 1;
+        }
+
+        #[expect(clippy::indexing_slicing)] // i < 256
+        {
+            table[i] = crc;
+        }
+        i += 1;
+    }
+
+    table
+};
+
+/// Computes the CRC32C (Castagnoli) checksum of the `bytes`
+fn crc32c(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, byte| {
+        let [low, ..] = crc.to_le_bytes();
+        #[expect(clippy::indexing_slicing)] // the table has 256 entries
+        let entry = CRC32C_TABLE[usize::from(low ^ byte)];
+        entry ^ (crc >> 8)
+    })
+}
+
+/// Computes the Adler-32 checksum of the `bytes`
+fn adler32(bytes: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    // largest number of bytes that can be summed before the sums overflow
+    const CHUNK: usize = 5552;
+
+    let (mut a, mut b) = (1_u32, 0_u32);
+
+    for chunk in bytes.chunks(CHUNK) {
+        for byte in chunk {
+            a += u32::from(*byte);
+            b += a;
+        }
+        a %= MOD;
+        b %= MOD;
+    }
+
+    (b << 16) | a
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use ndarray::Array;
+    use numcodecs_identity::IdentityCodec;
+    use numcodecs_zlib::ZlibCodec;
+    use serde_json::json;
+
+    use super::*;
+
+    numcodecs_registry::export_global! {
+        static REGISTRY: numcodecs_registry::EmptyRegistry = numcodecs_registry::EmptyRegistry;
+    }
+
+    fn zlib() -> ZlibCodec {
+        serde_json::from_value(json!({ "level": 6 })).unwrap()
+    }
+
+    fn checksum(checksum: ChecksumAlgorithm) -> ChecksumCodec {
+        ChecksumCodec {
+            codec: ErasedDynCodec::new(zlib()),
+            checksum,
+            version: StaticCodecVersion,
+        }
+    }
+
+    #[test]
+    fn check_values() {
+        assert_eq!(ChecksumAlgorithm::Crc32c.checksum(b""), 0);
+        assert_eq!(
+            ChecksumAlgorithm::Crc32c.checksum(b"123456789"),
+            0xE306_9283
+        );
+        assert_eq!(ChecksumAlgorithm::Adler32.checksum(b""), 1);
+        assert_eq!(
+            ChecksumAlgorithm::Adler32.checksum(b"Wikipedia"),
+            0x11E6_0398
+        );
+        assert_eq!(ChecksumAlgorithm::Xxh3.checksum(b""), 0x2D06_8005_38D3_94C2);
+
+        let bytes = vec![0xFF_u8; 100_000];
+        let (mut a, mut b) = (1_u64, 0_u64);
+        for byte in &bytes {
+            a = (a + u64::from(*byte)) % 65521;
+            b = (b + a) % 65521;
+        }
+        assert_eq!(ChecksumAlgorithm::Adler32.checksum(&bytes), (b << 16) | a);
+    }
+
+    #[test]
+    fn roundtrip() {
+        #[expect(clippy::cast_precision_loss)]
+        let data = Array::from_shape_fn((8, 16), |(i, j)| (i * 16 + j) as f32 * 0.5).into_dyn();
+
+        for algorithm in [
+            ChecksumAlgorithm::Crc32c,
+            ChecksumAlgorithm::Adler32,
+            ChecksumAlgorithm::Xxh3,
+        ] {
+            let codec = checksum(algorithm);
+
+            let encoded = codec.encode(AnyCowArray::F32(data.view().into())).unwrap();
+            let decoded = codec.decode(encoded.cow()).unwrap();
+            assert_eq!(decoded, AnyArray::F32(data.clone()));
+
+            let mut decoded = Array::zeros(data.shape());
+            codec
+                .decode_into(encoded.view(), AnyArrayViewMut::F32(decoded.view_mut()))
+                .unwrap();
+            assert_eq!(decoded, data);
+        }
+    }
+
+    #[test]
+    fn zarr_crc32c_layout() {
+        let data = Array::from_vec(vec![1_u8, 2, 3, 4, 5]).into_dyn();
+
+        let codec = checksum(ChecksumAlgorithm::Crc32c);
+        let encoded = codec.encode(AnyCowArray::U8(data.view().into())).unwrap();
+
+        let mut expected = zlib()
+            .encode(AnyCowArray::U8(data.view().into()))
+            .unwrap()
+            .as_bytes()
+            .into_owned();
+        let crc = crc32c(&expected);
+        expected.extend_from_slice(&crc.to_le_bytes());
+
+        assert_eq!(encoded, AnyArray::U8(Array::from_vec(expected).into_dyn()));
+    }
+
+    #[test]
+    fn corrupted() {
+        let data = Array::from_vec(vec![1.0_f64, 2.0, 3.0]).into_dyn();
+
+        for algorithm in [
+            ChecksumAlgorithm::Crc32c,
+            ChecksumAlgorithm::Adler32,
+            ChecksumAlgorithm::Xxh3,
+        ] {
+            let codec = checksum(algorithm);
+            let AnyArray::U8(mut encoded) =
+                codec.encode(AnyCowArray::F64(data.view().into())).unwrap()
+            else {
+                panic!("encoded data has the wrong dtype");
+            };
+
+            if let Some(byte) = encoded.get_mut([2]) {
+                *byte ^= 0x10;
+            }
+
+            assert!(matches!(
+                codec.decode(AnyCowArray::U8(encoded.view().into())),
+                Err(ChecksumCodecError::ChecksumMismatch { algorithm: a, .. }) if a == algorithm
+            ));
+
+            assert!(matches!(
+                codec.decode(AnyCowArray::U8(Array::zeros(3).into_dyn().into())),
+                Err(ChecksumCodecError::DecodeMissingChecksum { len: 3, .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn inner_encoding_not_bytes() {
+        let codec = ChecksumCodec {
+            codec: ErasedDynCodec::new(IdentityCodec {
+                version: StaticCodecVersion,
+            }),
+            checksum: ChecksumAlgorithm::Crc32c,
+            version: StaticCodecVersion,
+        };
+
+        assert!(matches!(
+            codec.encode(AnyCowArray::I16(Array::zeros(4).into_dyn().into())),
+            Err(ChecksumCodecError::InnerEncodingNotBytes {
+                dtype: AnyArrayDType::I16
+            })
+        ));
+        assert!(matches!(
+            codec.encode(AnyCowArray::U8(Array::zeros((2, 2)).into_dyn().into())),
+            Err(ChecksumCodecError::InnerEncodingNotOneDimensional { .. })
+        ));
+    }
+}
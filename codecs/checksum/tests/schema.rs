@@ -0,0 +1,27 @@
+#![expect(missing_docs)]
+
+use ::{
+    ndarray as _, numcodecs_identity as _, numcodecs_zlib as _, schemars as _, serde as _,
+    serde_json as _, thiserror as _, xxhash_rust as _,
+};
+
+use numcodecs::{DynCodecType, StaticCodecType};
+use numcodecs_checksum::ChecksumCodec;
+
+#[test]
+fn schema() {
+    let schema = format!(
+        "{:#}",
+        StaticCodecType::<ChecksumCodec>::of()
+            .codec_config_schema()
+            .to_value()
+    );
+
+    if schema != include_str!("schema.json") {
+        panic!("Checksum schema has changed\n===\n{schema}\n===");
+    }
+}
+
+numcodecs_registry::export_global! {
+    static REGISTRY: numcodecs_registry::EmptyRegistry = numcodecs_registry::EmptyRegistry;
+}
@@ -0,0 +1,441 @@
+//! [![CI Status]][workflow] [![MSRV]][repo] [![Latest Version]][crates.io] [![Rust Doc Crate]][docs.rs] [![Rust Doc Main]][docs]
+//!
+//! [CI Status]: https://img.shields.io/github/actions/workflow/status/juntyr/numcodecs-rs/ci.yml?branch=main
+//! [workflow]: https://github.com/juntyr/numcodecs-rs/actions/workflows/ci.yml?query=branch%3Amain
+//!
+//! [MSRV]: https://img.shields.io/badge/MSRV-1.87.0-blue
+//! [repo]: https://github.com/juntyr/numcodecs-rs
+//!
+//! [Latest Version]: https://img.shields.io/crates/v/numcodecs-delta
+//! [crates.io]: https://crates.io/crates/numcodecs-delta
+//!
+//! [Rust Doc Crate]: https://img.shields.io/docsrs/numcodecs-delta
+//! [docs.rs]: https://docs.rs/numcodecs-delta/
+//!
+//! [Rust Doc Main]: https://img.shields.io/badge/docs-main-blue
+//! [docs]: https://juntyr.github.io/numcodecs-rs/numcodecs_delta
+//!
+//! Delta codec implementation for the [`numcodecs`] API.
+
+use std::num::NonZeroUsize;
+
+use ndarray::{Array, ArrayBase, ArrayViewMut, ArrayViewMut1, Axis, Data, DataMut, Dimension};
+use numcodecs::{
+    AnyArray, AnyArrayAssignError, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray,
+    ArrayDType, ArrayDataMutExt, Codec, StaticCodec, StaticCodecConfig, StaticCodecVersion,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+/// Delta codec which replaces every element with its difference to the
+/// previous element along an axis on encoding, and reverts the differences
+/// with a cumulative sum on decoding.
+///
+/// Integer elements are differenced using wrapping arithmetic. Floating point
+/// elements are differenced by computing the XOR of their bit patterns,
+/// similar to the Gorilla time series compression, such that the encoding is
+/// lossless for all dtypes.
+///
+/// The encoded array has the same dtype and shape as the input array.
+pub struct DeltaCodec {
+    /// The axis along which the differences are computed.
+    ///
+    /// If no axis is provided, the differences are computed over the
+    /// flattened array in standard (row-major) order.
+    #[serde(default)]
+    pub axis: Option<usize>,
+    /// The order of the differences, i.e. how many times the difference is
+    /// applied.
+    ///
+    /// An order of 1 computes the first differences, an order of 2 the
+    /// differences of the first differences, and so on.
+    #[serde(default = "default_order")]
+    pub order: NonZeroUsize,
+    /// The codec's encoding format version. Do not provide this parameter explicitly.
+    #[serde(default, rename = "_version")]
+    pub version: StaticCodecVersion<1, 0, 0>,
+}
+
+const fn default_order() -> NonZeroUsize {
+    NonZeroUsize::MIN
+}
+
+impl Codec for DeltaCodec {
+    type Error = DeltaCodecError;
+
+    fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        match data {
+            AnyCowArray::U8(data) => Ok(AnyArray::U8(delta(data, self.axis, self.order)?)),
+            AnyCowArray::U16(data) => Ok(AnyArray::U16(delta(data, self.axis, self.order)?)),
+            AnyCowArray::U32(data) => Ok(AnyArray::U32(delta(data, self.axis, self.order)?)),
+            AnyCowArray::U64(data) => Ok(AnyArray::U64(delta(data, self.axis, self.order)?)),
+            AnyCowArray::I8(data) => Ok(AnyArray::I8(delta(data, self.axis, self.order)?)),
+            AnyCowArray::I16(data) => Ok(AnyArray::I16(delta(data, self.axis, self.order)?)),
+            AnyCowArray::I32(data) => Ok(AnyArray::I32(delta(data, self.axis, self.order)?)),
+            AnyCowArray::I64(data) => Ok(AnyArray::I64(delta(data, self.axis, self.order)?)),
+            AnyCowArray::F32(data) => Ok(AnyArray::F32(delta(data, self.axis, self.order)?)),
+            AnyCowArray::F64(data) => Ok(AnyArray::F64(delta(data, self.axis, self.order)?)),
+            data => Err(DeltaCodecError::UnsupportedDtype(data.dtype())),
+        }
+    }
+
+    fn decode(&self, encoded: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        match encoded {
+            AnyCowArray::U8(encoded) => {
+                Ok(AnyArray::U8(undo_delta(encoded, self.axis, self.order)?))
+            }
+            AnyCowArray::U16(encoded) => {
+                Ok(AnyArray::U16(undo_delta(encoded, self.axis, self.order)?))
+            }
+            AnyCowArray::U32(encoded) => {
+                Ok(AnyArray::U32(undo_delta(encoded, self.axis, self.order)?))
+            }
+            AnyCowArray::U64(encoded) => {
+                Ok(AnyArray::U64(undo_delta(encoded, self.axis, self.order)?))
+            }
+            AnyCowArray::I8(encoded) => {
+                Ok(AnyArray::I8(undo_delta(encoded, self.axis, self.order)?))
+            }
+            AnyCowArray::I16(encoded) => {
+                Ok(AnyArray::I16(undo_delta(encoded, self.axis, self.order)?))
+            }
+            AnyCowArray::I32(encoded) => {
+                Ok(AnyArray::I32(undo_delta(encoded, self.axis, self.order)?))
+            }
+            AnyCowArray::I64(encoded) => {
+                Ok(AnyArray::I64(undo_delta(encoded, self.axis, self.order)?))
+            }
+            AnyCowArray::F32(encoded) => {
+                Ok(AnyArray::F32(undo_delta(encoded, self.axis, self.order)?))
+            }
+            AnyCowArray::F64(encoded) => {
+                Ok(AnyArray::F64(undo_delta(encoded, self.axis, self.order)?))
+            }
+            encoded => Err(DeltaCodecError::UnsupportedDtype(encoded.dtype())),
+        }
+    }
+
+    fn decode_into(
+        &self,
+        encoded: AnyArrayView,
+        mut decoded: AnyArrayViewMut,
+    ) -> Result<(), Self::Error> {
+        decoded.assign(&encoded)?;
+
+        match decoded {
+            AnyArrayViewMut::U8(decoded) => undo_delta_in_place(decoded, self.axis, self.order),
+            AnyArrayViewMut::U16(decoded) => undo_delta_in_place(decoded, self.axis, self.order),
+            AnyArrayViewMut::U32(decoded) => undo_delta_in_place(decoded, self.axis, self.order),
+            AnyArrayViewMut::U64(decoded) => undo_delta_in_place(decoded, self.axis, self.order),
+            AnyArrayViewMut::I8(decoded) => undo_delta_in_place(decoded, self.axis, self.order),
+            AnyArrayViewMut::I16(decoded) => undo_delta_in_place(decoded, self.axis, self.order),
+            AnyArrayViewMut::I32(decoded) => undo_delta_in_place(decoded, self.axis, self.order),
+            AnyArrayViewMut::I64(decoded) => undo_delta_in_place(decoded, self.axis, self.order),
+            AnyArrayViewMut::F32(decoded) => undo_delta_in_place(decoded, self.axis, self.order),
+            AnyArrayViewMut::F64(decoded) => undo_delta_in_place(decoded, self.axis, self.order),
+            decoded => Err(DeltaCodecError::UnsupportedDtype(decoded.dtype())),
+        }
+    }
+}
+
+impl StaticCodec for DeltaCodec {
+    const CODEC_ID: &'static str = "delta.rs";
+
+    type Config<'de> = Self;
+
+    fn from_config(config: Self::Config<'_>) -> Self {
+        config
+    }
+
+    fn get_config(&self) -> StaticCodecConfig<'_, Self> {
+        StaticCodecConfig::from(self)
+    }
+}
+
+#[derive(Debug, Error)]
+/// Errors that may occur when applying the [`DeltaCodec`].
+pub enum DeltaCodecError {
+    /// [`DeltaCodec`] does not support the dtype
+    #[error("Delta does not support the dtype {0}")]
+    UnsupportedDtype(AnyArrayDType),
+    /// [`DeltaCodec`] cannot encode or decode along an invalid `axis` for an
+    /// array with `ndim` dimensions
+    #[error(
+        "Delta cannot encode or decode along an invalid axis {axis} for an array with {ndim} dimensions"
+    )]
+    InvalidAxis {
+        /// The out-of-bounds axis index
+        axis: usize,
+        /// The number of dimensions of the array
+        ndim: usize,
+    },
+    /// [`DeltaCodec`] cannot decode into the provided array
+    #[error("Delta cannot decode into the provided array")]
+    MismatchedDecodeIntoArray {
+        /// The source of the error
+        #[from]
+        source: AnyArrayAssignError,
+    },
+}
+
+/// Compute the `order`-th differences of the `data` array along the `axis`,
+/// or along the flattened array if no `axis` is given.
+///
+/// The first `order` elements along every lane are kept as partial
+/// differences such that the differences can be reverted with
+/// [`undo_delta`].
+///
+/// # Errors
+///
+/// Errors with [`DeltaCodecError::InvalidAxis`] if the `axis` is out of bounds.
+pub fn delta<T: DeltaElement, S: Data<Elem = T>, D: Dimension>(
+    data: ArrayBase<S, D>,
+    axis: Option<usize>,
+    order: NonZeroUsize,
+) -> Result<Array<T, D>, DeltaCodecError> {
+    let mut encoded = data.into_owned();
+
+    apply_along_lanes(&mut encoded, axis, |mut lane| {
+        for _ in 0..order.get() {
+            let mut iter = lane.iter_mut();
+            let Some(mut prev) = iter.next().map(|x| *x) else {
+                return;
+            };
+
+            for x in iter {
+                let current = *x;
+                *x = current.delta(prev);
+                prev = current;
+            }
+        }
+    })?;
+
+    Ok(encoded)
+}
+
+/// Revert the `order`-th differences of the `encoded` array along the `axis`,
+/// or along the flattened array if no `axis` is given, that were computed
+/// using [`delta`].
+///
+/// # Errors
+///
+/// Errors with [`DeltaCodecError::InvalidAxis`] if the `axis` is out of bounds.
+pub fn undo_delta<T: DeltaElement, S: Data<Elem = T>, D: Dimension>(
+    encoded: ArrayBase<S, D>,
+    axis: Option<usize>,
+    order: NonZeroUsize,
+) -> Result<Array<T, D>, DeltaCodecError> {
+    let mut decoded = encoded.into_owned();
+    undo_delta_in_place(decoded.view_mut(), axis, order)?;
+    Ok(decoded)
+}
+
+/// Revert the `order`-th differences of the `data` array in place along the
+/// `axis`, or along the flattened array if no `axis` is given, that were
+/// computed using [`delta`].
+///
+/// # Errors
+///
+/// Errors with [`DeltaCodecError::InvalidAxis`] if the `axis` is out of bounds.
+pub fn undo_delta_in_place<T: DeltaElement, D: Dimension>(
+    mut data: ArrayViewMut<T, D>,
+    axis: Option<usize>,
+    order: NonZeroUsize,
+) -> Result<(), DeltaCodecError> {
+    apply_along_lanes(&mut data, axis, |mut lane| {
+        for _ in 0..order.get() {
+            let mut iter = lane.iter_mut();
+            let Some(mut prev) = iter.next().map(|x| *x) else {
+                return;
+            };
+
+            for x in iter {
+                *x = x.undo_delta(prev);
+                prev = *x;
+            }
+        }
+    })
+}
+
+fn apply_along_lanes<T: DeltaElement, S: DataMut<Elem = T>, D: Dimension>(
+    data: &mut ArrayBase<S, D>,
+    axis: Option<usize>,
+    mut apply: impl FnMut(ArrayViewMut1<T>),
+) -> Result<(), DeltaCodecError> {
+    let Some(axis) = axis else {
+        // iterate over the flattened array in standard order
+        let () = data.with_slice_mut(|data| apply(ArrayViewMut1::from(data)));
+        return Ok(());
+    };
+
+    if axis >= data.ndim() {
+        return Err(DeltaCodecError::InvalidAxis {
+            axis,
+            ndim: data.ndim(),
+        });
+    }
+
+    for lane in data.lanes_mut(Axis(axis)) {
+        apply(lane);
+    }
+
+    Ok(())
+}
+
+/// Array element types that can be differenced losslessly.
+pub trait DeltaElement: ArrayDType + Copy {
+    /// Compute the difference between `self` and the `prev`ious element.
+    #[must_use]
+    fn delta(self, prev: Self) -> Self;
+
+    /// Revert the difference `self` to the `prev`ious element.
+    #[must_use]
+    fn undo_delta(self, prev: Self) -> Self;
+}
+
+macro_rules! impl_delta_element_for_int {
+    ($($ty:ty),*) => {
+        $(
+            impl DeltaElement for $ty {
+                fn delta(self, prev: Self) -> Self {
+                    self.wrapping_sub(prev)
+                }
+
+                fn undo_delta(self, prev: Self) -> Self {
+                    self.wrapping_add(prev)
+                }
+            }
+        )*
+    };
+}
+
+impl_delta_element_for_int! { u8, u16, u32, u64, i8, i16, i32, i64 }
+
+impl DeltaElement for f32 {
+    fn delta(self, prev: Self) -> Self {
+        Self::from_bits(self.to_bits() ^ prev.to_bits())
+    }
+
+    fn undo_delta(self, prev: Self) -> Self {
+        Self::from_bits(self.to_bits() ^ prev.to_bits())
+    }
+}
+
+impl DeltaElement for f64 {
+    fn delta(self, prev: Self) -> Self {
+        Self::from_bits(self.to_bits() ^ prev.to_bits())
+    }
+
+    fn undo_delta(self, prev: Self) -> Self {
+        Self::from_bits(self.to_bits() ^ prev.to_bits())
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use ndarray::{Array1, ArrayD};
+
+    use super::*;
+
+    #[test]
+    fn first_and_second_order() {
+        let data = Array1::from_vec(vec![1_i32, 4, 9, 16, 25]);
+
+        let encoded = delta(data.view(), None, NonZeroUsize::MIN).unwrap();
+        assert_eq!(encoded, Array1::from_vec(vec![1, 3, 5, 7, 9]));
+
+        let encoded = delta(data.view(), None, NonZeroUsize::new(2).unwrap()).unwrap();
+        assert_eq!(encoded, Array1::from_vec(vec![1, 2, 2, 2, 2]));
+
+        let decoded = undo_delta(encoded, None, NonZeroUsize::new(2).unwrap()).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn wrapping_integers() {
+        let data = Array1::from_vec(vec![u8::MAX, 0, u8::MAX, 1]);
+
+        let encoded = delta(data.view(), None, NonZeroUsize::MIN).unwrap();
+        assert_eq!(encoded, Array1::from_vec(vec![u8::MAX, 1, u8::MAX, 2]));
+
+        let decoded = undo_delta(encoded, None, NonZeroUsize::MIN).unwrap();
+        assert_eq!(decoded, data);
+    }
+
+    #[test]
+    fn xor_floats() {
+        let data = Array1::from_vec(vec![1.0_f64, 1.0, -0.0, f64::NAN, f64::INFINITY, 1e-300]);
+
+        for order in 1..=4 {
+            let order = NonZeroUsize::new(order).unwrap();
+
+            let encoded = delta(data.view(), None, order).unwrap();
+            let decoded = undo_delta(encoded, None, order).unwrap();
+
+            for (d, x) in decoded.iter().zip(data.iter()) {
+                assert_eq!(d.to_bits(), x.to_bits());
+            }
+        }
+
+        let encoded = delta(data.view(), None, NonZeroUsize::MIN).unwrap();
+        assert_eq!(encoded.get(1).unwrap().to_bits(), 0);
+    }
+
+    #[test]
+    fn along_axis() {
+        let data = ArrayD::from_shape_fn(vec![4, 3, 5], |idx| {
+            #[expect(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+            let x = (idx[0] * 100 + idx[1] * 10 + idx[2]) as i16;
+            x
+        });
+
+        for (axis, stride) in [100, 10, 1].into_iter().enumerate() {
+            let encoded = delta(data.view(), Some(axis), NonZeroUsize::MIN).unwrap();
+
+            for (i, lane) in encoded.lanes(Axis(axis)).into_iter().enumerate() {
+                assert!(
+                    lane.iter().skip(1).all(|x| *x == stride),
+                    "lane {i} along axis {axis}: {lane}"
+                );
+            }
+
+            let decoded = undo_delta(encoded.view(), Some(axis), NonZeroUsize::MIN).unwrap();
+            assert_eq!(decoded, data);
+
+            let mut decoded = AnyArray::zeros(AnyArrayDType::I16, data.shape());
+            DeltaCodec {
+                axis: Some(axis),
+                order: NonZeroUsize::MIN,
+                version: StaticCodecVersion,
+            }
+            .decode_into(AnyArrayView::I16(encoded.view()), decoded.view_mut())
+            .unwrap();
+            assert_eq!(decoded, AnyArray::I16(data.clone()));
+        }
+
+        assert!(matches!(
+            delta(data.view(), Some(3), NonZeroUsize::MIN),
+            Err(DeltaCodecError::InvalidAxis { axis: 3, ndim: 3 })
+        ));
+    }
+
+    #[test]
+    fn non_standard_order() {
+        let data = ArrayD::from_shape_fn(vec![6, 7], |idx| {
+            #[expect(clippy::cast_possible_truncation)]
+            let x = (idx[0] * idx[1]) as u32;
+            x
+        });
+        let data = data.reversed_axes();
+
+        for axis in [None, Some(0), Some(1)] {
+            let encoded = delta(data.view(), axis, NonZeroUsize::new(3).unwrap()).unwrap();
+            let decoded = undo_delta(encoded, axis, NonZeroUsize::new(3).unwrap()).unwrap();
+            assert_eq!(decoded, data);
+        }
+    }
+}
@@ -0,0 +1,393 @@
+//! [![CI Status]][workflow] [![MSRV]][repo] [![Latest Version]][crates.io] [![Rust Doc Crate]][docs.rs] [![Rust Doc Main]][docs]
+//!
+//! [CI Status]: https://img.shields.io/github/actions/workflow/status/juntyr/numcodecs-rs/ci.yml?branch=main
+//! [workflow]: https://github.com/juntyr/numcodecs-rs/actions/workflows/ci.yml?query=branch%3Amain
+//!
+//! [MSRV]: https://img.shields.io/badge/MSRV-1.87.0-blue
+//! [repo]: https://github.com/juntyr/numcodecs-rs
+//!
+//! [Latest Version]: https://img.shields.io/crates/v/numcodecs-dispatch
+//! [crates.io]: https://crates.io/crates/numcodecs-dispatch
+//!
+//! [Rust Doc Crate]: https://img.shields.io/docsrs/numcodecs-dispatch
+//! [docs.rs]: https://docs.rs/numcodecs-dispatch/
+//!
+//! [Rust Doc Main]: https://img.shields.io/badge/docs-main-blue
+//! [docs]: https://juntyr.github.io/numcodecs-rs/numcodecs_dispatch
+//!
+//! Dtype-dispatching meta-codec implementation for the [`numcodecs`] API.
+
+use numcodecs::{
+    AnyArray, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray, Codec, DynCodec,
+    ErasedDynCodec, ErasedError, StaticCodec, StaticCodecConfig, StaticCodecVersion,
+};
+use numcodecs_registry::GlobalRegistry;
+use numcodecs_utils::framing::{
+    FramingError, decode_framed, encode_framed, framed_array, framed_bytes,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+type DispatchCodecVersion = StaticCodecVersion<1, 0, 0>;
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+/// Dispatch meta-codec which selects the codec that is used to encode the
+/// data based on the data's dtype.
+///
+/// The rules are checked in order and the data is encoded with the codec of
+/// the first rule that matches its dtype. The index of the selected rule is
+/// recorded in the encoding such that decoding is dispatched to the same
+/// codec.
+pub struct DispatchCodec {
+    /// The ordered list of rules that dispatch dtypes to codecs
+    pub rules: Vec<DispatchRule>,
+    /// The codec's encoding format version. Do not provide this parameter explicitly.
+    #[serde(default, rename = "_version")]
+    pub version: DispatchCodecVersion,
+}
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+/// Rule that dispatches data of the matching dtypes to a codec
+pub struct DispatchRule {
+    /// The dtypes or dtype classes that are matched by this rule
+    pub dtypes: Vec<DispatchDType>,
+    /// The configuration of the codec that matching data is dispatched to
+    #[serde(serialize_with = "DynCodec::get_config")]
+    #[serde(deserialize_with = "GlobalRegistry::codec_from_config")]
+    #[schemars(schema_with = "ErasedDynCodec::codec_config_schema")]
+    pub codec: ErasedDynCodec,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+/// Dtype, or class of dtypes, that is matched by a dispatch rule
+pub enum DispatchDType {
+    /// A single dtype
+    DType(AnyArrayDType),
+    /// A class of dtypes
+    Class(DTypeClass),
+}
+
+impl DispatchDType {
+    /// Returns `true` iff the `dtype` is matched
+    #[must_use]
+    pub fn matches(self, dtype: AnyArrayDType) -> bool {
+        match self {
+            Self::DType(expected) => expected == dtype,
+            Self::Class(class) => class.contains(dtype),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+/// Class of dtypes, named after numpy's abstract scalar types
+pub enum DTypeClass {
+    /// Signed and unsigned integer dtypes
+    #[serde(rename = "integer")]
+    Integer,
+    /// Signed integer dtypes
+    #[serde(rename = "signedinteger")]
+    SignedInteger,
+    /// Unsigned integer dtypes
+    #[serde(rename = "unsignedinteger")]
+    UnsignedInteger,
+    /// Floating point dtypes
+    #[serde(rename = "floating")]
+    Floating,
+}
+
+impl DTypeClass {
+    /// Returns `true` iff the `dtype` belongs to this class
+    #[must_use]
+    pub const fn contains(self, dtype: AnyArrayDType) -> bool {
+        let (signed, unsigned, floating) = match dtype {
+            AnyArrayDType::I8 | AnyArrayDType::I16 | AnyArrayDType::I32 | AnyArrayDType::I64 => {
+                (true, false, false)
+            }
+            AnyArrayDType::U8 | AnyArrayDType::U16 | AnyArrayDType::U32 | AnyArrayDType::U64 => {
+                (false, true, false)
+            }
+            AnyArrayDType::F32 | AnyArrayDType::F64 => (false, false, true),
+            _ => (false, false, false),
+        };
+
+        match self {
+            Self::Integer => signed || unsigned,
+            Self::SignedInteger => signed,
+            Self::UnsignedInteger => unsigned,
+            Self::Floating => floating,
+        }
+    }
+}
+
+impl DispatchCodec {
+    /// Returns the index of the first rule that matches the `dtype`, together
+    /// with the codec that the rule dispatches to, if any rule matches
+    #[must_use]
+    pub fn dispatch(&self, dtype: AnyArrayDType) -> Option<(usize, &ErasedDynCodec)> {
+        self.rules
+            .iter()
+            .enumerate()
+            .find(|(_, rule)| rule.dtypes.iter().any(|d| d.matches(dtype)))
+            .map(|(index, rule)| (index, &rule.codec))
+    }
+}
+
+impl Codec for DispatchCodec {
+    type Error = DispatchCodecError;
+
+    fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        let Some((rule, codec)) = self.dispatch(data.dtype()) else {
+            return Err(DispatchCodecError::UnsupportedDtype(data.dtype()));
+        };
+
+        let encoded = codec
+            .encode(data)
+            .map_err(|err| DispatchCodecError::InnerEncodeFailed { source: err })?;
+
+        let encoded_bytes = encode_framed(
+            &CompressionHeader {
+                rule,
+                version: StaticCodecVersion,
+            },
+            &encoded,
+        )?;
+
+        Ok(framed_array(encoded_bytes))
+    }
+
+    fn decode(&self, encoded: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        let (codec, inner_encoded) = self.split_encoded(&encoded.view())?;
+
+        codec
+            .decode(inner_encoded.into_cow())
+            .map_err(|err| DispatchCodecError::InnerDecodeFailed { source: err })
+    }
+
+    fn decode_into(
+        &self,
+        encoded: AnyArrayView,
+        decoded: AnyArrayViewMut,
+    ) -> Result<(), Self::Error> {
+        let (codec, inner_encoded) = self.split_encoded(&encoded)?;
+
+        codec
+            .decode_into(inner_encoded.view(), decoded)
+            .map_err(|err| DispatchCodecError::InnerDecodeFailed { source: err })
+    }
+}
+
+impl StaticCodec for DispatchCodec {
+    const CODEC_ID: &'static str = "dispatch.rs";
+
+    type Config<'de> = Self;
+
+    fn from_config(config: Self::Config<'_>) -> Self {
+        config
+    }
+
+    fn get_config(&self) -> StaticCodecConfig<'_, Self> {
+        StaticCodecConfig::from(self)
+    }
+}
+
+#[derive(Debug, Error)]
+/// Errors that may occur when applying the [`DispatchCodec`].
+pub enum DispatchCodecError {
+    /// [`DispatchCodec`] has no rule that matches the dtype
+    #[error("Dispatch has no rule that matches the dtype {0}")]
+    UnsupportedDtype(AnyArrayDType),
+    /// [`DispatchCodec`] failed to encode the data with the dispatched codec
+    #[error("Dispatch failed to encode the data with the dispatched codec")]
+    InnerEncodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`DispatchCodec`] failed to decode the data with the dispatched codec
+    #[error("Dispatch failed to decode the data with the dispatched codec")]
+    InnerDecodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`DispatchCodec`] failed to frame or unframe the dispatched codec's
+    /// encoding
+    #[error("Dispatch failed to frame or unframe the dispatched codec's encoding")]
+    FramingFailed {
+        /// The source of the error
+        #[from]
+        source: FramingError,
+    },
+    /// [`DispatchCodec`] decoded a rule index that is out of bounds for its
+    /// rules
+    #[error("Dispatch decoded the rule index {rule} but only has {rules} rules")]
+    DecodeInvalidRule {
+        /// The decoded rule index
+        rule: usize,
+        /// The number of rules of the codec
+        rules: usize,
+    },
+}
+
+impl DispatchCodec {
+    /// Splits the `encoded` data into the codec of the recorded rule and the
+    /// encoding that was produced by that codec
+    fn split_encoded(
+        &self,
+        encoded: &AnyArrayView,
+    ) -> Result<(&ErasedDynCodec, AnyArray), DispatchCodecError> {
+        let (header, inner_encoded, _) =
+            decode_framed::<CompressionHeader>(&framed_bytes(encoded)?)?;
+
+        let Some(rule) = self.rules.get(header.rule) else {
+            return Err(DispatchCodecError::DecodeInvalidRule {
+                rule: header.rule,
+                rules: self.rules.len(),
+            });
+        };
+
+        Ok((&rule.codec, inner_encoded))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CompressionHeader {
+    rule: usize,
+    version: DispatchCodecVersion,
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use ndarray::Array;
+    use numcodecs_identity::IdentityCodec;
+    use numcodecs_round::RoundCodec;
+    use serde_json::json;
+
+    use super::*;
+
+    numcodecs_registry::export_global! {
+        static REGISTRY: numcodecs_registry::EmptyRegistry = numcodecs_registry::EmptyRegistry;
+    }
+
+    fn round() -> ErasedDynCodec {
+        let round: RoundCodec = serde_json::from_value(json!({
+            "precision": 1.0,
+        }))
+        .unwrap();
+
+        ErasedDynCodec::new(round)
+    }
+
+    fn identity() -> ErasedDynCodec {
+        ErasedDynCodec::new(IdentityCodec {
+            version: StaticCodecVersion,
+        })
+    }
+
+    fn dispatch(rules: Vec<(Vec<DispatchDType>, ErasedDynCodec)>) -> DispatchCodec {
+        DispatchCodec {
+            rules: rules
+                .into_iter()
+                .map(|(dtypes, codec)| DispatchRule { dtypes, codec })
+                .collect(),
+            version: StaticCodecVersion,
+        }
+    }
+
+    #[test]
+    fn dispatch_by_class() {
+        let codec = dispatch(vec![
+            (vec![DispatchDType::Class(DTypeClass::Floating)], round()),
+            (vec![DispatchDType::Class(DTypeClass::Integer)], identity()),
+        ]);
+
+        let floats = Array::from_vec(vec![0.25_f64, 1.75, -3.5, 42.0]).into_dyn();
+        let encoded = codec
+            .encode(AnyCowArray::F64(floats.view().into()))
+            .unwrap();
+        let decoded = codec.decode(encoded.cow()).unwrap();
+        assert_eq!(
+            decoded,
+            AnyArray::F64(Array::from_vec(vec![0.0, 2.0, -4.0, 42.0]).into_dyn())
+        );
+
+        let ints = Array::from_vec(vec![1_i32, -2, 3, i32::MAX]).into_dyn();
+        let encoded = codec.encode(AnyCowArray::I32(ints.view().into())).unwrap();
+        let mut decoded = Array::zeros(ints.shape());
+        codec
+            .decode_into(encoded.view(), AnyArrayViewMut::I32(decoded.view_mut()))
+            .unwrap();
+        assert_eq!(decoded, ints);
+    }
+
+    #[test]
+    fn first_matching_rule() {
+        let codec = dispatch(vec![
+            (vec![DispatchDType::DType(AnyArrayDType::F32)], identity()),
+            (vec![DispatchDType::Class(DTypeClass::Floating)], round()),
+        ]);
+
+        assert_eq!(codec.dispatch(AnyArrayDType::F32).map(|(i, _)| i), Some(0));
+        assert_eq!(codec.dispatch(AnyArrayDType::F64).map(|(i, _)| i), Some(1));
+
+        let data = Array::from_vec(vec![0.25_f32, 1.75]).into_dyn();
+        let encoded = codec.encode(AnyCowArray::F32(data.view().into())).unwrap();
+        let decoded = codec.decode(encoded.cow()).unwrap();
+        assert_eq!(decoded, AnyArray::F32(data));
+    }
+
+    #[test]
+    fn unsupported_dtype() {
+        let codec = dispatch(vec![(
+            vec![DispatchDType::Class(DTypeClass::SignedInteger)],
+            identity(),
+        )]);
+
+        assert!(matches!(
+            codec.encode(AnyCowArray::U8(Array::zeros(4).into_dyn().into())),
+            Err(DispatchCodecError::UnsupportedDtype(AnyArrayDType::U8))
+        ));
+    }
+
+    #[test]
+    fn invalid_rule() {
+        let codec = dispatch(vec![
+            (vec![DispatchDType::Class(DTypeClass::Floating)], round()),
+            (vec![DispatchDType::Class(DTypeClass::Integer)], identity()),
+        ]);
+
+        let data = Array::from_vec(vec![1_u16, 2, 3]).into_dyn();
+        let encoded = codec.encode(AnyCowArray::U16(data.view().into())).unwrap();
+
+        let codec = dispatch(vec![(
+            vec![DispatchDType::Class(DTypeClass::Floating)],
+            round(),
+        )]);
+
+        assert!(matches!(
+            codec.decode(encoded.cow()),
+            Err(DispatchCodecError::DecodeInvalidRule { rule: 1, rules: 1 })
+        ));
+    }
+
+    #[test]
+    fn dispatch_dtype_config() {
+        assert_eq!(
+            serde_json::from_value::<Vec<DispatchDType>>(json!([
+                "f32", "int64", "integer", "floating"
+            ]))
+            .unwrap(),
+            vec![
+                DispatchDType::DType(AnyArrayDType::F32),
+                DispatchDType::DType(AnyArrayDType::I64),
+                DispatchDType::Class(DTypeClass::Integer),
+                DispatchDType::Class(DTypeClass::Floating),
+            ]
+        );
+
+        assert!(serde_json::from_value::<DispatchDType>(json!("complex")).is_err());
+    }
+}
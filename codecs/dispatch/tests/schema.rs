@@ -0,0 +1,27 @@
+#![expect(missing_docs)]
+
+use ::{
+    ndarray as _, numcodecs_identity as _, numcodecs_round as _, numcodecs_utils as _,
+    schemars as _, serde as _, serde_json as _, thiserror as _,
+};
+
+use numcodecs::{DynCodecType, StaticCodecType};
+use numcodecs_dispatch::DispatchCodec;
+
+#[test]
+fn schema() {
+    let schema = format!(
+        "{:#}",
+        StaticCodecType::<DispatchCodec>::of()
+            .codec_config_schema()
+            .to_value()
+    );
+
+    if schema != include_str!("schema.json") {
+        panic!("Dispatch schema has changed\n===\n{schema}\n===");
+    }
+}
+
+numcodecs_registry::export_global! {
+    static REGISTRY: numcodecs_registry::EmptyRegistry = numcodecs_registry::EmptyRegistry;
+}
@@ -27,14 +27,15 @@ use std::{borrow::Cow, num::NonZeroUsize};
 use ndarray::{Array, Array1, ArrayBase, ArrayViewMut, Axis, Data, DataMut, Dimension, IxDyn};
 use num_traits::Float;
 use numcodecs::{
-    AnyArray, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray, Codec, StaticCodec,
-    StaticCodecConfig, StaticCodecVersion,
+    AnyArray, AnyArrayAssignError, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray,
+    Codec, StaticCodec, StaticCodecConfig, StaticCodecVersion,
 };
+use numcodecs_utils::slices::{self, SliceFramingError, SlicesError};
 use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 
-type EbccCodecVersion = StaticCodecVersion<0, 1, 1>;
+type EbccCodecVersion = StaticCodecVersion<0, 1, 2>;
 
 /// Codec providing compression using EBCC.
 ///
@@ -57,6 +58,13 @@ pub struct EbccCodec {
     /// Optional EBCC-internal chunk shape.
     #[serde(default)]
     pub chunk_shape: EbccChunkShape,
+    /// The optional number of threads with which the 3D slices are encoded
+    /// and decoded in parallel
+    ///
+    /// If `None`, the slices are processed serially. The encoded bytes do not
+    /// depend on the number of threads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_threads: Option<NonZeroUsize>,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default, rename = "_version")]
     pub version: EbccCodecVersion,
@@ -107,11 +115,12 @@ impl Codec for EbccCodec {
     fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
         match data {
             AnyCowArray::F32(data) => Ok(AnyArray::U8(
-                Array1::from(compress(
+                Array1::from(compress_with_threads(
                     data,
                     self.residual,
                     self.base_cr,
                     self.chunk_shape,
+                    self.num_threads,
                 )?)
                 .into_dyn(),
             )),
@@ -132,7 +141,7 @@ impl Codec for EbccCodec {
             });
         }
 
-        decompress(&AnyCowArray::U8(encoded).as_bytes())
+        decompress_with_threads(&AnyCowArray::U8(encoded).as_bytes(), self.num_threads)
     }
 
     fn decode_into(
@@ -153,14 +162,55 @@ impl Codec for EbccCodec {
         }
 
         match decoded {
-            AnyArrayViewMut::F32(decoded) => {
-                decompress_into(&AnyArrayView::U8(encoded).as_bytes(), decoded)
-            }
+            AnyArrayViewMut::F32(decoded) => decompress_into_with_threads(
+                &AnyArrayView::U8(encoded).as_bytes(),
+                decoded,
+                self.num_threads,
+            ),
             decoded => Err(EbccCodecError::UnsupportedDtype(decoded.dtype())),
         }
     }
 }
 
+impl EbccCodec {
+    /// Decodes only the 3D slices at the `indices` along the first axis of
+    /// the `encoded` array into the `decoded` array.
+    ///
+    /// The `decoded` array must have the shape of the encoded data, except
+    /// that its first axis must have length `indices.len()`.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`EbccCodecError`] if the `encoded` array is not a
+    /// one-dimensional byte array, or if decoding the selected slices with
+    /// [`decompress_slices_into`] fails.
+    pub fn decode_slices_into(
+        &self,
+        encoded: AnyArrayView,
+        indices: &[usize],
+        decoded: AnyArrayViewMut,
+    ) -> Result<(), EbccCodecError> {
+        let AnyArrayView::U8(encoded) = encoded else {
+            return Err(EbccCodecError::EncodedDataNotBytes {
+                dtype: encoded.dtype(),
+            });
+        };
+
+        if !matches!(encoded.shape(), [_]) {
+            return Err(EbccCodecError::EncodedDataNotOneDimensional {
+                shape: encoded.shape().to_vec(),
+            });
+        }
+
+        decompress_slices_into(
+            &AnyArrayView::U8(encoded).as_bytes(),
+            indices,
+            decoded,
+            self.num_threads,
+        )
+    }
+}
+
 impl StaticCodec for EbccCodec {
     const CODEC_ID: &'static str = "ebcc.rs";
 
@@ -249,6 +299,17 @@ pub enum EbccCodecError {
     /// [`EbccCodec`] failed to decode from an excessive number of slices
     #[error("Ebcc failed to decode from an excessive number of slices")]
     DecodeTooManySlices,
+    /// [`EbccCodec`] failed to decode from a corrupted slice index
+    #[error("Ebcc failed to decode from a corrupted slice index")]
+    DecodeCorruptedSliceIndex,
+    /// [`EbccCodec`] cannot decode a 3D slice at an out-of-bounds index
+    #[error("Ebcc cannot decode the slice at index {index} of an axis of length {len}")]
+    SliceIndexOutOfBounds {
+        /// The out-of-bounds slice index
+        index: usize,
+        /// The length of the first axis of the encoded array
+        len: usize,
+    },
     /// [`EbccCodec`] failed to decode the data
     #[error("Ebcc failed to decode the data")]
     EbccDecodeFailed {
@@ -257,6 +318,33 @@ pub enum EbccCodecError {
     },
 }
 
+impl From<SlicesError> for EbccCodecError {
+    fn from(err: SlicesError) -> Self {
+        match err {
+            SlicesError::SliceEncodeFailed { source } => Self::SliceEncodeFailed {
+                source: EbccSliceError(source),
+            },
+            SlicesError::SliceDecodeFailed { source } => Self::SliceDecodeFailed {
+                source: EbccSliceError(source),
+            },
+            SlicesError::DecodeTooManySlices => Self::DecodeTooManySlices,
+            SlicesError::DecodeCorruptedSliceIndex => Self::DecodeCorruptedSliceIndex,
+            SlicesError::SliceIndexOutOfBounds { index, len } => {
+                Self::SliceIndexOutOfBounds { index, len }
+            }
+            SlicesError::MismatchedDecodeIntoArray {
+                source: AnyArrayAssignError::DTypeMismatch { dst, .. },
+            } => Self::UnsupportedDtype(dst),
+            SlicesError::MismatchedDecodeIntoArray {
+                source: AnyArrayAssignError::ShapeMismatch { src, dst },
+            } => Self::DecodeIntoShapeMismatch {
+                decoded: src,
+                array: dst,
+            },
+        }
+    }
+}
+
 #[expect(clippy::derive_partial_eq_without_eq)] // floats are not Eq
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Hash)]
 /// Positive floating point number
@@ -314,7 +402,7 @@ pub struct EbccHeaderError(postcard::Error);
 #[derive(Debug, Error)]
 #[error(transparent)]
 /// Opaque error for when encoding or decoding a 3D slice fails
-pub struct EbccSliceError(postcard::Error);
+pub struct EbccSliceError(SliceFramingError);
 
 #[derive(Debug, Error)]
 #[error(transparent)]
@@ -324,6 +412,12 @@ pub struct EbccCodingError(ebcc::EBCCError);
 /// Compress the `data` array using EBCC with the provided `residual` and
 /// `base_cr`. The `data` is internally chunked using the `chunk_shape`.
 ///
+/// The 3D slices are compressed serially. Use [`compress_with_threads`] to
+/// compress the slices in parallel.
+///
+/// The encoded data contains an index of the byte offsets of all 3D slices,
+/// which allows [`decompress_slices_into`] to decode only selected slices.
+///
 /// # Errors
 ///
 /// Errors with
@@ -339,91 +433,103 @@ pub fn compress<S: Data<Elem = f32>, D: Dimension>(
     base_cr: Positive<f32>,
     chunk_shape: EbccChunkShape,
 ) -> Result<Vec<u8>, EbccCodecError> {
+    compress_with_threads(data, residual, base_cr, chunk_shape, None)
+}
+
+/// Compress the `data` array using EBCC with the provided `residual` and
+/// `base_cr`. The `data` is internally chunked using the `chunk_shape`.
+///
+/// The 3D slices are compressed in parallel using up to `num_threads` threads
+/// if provided, or serially otherwise. The output is the same in both cases.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`EbccCodecError::HeaderEncodeFailed`] if encoding the header failed
+/// - [`EbccCodecError::InsufficientDimensions`] if the `data` has fewer than
+///   two dimensions or the last two dimensions are not at least 32x32
+/// - [`EbccCodecError::EbccEncodeFailed`] if encoding with EBCC failed
+/// - [`EbccCodecError::SliceEncodeFailed`] if encoding a 3D slice failed
+#[allow(clippy::missing_panics_doc)]
+pub fn compress_with_threads<S: Data<Elem = f32>, D: Dimension>(
+    data: ArrayBase<S, D>,
+    residual: EbccResidualType,
+    base_cr: Positive<f32>,
+    chunk_shape: EbccChunkShape,
+    num_threads: Option<NonZeroUsize>,
+) -> Result<Vec<u8>, EbccCodecError> {
+    let data = data.into_dyn();
+
+    // EBCC cannot handle zero-length dimensions, which have no slices
+    if !data.is_empty() {
+        match *data.shape() {
+            [.., height, width] if (width >= 32) && (height >= 32) => (),
+            _ => {
+                return Err(EbccCodecError::InsufficientDimensions {
+                    shape: Vec::from(data.shape()),
+                });
+            }
+        }
+    }
+
+    let (slice_offsets, slices) =
+        slices::encode_slices(&data, num_threads, |mut slice, slice_shape: [usize; 3]| {
+            while slice.ndim() < 3 {
+                slice = slice.insert_axis(Axis(0));
+            }
+            #[expect(clippy::unwrap_used)]
+            // slice must now have at least three axes, and all but the last
+            //  three must be of size 1
+            let slice = slice.into_shape_with_order(slice_shape).unwrap();
+
+            ebcc::ebcc_encode_chunking_compat(
+                slice,
+                &ebcc::EBCCConfig {
+                    base_cr: base_cr.0,
+                    residual_compression_type: match residual {
+                        EbccResidualType::Jpeg2000Only => ebcc::EBCCResidualType::Jpeg2000Only,
+                        EbccResidualType::AbsoluteError { error } => {
+                            ebcc::EBCCResidualType::AbsoluteError(error.0)
+                        }
+                        EbccResidualType::RelativeError { error } => {
+                            ebcc::EBCCResidualType::RelativeError(error.0)
+                        }
+                    },
+                },
+                match chunk_shape {
+                    EbccChunkShape::Auto => ebcc::EBCCCompatChunkShape::Auto,
+                    EbccChunkShape::Explicit(chunk_shape) => {
+                        ebcc::EBCCCompatChunkShape::Explicit(chunk_shape)
+                    }
+                },
+            )
+            .map_err(|err| EbccCodecError::EbccEncodeFailed {
+                source: EbccCodingError(err),
+            })
+        })?;
+
     let mut encoded = postcard::to_extend(
         &CompressionHeader {
             dtype: EbccDType::F32,
             shape: Cow::Borrowed(data.shape()),
             version: StaticCodecVersion,
+            slice_offsets,
         },
         Vec::new(),
     )
     .map_err(|err| EbccCodecError::HeaderEncodeFailed {
         source: EbccHeaderError(err),
     })?;
-
-    // EBCC cannot handle zero-length dimensions
-    if data.is_empty() {
-        return Ok(encoded);
-    }
-
-    let mut chunk_size = Vec::from(data.shape());
-    let (width, height, depth) = match *chunk_size.as_mut_slice() {
-        [ref mut rest @ .., depth, height, width] => {
-            for r in rest {
-                *r = 1;
-            }
-            (width, height, depth)
-        }
-        [height, width] => (width, height, 1),
-        _ => {
-            return Err(EbccCodecError::InsufficientDimensions {
-                shape: Vec::from(data.shape()),
-            });
-        }
-    };
-
-    if (width < 32) || (height < 32) {
-        return Err(EbccCodecError::InsufficientDimensions {
-            shape: Vec::from(data.shape()),
-        });
-    }
-
-    for mut slice in data.into_dyn().exact_chunks(chunk_size.as_slice()) {
-        while slice.ndim() < 3 {
-            slice = slice.insert_axis(Axis(0));
-        }
-        #[expect(clippy::unwrap_used)]
-        // slice must now have at least three axes, and all but the last three
-        //  must be of size 1
-        let slice = slice.into_shape_with_order((depth, height, width)).unwrap();
-
-        let encoded_slice = ebcc::ebcc_encode_chunking_compat(
-            slice,
-            &ebcc::EBCCConfig {
-                base_cr: base_cr.0,
-                residual_compression_type: match residual {
-                    EbccResidualType::Jpeg2000Only => ebcc::EBCCResidualType::Jpeg2000Only,
-                    EbccResidualType::AbsoluteError { error } => {
-                        ebcc::EBCCResidualType::AbsoluteError(error.0)
-                    }
-                    EbccResidualType::RelativeError { error } => {
-                        ebcc::EBCCResidualType::RelativeError(error.0)
-                    }
-                },
-            },
-            match chunk_shape {
-                EbccChunkShape::Auto => ebcc::EBCCCompatChunkShape::Auto,
-                EbccChunkShape::Explicit(chunk_shape) => {
-                    ebcc::EBCCCompatChunkShape::Explicit(chunk_shape)
-                }
-            },
-        )
-        .map_err(|err| EbccCodecError::EbccEncodeFailed {
-            source: EbccCodingError(err),
-        })?;
-
-        encoded = postcard::to_extend(encoded_slice.as_slice(), encoded).map_err(|err| {
-            EbccCodecError::SliceEncodeFailed {
-                source: EbccSliceError(err),
-            }
-        })?;
-    }
+    encoded.extend_from_slice(&slices);
 
     Ok(encoded)
 }
 
 /// Decompress the `encoded` data into an array using EBCC.
 ///
+/// The 3D slices are decompressed serially. Use [`decompress_with_threads`]
+/// to decompress the slices in parallel.
+///
 /// # Errors
 ///
 /// Errors with
@@ -433,21 +539,37 @@ pub fn compress<S: Data<Elem = f32>, D: Dimension>(
 /// - [`EbccCodecError::DecodeTooManySlices`] if the encoded data contains
 ///   too many slices
 pub fn decompress(encoded: &[u8]) -> Result<AnyArray, EbccCodecError> {
+    decompress_with_threads(encoded, None)
+}
+
+/// Decompress the `encoded` data into an array using EBCC.
+///
+/// The 3D slices are decompressed in parallel using up to `num_threads`
+/// threads if provided, or serially otherwise.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`EbccCodecError::HeaderDecodeFailed`] if decoding the header failed
+/// - [`EbccCodecError::SliceDecodeFailed`] if decoding a 3D slice failed
+/// - [`EbccCodecError::EbccDecodeFailed`] if decoding with EBCC failed
+/// - [`EbccCodecError::DecodeTooManySlices`] if the encoded data contains
+///   too many slices
+pub fn decompress_with_threads(
+    encoded: &[u8],
+    num_threads: Option<NonZeroUsize>,
+) -> Result<AnyArray, EbccCodecError> {
     fn decompress_typed(
         encoded: &[u8],
         shape: &[usize],
+        num_threads: Option<NonZeroUsize>,
     ) -> Result<Array<f32, IxDyn>, EbccCodecError> {
         let mut decoded = Array::<f32, _>::zeros(shape);
-        decompress_into_typed(encoded, decoded.view_mut())?;
+        decompress_into_typed(encoded, decoded.view_mut(), num_threads)?;
         Ok(decoded)
     }
 
-    let (header, encoded) =
-        postcard::take_from_bytes::<CompressionHeader>(encoded).map_err(|err| {
-            EbccCodecError::HeaderDecodeFailed {
-                source: EbccHeaderError(err),
-            }
-        })?;
+    let (header, encoded) = decode_header(encoded)?;
 
     // Return empty data for zero-size arrays
     if header.shape.iter().copied().any(|s| s == 0) {
@@ -457,12 +579,19 @@ pub fn decompress(encoded: &[u8]) -> Result<AnyArray, EbccCodecError> {
     }
 
     match header.dtype {
-        EbccDType::F32 => Ok(AnyArray::F32(decompress_typed(encoded, &header.shape)?)),
+        EbccDType::F32 => Ok(AnyArray::F32(decompress_typed(
+            encoded,
+            &header.shape,
+            num_threads,
+        )?)),
     }
 }
 
 /// Decompress the `encoded` data into the `decoded` array using EBCC.
 ///
+/// The 3D slices are decompressed serially. Use
+/// [`decompress_into_with_threads`] to decompress the slices in parallel.
+///
 /// # Errors
 ///
 /// Errors with
@@ -477,12 +606,30 @@ pub fn decompress_into<S: DataMut<Elem = f32>, D: Dimension>(
     encoded: &[u8],
     decoded: ArrayBase<S, D>,
 ) -> Result<(), EbccCodecError> {
-    let (header, encoded) =
-        postcard::take_from_bytes::<CompressionHeader>(encoded).map_err(|err| {
-            EbccCodecError::HeaderDecodeFailed {
-                source: EbccHeaderError(err),
-            }
-        })?;
+    decompress_into_with_threads(encoded, decoded, None)
+}
+
+/// Decompress the `encoded` data into the `decoded` array using EBCC.
+///
+/// The 3D slices are decompressed in parallel using up to `num_threads`
+/// threads if provided, or serially otherwise.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`EbccCodecError::HeaderDecodeFailed`] if decoding the header failed
+/// - [`EbccCodecError::DecodeIntoShapeMismatch`] is the `decoded` array shape
+///   does not match the shape of the decoded data
+/// - [`EbccCodecError::SliceDecodeFailed`] if decoding a 3D slice failed
+/// - [`EbccCodecError::EbccDecodeFailed`] if decoding with EBCC failed
+/// - [`EbccCodecError::DecodeTooManySlices`] if the encoded data contains
+///   too many slices
+pub fn decompress_into_with_threads<S: DataMut<Elem = f32>, D: Dimension>(
+    encoded: &[u8],
+    decoded: ArrayBase<S, D>,
+    num_threads: Option<NonZeroUsize>,
+) -> Result<(), EbccCodecError> {
+    let (header, encoded) = decode_header(encoded)?;
 
     if decoded.shape() != &*header.shape {
         return Err(EbccCodecError::DecodeIntoShapeMismatch {
@@ -499,56 +646,114 @@ pub fn decompress_into<S: DataMut<Elem = f32>, D: Dimension>(
     }
 
     match header.dtype {
-        EbccDType::F32 => decompress_into_typed(encoded, decoded.into_dyn().view_mut()),
+        EbccDType::F32 => {
+            decompress_into_typed(encoded, decoded.into_dyn().view_mut(), num_threads)
+        }
     }
 }
 
-fn decompress_into_typed(
-    mut encoded: &[u8],
-    mut decoded: ArrayViewMut<f32, IxDyn>,
+/// Decompress only the 3D slices at the `indices` along the first axis of the
+/// `encoded` data into the `decoded` array using EBCC.
+///
+/// The `decoded` array must have the shape of the encoded data, except that
+/// its first axis must have length `indices.len()`. For arrays with more than
+/// three dimensions, only the 3D slices that are selected by the `indices`
+/// are decoded, using up to `num_threads` threads if provided, or serially
+/// otherwise.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`EbccCodecError::HeaderDecodeFailed`] if decoding the header failed
+/// - [`EbccCodecError::UnsupportedDtype`] if the `decoded` array's dtype does
+///   not match the dtype of the encoded data
+/// - [`EbccCodecError::SliceIndexOutOfBounds`] if any of the `indices` is out
+///   of bounds for the first axis of the encoded data
+/// - [`EbccCodecError::DecodeIntoShapeMismatch`] is the `decoded` array shape
+///   does not match the shape of the selected slices
+/// - [`EbccCodecError::DecodeCorruptedSliceIndex`] if the slice index of the
+///   encoded data is corrupted
+/// - [`EbccCodecError::SliceDecodeFailed`] if decoding a 3D slice failed
+/// - [`EbccCodecError::EbccDecodeFailed`] if decoding with EBCC failed
+pub fn decompress_slices_into(
+    encoded: &[u8],
+    indices: &[usize],
+    decoded: AnyArrayViewMut,
+    num_threads: Option<NonZeroUsize>,
 ) -> Result<(), EbccCodecError> {
-    let mut chunk_size = Vec::from(decoded.shape());
-    let (width, height, depth) = match *chunk_size.as_mut_slice() {
-        [ref mut rest @ .., depth, height, width] => {
-            for r in rest {
-                *r = 1;
-            }
-            (width, height, depth)
-        }
-        [height, width] => (width, height, 1),
-        [width] => (width, 1, 1),
-        [] => (1, 1, 1),
+    let (header, encoded) = decode_header(encoded)?;
+
+    let dtype = match header.dtype {
+        EbccDType::F32 => AnyArrayDType::F32,
     };
+    slices::check_decode_selected_slices_into(dtype, &header.shape, indices, &decoded)?;
+
+    match decoded {
+        AnyArrayViewMut::F32(decoded) => slices::decode_selected_slices_into(
+            encoded,
+            &header.slice_offsets,
+            &header.shape,
+            indices,
+            decoded,
+            num_threads,
+            decompress_slice_into,
+        ),
+        decoded => Err(EbccCodecError::UnsupportedDtype(decoded.dtype())),
+    }
+}
 
-    for mut slice in decoded.exact_chunks_mut(chunk_size.as_slice()) {
-        let (encoded_slice, rest) =
-            postcard::take_from_bytes::<Cow<[u8]>>(encoded).map_err(|err| {
-                EbccCodecError::SliceDecodeFailed {
-                    source: EbccSliceError(err),
-                }
-            })?;
-        encoded = rest;
-
-        while slice.ndim() < 3 {
-            slice = slice.insert_axis(Axis(0));
-        }
-        #[expect(clippy::unwrap_used)]
-        // slice must now have at least three axes, and all but the last
-        //  three must be of size 1
-        let slice = slice.into_shape_with_order((depth, height, width)).unwrap();
+fn decompress_into_typed(
+    encoded: &[u8],
+    decoded: ArrayViewMut<f32, IxDyn>,
+    num_threads: Option<NonZeroUsize>,
+) -> Result<(), EbccCodecError> {
+    slices::decode_slices_into(encoded, decoded, num_threads, decompress_slice_into)
+}
 
-        ebcc::ebcc_decode_chunking_into(&encoded_slice, slice).map_err(|err| {
-            EbccCodecError::EbccDecodeFailed {
-                source: EbccCodingError(err),
-            }
-        })?;
+/// Decodes the header of the `encoded` data
+///
+/// The slice index of data that was encoded with the index-less format
+/// version 0.1.1 is reconstructed by walking over the slices.
+fn decode_header(encoded: &[u8]) -> Result<(CompressionHeader<'_>, &[u8]), EbccCodecError> {
+    if let Ok((header, encoded)) = postcard::take_from_bytes::<LegacyCompressionHeader>(encoded) {
+        let slice_offsets = slices::slice_offsets(encoded, slices::num_slices::<3>(&header.shape))?;
+
+        return Ok((
+            CompressionHeader {
+                dtype: header.dtype,
+                shape: header.shape,
+                version: StaticCodecVersion,
+                slice_offsets,
+            },
+            encoded,
+        ));
     }
 
-    if !encoded.is_empty() {
-        return Err(EbccCodecError::DecodeTooManySlices);
+    postcard::take_from_bytes::<CompressionHeader>(encoded).map_err(|err| {
+        EbccCodecError::HeaderDecodeFailed {
+            source: EbccHeaderError(err),
+        }
+    })
+}
+
+fn decompress_slice_into(
+    encoded_slice: &[u8],
+    mut slice: ArrayViewMut<f32, IxDyn>,
+    slice_shape: [usize; 3],
+) -> Result<(), EbccCodecError> {
+    while slice.ndim() < 3 {
+        slice = slice.insert_axis(Axis(0));
     }
+    #[expect(clippy::unwrap_used)]
+    // slice must now have at least three axes, and all but the last
+    //  three must be of size 1
+    let slice = slice.into_shape_with_order(slice_shape).unwrap();
 
-    Ok(())
+    ebcc::ebcc_decode_chunking_into(encoded_slice, slice).map_err(|err| {
+        EbccCodecError::EbccDecodeFailed {
+            source: EbccCodingError(err),
+        }
+    })
 }
 
 #[derive(Serialize, Deserialize)]
@@ -557,6 +762,17 @@ struct CompressionHeader<'a> {
     #[serde(borrow)]
     shape: Cow<'a, [usize]>,
     version: EbccCodecVersion,
+    slice_offsets: Vec<usize>,
+}
+
+/// Header of the format version 0.1.1, which has no slice index
+#[derive(Deserialize)]
+struct LegacyCompressionHeader<'a> {
+    dtype: EbccDType,
+    #[serde(borrow)]
+    shape: Cow<'a, [usize]>,
+    #[expect(dead_code)]
+    version: StaticCodecVersion<0, 1, 1>,
 }
 
 /// Dtypes that EBCC can compress and decompress
@@ -577,6 +793,7 @@ mod tests {
             base_cr: Positive(10.0),
             version: StaticCodecVersion,
             chunk_shape: EbccChunkShape::Auto,
+            num_threads: None,
         };
 
         let data = Array1::<i32>::zeros(100);
@@ -592,6 +809,7 @@ mod tests {
             base_cr: Positive(10.0),
             version: StaticCodecVersion,
             chunk_shape: EbccChunkShape::Auto,
+            num_threads: None,
         };
 
         // Test dimensions too small (32 < 32x32 requirement)
@@ -650,6 +868,7 @@ mod tests {
             base_cr: Positive(20.0),
             version: StaticCodecVersion,
             chunk_shape: EbccChunkShape::Auto,
+            num_threads: None,
         };
 
         let encoded = codec.encode(AnyArray::F32(data.clone().into_dyn()).into_cow())?;
@@ -714,6 +933,7 @@ mod tests {
             base_cr: Positive(20.0),
             version: StaticCodecVersion,
             chunk_shape: EbccChunkShape::Auto,
+            num_threads: None,
         };
 
         let encoded = codec.encode(AnyArray::F32(data.clone().into_dyn()).into_cow())?;
@@ -753,4 +973,152 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_random_access_slices() -> Result<(), EbccCodecError> {
+        #[expect(clippy::cast_precision_loss)]
+        let data = Array::from_shape_fn((4, 2, 2, 32, 32), |(a, b, c, i, j)| {
+            ((a * 4 + b * 2 + c) as f32) + ((i * 32 + j) as f32 / 1024.0).sin()
+        });
+
+        let codec = EbccCodec {
+            residual: EbccResidualType::AbsoluteError {
+                error: Positive(0.1),
+            },
+            base_cr: Positive(10.0),
+            version: StaticCodecVersion,
+            chunk_shape: EbccChunkShape::Auto,
+            num_threads: None,
+        };
+
+        let encoded = codec.encode(AnyArray::F32(data.into_dyn()).into_cow())?;
+        let decoded = codec.decode(encoded.cow())?;
+
+        let AnyArray::F32(decoded) = decoded else {
+            return Err(EbccCodecError::UnsupportedDtype(decoded.dtype()));
+        };
+
+        for indices in [&[][..], &[0], &[3, 1], &[2, 0, 2]] {
+            let mut slices = Array::<f32, _>::zeros((indices.len(), 2, 2, 32, 32)).into_dyn();
+            codec.decode_slices_into(
+                encoded.view(),
+                indices,
+                AnyArrayViewMut::F32(slices.view_mut()),
+            )?;
+
+            assert_eq!(slices, decoded.select(Axis(0), indices));
+        }
+
+        let mut slices = Array::<f32, _>::zeros((1, 2, 2, 32, 32)).into_dyn();
+        let result = codec.decode_slices_into(
+            encoded.view(),
+            &[4],
+            AnyArrayViewMut::F32(slices.view_mut()),
+        );
+        assert!(matches!(
+            result,
+            Err(EbccCodecError::SliceIndexOutOfBounds { index: 4, len: 4 })
+        ));
+
+        let result = codec.decode_slices_into(
+            encoded.view(),
+            &[0, 1],
+            AnyArrayViewMut::F32(slices.view_mut()),
+        );
+        assert!(matches!(
+            result,
+            Err(EbccCodecError::DecodeIntoShapeMismatch { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_slices() -> Result<(), EbccCodecError> {
+        #[expect(clippy::cast_precision_loss)]
+        let data = Array::from_shape_fn((5, 2, 32, 32), |(a, b, i, j)| {
+            ((a * 2 + b) as f32) + ((i * 32 + j) as f32 / 1024.0).sin()
+        });
+        let residual = EbccResidualType::AbsoluteError {
+            error: Positive(0.1),
+        };
+
+        let encoded = compress(data.view(), residual, Positive(10.0), EbccChunkShape::Auto)?;
+        let decoded = decompress(&encoded)?;
+
+        for num_threads in [1, 2, 4, 64] {
+            let num_threads = NonZeroUsize::new(num_threads);
+
+            let encoded_parallel = compress_with_threads(
+                data.view(),
+                residual,
+                Positive(10.0),
+                EbccChunkShape::Auto,
+                num_threads,
+            )?;
+            assert_eq!(encoded_parallel, encoded);
+
+            let decoded_parallel = decompress_with_threads(&encoded, num_threads)?;
+            assert_eq!(decoded_parallel, decoded);
+        }
+
+        let AnyArray::F32(decoded) = decoded else {
+            return Err(EbccCodecError::UnsupportedDtype(decoded.dtype()));
+        };
+
+        for indices in [&[][..], &[0], &[4, 1], &[3, 0, 3]] {
+            for num_threads in [None, NonZeroUsize::new(2)] {
+                let mut slices = Array::<f32, _>::zeros((indices.len(), 2, 32, 32)).into_dyn();
+                decompress_slices_into(
+                    &encoded,
+                    indices,
+                    AnyArrayViewMut::F32(slices.view_mut()),
+                    num_threads,
+                )?;
+
+                assert_eq!(slices, decoded.select(Axis(0), indices));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_legacy_slices_without_index() -> Result<(), EbccCodecError> {
+        #[expect(clippy::cast_precision_loss)]
+        let data = Array::from_shape_fn((3, 2, 32, 32), |(a, b, i, j)| {
+            ((a * 2 + b) as f32) + ((i * 32 + j) as f32 / 1024.0).sin()
+        });
+
+        let encoded = compress(
+            data.view(),
+            EbccResidualType::AbsoluteError {
+                error: Positive(0.1),
+            },
+            Positive(10.0),
+            EbccChunkShape::Auto,
+        )?;
+        let decoded = decompress(&encoded)?;
+
+        let (header, slices) = decode_header(&encoded)?;
+        let mut legacy = postcard::to_extend(
+            &(header.dtype, &header.shape, StaticCodecVersion::<0, 1, 1>),
+            Vec::new(),
+        )
+        .map_err(|err| EbccCodecError::HeaderEncodeFailed {
+            source: EbccHeaderError(err),
+        })?;
+        legacy.extend_from_slice(slices);
+
+        assert_eq!(decompress(&legacy)?, decoded);
+
+        let AnyArray::F32(decoded) = decoded else {
+            return Err(EbccCodecError::UnsupportedDtype(decoded.dtype()));
+        };
+        let mut slices = Array::<f32, _>::zeros((1, 2, 32, 32)).into_dyn();
+        decompress_slices_into(&legacy, &[1], AnyArrayViewMut::F32(slices.view_mut()), None)?;
+        assert_eq!(slices, decoded.select(Axis(0), &[1]));
+
+        Ok(())
+    }
 }
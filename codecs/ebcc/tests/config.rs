@@ -3,7 +3,10 @@
 
 use std::num::NonZeroUsize;
 
-use ::{ebcc as _, ndarray as _, num_traits as _, postcard as _, schemars as _, thiserror as _};
+use ::{
+    ebcc as _, ndarray as _, num_traits as _, numcodecs_utils as _, postcard as _, schemars as _,
+    thiserror as _,
+};
 
 use numcodecs::StaticCodec;
 use numcodecs_ebcc::{EbccChunkShape, EbccCodec, EbccResidualType};
@@ -17,18 +17,28 @@
 //!
 //! `$\frac{x - o}{s}$` codec implementation for the [`numcodecs`] API.
 
+use std::borrow::Cow;
+
 use ndarray::{Array, ArrayBase, ArrayView, ArrayViewMut, Data, Dimension};
 use num_traits::Float;
 use numcodecs::{
     AnyArray, AnyArrayAssignError, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray,
-    Codec, StaticCodec, StaticCodecConfig, StaticCodecVersion,
+    Codec, DynCodec, ErasedDynCodec, ErasedError, StaticCodec, StaticCodecConfig,
+    StaticCodecVersion,
+};
+use numcodecs_registry::GlobalRegistry;
+use numcodecs_utils::framing::{
+    FramingError, decode_framed, encode_framed, framed_array, framed_bytes,
 };
-use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 
+type FixedOffsetScaleCodecVersion = StaticCodecVersion<1, 1, 0>;
+
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
-#[serde(deny_unknown_fields)]
+// serde cannot deny unknown fields because of the flatten
+#[schemars(deny_unknown_fields)]
 /// Fixed offset-scale codec which calculates `$c = \frac{x - o}{s}$` on
 /// encoding and `$d = (c \cdot s) + o$` on decoding.
 ///
@@ -37,48 +47,181 @@ use thiserror::Error;
 /// - Setting `$o = \text{min}(x)$` and `$s = \text{max}(x) - \text{min}(x)$`
 ///   standardizes the data.
 ///
+/// The offset and scale can either be fixed in the config or be computed from
+/// the data during encoding. In the latter case, the scaled data, which keeps
+/// its shape, is encoded with a wrapped codec. The encoded array is then a
+/// one-dimensional byte array that stores the offset and scale next to the
+/// wrapped codec's encoding, and must only be compressed further with lossless
+/// codecs.
+///
 /// The codec only supports floating point numbers.
 pub struct FixedOffsetScaleCodec {
-    /// The offset of the data.
-    pub offset: f64,
-    /// The scale of the data.
-    pub scale: f64,
+    /// The mode in which the offset and scale are determined.
+    #[serde(flatten)]
+    pub mode: FixedOffsetScaleMode,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default, rename = "_version")]
-    pub version: StaticCodecVersion<1, 0, 0>,
+    pub version: FixedOffsetScaleCodecVersion,
+}
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "mode")]
+#[serde(deny_unknown_fields)]
+/// Mode in which the offset and scale are determined
+pub enum FixedOffsetScaleMode {
+    /// The data is standardized using `$o = \text{min}(x)$` and
+    /// `$s = \text{max}(x) - \text{min}(x)$`, which are computed during
+    /// encoding.
+    #[serde(rename = "min-max")]
+    MinMax {
+        /// The configuration of the wrapped codec, which encodes the
+        /// standardized data.
+        #[serde(serialize_with = "DynCodec::get_config")]
+        #[serde(deserialize_with = "GlobalRegistry::codec_from_config")]
+        #[schemars(schema_with = "ErasedDynCodec::codec_config_schema")]
+        codec: ErasedDynCodec,
+    },
+    /// The data is normalized using `$o = \text{mean}(x)$` and
+    /// `$s = \text{std}(x)$`, which are computed during encoding.
+    #[serde(rename = "mean-std")]
+    MeanStd {
+        /// The configuration of the wrapped codec, which encodes the
+        /// normalized data.
+        #[serde(serialize_with = "DynCodec::get_config")]
+        #[serde(deserialize_with = "GlobalRegistry::codec_from_config")]
+        #[schemars(schema_with = "ErasedDynCodec::codec_config_schema")]
+        codec: ErasedDynCodec,
+    },
+    /// The data is robustly standardized using
+    /// `$o = \text{P}_{lower}(x)$` and
+    /// `$s = \text{P}_{upper}(x) - \text{P}_{lower}(x)$`, which are computed
+    /// during encoding.
+    #[serde(rename = "percentile")]
+    Percentile {
+        /// The lower percentile, in `$[0, 100]$`, which is used as the offset.
+        lower: Percentile<f64>,
+        /// The upper percentile, in `$[0, 100]$`, which is used to compute the
+        /// scale.
+        upper: Percentile<f64>,
+        /// The configuration of the wrapped codec, which encodes the
+        /// standardized data.
+        #[serde(serialize_with = "DynCodec::get_config")]
+        #[serde(deserialize_with = "GlobalRegistry::codec_from_config")]
+        #[schemars(schema_with = "ErasedDynCodec::codec_config_schema")]
+        codec: ErasedDynCodec,
+    },
+    /// The offset and scale are fixed in the config.
+    ///
+    /// This is the default mode, which is used if no `mode` is specified.
+    #[serde(untagged)]
+    Fixed {
+        /// The offset of the data.
+        offset: f64,
+        /// The scale of the data.
+        scale: f64,
+    },
 }
 
 impl Codec for FixedOffsetScaleCodec {
     type Error = FixedOffsetScaleCodecError;
 
     fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
-        match data {
-            #[expect(clippy::cast_possible_truncation)]
-            AnyCowArray::F32(data) => Ok(AnyArray::F32(scale(
-                data,
-                self.offset as f32,
-                self.scale as f32,
-            ))),
-            AnyCowArray::F64(data) => Ok(AnyArray::F64(scale(data, self.offset, self.scale))),
-            encoded => Err(FixedOffsetScaleCodecError::UnsupportedDtype(
-                encoded.dtype(),
-            )),
-        }
+        let codec = match &self.mode {
+            FixedOffsetScaleMode::Fixed { offset, scale: s } => {
+                return match data {
+                    #[expect(clippy::cast_possible_truncation)]
+                    AnyCowArray::F32(data) => {
+                        Ok(AnyArray::F32(scale(data, *offset as f32, *s as f32)))
+                    }
+                    AnyCowArray::F64(data) => Ok(AnyArray::F64(scale(data, *offset, *s))),
+                    encoded => Err(FixedOffsetScaleCodecError::UnsupportedDtype(
+                        encoded.dtype(),
+                    )),
+                };
+            }
+            FixedOffsetScaleMode::MinMax { codec }
+            | FixedOffsetScaleMode::MeanStd { codec }
+            | FixedOffsetScaleMode::Percentile { codec, .. } => codec,
+        };
+
+        let (offset, s, scaled) = match data {
+            AnyCowArray::F32(data) => {
+                let (offset, s) = adaptive_offset_scale(&data, &self.mode)?;
+                (
+                    f64::from(offset),
+                    f64::from(s),
+                    AnyArray::F32(scale(data, offset, s)),
+                )
+            }
+            AnyCowArray::F64(data) => {
+                let (offset, s) = adaptive_offset_scale(&data, &self.mode)?;
+                (offset, s, AnyArray::F64(scale(data, offset, s)))
+            }
+            encoded => {
+                return Err(FixedOffsetScaleCodecError::UnsupportedDtype(
+                    encoded.dtype(),
+                ));
+            }
+        };
+
+        let encoded = codec
+            .encode(scaled.into_cow())
+            .map_err(|err| FixedOffsetScaleCodecError::InnerEncodeFailed { source: err })?;
+
+        let encoded = encode_framed(
+            &CompressionHeader {
+                offset,
+                scale: s,
+                version: StaticCodecVersion,
+            },
+            &encoded,
+        )?;
+
+        Ok(framed_array(encoded))
     }
 
     fn decode(&self, encoded: AnyCowArray) -> Result<AnyArray, Self::Error> {
-        match encoded {
+        let codec = match &self.mode {
+            FixedOffsetScaleMode::Fixed { offset, scale } => {
+                return match encoded {
+                    #[expect(clippy::cast_possible_truncation)]
+                    AnyCowArray::F32(encoded) => Ok(AnyArray::F32(unscale(
+                        encoded,
+                        *offset as f32,
+                        *scale as f32,
+                    ))),
+                    AnyCowArray::F64(encoded) => {
+                        Ok(AnyArray::F64(unscale(encoded, *offset, *scale)))
+                    }
+                    encoded => Err(FixedOffsetScaleCodecError::UnsupportedDtype(
+                        encoded.dtype(),
+                    )),
+                };
+            }
+            FixedOffsetScaleMode::MinMax { codec }
+            | FixedOffsetScaleMode::MeanStd { codec }
+            | FixedOffsetScaleMode::Percentile { codec, .. } => codec,
+        };
+
+        let encoded = encoded.view();
+        let (header, encoded, _) = decode_framed::<CompressionHeader>(&framed_bytes(&encoded)?)?;
+
+        let decoded = codec
+            .decode(encoded.into_cow())
+            .map_err(|err| FixedOffsetScaleCodecError::InnerDecodeFailed { source: err })?;
+
+        match decoded {
             #[expect(clippy::cast_possible_truncation)]
-            AnyCowArray::F32(encoded) => Ok(AnyArray::F32(unscale(
-                encoded,
-                self.offset as f32,
-                self.scale as f32,
+            AnyArray::F32(decoded) => Ok(AnyArray::F32(unscale(
+                decoded,
+                header.offset as f32,
+                header.scale as f32,
             ))),
-            AnyCowArray::F64(encoded) => {
-                Ok(AnyArray::F64(unscale(encoded, self.offset, self.scale)))
+            AnyArray::F64(decoded) => {
+                Ok(AnyArray::F64(unscale(decoded, header.offset, header.scale)))
             }
-            encoded => Err(FixedOffsetScaleCodecError::UnsupportedDtype(
-                encoded.dtype(),
+            decoded => Err(FixedOffsetScaleCodecError::UnsupportedDtype(
+                decoded.dtype(),
             )),
         }
     }
@@ -86,28 +229,64 @@ impl Codec for FixedOffsetScaleCodec {
     fn decode_into(
         &self,
         encoded: AnyArrayView,
-        decoded: AnyArrayViewMut,
+        mut decoded: AnyArrayViewMut,
     ) -> Result<(), Self::Error> {
-        match (encoded, decoded) {
+        let codec = match &self.mode {
+            FixedOffsetScaleMode::Fixed { offset, scale } => {
+                return match (encoded, decoded) {
+                    #[expect(clippy::cast_possible_truncation)]
+                    (AnyArrayView::F32(encoded), AnyArrayViewMut::F32(decoded)) => {
+                        unscale_into(encoded, decoded, *offset as f32, *scale as f32)
+                    }
+                    (AnyArrayView::F64(encoded), AnyArrayViewMut::F64(decoded)) => {
+                        unscale_into(encoded, decoded, *offset, *scale)
+                    }
+                    (encoded @ (AnyArrayView::F32(_) | AnyArrayView::F64(_)), decoded) => {
+                        Err(FixedOffsetScaleCodecError::MismatchedDecodeIntoArray {
+                            source: AnyArrayAssignError::DTypeMismatch {
+                                src: encoded.dtype(),
+                                dst: decoded.dtype(),
+                            },
+                        })
+                    }
+                    (encoded, _decoded) => Err(FixedOffsetScaleCodecError::UnsupportedDtype(
+                        encoded.dtype(),
+                    )),
+                };
+            }
+            FixedOffsetScaleMode::MinMax { codec }
+            | FixedOffsetScaleMode::MeanStd { codec }
+            | FixedOffsetScaleMode::Percentile { codec, .. } => codec,
+        };
+
+        if !matches!(decoded.dtype(), AnyArrayDType::F32 | AnyArrayDType::F64) {
+            return Err(FixedOffsetScaleCodecError::UnsupportedDtype(
+                decoded.dtype(),
+            ));
+        }
+
+        let (header, encoded, _) = decode_framed::<CompressionHeader>(&framed_bytes(&encoded)?)?;
+
+        codec
+            .decode_into(encoded.view(), decoded.view_mut())
+            .map_err(|err| FixedOffsetScaleCodecError::InnerDecodeFailed { source: err })?;
+
+        match decoded {
             #[expect(clippy::cast_possible_truncation)]
-            (AnyArrayView::F32(encoded), AnyArrayViewMut::F32(decoded)) => {
-                unscale_into(encoded, decoded, self.offset as f32, self.scale as f32)
+            AnyArrayViewMut::F32(mut decoded) => {
+                decoded.mapv_inplace(|x| x.mul_add(header.scale as f32, header.offset as f32));
             }
-            (AnyArrayView::F64(encoded), AnyArrayViewMut::F64(decoded)) => {
-                unscale_into(encoded, decoded, self.offset, self.scale)
+            AnyArrayViewMut::F64(mut decoded) => {
+                decoded.mapv_inplace(|x| x.mul_add(header.scale, header.offset));
             }
-            (encoded @ (AnyArrayView::F32(_) | AnyArrayView::F64(_)), decoded) => {
-                Err(FixedOffsetScaleCodecError::MismatchedDecodeIntoArray {
-                    source: AnyArrayAssignError::DTypeMismatch {
-                        src: encoded.dtype(),
-                        dst: decoded.dtype(),
-                    },
-                })
+            decoded => {
+                return Err(FixedOffsetScaleCodecError::UnsupportedDtype(
+                    decoded.dtype(),
+                ));
             }
-            (encoded, _decoded) => Err(FixedOffsetScaleCodecError::UnsupportedDtype(
-                encoded.dtype(),
-            )),
         }
+
+        Ok(())
     }
 }
 
@@ -125,12 +304,89 @@ impl StaticCodec for FixedOffsetScaleCodec {
     }
 }
 
+#[expect(clippy::derive_partial_eq_without_eq)] // floats are not Eq
+#[derive(Copy, Clone, PartialEq, PartialOrd, Hash)]
+/// Floating point percentile in `$[0, 100]$`
+pub struct Percentile<T: Float>(T);
+
+impl Serialize for Percentile<f64> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Percentile<f64> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let x = f64::deserialize(deserializer)?;
+
+        if (0.0..=100.0).contains(&x) {
+            Ok(Self(x))
+        } else {
+            Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Float(x),
+                &"a value in [0.0, 100.0]",
+            ))
+        }
+    }
+}
+
+impl JsonSchema for Percentile<f64> {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("PercentileF64")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed(concat!(module_path!(), "::", "Percentile<f64>"))
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "minimum": 0.0,
+            "maximum": 100.0
+        })
+    }
+}
+
 #[derive(Debug, Error)]
 /// Errors that may occur when applying the [`FixedOffsetScaleCodec`].
 pub enum FixedOffsetScaleCodecError {
     /// [`FixedOffsetScaleCodec`] does not support the dtype
     #[error("FixedOffsetScale does not support the dtype {0}")]
     UnsupportedDtype(AnyArrayDType),
+    /// [`FixedOffsetScaleCodec`] cannot use a `lower` percentile that
+    /// exceeds the `upper` percentile
+    #[error(
+        "FixedOffsetScale cannot use a lower percentile {lower} that exceeds the upper percentile {upper}"
+    )]
+    InvalidPercentiles {
+        /// The lower percentile
+        lower: f64,
+        /// The upper percentile
+        upper: f64,
+    },
+    /// [`FixedOffsetScaleCodec`] failed to encode the scaled data with the
+    /// wrapped codec
+    #[error("FixedOffsetScale failed to encode the scaled data with the wrapped codec")]
+    InnerEncodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`FixedOffsetScaleCodec`] failed to decode the scaled data with the
+    /// wrapped codec
+    #[error("FixedOffsetScale failed to decode the scaled data with the wrapped codec")]
+    InnerDecodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`FixedOffsetScaleCodec`] failed to frame or unframe the wrapped
+    /// codec's encoding together with the offset and scale
+    #[error("FixedOffsetScale failed to frame or unframe the wrapped codec's encoding")]
+    FramingFailed {
+        /// The source of the error
+        #[from]
+        source: FramingError,
+    },
     /// [`FixedOffsetScaleCodec`] cannot decode into the provided array
     #[error("FixedOffsetScale cannot decode into the provided array")]
     MismatchedDecodeIntoArray {
@@ -198,10 +454,124 @@ pub fn unscale_into<T: Float, D: Dimension>(
     Ok(())
 }
 
+/// Compute the offset and scale of the input `data` array for the adaptive
+/// `mode`.
+///
+/// Non-finite values are ignored. If the data contains no finite values, the
+/// offset is zero and the scale is one. A zero scale, e.g. for constant data,
+/// is replaced by one.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`FixedOffsetScaleCodecError::InvalidPercentiles`] if the lower
+///   percentile exceeds the upper percentile
+pub fn adaptive_offset_scale<T: Float, S: Data<Elem = T>, D: Dimension>(
+    data: &ArrayBase<S, D>,
+    mode: &FixedOffsetScaleMode,
+) -> Result<(T, T), FixedOffsetScaleCodecError> {
+    let (offset, scale) = match mode {
+        FixedOffsetScaleMode::Fixed { offset, scale } => (
+            T::from(*offset).unwrap_or_else(T::nan),
+            T::from(*scale).unwrap_or_else(T::nan),
+        ),
+        FixedOffsetScaleMode::MinMax { .. } => {
+            let (min, max) = data
+                .iter()
+                .filter(|x| x.is_finite())
+                .fold(None, |acc, &x| match acc {
+                    None => Some((x, x)),
+                    Some((min, max)) => Some((x.min(min), x.max(max))),
+                })
+                .unwrap_or_else(|| (T::zero(), T::one()));
+            (min, max - min)
+        }
+        FixedOffsetScaleMode::MeanStd { .. } => {
+            let (sum, count) = data
+                .iter()
+                .filter(|x| x.is_finite())
+                .fold((T::zero(), 0_usize), |(sum, count), &x| {
+                    (sum + x, count + 1)
+                });
+            let Some(count) = T::from(count).filter(|count| !count.is_zero()) else {
+                return Ok((T::zero(), T::one()));
+            };
+            let mean = sum / count;
+            let variance = data
+                .iter()
+                .filter(|x| x.is_finite())
+                .fold(T::zero(), |acc, &x| (x - mean).mul_add(x - mean, acc))
+                / count;
+            (mean, variance.sqrt())
+        }
+        FixedOffsetScaleMode::Percentile { lower, upper, .. } => {
+            if lower.0 > upper.0 {
+                return Err(FixedOffsetScaleCodecError::InvalidPercentiles {
+                    lower: lower.0,
+                    upper: upper.0,
+                });
+            }
+
+            let mut sorted = data
+                .iter()
+                .copied()
+                .filter(|x| x.is_finite())
+                .collect::<Vec<_>>();
+            sorted.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+            let (Some(lower), Some(upper)) =
+                (percentile(&sorted, lower.0), percentile(&sorted, upper.0))
+            else {
+                return Ok((T::zero(), T::one()));
+            };
+            (lower, upper - lower)
+        }
+    };
+
+    if scale.is_zero() {
+        return Ok((offset, T::one()));
+    }
+
+    Ok((offset, scale))
+}
+
+/// Compute the `p`-th percentile, in `$[0, 100]$`, of the `sorted` data using
+/// linear interpolation between the closest ranks.
+fn percentile<T: Float>(sorted: &[T], p: f64) -> Option<T> {
+    let last = sorted.len().checked_sub(1)?;
+
+    #[expect(clippy::cast_precision_loss)]
+    let rank = (p / 100.0) * (last as f64);
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let lower_rank = (rank.floor() as usize).min(last);
+    let upper_rank = (lower_rank + 1).min(last);
+
+    let lower = *sorted.get(lower_rank)?;
+    let upper = *sorted.get(upper_rank)?;
+    let fraction = T::from(rank - rank.floor())?;
+
+    Some(fraction.mul_add(upper - lower, lower))
+}
+
+#[derive(Serialize, Deserialize)]
+struct CompressionHeader {
+    offset: f64,
+    scale: f64,
+    version: FixedOffsetScaleCodecVersion,
+}
+
 #[cfg(test)]
 mod tests {
+    use numcodecs_identity::IdentityCodec;
+    use numcodecs_round::RoundCodec;
+    use serde_json::json;
+
     use super::*;
 
+    numcodecs_registry::export_global! {
+        static REGISTRY: numcodecs_registry::EmptyRegistry = numcodecs_registry::EmptyRegistry;
+    }
+
     #[test]
     fn identity() {
         let data = (0..1000).map(f64::from).collect::<Vec<_>>();
@@ -237,4 +607,189 @@ mod tests {
             assert_eq!((*r).to_bits(), (*d).to_bits());
         }
     }
+
+    fn identity_codec() -> ErasedDynCodec {
+        ErasedDynCodec::new(IdentityCodec {
+            version: StaticCodecVersion,
+        })
+    }
+
+    #[test]
+    fn adaptive_roundtrip() -> Result<(), FixedOffsetScaleCodecError> {
+        let data = Array::from_shape_fn((10, 20), |(i, j)| {
+            f64::from(u32::try_from(i * 20 + j).unwrap_or(0))
+                .sin()
+                .mul_add(100.0, 42.0)
+        });
+
+        for mode in [
+            FixedOffsetScaleMode::MinMax {
+                codec: identity_codec(),
+            },
+            FixedOffsetScaleMode::MeanStd {
+                codec: identity_codec(),
+            },
+            FixedOffsetScaleMode::Percentile {
+                lower: Percentile(5.0),
+                upper: Percentile(95.0),
+                codec: identity_codec(),
+            },
+        ] {
+            let codec = FixedOffsetScaleCodec {
+                mode,
+                version: StaticCodecVersion,
+            };
+
+            let encoded = codec.encode(AnyCowArray::F64(data.view().into_dyn().into()))?;
+            assert_eq!(encoded.dtype(), AnyArrayDType::U8);
+            assert_eq!(encoded.shape().len(), 1);
+
+            let decoded = codec.decode(encoded.cow())?;
+            let AnyArray::F64(decoded) = decoded else {
+                return Err(FixedOffsetScaleCodecError::UnsupportedDtype(
+                    decoded.dtype(),
+                ));
+            };
+            assert_eq!(decoded.shape(), data.shape());
+            for (r, d) in data.iter().zip(decoded.iter()) {
+                assert!((r - d).abs() < 1e-10, "{r} {d}");
+            }
+
+            let mut decoded_into = Array::zeros(data.raw_dim()).into_dyn();
+            codec.decode_into(
+                encoded.view(),
+                AnyArrayViewMut::F64(decoded_into.view_mut()),
+            )?;
+            assert_eq!(decoded_into, decoded);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[expect(clippy::unwrap_used)]
+    fn wrapped_codec_sees_scaled_data() -> Result<(), FixedOffsetScaleCodecError> {
+        let round: RoundCodec = serde_json::from_value(json!({ "precision": 0.01 })).unwrap();
+        let codec = FixedOffsetScaleCodec {
+            mode: FixedOffsetScaleMode::MinMax {
+                codec: ErasedDynCodec::new(round),
+            },
+            version: StaticCodecVersion,
+        };
+
+        let data = Array::from_shape_fn((8, 16), |(i, j)| {
+            f32::from(u16::try_from(i * 16 + j).unwrap_or(0)).mul_add(0.5, -20.0)
+        });
+
+        let encoded = codec.encode(AnyCowArray::F32(data.view().into_dyn().into()))?;
+
+        let encoded_bytes = encoded.view();
+        let (_, inner, _) = decode_framed::<CompressionHeader>(&framed_bytes(&encoded_bytes)?)?;
+        let AnyArray::F32(inner) = inner else {
+            return Err(FixedOffsetScaleCodecError::UnsupportedDtype(inner.dtype()));
+        };
+        assert_eq!(inner.shape(), data.shape());
+        assert!(inner.iter().all(|x| (0.0..=1.0).contains(x)));
+
+        let AnyArray::F32(decoded) = codec.decode(encoded.cow())? else {
+            return Err(FixedOffsetScaleCodecError::UnsupportedDtype(
+                AnyArrayDType::F32,
+            ));
+        };
+        // the wrapped codec's error of 0.005 is multiplied by the scale
+        for (r, d) in data.iter().zip(decoded.iter()) {
+            assert!((r - d).abs() <= 0.005 * 63.5 * 1.001, "{r} {d}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn min_max_standardizes() -> Result<(), FixedOffsetScaleCodecError> {
+        let data = Array::from_vec(vec![-2.0_f32, 0.0, 6.0]);
+
+        let (offset, s) = adaptive_offset_scale(
+            &data,
+            &FixedOffsetScaleMode::MinMax {
+                codec: identity_codec(),
+            },
+        )?;
+        assert_eq!(offset.to_bits(), (-2.0_f32).to_bits());
+        assert_eq!(s.to_bits(), 8.0_f32.to_bits());
+
+        let encoded = scale(data, offset, s);
+        assert!(encoded.iter().all(|x| (0.0..=1.0).contains(x)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn percentiles() -> Result<(), FixedOffsetScaleCodecError> {
+        let data = Array::from_vec((0..=100).map(f64::from).collect());
+
+        let (offset, scale) = adaptive_offset_scale(
+            &data,
+            &FixedOffsetScaleMode::Percentile {
+                lower: Percentile(25.0),
+                upper: Percentile(75.0),
+                codec: identity_codec(),
+            },
+        )?;
+        assert_eq!(offset.to_bits(), 25.0_f64.to_bits());
+        assert_eq!(scale.to_bits(), 50.0_f64.to_bits());
+
+        assert!(matches!(
+            adaptive_offset_scale(
+                &data,
+                &FixedOffsetScaleMode::Percentile {
+                    lower: Percentile(75.0),
+                    upper: Percentile(25.0),
+                    codec: identity_codec(),
+                },
+            ),
+            Err(FixedOffsetScaleCodecError::InvalidPercentiles { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn adaptive_rejects_non_byte_encoding() {
+        let codec = FixedOffsetScaleCodec {
+            mode: FixedOffsetScaleMode::MeanStd {
+                codec: identity_codec(),
+            },
+            version: StaticCodecVersion,
+        };
+
+        assert!(matches!(
+            codec.decode(AnyCowArray::F64(Array::zeros(4).into_dyn().into())),
+            Err(FixedOffsetScaleCodecError::FramingFailed {
+                source: FramingError::EncodedDataNotBytes {
+                    dtype: AnyArrayDType::F64
+                }
+            })
+        ));
+    }
+
+    #[test]
+    #[expect(clippy::unwrap_used)]
+    fn fixed_mode_is_default() {
+        for config in [
+            json!({ "offset": 1.0, "scale": 2.0 }),
+            json!({ "offset": 1.0, "scale": 2.0, "_version": "1.0.0" }),
+        ] {
+            let codec: FixedOffsetScaleCodec = serde_json::from_value(config).unwrap();
+            assert!(matches!(
+                codec.mode,
+                FixedOffsetScaleMode::Fixed { offset, scale }
+                    if offset.to_bits() == 1.0_f64.to_bits() && scale.to_bits() == 2.0_f64.to_bits()
+            ));
+
+            assert_eq!(
+                serde_json::to_value(&codec).unwrap(),
+                json!({ "offset": 1.0, "scale": 2.0, "_version": "1.1.0" })
+            );
+        }
+    }
 }
@@ -19,11 +19,15 @@
 
 #![allow(clippy::multiple_crate_versions)]
 
-use std::{borrow::Cow, num::NonZeroUsize, ops::AddAssign};
+use std::{
+    borrow::Cow,
+    num::{NonZeroU8, NonZeroUsize},
+    ops::AddAssign,
+};
 
 use burn::{
     backend::{Autodiff, NdArray, ndarray::NdArrayDevice},
-    module::{Module, Param},
+    module::{AutodiffModule, Module, Param},
     nn::loss::{MseLoss, Reduction},
     optim::{AdamConfig, GradientsParams, Optimizer},
     prelude::Backend,
@@ -37,13 +41,13 @@ use burn::{
 };
 use itertools::Itertools;
 use ndarray::{Array, ArrayBase, ArrayView, ArrayViewMut, Data, Dimension, Ix1, Order, Zip};
-use num_traits::{ConstOne, ConstZero, Float as FloatTrait, FromPrimitive};
+use num_traits::{ConstOne, ConstZero, Float as FloatTrait, FromPrimitive, ToPrimitive};
 use numcodecs::{
     AnyArray, AnyArrayAssignError, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray,
     Codec, StaticCodec, StaticCodecConfig, StaticCodecVersion,
 };
 use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::DeserializeOwned};
 use thiserror::Error;
 
 // FIXME: bytemuck 1.24 fails to compile on 1.87
@@ -57,10 +61,12 @@ use ::wasm_bindgen as _;
 use ::serde_json as _;
 
 mod modules;
+mod quantization;
 
 use modules::{Model, ModelConfig, ModelExtra, ModelRecord};
+use quantization::{QuantizedModelExtra, WeightDequantizer, WeightQuantizer};
 
-type FourierNetworkCodecVersion = StaticCodecVersion<0, 1, 0>;
+type FourierNetworkCodecVersion = StaticCodecVersion<0, 1, 1>;
 
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -79,7 +85,12 @@ pub struct FourierNetworkCodec {
     pub num_blocks: NonZeroUsize,
     /// The learning rate for the `Adam` optimizer
     pub learning_rate: Positive<f64>,
+    /// The schedule with which the learning rate is adjusted during training
+    #[serde(default)]
+    pub learning_rate_schedule: LearningRateSchedule,
     /// The number of epochs for which the network is trained
+    ///
+    /// If early stopping is enabled, this is the maximum number of epochs.
     pub num_epochs: usize,
     /// The optional mini-batch size used during training
     ///
@@ -89,6 +100,19 @@ pub struct FourierNetworkCodec {
     #[serde(deserialize_with = "deserialize_required_option")]
     #[schemars(required, extend("type" = ["integer", "null"]))]
     pub mini_batch_size: Option<NonZeroUsize>,
+    /// The optional early stopping criterion
+    ///
+    /// If enabled, training stops before `num_epochs` are reached once the
+    /// reconstruction error meets the target or stops improving.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub early_stopping: Option<EarlyStopping>,
+    /// The post-training quantization of the network weights
+    #[serde(default)]
+    pub weight_quantization: WeightQuantization,
+    /// Whether the encoded network weights are additionally entropy coded
+    /// using DEFLATE
+    #[serde(default)]
+    pub weight_entropy_coding: bool,
     /// The seed for the random number generator used during encoding
     pub seed: u64,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
@@ -96,6 +120,123 @@ pub struct FourierNetworkCodec {
     pub version: FourierNetworkCodecVersion,
 }
 
+#[derive(Copy, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "schedule")]
+#[serde(deny_unknown_fields)]
+/// Schedule with which the learning rate is adjusted after every epoch
+pub enum LearningRateSchedule {
+    /// The learning rate stays constant
+    #[default]
+    #[serde(rename = "constant")]
+    Constant,
+    /// The learning rate decays exponentially, i.e. it is multiplied by
+    /// `decay` after every epoch
+    #[serde(rename = "exponential")]
+    Exponential {
+        /// The multiplicative decay factor, which should be at most one
+        decay: Positive<f64>,
+    },
+    /// The learning rate is annealed from the initial `learning_rate` down
+    /// to `min_learning_rate` along a half cosine wave over `num_epochs`
+    #[serde(rename = "cosine")]
+    Cosine {
+        /// The minimum learning rate that is reached in the final epoch
+        min_learning_rate: Positive<f64>,
+    },
+}
+
+impl LearningRateSchedule {
+    /// Computes the learning rate for the one-based `epoch` out of
+    /// `num_epochs`, starting from the initial `learning_rate`.
+    #[must_use]
+    pub fn learning_rate(
+        &self,
+        Positive(learning_rate): Positive<f64>,
+        epoch: usize,
+        num_epochs: usize,
+    ) -> f64 {
+        #[expect(clippy::cast_precision_loss)]
+        let (epoch, num_epochs) = (
+            epoch.saturating_sub(1) as f64,
+            num_epochs.saturating_sub(1).max(1) as f64,
+        );
+
+        match self {
+            Self::Constant => learning_rate,
+            Self::Exponential {
+                decay: Positive(decay),
+            } => learning_rate * decay.powf(epoch),
+            Self::Cosine {
+                min_learning_rate: Positive(min_learning_rate),
+            } => {
+                let progress = (epoch / num_epochs).min(1.0);
+                let cosine = 0.5 * (1.0 + (core::f64::consts::PI * progress).cos());
+                (learning_rate - min_learning_rate).mul_add(cosine, *min_learning_rate)
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+/// Early stopping criterion, with which training stops once the
+/// reconstruction error has reached a target or stopped improving
+///
+/// The reconstruction error is evaluated after every epoch by making a
+/// prediction for the full data, exactly as during decoding.
+pub struct EarlyStopping {
+    /// The metric with which the reconstruction error is measured
+    pub metric: ErrorMetric,
+    /// The target reconstruction error, in the units of the data, at or below
+    /// which training stops
+    pub target_error: Positive<f64>,
+    /// The optional number of epochs without an improvement in the
+    /// reconstruction error after which training stops
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub patience: Option<NonZeroUsize>,
+}
+
+#[derive(Copy, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "quantization")]
+#[serde(deny_unknown_fields)]
+/// Post-training quantization of the network weights
+///
+/// Quantization is applied once training has finished. The reconstruction
+/// error of the quantized network can be measured with the
+/// `reconstruction_error` function.
+pub enum WeightQuantization {
+    /// The weights are stored with full precision
+    #[default]
+    #[serde(rename = "none")]
+    None,
+    /// The weights of each tensor are linearly quantized between the
+    /// tensor's minimum and maximum
+    #[serde(rename = "linear")]
+    Linear {
+        /// The number of bits per quantized weight, at most 16
+        bits: NonZeroU8,
+    },
+    /// The mantissa of each weight is rounded to keep only `keepbits` bits,
+    /// after which the dropped trailing bits are not stored
+    #[serde(rename = "bit-round")]
+    BitRound {
+        /// The number of mantissa bits to keep, at most 23 for `float32` and
+        /// 52 for `float64` data
+        keepbits: u8,
+    },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+/// Metric with which the reconstruction error is measured
+pub enum ErrorMetric {
+    /// The maximum absolute error
+    #[serde(rename = "max-abs")]
+    MaxAbs,
+    /// The root mean square error
+    #[serde(rename = "rmse")]
+    Rmse,
+}
+
 // using this wrapper function makes an Option<T> required
 fn deserialize_required_option<'de, T: serde::Deserialize<'de>, D: serde::Deserializer<'de>>(
     deserializer: D,
@@ -109,29 +250,37 @@ impl Codec for FourierNetworkCodec {
     fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
         match data {
             AnyCowArray::F32(data) => Ok(AnyArray::U8(
-                encode::<f32, _, _, Autodiff<NdArray<f32>>>(
+                encode_with_training::<f32, _, _, Autodiff<NdArray<f32>>>(
                     &NdArrayDevice::Cpu,
                     data,
                     self.fourier_features,
                     self.fourier_scale,
                     self.num_blocks,
                     self.learning_rate,
+                    self.learning_rate_schedule,
                     self.num_epochs,
                     self.mini_batch_size,
+                    self.early_stopping,
+                    self.weight_quantization,
+                    self.weight_entropy_coding,
                     self.seed,
                 )?
                 .into_dyn(),
             )),
             AnyCowArray::F64(data) => Ok(AnyArray::U8(
-                encode::<f64, _, _, Autodiff<NdArray<f64>>>(
+                encode_with_training::<f64, _, _, Autodiff<NdArray<f64>>>(
                     &NdArrayDevice::Cpu,
                     data,
                     self.fourier_features,
                     self.fourier_scale,
                     self.num_blocks,
                     self.learning_rate,
+                    self.learning_rate_schedule,
                     self.num_epochs,
                     self.mini_batch_size,
+                    self.early_stopping,
+                    self.weight_quantization,
+                    self.weight_entropy_coding,
                     self.seed,
                 )?
                 .into_dyn(),
@@ -162,20 +311,28 @@ impl Codec for FourierNetworkCodec {
         };
 
         match decoded {
-            AnyArrayViewMut::F32(decoded) => decode_into::<f32, _, _, NdArray<f32>>(
-                &NdArrayDevice::Cpu,
-                encoded,
-                decoded,
-                self.fourier_features,
-                self.num_blocks,
-            ),
-            AnyArrayViewMut::F64(decoded) => decode_into::<f64, _, _, NdArray<f64>>(
-                &NdArrayDevice::Cpu,
-                encoded,
-                decoded,
-                self.fourier_features,
-                self.num_blocks,
-            ),
+            AnyArrayViewMut::F32(decoded) => {
+                decode_into_with_weight_coding::<f32, _, _, NdArray<f32>>(
+                    &NdArrayDevice::Cpu,
+                    encoded,
+                    decoded,
+                    self.fourier_features,
+                    self.num_blocks,
+                    self.weight_quantization,
+                    self.weight_entropy_coding,
+                )
+            }
+            AnyArrayViewMut::F64(decoded) => {
+                decode_into_with_weight_coding::<f64, _, _, NdArray<f64>>(
+                    &NdArrayDevice::Cpu,
+                    encoded,
+                    decoded,
+                    self.fourier_features,
+                    self.num_blocks,
+                    self.weight_quantization,
+                    self.weight_entropy_coding,
+                )
+            }
             decoded => Err(FourierNetworkCodecError::UnsupportedDtype(decoded.dtype())),
         }
     }
@@ -276,6 +433,31 @@ pub enum FourierNetworkCodecError {
         /// The unexpected shape of the encoded array
         shape: Vec<usize>,
     },
+    /// [`FourierNetworkCodec`] cannot resume training from a checkpoint with
+    /// a mismatching network architecture or data dimensionality
+    #[error(
+        "FourierNetwork cannot resume training from a checkpoint with a mismatching network architecture or data dimensionality"
+    )]
+    MismatchedCheckpoint,
+    /// [`FourierNetworkCodec`] cannot quantize the network weights with more
+    /// bits than the data type supports
+    #[error(
+        "FourierNetwork cannot quantize the network weights with {bits} bits, at most {max} bits are supported"
+    )]
+    ExcessiveQuantizationBits {
+        /// The requested number of bits
+        bits: u8,
+        /// The maximum supported number of bits
+        max: u32,
+    },
+    /// [`FourierNetworkCodec`] failed to encode or decode the quantized or
+    /// entropy coded network weights
+    #[error("FourierNetwork failed to encode or decode the network weights")]
+    WeightCodingFailed {
+        /// The source of the error
+        #[from]
+        source: WeightCodingError,
+    },
     /// [`FourierNetworkCodec`] cannot decode into the provided array
     #[error("FourierNetwork cannot decode into the provided array")]
     MismatchedDecodeIntoArray {
@@ -290,37 +472,92 @@ pub enum FourierNetworkCodecError {
 /// Opaque error for when an error occurs in the neural network
 pub struct NeuralNetworkError(RecorderError);
 
+#[derive(Debug, Error)]
+#[error(transparent)]
+/// Opaque error for when encoding or decoding the network weights fails
+pub struct WeightCodingError(WeightCodingErrorKind);
+
+#[derive(Debug, Error)]
+enum WeightCodingErrorKind {
+    #[error("failed to (de)serialize the quantized network weights")]
+    Postcard(#[from] postcard::Error),
+    #[error("failed to inflate the entropy coded network weights")]
+    Inflate(#[from] miniz_oxide::inflate::DecompressError),
+    #[error("the encoded network weights do not match the network architecture")]
+    MismatchedArchitecture,
+}
+
 /// Floating point types.
 pub trait FloatExt:
-    AddAssign + BurnElement + ConstOne + ConstZero + FloatTrait + FromPrimitive
+    AddAssign
+    + BurnElement
+    + ConstOne
+    + ConstZero
+    + FloatTrait
+    + FromPrimitive
+    + Serialize
+    + DeserializeOwned
 {
     /// The precision of this floating point type
     type Precision: PrecisionSettings;
 
+    /// The number of explicitly stored mantissa bits
+    const MANTISSA_BITS: u32;
+    /// The total number of bits
+    const BITS: u32;
+
     /// Convert a usize to a floating point number
     fn from_usize(x: usize) -> Self;
+
+    /// Returns the raw binary representation, zero-extended to 64 bits
+    fn to_bits_u64(self) -> u64;
+
+    /// Creates a floating point number from the raw binary representation in
+    /// the lowest [`Self::BITS`] bits
+    fn from_bits_u64(bits: u64) -> Self;
 }
 
 impl FloatExt for f32 {
     type Precision = FullPrecisionSettings;
 
+    const MANTISSA_BITS: u32 = Self::MANTISSA_DIGITS - 1;
+    const BITS: u32 = 32;
+
     #[expect(clippy::cast_precision_loss)]
     fn from_usize(x: usize) -> Self {
         x as Self
     }
+
+    fn to_bits_u64(self) -> u64 {
+        u64::from(self.to_bits())
+    }
+
+    #[expect(clippy::cast_possible_truncation)] // only the lowest 32 bits are used
+    fn from_bits_u64(bits: u64) -> Self {
+        Self::from_bits(bits as u32)
+    }
 }
 
 impl FloatExt for f64 {
     type Precision = DoublePrecisionSettings;
 
+    const MANTISSA_BITS: u32 = Self::MANTISSA_DIGITS - 1;
+    const BITS: u32 = 64;
+
     #[expect(clippy::cast_precision_loss)]
     fn from_usize(x: usize) -> Self {
         x as Self
     }
+
+    fn to_bits_u64(self) -> u64 {
+        self.to_bits()
+    }
+
+    fn from_bits_u64(bits: u64) -> Self {
+        Self::from_bits(bits)
+    }
 }
 
-#[expect(clippy::similar_names)] // train_xs and train_ys
-#[expect(clippy::missing_panics_doc)] // only panics on implementation bugs
 #[expect(clippy::too_many_arguments)] // FIXME
 /// Encodes the `data` by training a fourier feature neural network.
 ///
@@ -334,6 +571,9 @@ impl FloatExt for f64 {
 ///
 /// All random numbers are generated using the provided `seed`.
 ///
+/// See [`encode_with_training`] for learning rate schedules, early stopping,
+/// and weight quantization.
+///
 /// # Errors
 ///
 /// Errors with
@@ -351,6 +591,218 @@ pub fn encode<T: FloatExt, S: Data<Elem = T>, D: Dimension, B: AutodiffBackend<F
     num_epochs: usize,
     mini_batch_size: Option<NonZeroUsize>,
     seed: u64,
+) -> Result<Array<u8, Ix1>, FourierNetworkCodecError> {
+    encode_with_training::<T, S, D, B>(
+        device,
+        data,
+        fourier_features,
+        fourier_scale,
+        num_blocks,
+        learning_rate,
+        LearningRateSchedule::Constant,
+        num_epochs,
+        mini_batch_size,
+        None,
+        WeightQuantization::None,
+        false,
+        seed,
+    )
+}
+
+#[expect(clippy::too_many_arguments)] // FIXME
+/// Encodes the `data` by training a fourier feature neural network, like
+/// [`encode`], with additional control over the training and the encoding
+/// of the network weights.
+///
+/// The `fourier_features` are randomly sampled from a normal distribution with
+/// zero mean and `fourier_scale` standard deviation.
+///
+/// The neural network consists of `num_blocks` blocks.
+///
+/// The network is trained for `num_epochs` using the `learning_rate`, which
+/// is adjusted by the `learning_rate_schedule`, and mini-batches of
+/// `mini_batch_size` if mini-batching is enabled. If `early_stopping` is
+/// enabled, training may stop before `num_epochs` are reached.
+///
+/// After training, the network weights are quantized using the
+/// `weight_quantization` and, if `weight_entropy_coding` is enabled, the
+/// encoded network is compressed using DEFLATE. The resulting reconstruction
+/// error can be measured with [`reconstruction_error`].
+///
+/// All random numbers are generated using the provided `seed`.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`FourierNetworkCodecError::NonFiniteData`] if any data element is
+///   non-finite (infinite or NaN)
+/// - [`FourierNetworkCodecError::ExcessiveQuantizationBits`] if the
+///   `weight_quantization` uses more bits than are supported for `T`
+/// - [`FourierNetworkCodecError::NeuralNetworkError`] if an error occurs during
+///   the neural network computation
+/// - [`FourierNetworkCodecError::WeightCodingFailed`] if encoding the
+///   quantized network weights failed
+pub fn encode_with_training<
+    T: FloatExt,
+    S: Data<Elem = T>,
+    D: Dimension,
+    B: AutodiffBackend<FloatElem = T>,
+>(
+    device: &B::Device,
+    data: ArrayBase<S, D>,
+    fourier_features: NonZeroUsize,
+    fourier_scale: Positive<f64>,
+    num_blocks: NonZeroUsize,
+    learning_rate: Positive<f64>,
+    learning_rate_schedule: LearningRateSchedule,
+    num_epochs: usize,
+    mini_batch_size: Option<NonZeroUsize>,
+    early_stopping: Option<EarlyStopping>,
+    weight_quantization: WeightQuantization,
+    weight_entropy_coding: bool,
+    seed: u64,
+) -> Result<Array<u8, Ix1>, FourierNetworkCodecError> {
+    encode_with_initialization::<T, S, D, B>(
+        device,
+        data,
+        Initialization::Random { fourier_scale },
+        fourier_features,
+        num_blocks,
+        learning_rate,
+        learning_rate_schedule,
+        num_epochs,
+        mini_batch_size,
+        early_stopping,
+        weight_quantization,
+        weight_entropy_coding,
+        seed,
+    )
+}
+
+#[expect(clippy::too_many_arguments)] // FIXME
+/// Encodes the `data` by resuming the training of a fourier feature neural
+/// network from a `checkpoint`.
+///
+/// The `checkpoint` must be the output of a previous [`encode`],
+/// [`encode_with_training`], or [`encode_from_checkpoint`] call on data of the same dimensionality and
+/// with the same number of `fourier_features` and `num_blocks`, and the same
+/// `weight_quantization` and `weight_entropy_coding`. Its fourier
+/// features, data normalization, and network weights are reused. Note that the
+/// state of the `Adam` optimizer is not checkpointed and starts afresh.
+///
+/// The network is then trained for (up to) `num_epochs` more epochs and
+/// encoded exactly like in [`encode_with_training`]. If the checkpoint was quantized, the
+/// training continues from the quantized weights.
+///
+/// All random numbers are generated using the provided `seed`.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`FourierNetworkCodecError::NonFiniteData`] if any data element is
+///   non-finite (infinite or NaN)
+/// - [`FourierNetworkCodecError::ExcessiveQuantizationBits`] if the
+///   `weight_quantization` uses more bits than are supported for `T`
+/// - [`FourierNetworkCodecError::MismatchedCheckpoint`] if the `checkpoint`
+///   has a different network architecture or data dimensionality
+/// - [`FourierNetworkCodecError::NeuralNetworkError`] if an error occurs during
+///   the neural network computation
+/// - [`FourierNetworkCodecError::WeightCodingFailed`] if decoding the
+///   `checkpoint` or encoding the quantized network weights failed
+pub fn encode_from_checkpoint<
+    T: FloatExt,
+    S: Data<Elem = T>,
+    C: Data<Elem = u8>,
+    D: Dimension,
+    B: AutodiffBackend<FloatElem = T>,
+>(
+    device: &B::Device,
+    data: ArrayBase<S, D>,
+    checkpoint: ArrayBase<C, Ix1>,
+    fourier_features: NonZeroUsize,
+    num_blocks: NonZeroUsize,
+    learning_rate: Positive<f64>,
+    learning_rate_schedule: LearningRateSchedule,
+    num_epochs: usize,
+    mini_batch_size: Option<NonZeroUsize>,
+    early_stopping: Option<EarlyStopping>,
+    weight_quantization: WeightQuantization,
+    weight_entropy_coding: bool,
+    seed: u64,
+) -> Result<Array<u8, Ix1>, FourierNetworkCodecError> {
+    if data.is_empty() {
+        return Ok(Array::from_vec(Vec::new()));
+    }
+
+    if checkpoint.is_empty() {
+        return Err(FourierNetworkCodecError::MismatchedCheckpoint);
+    }
+
+    check_weight_quantization::<T>(weight_quantization)?;
+
+    let Some(checkpoint) = decode_model::<T, _, B>(
+        device,
+        checkpoint,
+        data.ndim(),
+        fourier_features,
+        num_blocks,
+        weight_quantization,
+        weight_entropy_coding,
+    )?
+    else {
+        return Err(FourierNetworkCodecError::MismatchedCheckpoint);
+    };
+
+    encode_with_initialization(
+        device,
+        data,
+        Initialization::Checkpoint(Box::new(checkpoint)),
+        fourier_features,
+        num_blocks,
+        learning_rate,
+        learning_rate_schedule,
+        num_epochs,
+        mini_batch_size,
+        early_stopping,
+        weight_quantization,
+        weight_entropy_coding,
+        seed,
+    )
+}
+
+enum Initialization<B: Backend> {
+    Random { fourier_scale: Positive<f64> },
+    Checkpoint(Box<DecodedModel<B>>),
+}
+
+struct DecodedModel<B: Backend> {
+    model: Model<B>,
+    b_t: Tensor<B, 2, Float>,
+    mean: B::FloatElem,
+    stdv: B::FloatElem,
+}
+
+#[expect(clippy::similar_names)] // train_xs and train_ys
+#[expect(clippy::too_many_arguments)] // FIXME
+fn encode_with_initialization<
+    T: FloatExt,
+    S: Data<Elem = T>,
+    D: Dimension,
+    B: AutodiffBackend<FloatElem = T>,
+>(
+    device: &B::Device,
+    data: ArrayBase<S, D>,
+    initialization: Initialization<B>,
+    fourier_features: NonZeroUsize,
+    num_blocks: NonZeroUsize,
+    learning_rate: Positive<f64>,
+    learning_rate_schedule: LearningRateSchedule,
+    num_epochs: usize,
+    mini_batch_size: Option<NonZeroUsize>,
+    early_stopping: Option<EarlyStopping>,
+    weight_quantization: WeightQuantization,
+    weight_entropy_coding: bool,
+    seed: u64,
 ) -> Result<Array<u8, Ix1>, FourierNetworkCodecError> {
     let Some(mean) = data.mean() else {
         return Ok(Array::from_vec(Vec::new()));
@@ -362,13 +814,30 @@ pub fn encode<T: FloatExt, S: Data<Elem = T>, D: Dimension, B: AutodiffBackend<F
         return Err(FourierNetworkCodecError::NonFiniteData);
     }
 
+    check_weight_quantization::<T>(weight_quantization)?;
+
     B::seed(seed);
 
-    let b_t = Tensor::<B, 2, Float>::random(
-        [data.ndim(), fourier_features.get()],
-        Distribution::Normal(0.0, fourier_scale.0),
-        device,
-    );
+    let (model, b_t, mean, stdv) = match initialization {
+        Initialization::Random { fourier_scale } => {
+            let b_t = Tensor::<B, 2, Float>::random(
+                [data.ndim(), fourier_features.get()],
+                Distribution::Normal(0.0, fourier_scale.0),
+                device,
+            );
+            let model = ModelConfig::new(fourier_features, num_blocks).init(device);
+            (model, b_t, mean, stdv)
+        }
+        Initialization::Checkpoint(checkpoint) => {
+            let DecodedModel {
+                model,
+                b_t,
+                mean,
+                stdv,
+            } = *checkpoint;
+            (model, b_t, mean, stdv)
+        }
+    };
 
     let train_xs = flat_grid_like(&data, device);
     let train_xs = fourier_mapping(train_xs, b_t.clone());
@@ -389,43 +858,111 @@ pub fn encode<T: FloatExt, S: Data<Elem = T>, D: Dimension, B: AutodiffBackend<F
         device,
         &train_xs,
         &train_ys,
-        fourier_features,
-        num_blocks,
+        model,
         learning_rate,
+        learning_rate_schedule,
         num_epochs,
         mini_batch_size,
+        early_stopping,
         stdv,
     );
 
-    let extra = ModelExtra {
-        model: model.into_record(),
-        b_t: Param::from_tensor(b_t).set_require_grad(false),
-        mean: Param::from_tensor(Tensor::from_data(
-            TensorData::new(vec![mean], vec![1]),
-            device,
-        ))
-        .set_require_grad(false),
-        stdv: Param::from_tensor(Tensor::from_data(
-            TensorData::new(vec![stdv], vec![1]),
-            device,
-        ))
-        .set_require_grad(false),
-        version: StaticCodecVersion,
-    };
+    let encoded = encode_model(
+        device,
+        model,
+        b_t,
+        mean,
+        stdv,
+        &train_xs,
+        &train_ys,
+        weight_quantization,
+    )?;
 
-    let recorder = BinBytesRecorder::<T::Precision>::new();
-    let encoded = recorder.record(extra, ()).map_err(NeuralNetworkError)?;
+    let encoded = if weight_entropy_coding {
+        miniz_oxide::deflate::compress_to_vec(&encoded, DEFLATE_LEVEL)
+    } else {
+        encoded
+    };
 
     Ok(Array::from_vec(encoded))
 }
 
-#[expect(clippy::missing_panics_doc)] // only panics on implementation bugs
+#[expect(clippy::similar_names)] // train_xs and train_ys
+#[expect(clippy::too_many_arguments)] // FIXME
+fn encode_model<T: FloatExt, B: AutodiffBackend<FloatElem = T>>(
+    device: &B::Device,
+    model: Model<B>,
+    b_t: Tensor<B, 2, Float>,
+    mean: T,
+    stdv: T,
+    train_xs: &Tensor<B, 2, Float>,
+    train_ys: &Tensor<B, 2, Float>,
+    weight_quantization: WeightQuantization,
+) -> Result<Vec<u8>, FourierNetworkCodecError> {
+    let encoded = match weight_quantization {
+        WeightQuantization::None => {
+            let extra = ModelExtra {
+                model: model.into_record(),
+                b_t: Param::from_tensor(b_t).set_require_grad(false),
+                mean: Param::from_tensor(Tensor::from_data(
+                    TensorData::new(vec![mean], vec![1]),
+                    device,
+                ))
+                .set_require_grad(false),
+                stdv: Param::from_tensor(Tensor::from_data(
+                    TensorData::new(vec![stdv], vec![1]),
+                    device,
+                ))
+                .set_require_grad(false),
+                version: StaticCodecVersion,
+            };
+
+            let recorder = BinBytesRecorder::<T::Precision>::new();
+            recorder.record(extra, ()).map_err(NeuralNetworkError)?
+        }
+        weight_quantization => {
+            let mut quantizer = WeightQuantizer::new(weight_quantization);
+            let model = model.map(&mut quantizer);
+            let (ranges, weights) = quantizer.finish();
+
+            let rmse =
+                model_reconstruction_error(&model, train_xs, train_ys, ErrorMetric::Rmse, stdv);
+            let max_abs =
+                model_reconstruction_error(&model, train_xs, train_ys, ErrorMetric::MaxAbs, stdv);
+            log::info!(
+                "quantized network weights to {} bytes with rmse={rmse:0.3} and max-abs={max_abs:0.3}",
+                weights.len()
+            );
+
+            #[expect(clippy::unwrap_used)] // same generic type, check must succeed
+            let b_t = b_t.into_data().to_vec::<T>().unwrap();
+
+            let extra = QuantizedModelExtra {
+                b_t,
+                mean,
+                stdv,
+                ranges,
+                weights,
+                version: StaticCodecVersion,
+            };
+
+            postcard::to_extend(&extra, Vec::new())
+                .map_err(|err| WeightCodingError(WeightCodingErrorKind::Postcard(err)))?
+        }
+    };
+
+    Ok(encoded)
+}
+
 /// Decodes the `encoded` data into the `decoded` output array by making a
 /// prediction using the fourier feature neural network.
 ///
 /// The network must have been trained during [`encode`] using the same number
 /// of `feature_features` and `num_blocks`.
 ///
+/// See [`decode_into_with_weight_coding`] for networks whose weights were
+/// quantized or entropy coded by [`encode_with_training`].
+///
 /// # Errors
 ///
 /// Errors with
@@ -433,12 +970,56 @@ pub fn encode<T: FloatExt, S: Data<Elem = T>, D: Dimension, B: AutodiffBackend<F
 ///   array is empty but the decoded array is not
 /// - [`FourierNetworkCodecError::NeuralNetworkError`] if an error occurs during
 ///   the neural network computation
+/// - [`FourierNetworkCodecError::WeightCodingFailed`] if the network weights
+///   do not match the network architecture
 pub fn decode_into<T: FloatExt, S: Data<Elem = u8>, D: Dimension, B: Backend<FloatElem = T>>(
+    device: &B::Device,
+    encoded: ArrayBase<S, Ix1>,
+    decoded: ArrayViewMut<T, D>,
+    fourier_features: NonZeroUsize,
+    num_blocks: NonZeroUsize,
+) -> Result<(), FourierNetworkCodecError> {
+    decode_into_with_weight_coding::<T, S, D, B>(
+        device,
+        encoded,
+        decoded,
+        fourier_features,
+        num_blocks,
+        WeightQuantization::None,
+        false,
+    )
+}
+
+#[expect(clippy::missing_panics_doc)] // only panics on implementation bugs
+/// Decodes the `encoded` data into the `decoded` output array by making a
+/// prediction using the fourier feature neural network.
+///
+/// The network must have been trained during [`encode_with_training`] using
+/// the same number of `feature_features` and `num_blocks`, and encoded using
+/// the same `weight_quantization` and `weight_entropy_coding`.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`FourierNetworkCodecError::MismatchedDecodeIntoArray`] if the encoded
+///   array is empty but the decoded array is not
+/// - [`FourierNetworkCodecError::NeuralNetworkError`] if an error occurs during
+///   the neural network computation
+/// - [`FourierNetworkCodecError::WeightCodingFailed`] if decoding the
+///   network weights failed or they do not match the network architecture
+pub fn decode_into_with_weight_coding<
+    T: FloatExt,
+    S: Data<Elem = u8>,
+    D: Dimension,
+    B: Backend<FloatElem = T>,
+>(
     device: &B::Device,
     encoded: ArrayBase<S, Ix1>,
     mut decoded: ArrayViewMut<T, D>,
     fourier_features: NonZeroUsize,
     num_blocks: NonZeroUsize,
+    weight_quantization: WeightQuantization,
+    weight_entropy_coding: bool,
 ) -> Result<(), FourierNetworkCodecError> {
     if encoded.is_empty() {
         if decoded.is_empty() {
@@ -453,17 +1034,23 @@ pub fn decode_into<T: FloatExt, S: Data<Elem = u8>, D: Dimension, B: Backend<Flo
         });
     }
 
-    let encoded = encoded.into_owned().into_raw_vec_and_offset().0;
-
-    let recorder = BinBytesRecorder::<T::Precision>::new();
-    let record: ModelExtra<B> = recorder.load(encoded, device).map_err(NeuralNetworkError)?;
-
-    let model = ModelConfig::new(fourier_features, num_blocks)
-        .init(device)
-        .load_record(record.model);
-    let b_t = record.b_t.into_value();
-    let mean = record.mean.into_value().into_scalar();
-    let stdv = record.stdv.into_value().into_scalar();
+    let Some(DecodedModel {
+        model,
+        b_t,
+        mean,
+        stdv,
+    }) = decode_model::<T, _, B>(
+        device,
+        encoded,
+        decoded.ndim(),
+        fourier_features,
+        num_blocks,
+        weight_quantization,
+        weight_entropy_coding,
+    )?
+    else {
+        return Err(WeightCodingError(WeightCodingErrorKind::MismatchedArchitecture).into());
+    };
 
     let test_xs = flat_grid_like(&decoded, device);
     let test_xs = fourier_mapping(test_xs, b_t);
@@ -479,6 +1066,154 @@ pub fn decode_into<T: FloatExt, S: Data<Elem = u8>, D: Dimension, B: Backend<Flo
     Ok(())
 }
 
+#[expect(clippy::too_many_arguments)] // FIXME
+/// Measures the reconstruction error, in the units of the data, with which
+/// the `encoded` network reproduces the `data`, using the `metric`.
+///
+/// This can be used to report the error that was introduced by the
+/// `weight_quantization`, since decoding uses the quantized network weights.
+///
+/// The `encoded` network must have been produced by [`encode`],
+/// [`encode_with_training`], or [`encode_from_checkpoint`] on the `data` using the same number of
+/// `fourier_features` and `num_blocks`, and the same `weight_quantization`
+/// and `weight_entropy_coding`.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`FourierNetworkCodecError::MismatchedDecodeIntoArray`] if the encoded
+///   array is empty but the data is not
+/// - [`FourierNetworkCodecError::NeuralNetworkError`] if an error occurs during
+///   the neural network computation
+/// - [`FourierNetworkCodecError::WeightCodingFailed`] if decoding the
+///   network weights failed or they do not match the network architecture
+pub fn reconstruction_error<
+    T: FloatExt,
+    S: Data<Elem = T>,
+    C: Data<Elem = u8>,
+    D: Dimension,
+    B: Backend<FloatElem = T>,
+>(
+    device: &B::Device,
+    data: &ArrayBase<S, D>,
+    encoded: ArrayBase<C, Ix1>,
+    metric: ErrorMetric,
+    fourier_features: NonZeroUsize,
+    num_blocks: NonZeroUsize,
+    weight_quantization: WeightQuantization,
+    weight_entropy_coding: bool,
+) -> Result<T, FourierNetworkCodecError> {
+    let mut decoded = Array::zeros(data.raw_dim());
+    decode_into_with_weight_coding::<T, _, _, B>(
+        device,
+        encoded,
+        decoded.view_mut(),
+        fourier_features,
+        num_blocks,
+        weight_quantization,
+        weight_entropy_coding,
+    )?;
+
+    let error = match metric {
+        ErrorMetric::MaxAbs => Zip::from(data)
+            .and(&decoded)
+            .fold(T::ZERO, |error, &x, &y| error.max((x - y).abs())),
+        ErrorMetric::Rmse => {
+            let mut sum = T::ZERO;
+            Zip::from(data)
+                .and(&decoded)
+                .for_each(|&x, &y| sum += (x - y) * (x - y));
+            (sum / <T as FloatExt>::from_usize(data.len().max(1))).sqrt()
+        }
+    };
+
+    Ok(error)
+}
+
+const DEFLATE_LEVEL: u8 = 9;
+
+fn check_weight_quantization<T: FloatExt>(
+    weight_quantization: WeightQuantization,
+) -> Result<(), FourierNetworkCodecError> {
+    let bits = match weight_quantization {
+        WeightQuantization::None => return Ok(()),
+        WeightQuantization::Linear { bits } => bits.get(),
+        WeightQuantization::BitRound { keepbits } => keepbits,
+    };
+
+    quantization::excessive_quantization_bits::<T>(weight_quantization).map_or(Ok(()), |max| {
+        Err(FourierNetworkCodecError::ExcessiveQuantizationBits { bits, max })
+    })
+}
+
+/// Decodes the network from the `encoded` bytes, returning `None` if it does
+/// not match the network architecture or data dimensionality
+fn decode_model<T: FloatExt, S: Data<Elem = u8>, B: Backend<FloatElem = T>>(
+    device: &B::Device,
+    encoded: ArrayBase<S, Ix1>,
+    ndim: usize,
+    fourier_features: NonZeroUsize,
+    num_blocks: NonZeroUsize,
+    weight_quantization: WeightQuantization,
+    weight_entropy_coding: bool,
+) -> Result<Option<DecodedModel<B>>, FourierNetworkCodecError> {
+    let encoded = encoded.into_owned().into_raw_vec_and_offset().0;
+
+    let encoded = if weight_entropy_coding {
+        miniz_oxide::inflate::decompress_to_vec(&encoded)
+            .map_err(|err| WeightCodingError(WeightCodingErrorKind::Inflate(err)))?
+    } else {
+        encoded
+    };
+
+    let config = ModelConfig::new(fourier_features, num_blocks);
+
+    if matches!(weight_quantization, WeightQuantization::None) {
+        let recorder = BinBytesRecorder::<T::Precision>::new();
+        let record: ModelExtra<B> = recorder.load(encoded, device).map_err(NeuralNetworkError)?;
+
+        if record.b_t.dims() != [ndim, fourier_features.get()]
+            || !config.is_compatible_with(&record.model)
+        {
+            return Ok(None);
+        }
+
+        return Ok(Some(DecodedModel {
+            model: config.init(device).load_record(record.model),
+            b_t: record.b_t.into_value(),
+            mean: record.mean.into_value().into_scalar(),
+            stdv: record.stdv.into_value().into_scalar(),
+        }));
+    }
+
+    check_weight_quantization::<T>(weight_quantization)?;
+
+    let record: QuantizedModelExtra<T> = postcard::from_bytes(&encoded)
+        .map_err(|err| WeightCodingError(WeightCodingErrorKind::Postcard(err)))?;
+
+    if record.b_t.len() != ndim * fourier_features.get() {
+        return Ok(None);
+    }
+
+    let mut dequantizer =
+        WeightDequantizer::new(weight_quantization, &record.ranges, &record.weights);
+    let model = config.init(device).map(&mut dequantizer);
+
+    if !dequantizer.finish() {
+        return Ok(None);
+    }
+
+    Ok(Some(DecodedModel {
+        model,
+        b_t: Tensor::from_data(
+            TensorData::new(record.b_t, [ndim, fourier_features.get()]),
+            device,
+        ),
+        mean: record.mean,
+        stdv: record.stdv,
+    }))
+}
+
 fn flat_grid_like<T: FloatExt, S: Data<Elem = T>, D: Dimension, B: Backend<FloatElem = T>>(
     a: &ArrayBase<S, D>,
     device: &B::Device,
@@ -516,24 +1251,33 @@ fn train<T: FloatExt, B: AutodiffBackend<FloatElem = T>>(
     device: &B::Device,
     train_xs: &Tensor<B, 2, Float>,
     train_ys: &Tensor<B, 2, Float>,
-    fourier_features: NonZeroUsize,
-    num_blocks: NonZeroUsize,
+    mut model: Model<B>,
     learning_rate: Positive<f64>,
+    learning_rate_schedule: LearningRateSchedule,
     num_epochs: usize,
     mini_batch_size: Option<NonZeroUsize>,
+    early_stopping: Option<EarlyStopping>,
     stdv: T,
 ) -> Model<B> {
     let num_samples = train_ys.shape().num_elements();
     let num_batches = mini_batch_size.map(|b| num_samples.div_ceil(b.get()));
 
-    let mut model = ModelConfig::new(fourier_features, num_blocks).init(device);
     let mut optim = AdamConfig::new().init();
 
-    let mut best_loss = T::infinity();
+    // without early stopping, the checkpoint with the lowest loss is kept,
+    //  otherwise the one with the lowest reconstruction error
+    let mut best_error = T::infinity();
     let mut best_epoch = 0;
     let mut best_model_checkpoint = model.clone().into_record().into_item::<T::Precision>();
 
+    let mut last_epoch = 0;
+
     for epoch in 1..=num_epochs {
+        last_epoch = epoch;
+
+        let epoch_learning_rate =
+            learning_rate_schedule.learning_rate(learning_rate, epoch, num_epochs);
+
         #[expect(clippy::option_if_let_else)]
         let (train_xs_batches, train_ys_batches) = match num_batches {
             Some(num_batches) => {
@@ -567,7 +1311,7 @@ fn train<T: FloatExt, B: AutodiffBackend<FloatElem = T>>(
                 MseLoss::new().forward(prediction.clone(), train_ys_batch.clone(), Reduction::Mean);
 
             let grads = GradientsParams::from_grads(loss.backward(), &model);
-            model = optim.step(learning_rate.0, model, grads);
+            model = optim.step(epoch_learning_rate, model, grads);
 
             loss_sum += loss.into_scalar();
 
@@ -580,12 +1324,6 @@ fn train<T: FloatExt, B: AutodiffBackend<FloatElem = T>>(
 
         let loss_mean = loss_sum / <T as FloatExt>::from_usize(num_batches.unwrap_or(1));
 
-        if loss_mean < best_loss {
-            best_loss = loss_mean;
-            best_epoch = epoch;
-            best_model_checkpoint = model.clone().into_record().into_item::<T::Precision>();
-        }
-
         let rmse = stdv * (se_sum / <T as FloatExt>::from_usize(num_samples)).sqrt();
         let mae = stdv * ae_sum / <T as FloatExt>::from_usize(num_samples);
         let l_inf = stdv * l_inf;
@@ -593,17 +1331,70 @@ fn train<T: FloatExt, B: AutodiffBackend<FloatElem = T>>(
         log::info!(
             "[{epoch}/{num_epochs}]: loss={loss_mean:0.3} MAE={mae:0.3} RMSE={rmse:0.3} Linf={l_inf:0.3}"
         );
+
+        let Some(early_stopping) = early_stopping else {
+            if loss_mean < best_error {
+                best_error = loss_mean;
+                best_epoch = epoch;
+                best_model_checkpoint = model.clone().into_record().into_item::<T::Precision>();
+            }
+
+            continue;
+        };
+
+        let error =
+            model_reconstruction_error(&model, train_xs, train_ys, early_stopping.metric, stdv);
+
+        if error < best_error {
+            best_error = error;
+            best_epoch = epoch;
+            best_model_checkpoint = model.clone().into_record().into_item::<T::Precision>();
+        }
+
+        if ToPrimitive::to_f64(&error).is_some_and(|error| error <= early_stopping.target_error.0) {
+            log::info!("stopping early at epoch {epoch} with target error={error:0.3}");
+            break;
+        }
+
+        if let Some(patience) = early_stopping.patience {
+            if (epoch - best_epoch) >= patience.get() {
+                log::info!("stopping early at epoch {epoch} after {patience} epochs of patience");
+                break;
+            }
+        }
     }
 
-    if best_epoch != num_epochs {
+    if best_epoch != last_epoch {
         model = model.load_record(ModelRecord::from_item(best_model_checkpoint, device));
 
-        log::info!("restored from epoch {best_epoch} with lowest loss={best_loss:0.3}");
+        if early_stopping.is_some() {
+            log::info!("restored from epoch {best_epoch} with lowest error={best_error:0.3}");
+        } else {
+            log::info!("restored from epoch {best_epoch} with lowest loss={best_error:0.3}");
+        }
     }
 
     model
 }
 
+/// Computes the reconstruction error of the `model`, in the units of the data,
+/// by making a prediction in inference mode, exactly as during decoding
+fn model_reconstruction_error<T: FloatExt, B: AutodiffBackend<FloatElem = T>>(
+    model: &Model<B>,
+    xs: &Tensor<B, 2, Float>,
+    ys: &Tensor<B, 2, Float>,
+    metric: ErrorMetric,
+    stdv: T,
+) -> T {
+    let prediction = model.valid().forward(xs.clone().inner());
+    let err = prediction - ys.clone().inner();
+
+    match metric {
+        ErrorMetric::MaxAbs => stdv * err.abs().max().into_scalar(),
+        ErrorMetric::Rmse => stdv * (err.clone() * err).mean().into_scalar().sqrt(),
+    }
+}
+
 #[cfg(test)]
 #[expect(clippy::unwrap_used)]
 mod tests {
@@ -762,4 +1553,382 @@ mod tests {
             .unwrap();
         }
     }
+
+    #[test]
+    fn learning_rate_schedule() {
+        let learning_rate = Positive(1e-2);
+
+        for epoch in 1..=10 {
+            assert_eq!(
+                LearningRateSchedule::Constant
+                    .learning_rate(learning_rate, epoch, 10)
+                    .to_bits(),
+                learning_rate.0.to_bits()
+            );
+        }
+
+        let exponential = LearningRateSchedule::Exponential {
+            decay: Positive(0.5),
+        };
+        assert!((exponential.learning_rate(learning_rate, 1, 10) - 1e-2).abs() < 1e-12);
+        assert!((exponential.learning_rate(learning_rate, 3, 10) - 2.5e-3).abs() < 1e-12);
+
+        let cosine = LearningRateSchedule::Cosine {
+            min_learning_rate: Positive(1e-4),
+        };
+        assert!((cosine.learning_rate(learning_rate, 1, 11) - 1e-2).abs() < 1e-12);
+        assert!((cosine.learning_rate(learning_rate, 6, 11) - 5.05e-3).abs() < 1e-12);
+        assert!((cosine.learning_rate(learning_rate, 11, 11) - 1e-4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn early_stopping() {
+        std::mem::drop(simple_logger::init());
+
+        let data = Array::linspace(0.0_f64, 100.0_f64, 100);
+
+        let fourier_features = NonZeroUsize::new(16).unwrap();
+        let num_blocks = NonZeroUsize::new(2).unwrap();
+
+        for metric in [ErrorMetric::Rmse, ErrorMetric::MaxAbs] {
+            let early_stopping = EarlyStopping {
+                metric,
+                target_error: Positive(10.0),
+                patience: Some(NonZeroUsize::new(10).unwrap()),
+            };
+
+            let encode = || {
+                encode_with_training::<f64, _, _, Autodiff<NdArray<f64>>>(
+                    &NdArrayDevice::Cpu,
+                    data.view(),
+                    fourier_features,
+                    Positive(10.0),
+                    num_blocks,
+                    Positive(1e-3),
+                    LearningRateSchedule::Exponential {
+                        decay: Positive(0.99),
+                    },
+                    10_000,
+                    None,
+                    Some(early_stopping),
+                    WeightQuantization::None,
+                    false,
+                    42,
+                )
+                .unwrap()
+            };
+
+            let decoded = decode(encode(), data.dim(), fourier_features, num_blocks);
+            // training must be deterministic for a given seed
+            assert!(
+                decoded
+                    .iter()
+                    .zip(&decode(encode(), data.dim(), fourier_features, num_blocks))
+                    .all(|(a, b)| a.to_bits() == b.to_bits())
+            );
+
+            let error = match metric {
+                ErrorMetric::MaxAbs => data
+                    .iter()
+                    .zip(&decoded)
+                    .map(|(x, y)| (x - y).abs())
+                    .fold(0.0, f64::max),
+                ErrorMetric::Rmse => {
+                    let se = data
+                        .iter()
+                        .zip(&decoded)
+                        .map(|(x, y)| (x - y) * (x - y))
+                        .sum::<f64>();
+                    #[expect(clippy::cast_precision_loss)]
+                    let mse = se / (data.len() as f64);
+                    mse.sqrt()
+                }
+            };
+            assert!(error <= 10.0 + 1e-6, "{error} > 10.0");
+        }
+    }
+
+    #[test]
+    fn resume_from_checkpoint() {
+        std::mem::drop(simple_logger::init());
+
+        let data = Array::linspace(0.0_f64, 100.0_f64, 100);
+
+        let fourier_features = NonZeroUsize::new(16).unwrap();
+        let num_blocks = NonZeroUsize::new(2).unwrap();
+        let learning_rate = Positive(1e-4);
+
+        let checkpoint = encode_with_training::<f64, _, _, Autodiff<NdArray<f64>>>(
+            &NdArrayDevice::Cpu,
+            data.view(),
+            fourier_features,
+            Positive(10.0),
+            num_blocks,
+            learning_rate,
+            LearningRateSchedule::Constant,
+            10,
+            None,
+            None,
+            WeightQuantization::None,
+            false,
+            42,
+        )
+        .unwrap();
+
+        let resume = |num_blocks| {
+            encode_from_checkpoint::<f64, _, _, _, Autodiff<NdArray<f64>>>(
+                &NdArrayDevice::Cpu,
+                data.view(),
+                checkpoint.view(),
+                fourier_features,
+                num_blocks,
+                learning_rate,
+                LearningRateSchedule::Constant,
+                10,
+                None,
+                None,
+                WeightQuantization::None,
+                false,
+                42,
+            )
+        };
+
+        let decoded = decode(
+            resume(num_blocks).unwrap(),
+            data.dim(),
+            fourier_features,
+            num_blocks,
+        );
+        // resuming must be deterministic for a given seed
+        assert!(
+            decoded
+                .iter()
+                .zip(&decode(
+                    resume(num_blocks).unwrap(),
+                    data.dim(),
+                    fourier_features,
+                    num_blocks
+                ))
+                .all(|(a, b)| a.to_bits() == b.to_bits())
+        );
+
+        assert!(matches!(
+            resume(NonZeroUsize::MIN),
+            Err(FourierNetworkCodecError::MismatchedCheckpoint)
+        ));
+    }
+
+    #[test]
+    fn quantized_weights() {
+        std::mem::drop(simple_logger::init());
+
+        let data = Array::linspace(0.0_f64, 100.0_f64, 100);
+
+        let fourier_features = NonZeroUsize::new(16).unwrap();
+        let num_blocks = NonZeroUsize::new(2).unwrap();
+
+        let encode = |weight_quantization, weight_entropy_coding| {
+            encode_with_training::<f64, _, _, Autodiff<NdArray<f64>>>(
+                &NdArrayDevice::Cpu,
+                data.view(),
+                fourier_features,
+                Positive(10.0),
+                num_blocks,
+                Positive(1e-3),
+                LearningRateSchedule::Constant,
+                50,
+                None,
+                None,
+                weight_quantization,
+                weight_entropy_coding,
+                42,
+            )
+        };
+        let decode = |encoded, weight_quantization, weight_entropy_coding| {
+            let mut decoded = Array::<f64, _>::zeros(data.dim());
+            decode_into_with_weight_coding::<f64, _, _, NdArray<f64>>(
+                &NdArrayDevice::Cpu,
+                encoded,
+                decoded.view_mut(),
+                fourier_features,
+                num_blocks,
+                weight_quantization,
+                weight_entropy_coding,
+            )
+            .unwrap();
+            decoded
+        };
+
+        let full = encode(WeightQuantization::None, false).unwrap();
+        let full_len = full.len();
+        let reference = decode(full, WeightQuantization::None, false);
+
+        for (weight_quantization, max_error) in [
+            (
+                WeightQuantization::Linear {
+                    bits: NonZeroU8::new(8).unwrap(),
+                },
+                10.0,
+            ),
+            (
+                WeightQuantization::Linear {
+                    bits: NonZeroU8::new(16).unwrap(),
+                },
+                0.1,
+            ),
+            (WeightQuantization::BitRound { keepbits: 10 }, 1.0),
+        ] {
+            for weight_entropy_coding in [false, true] {
+                let encoded = encode(weight_quantization, weight_entropy_coding).unwrap();
+                assert!(encoded.len() < full_len, "{} >= {full_len}", encoded.len());
+
+                let decoded = decode(encoded, weight_quantization, weight_entropy_coding);
+                let error = reference
+                    .iter()
+                    .zip(&decoded)
+                    .map(|(x, y)| (x - y).abs())
+                    .fold(0.0, f64::max);
+                assert!(error <= max_error, "{error} > {max_error}");
+            }
+        }
+
+        let weight_quantization = WeightQuantization::Linear {
+            bits: NonZeroU8::new(12).unwrap(),
+        };
+        let checkpoint = encode(weight_quantization, true).unwrap();
+        let resumed = encode_from_checkpoint::<f64, _, _, _, Autodiff<NdArray<f64>>>(
+            &NdArrayDevice::Cpu,
+            data.view(),
+            checkpoint.view(),
+            fourier_features,
+            num_blocks,
+            Positive(1e-3),
+            LearningRateSchedule::Constant,
+            10,
+            None,
+            None,
+            weight_quantization,
+            true,
+            42,
+        )
+        .unwrap();
+        assert!(
+            decode(resumed, weight_quantization, true)
+                .iter()
+                .all(|x| x.is_finite())
+        );
+    }
+
+    #[test]
+    fn quantized_reconstruction_error() {
+        let data = Array::linspace(0.0_f64, 100.0_f64, 100);
+
+        let fourier_features = NonZeroUsize::new(16).unwrap();
+        let num_blocks = NonZeroUsize::new(2).unwrap();
+        let weight_quantization = WeightQuantization::Linear {
+            bits: NonZeroU8::new(8).unwrap(),
+        };
+
+        let encoded = encode_with_training::<f64, _, _, Autodiff<NdArray<f64>>>(
+            &NdArrayDevice::Cpu,
+            data.view(),
+            fourier_features,
+            Positive(10.0),
+            num_blocks,
+            Positive(1e-3),
+            LearningRateSchedule::Constant,
+            10,
+            None,
+            None,
+            weight_quantization,
+            true,
+            42,
+        )
+        .unwrap();
+
+        let error = |metric| {
+            reconstruction_error::<f64, _, _, _, NdArray<f64>>(
+                &NdArrayDevice::Cpu,
+                &data,
+                encoded.view(),
+                metric,
+                fourier_features,
+                num_blocks,
+                weight_quantization,
+                true,
+            )
+            .unwrap()
+        };
+
+        let mut decoded = Array::<f64, _>::zeros(data.dim());
+        decode_into_with_weight_coding::<f64, _, _, NdArray<f64>>(
+            &NdArrayDevice::Cpu,
+            encoded.view(),
+            decoded.view_mut(),
+            fourier_features,
+            num_blocks,
+            weight_quantization,
+            true,
+        )
+        .unwrap();
+        let errors = data.iter().zip(&decoded).map(|(x, y)| (x - y).abs());
+
+        assert_eq!(
+            error(ErrorMetric::MaxAbs).to_bits(),
+            errors.clone().fold(0.0, f64::max).to_bits()
+        );
+        #[expect(clippy::cast_precision_loss)]
+        let rmse = (errors.map(|e| e * e).sum::<f64>() / data.len() as f64).sqrt();
+        assert!((error(ErrorMetric::Rmse) - rmse).abs() <= 1e-9);
+    }
+
+    #[test]
+    fn excessive_quantization_bits() {
+        let encode = |weight_quantization| {
+            encode_with_training::<f64, _, _, Autodiff<NdArray<f64>>>(
+                &NdArrayDevice::Cpu,
+                Array::linspace(0.0_f64, 100.0_f64, 100),
+                NonZeroUsize::MIN,
+                Positive(1.0),
+                NonZeroUsize::MIN,
+                Positive(1e-4),
+                LearningRateSchedule::Constant,
+                10,
+                None,
+                None,
+                weight_quantization,
+                false,
+                42,
+            )
+        };
+
+        assert!(matches!(
+            encode(WeightQuantization::Linear {
+                bits: NonZeroU8::new(17).unwrap(),
+            }),
+            Err(FourierNetworkCodecError::ExcessiveQuantizationBits { bits: 17, max: 16 })
+        ));
+        assert!(matches!(
+            encode(WeightQuantization::BitRound { keepbits: 53 }),
+            Err(FourierNetworkCodecError::ExcessiveQuantizationBits { bits: 53, max: 52 })
+        ));
+    }
+
+    fn decode(
+        encoded: Array<u8, Ix1>,
+        shape: usize,
+        fourier_features: NonZeroUsize,
+        num_blocks: NonZeroUsize,
+    ) -> Array<f64, Ix1> {
+        let mut decoded = Array::<f64, _>::zeros(shape);
+        decode_into::<f64, _, _, NdArray<f64>>(
+            &NdArrayDevice::Cpu,
+            encoded,
+            decoded.view_mut(),
+            fourier_features,
+            num_blocks,
+        )
+        .unwrap();
+        decoded
+    }
 }
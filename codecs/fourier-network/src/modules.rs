@@ -93,6 +93,18 @@ impl ModelConfig {
             ln5: LinearConfig::new(self.fourier_features.get(), 1).init(device),
         }
     }
+
+    pub fn is_compatible_with<B: Backend>(&self, record: &ModelRecord<B>) -> bool {
+        let fourier_features = self.fourier_features.get();
+
+        record.ln1.weight.dims() == [fourier_features * 2, fourier_features]
+            && (record.bl2.len() + 1) == self.num_blocks.get()
+            && record
+                .bl2
+                .iter()
+                .all(|block| block.ln2_3.weight.dims() == [fourier_features, fourier_features])
+            && record.ln5.weight.dims() == [fourier_features, 1]
+    }
 }
 
 pub struct ModelExtra<B: Backend> {
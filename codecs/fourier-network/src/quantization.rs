@@ -0,0 +1,299 @@
+use std::num::NonZeroU8;
+
+use burn::{
+    module::{ModuleMapper, ParamId},
+    prelude::Backend,
+    tensor::{Tensor, TensorData},
+};
+use num_traits::ToPrimitive;
+
+use crate::{FloatExt, WeightQuantization};
+
+/// Maximum number of bits per linearly quantized weight
+pub const MAX_LINEAR_QUANTIZATION_BITS: u8 = 16;
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct QuantizedModelExtra<T> {
+    pub b_t: Vec<T>,
+    pub mean: T,
+    pub stdv: T,
+    pub ranges: Vec<(T, T)>,
+    pub weights: Vec<u8>,
+    pub version: crate::FourierNetworkCodecVersion,
+}
+
+/// Returns the maximum number of bits that the `quantization` supports for
+/// the floating point type `T`, if `quantization` uses more
+pub fn excessive_quantization_bits<T: FloatExt>(quantization: WeightQuantization) -> Option<u32> {
+    match quantization {
+        WeightQuantization::None => None,
+        WeightQuantization::Linear { bits } => (bits.get() > MAX_LINEAR_QUANTIZATION_BITS)
+            .then_some(u32::from(MAX_LINEAR_QUANTIZATION_BITS)),
+        WeightQuantization::BitRound { keepbits } => {
+            (u32::from(keepbits) > T::MANTISSA_BITS).then_some(T::MANTISSA_BITS)
+        }
+    }
+}
+
+/// Module mapper that quantizes every float tensor of a module in place and
+/// records the quantized codes
+pub struct WeightQuantizer<T> {
+    quantization: WeightQuantization,
+    ranges: Vec<(T, T)>,
+    writer: BitWriter,
+}
+
+impl<T: FloatExt> WeightQuantizer<T> {
+    pub const fn new(quantization: WeightQuantization) -> Self {
+        Self {
+            quantization,
+            ranges: Vec::new(),
+            writer: BitWriter::new(),
+        }
+    }
+
+    /// Returns the per-tensor value ranges and the packed quantized weights
+    pub fn finish(self) -> (Vec<(T, T)>, Vec<u8>) {
+        (self.ranges, self.writer.finish())
+    }
+
+    fn quantize(&mut self, values: &mut [T]) {
+        match self.quantization {
+            WeightQuantization::None => (),
+            WeightQuantization::Linear { bits } => {
+                let (min, max) = values
+                    .iter()
+                    .fold((T::infinity(), T::neg_infinity()), |(min, max), &x| {
+                        (min.min(x), max.max(x))
+                    });
+                let (min, max) = if min <= max {
+                    (min, max)
+                } else {
+                    (T::ZERO, T::ZERO)
+                };
+                self.ranges.push((min, max));
+
+                let linear = LinearQuantizer::new(bits, min, max);
+
+                for x in values {
+                    let code = linear.quantize(*x);
+                    self.writer.write(code, u32::from(bits.get()));
+                    *x = linear.dequantize(code);
+                }
+            }
+            WeightQuantization::BitRound { keepbits } => {
+                let shift = T::MANTISSA_BITS - u32::from(keepbits);
+                let width = T::BITS - shift;
+
+                for x in values {
+                    let code = bit_round(*x, shift) >> shift;
+                    self.writer.write(code, width);
+                    *x = T::from_bits_u64(code << shift);
+                }
+            }
+        }
+    }
+}
+
+impl<T: FloatExt, B: Backend<FloatElem = T>> ModuleMapper<B> for WeightQuantizer<T> {
+    fn map_float<const D: usize>(&mut self, _id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = tensor.shape();
+        let device = tensor.device();
+
+        #[expect(clippy::unwrap_used)] // same generic type, check must succeed
+        let mut values = tensor.into_data().to_vec::<T>().unwrap();
+        self.quantize(&mut values);
+
+        Tensor::from_data(TensorData::new(values, shape), &device)
+    }
+}
+
+/// Module mapper that replaces every float tensor of a freshly initialized
+/// module with its dequantized weights
+pub struct WeightDequantizer<'a, T> {
+    quantization: WeightQuantization,
+    ranges: std::slice::Iter<'a, (T, T)>,
+    reader: BitReader<'a>,
+    corrupted: bool,
+}
+
+impl<'a, T: FloatExt> WeightDequantizer<'a, T> {
+    pub fn new(quantization: WeightQuantization, ranges: &'a [(T, T)], weights: &'a [u8]) -> Self {
+        Self {
+            quantization,
+            ranges: ranges.iter(),
+            reader: BitReader::new(weights),
+            corrupted: false,
+        }
+    }
+
+    /// Returns `true` iff all quantized weights were consumed and matched the
+    /// module's architecture
+    pub fn finish(self) -> bool {
+        !self.corrupted && self.ranges.as_slice().is_empty() && self.reader.is_exhausted()
+    }
+
+    fn dequantize(&mut self, len: usize) -> Option<Vec<T>> {
+        match self.quantization {
+            WeightQuantization::None => None,
+            WeightQuantization::Linear { bits } => {
+                let &(min, max) = self.ranges.next()?;
+                let linear = LinearQuantizer::new(bits, min, max);
+
+                (0..len)
+                    .map(|_| {
+                        let code = self.reader.read(u32::from(bits.get()))?;
+                        Some(linear.dequantize(code))
+                    })
+                    .collect()
+            }
+            WeightQuantization::BitRound { keepbits } => {
+                let shift = T::MANTISSA_BITS - u32::from(keepbits);
+                let width = T::BITS - shift;
+
+                (0..len)
+                    .map(|_| {
+                        let code = self.reader.read(width)?;
+                        Some(T::from_bits_u64(code << shift))
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+impl<T: FloatExt, B: Backend<FloatElem = T>> ModuleMapper<B> for WeightDequantizer<'_, T> {
+    fn map_float<const D: usize>(&mut self, _id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let shape = tensor.shape();
+
+        let Some(values) = self.dequantize(shape.num_elements()) else {
+            self.corrupted = true;
+            return tensor;
+        };
+
+        Tensor::from_data(TensorData::new(values, shape), &tensor.device())
+    }
+}
+
+struct LinearQuantizer<T> {
+    min: T,
+    scale: T,
+    levels: T,
+}
+
+impl<T: FloatExt> LinearQuantizer<T> {
+    fn new(bits: NonZeroU8, min: T, max: T) -> Self {
+        let levels = <T as FloatExt>::from_usize((1_usize << bits.get()) - 1);
+
+        Self {
+            min,
+            scale: (max - min) / levels,
+            levels,
+        }
+    }
+
+    fn quantize(&self, x: T) -> u64 {
+        if self.scale > T::ZERO {
+            let code = ((x - self.min) / self.scale)
+                .round()
+                .clamp(T::ZERO, self.levels);
+            ToPrimitive::to_u64(&code).unwrap_or(0)
+        } else {
+            0
+        }
+    }
+
+    fn dequantize(&self, code: u64) -> T {
+        #[expect(clippy::cast_possible_truncation)] // codes have at most 16 bits
+        let code = <T as FloatExt>::from_usize(code as usize);
+        self.scale.mul_add(code, self.min)
+    }
+}
+
+/// Rounds the mantissa of `x` to nearest, ties to even, such that its lowest
+/// `shift` bits are zero
+fn bit_round<T: FloatExt>(x: T, shift: u32) -> u64 {
+    let bits = x.to_bits_u64();
+
+    if shift == 0 {
+        return bits;
+    }
+
+    let half = (1_u64 << (shift - 1)) - 1;
+    let mask = !((1_u64 << shift) - 1);
+    let tie = (bits >> shift) & 1;
+
+    bits.wrapping_add(half + tie) & mask & (u64::MAX >> (64 - T::BITS))
+}
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u128,
+    len: u32,
+}
+
+impl BitWriter {
+    const fn new() -> Self {
+        Self {
+            bytes: Vec::new(),
+            buffer: 0,
+            len: 0,
+        }
+    }
+
+    fn write(&mut self, code: u64, width: u32) {
+        self.buffer |= u128::from(code) << self.len;
+        self.len += width;
+
+        while self.len >= 8 {
+            #[expect(clippy::cast_possible_truncation)] // extract the lowest byte
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            #[expect(clippy::cast_possible_truncation)] // extract the lowest byte
+            self.bytes.push(self.buffer as u8);
+        }
+
+        self.bytes
+    }
+}
+
+struct BitReader<'a> {
+    bytes: std::slice::Iter<'a, u8>,
+    buffer: u128,
+    len: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes: bytes.iter(),
+            buffer: 0,
+            len: 0,
+        }
+    }
+
+    fn read(&mut self, width: u32) -> Option<u64> {
+        while self.len < width {
+            self.buffer |= u128::from(*self.bytes.next()?) << self.len;
+            self.len += 8;
+        }
+
+        #[expect(clippy::cast_possible_truncation)] // width is at most 64
+        let code = (self.buffer & ((1_u128 << width) - 1)) as u64;
+        self.buffer >>= width;
+        self.len -= width;
+
+        Some(code)
+    }
+
+    /// Returns `true` iff only zero-padding bits remain
+    fn is_exhausted(&self) -> bool {
+        self.bytes.as_slice().is_empty() && self.len < 8 && self.buffer == 0
+    }
+}
@@ -6,8 +6,9 @@ use serde::Deserialize;
 use serde_json::json;
 
 use ::{
-    burn as _, bytemuck as _, itertools as _, log as _, ndarray as _, num_traits as _,
-    schemars as _, simple_logger as _, thiserror as _, wasm_bindgen as _,
+    burn as _, bytemuck as _, itertools as _, log as _, miniz_oxide as _, ndarray as _,
+    num_traits as _, postcard as _, schemars as _, simple_logger as _, thiserror as _,
+    wasm_bindgen as _,
 };
 
 #[test]
@@ -67,3 +68,53 @@ fn config_mini_batch_size() {
 
     assert!(codec.mini_batch_size.is_some());
 }
+
+#[test]
+fn config_early_stopping() {
+    let codec = FourierNetworkCodec::from_config(
+        Deserialize::deserialize(json!({
+            "fourier_features": 16,
+            "fourier_scale": 10.0,
+            "num_blocks": 2,
+            "learning_rate": 1e-3,
+            "learning_rate_schedule": {
+                "schedule": "cosine",
+                "min_learning_rate": 1e-5,
+            },
+            "num_epochs": 1000,
+            "mini_batch_size": null,
+            "early_stopping": {
+                "metric": "max-abs",
+                "target_error": 0.1,
+                "patience": 50,
+            },
+            "seed": 42,
+        }))
+        .unwrap(),
+    );
+
+    assert!(codec.early_stopping.is_some());
+}
+
+#[test]
+fn config_weight_quantization() {
+    let codec = FourierNetworkCodec::from_config(
+        Deserialize::deserialize(json!({
+            "fourier_features": 16,
+            "fourier_scale": 10.0,
+            "num_blocks": 2,
+            "learning_rate": 1e-3,
+            "num_epochs": 1000,
+            "mini_batch_size": null,
+            "weight_quantization": {
+                "quantization": "linear",
+                "bits": 8,
+            },
+            "weight_entropy_coding": true,
+            "seed": 42,
+        }))
+        .unwrap(),
+    );
+
+    assert!(codec.weight_entropy_coding);
+}
@@ -4,9 +4,9 @@ use numcodecs::{DynCodecType, StaticCodecType};
 use numcodecs_fourier_network::FourierNetworkCodec;
 
 use ::{
-    burn as _, bytemuck as _, itertools as _, log as _, ndarray as _, num_traits as _,
-    schemars as _, serde as _, serde_json as _, simple_logger as _, thiserror as _,
-    wasm_bindgen as _,
+    burn as _, bytemuck as _, itertools as _, log as _, miniz_oxide as _, ndarray as _,
+    num_traits as _, postcard as _, schemars as _, serde as _, serde_json as _, simple_logger as _,
+    thiserror as _, wasm_bindgen as _,
 };
 
 #[test]
@@ -0,0 +1,486 @@
+//! [![CI Status]][workflow] [![MSRV]][repo] [![Latest Version]][crates.io] [![Rust Doc Crate]][docs.rs] [![Rust Doc Main]][docs]
+//!
+//! [CI Status]: https://img.shields.io/github/actions/workflow/status/juntyr/numcodecs-rs/ci.yml?branch=main
+//! [workflow]: https://github.com/juntyr/numcodecs-rs/actions/workflows/ci.yml?query=branch%3Amain
+//!
+//! [MSRV]: https://img.shields.io/badge/MSRV-1.87.0-blue
+//! [repo]: https://github.com/juntyr/numcodecs-rs
+//!
+//! [Latest Version]: https://img.shields.io/crates/v/numcodecs-guard
+//! [crates.io]: https://crates.io/crates/numcodecs-guard
+//!
+//! [Rust Doc Crate]: https://img.shields.io/docsrs/numcodecs-guard
+//! [docs.rs]: https://docs.rs/numcodecs-guard/
+//!
+//! [Rust Doc Main]: https://img.shields.io/badge/docs-main-blue
+//! [docs]: https://juntyr.github.io/numcodecs-rs/numcodecs_guard
+//!
+//! Error-bound guard meta-codec implementation for the [`numcodecs`] API.
+
+use std::borrow::Cow;
+
+use ndarray::{ArrayBase, ArrayViewMut, Data, IxDyn};
+use numcodecs::{
+    AnyArray, AnyArrayAssignError, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray,
+    ArrayDType, ArrayDataMutExt, Codec, DynCodec, ErasedDynCodec, ErasedError, StaticCodec,
+    StaticCodecConfig, StaticCodecVersion,
+};
+use numcodecs_registry::GlobalRegistry;
+use numcodecs_utils::{
+    corrections::{self, Corrections, CorrectionsError},
+    framing::{FramingError, decode_framed, encode_framed, framed_array, framed_bytes},
+};
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::DeserializeOwned};
+use thiserror::Error;
+
+type GuardCodecVersion = StaticCodecVersion<1, 0, 0>;
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+// serde cannot deny unknown fields because of the flatten
+#[schemars(deny_unknown_fields)]
+/// Guard meta-codec which wraps an existing lossy codec and guarantees a
+/// pointwise error bound for every decoded value.
+///
+/// During encoding, the data is encoded with the wrapped codec and then
+/// immediately decoded again. Every point whose error exceeds the error bound
+/// is stored exactly as a sparse correction next to the wrapped codec's
+/// encoding, and restored during decoding.
+///
+/// Non-finite values are always restored exactly.
+///
+/// The codec only supports floating point data.
+pub struct GuardCodec {
+    /// The configuration of the wrapped codec.
+    #[serde(serialize_with = "DynCodec::get_config")]
+    #[serde(deserialize_with = "GlobalRegistry::codec_from_config")]
+    #[schemars(schema_with = "ErasedDynCodec::codec_config_schema")]
+    pub codec: ErasedDynCodec,
+    /// The pointwise error bound that is guaranteed for every value
+    #[serde(flatten)]
+    pub error_bound: GuardErrorBound,
+    /// The codec's encoding format version. Do not provide this parameter explicitly.
+    #[serde(default, rename = "_version")]
+    pub version: GuardCodecVersion,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "eb_mode")]
+#[serde(deny_unknown_fields)]
+/// Pointwise error bound
+pub enum GuardErrorBound {
+    /// Pointwise absolute error bound, i.e. every decoded value `y` of an
+    /// input value `x` satisfies `|x - y| <= eb_abs`
+    #[serde(rename = "abs")]
+    Absolute {
+        /// Absolute error bound
+        eb_abs: NonNegative<f64>,
+    },
+    /// Pointwise relative error bound, i.e. every decoded value `y` of an
+    /// input value `x` satisfies `|x - y| <= |x| * eb_rel`
+    #[serde(rename = "rel")]
+    Relative {
+        /// Relative error bound
+        eb_rel: NonNegative<f64>,
+    },
+}
+
+impl Codec for GuardCodec {
+    type Error = GuardCodecError;
+
+    fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        if !matches!(data.dtype(), AnyArrayDType::F32 | AnyArrayDType::F64) {
+            return Err(GuardCodecError::UnsupportedDtype(data.dtype()));
+        }
+
+        let encoded = self
+            .codec
+            .encode(data.cow())
+            .map_err(|err| GuardCodecError::InnerEncodeFailed { source: err })?;
+        let decoded = self
+            .codec
+            .decode(encoded.cow())
+            .map_err(|err| GuardCodecError::InnerDecodeFailed { source: err })?;
+
+        let encoded_bytes = encode_framed(
+            &CompressionHeader {
+                dtype: data.dtype(),
+                shape: Cow::Borrowed(data.shape()),
+                version: StaticCodecVersion,
+            },
+            &encoded,
+        )?;
+
+        let encoded_bytes = match (&data, &decoded) {
+            (AnyCowArray::F32(data), AnyArray::F32(decoded)) => corrections::encode_corrections(
+                &guard_corrections(data, decoded, self.error_bound)?,
+                encoded_bytes,
+            )?,
+            (AnyCowArray::F64(data), AnyArray::F64(decoded)) => corrections::encode_corrections(
+                &guard_corrections(data, decoded, self.error_bound)?,
+                encoded_bytes,
+            )?,
+            (data, decoded) => {
+                return Err(GuardCodecError::MismatchedInnerDecode {
+                    source: AnyArrayAssignError::DTypeMismatch {
+                        src: decoded.dtype(),
+                        dst: data.dtype(),
+                    },
+                });
+            }
+        };
+
+        Ok(framed_array(encoded_bytes))
+    }
+
+    fn decode(&self, encoded: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        let encoded = encoded.view();
+        let encoded = framed_bytes(&encoded)?;
+        let (header, inner_encoded, corrections) = decode_framed::<CompressionHeader>(&encoded)?;
+
+        let mut decoded = AnyArray::zeros(header.dtype, &header.shape);
+        decode_into(
+            &self.codec,
+            &header,
+            &inner_encoded,
+            corrections,
+            decoded.view_mut(),
+        )?;
+
+        Ok(decoded)
+    }
+
+    fn decode_into(
+        &self,
+        encoded: AnyArrayView,
+        decoded: AnyArrayViewMut,
+    ) -> Result<(), Self::Error> {
+        let encoded = framed_bytes(&encoded)?;
+        let (header, inner_encoded, corrections) = decode_framed::<CompressionHeader>(&encoded)?;
+
+        decode_into(&self.codec, &header, &inner_encoded, corrections, decoded)
+    }
+}
+
+impl StaticCodec for GuardCodec {
+    const CODEC_ID: &'static str = "guard.rs";
+
+    type Config<'de> = Self;
+
+    fn from_config(config: Self::Config<'_>) -> Self {
+        config
+    }
+
+    fn get_config(&self) -> StaticCodecConfig<'_, Self> {
+        StaticCodecConfig::from(self)
+    }
+}
+
+#[derive(Debug, Error)]
+/// Errors that may occur when applying the [`GuardCodec`].
+pub enum GuardCodecError {
+    /// [`GuardCodec`] does not support the dtype
+    #[error("Guard does not support the dtype {0}")]
+    UnsupportedDtype(AnyArrayDType),
+    /// [`GuardCodec`] failed to encode the data with the wrapped codec
+    #[error("Guard failed to encode the data with the wrapped codec")]
+    InnerEncodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`GuardCodec`] failed to decode the data with the wrapped codec
+    #[error("Guard failed to decode the data with the wrapped codec")]
+    InnerDecodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`GuardCodec`] received decoded data from the wrapped codec that does
+    /// not match the encoded data
+    #[error(
+        "Guard received decoded data from the wrapped codec that does not match the encoded data"
+    )]
+    MismatchedInnerDecode {
+        /// The source of the error
+        source: AnyArrayAssignError,
+    },
+    /// [`GuardCodec`] failed to frame or unframe the wrapped codec's encoding
+    #[error("Guard failed to frame or unframe the wrapped codec's encoding")]
+    FramingFailed {
+        /// The source of the error
+        #[from]
+        source: FramingError,
+    },
+    /// [`GuardCodec`] failed to encode, decode, or apply the corrections
+    #[error("Guard failed to encode, decode, or apply the corrections")]
+    CorrectionsFailed {
+        /// The source of the error
+        #[from]
+        source: CorrectionsError,
+    },
+    /// [`GuardCodec`] cannot decode into the provided array
+    #[error("Guard cannot decode into the provided array")]
+    MismatchedDecodeIntoArray {
+        /// The source of the error
+        #[from]
+        source: AnyArrayAssignError,
+    },
+}
+
+/// Collects the flat index and exact value of every point in the `data` whose
+/// `decoded` value violates the `error_bound`
+fn guard_corrections<T: Float, S: Data<Elem = T>>(
+    data: &ArrayBase<S, IxDyn>,
+    decoded: &ArrayBase<impl Data<Elem = T>, IxDyn>,
+    error_bound: GuardErrorBound,
+) -> Result<Corrections<T>, GuardCodecError> {
+    if data.shape() != decoded.shape() {
+        return Err(GuardCodecError::MismatchedInnerDecode {
+            source: AnyArrayAssignError::ShapeMismatch {
+                src: decoded.shape().to_vec(),
+                dst: data.shape().to_vec(),
+            },
+        });
+    }
+
+    let corrections =
+        corrections::collect_corrections(data, decoded.iter().copied(), |_index, x, y| {
+            let (x_f64, y_f64) = (x.into_f64(), y.into_f64());
+
+            let bound = match error_bound {
+                GuardErrorBound::Absolute { eb_abs } => eb_abs.0,
+                GuardErrorBound::Relative { eb_rel } => x_f64.abs() * eb_rel.0,
+            };
+
+            // non-finite values produce a NaN error and are always corrected
+            !matches!(
+                (x_f64 - y_f64).abs().partial_cmp(&bound),
+                Some(std::cmp::Ordering::Less | std::cmp::Ordering::Equal)
+            )
+        });
+
+    Ok(corrections)
+}
+
+fn decode_into(
+    codec: &ErasedDynCodec,
+    header: &CompressionHeader,
+    inner_encoded: &AnyArray,
+    corrections: &[u8],
+    mut decoded: AnyArrayViewMut,
+) -> Result<(), GuardCodecError> {
+    if decoded.dtype() != header.dtype {
+        return Err(GuardCodecError::MismatchedDecodeIntoArray {
+            source: AnyArrayAssignError::DTypeMismatch {
+                src: header.dtype,
+                dst: decoded.dtype(),
+            },
+        });
+    }
+
+    if decoded.shape() != &*header.shape {
+        return Err(GuardCodecError::MismatchedDecodeIntoArray {
+            source: AnyArrayAssignError::ShapeMismatch {
+                src: header.shape.to_vec(),
+                dst: decoded.shape().to_vec(),
+            },
+        });
+    }
+
+    codec
+        .decode_into(inner_encoded.view(), decoded.view_mut())
+        .map_err(|err| GuardCodecError::InnerDecodeFailed { source: err })?;
+
+    match decoded {
+        AnyArrayViewMut::F32(decoded) => apply_corrections(decoded, corrections),
+        AnyArrayViewMut::F64(decoded) => apply_corrections(decoded, corrections),
+        decoded => Err(GuardCodecError::UnsupportedDtype(decoded.dtype())),
+    }
+}
+
+/// Applies the encoded `corrections` to the `decoded` data
+fn apply_corrections<T: Float>(
+    mut decoded: ArrayViewMut<T, IxDyn>,
+    corrections: &[u8],
+) -> Result<(), GuardCodecError> {
+    let (corrections, _) = corrections::decode_corrections::<T>(corrections)?;
+
+    decoded.with_slice_mut(|decoded| Ok(corrections::apply_corrections(decoded, corrections)?))
+}
+
+/// Floating point types.
+pub trait Float: Copy + ArrayDType + Serialize + DeserializeOwned {
+    /// Converts the value into a [`f64`] without loss of precision
+    #[must_use]
+    fn into_f64(self) -> f64;
+}
+
+impl Float for f32 {
+    fn into_f64(self) -> f64 {
+        f64::from(self)
+    }
+}
+
+impl Float for f64 {
+    fn into_f64(self) -> f64 {
+        self
+    }
+}
+
+#[expect(clippy::derive_partial_eq_without_eq)] // floats are not Eq
+#[derive(Copy, Clone, PartialEq, PartialOrd, Hash)]
+/// Non-negative floating point number
+pub struct NonNegative<T: Float>(T);
+
+impl Serialize for NonNegative<f64> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for NonNegative<f64> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let x = f64::deserialize(deserializer)?;
+
+        if x >= 0.0 {
+            Ok(Self(x))
+        } else {
+            Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Float(x),
+                &"a non-negative value",
+            ))
+        }
+    }
+}
+
+impl JsonSchema for NonNegative<f64> {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("NonNegativeF64")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed(concat!(module_path!(), "::", "NonNegative<f64>"))
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "minimum": 0.0
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CompressionHeader<'a> {
+    dtype: AnyArrayDType,
+    #[serde(borrow)]
+    shape: Cow<'a, [usize]>,
+    version: GuardCodecVersion,
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use ndarray::Array;
+    use numcodecs_round::RoundCodec;
+    use serde_json::json;
+
+    use super::*;
+
+    fn guard(error_bound: GuardErrorBound) -> GuardCodec {
+        let round: RoundCodec = serde_json::from_value(json!({ "precision": 1.0 })).unwrap();
+
+        GuardCodec {
+            codec: ErasedDynCodec::new(round),
+            error_bound,
+            version: StaticCodecVersion,
+        }
+    }
+
+    #[test]
+    fn absolute_error_bound() {
+        let codec = guard(GuardErrorBound::Absolute {
+            eb_abs: NonNegative(0.1),
+        });
+
+        #[expect(clippy::cast_precision_loss)]
+        let data = Array::from_shape_fn((16, 16), |(i, j)| {
+            ((i * 16 + j) as f64).mul_add(0.37, 1.0).sin() * 10.0
+        })
+        .into_dyn();
+
+        let encoded = codec.encode(AnyCowArray::F64(data.view().into())).unwrap();
+        let decoded = codec.decode(encoded.cow()).unwrap();
+
+        let mut decoded_into = Array::zeros(data.shape());
+        codec
+            .decode_into(
+                encoded.view(),
+                AnyArrayViewMut::F64(decoded_into.view_mut()),
+            )
+            .unwrap();
+        assert_eq!(decoded, AnyArray::F64(decoded_into.clone()));
+
+        let mut num_corrected = 0;
+        for (x, y) in data.iter().zip(decoded_into.iter()) {
+            assert!((x - y).abs() <= 0.1, "{x} vs {y}");
+            if x.to_bits() == y.to_bits() {
+                num_corrected += 1;
+            }
+        }
+
+        // only some points need to be corrected
+        assert!(num_corrected > 0);
+        assert!(num_corrected < data.len());
+    }
+
+    #[test]
+    fn relative_error_bound() {
+        let codec = guard(GuardErrorBound::Relative {
+            eb_rel: NonNegative(0.01),
+        });
+
+        #[expect(clippy::cast_precision_loss)]
+        let data = Array::from_shape_fn(100, |i| (i as f32 - 50.0) * 0.75).into_dyn();
+
+        let encoded = codec.encode(AnyCowArray::F32(data.view().into())).unwrap();
+        let AnyArray::F32(decoded) = codec.decode(encoded.cow()).unwrap() else {
+            panic!("decoded data has the wrong dtype");
+        };
+
+        for (x, y) in data.iter().zip(decoded.iter()) {
+            assert!((x - y).abs() <= x.abs() * 0.01, "{x} vs {y}");
+        }
+    }
+
+    #[test]
+    fn non_finite() {
+        let codec = guard(GuardErrorBound::Absolute {
+            eb_abs: NonNegative(0.5),
+        });
+
+        let data =
+            Array::from_vec(vec![1.25, f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 3.0]).into_dyn();
+
+        let encoded = codec.encode(AnyCowArray::F64(data.view().into())).unwrap();
+        let AnyArray::F64(decoded) = codec.decode(encoded.cow()).unwrap() else {
+            panic!("decoded data has the wrong dtype");
+        };
+
+        // all but the first value are restored exactly
+        for (x, y) in data.iter().zip(decoded.iter()).skip(1) {
+            assert_eq!(x.to_bits(), y.to_bits(), "{x} vs {y}");
+        }
+    }
+
+    #[test]
+    fn unsupported_dtype() {
+        let codec = guard(GuardErrorBound::Absolute {
+            eb_abs: NonNegative(0.5),
+        });
+
+        assert!(matches!(
+            codec.encode(AnyCowArray::I32(Array::zeros(4).into_dyn().into())),
+            Err(GuardCodecError::UnsupportedDtype(AnyArrayDType::I32))
+        ));
+    }
+}
@@ -0,0 +1,751 @@
+//! [![CI Status]][workflow] [![MSRV]][repo] [![Latest Version]][crates.io] [![Rust Doc Crate]][docs.rs] [![Rust Doc Main]][docs]
+//!
+//! [CI Status]: https://img.shields.io/github/actions/workflow/status/juntyr/numcodecs-rs/ci.yml?branch=main
+//! [workflow]: https://github.com/juntyr/numcodecs-rs/actions/workflows/ci.yml?query=branch%3Amain
+//!
+//! [MSRV]: https://img.shields.io/badge/MSRV-1.87.0-blue
+//! [repo]: https://github.com/juntyr/numcodecs-rs
+//!
+//! [Latest Version]: https://img.shields.io/crates/v/numcodecs-instrument
+//! [crates.io]: https://crates.io/crates/numcodecs-instrument
+//!
+//! [Rust Doc Crate]: https://img.shields.io/docsrs/numcodecs-instrument
+//! [docs.rs]: https://docs.rs/numcodecs-instrument/
+//!
+//! [Rust Doc Main]: https://img.shields.io/badge/docs-main-blue
+//! [docs]: https://juntyr.github.io/numcodecs-rs/numcodecs_instrument
+//!
+//! Timing and size instrumentation meta-codec implementation for the
+//! [`numcodecs`] API.
+
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    fmt,
+    sync::{Arc, Mutex, PoisonError, RwLock},
+    time::{Duration, Instant},
+};
+
+use numcodecs::{
+    AnyArray, AnyArrayBase, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray, AnyRawData,
+    Codec, DynCodec, DynCodecType, ErasedDynCodec, ErasedError, StaticCodec, StaticCodecConfig,
+    StaticCodecVersion,
+};
+use numcodecs_registry::GlobalRegistry;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+type InstrumentCodecVersion = StaticCodecVersion<1, 0, 0>;
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+// serde cannot deny unknown fields because of the flatten
+#[schemars(deny_unknown_fields)]
+/// Instrumentation meta-codec which wraps an existing codec and reports the
+/// wall time, the input and output sizes, dtypes, and shapes of every encode
+/// and decode call to a sink.
+///
+/// Instrumentation codecs can be nested, e.g. around every stage of a codec
+/// stack, in which case every measurement records the path of the enclosing
+/// instrumented stages and the time spent outside of the nested stages.
+///
+/// The encoding is that of the wrapped codec.
+pub struct InstrumentCodec {
+    /// The configuration of the wrapped codec.
+    #[serde(serialize_with = "DynCodec::get_config")]
+    #[serde(deserialize_with = "GlobalRegistry::codec_from_config")]
+    #[schemars(schema_with = "ErasedDynCodec::codec_config_schema")]
+    pub codec: ErasedDynCodec,
+    /// The optional name of the instrumented stage, which defaults to the
+    /// wrapped codec's id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage: Option<String>,
+    /// The sink to which the measurements are reported
+    #[serde(flatten)]
+    pub sink: InstrumentSink,
+    /// The codec's encoding format version. Do not provide this parameter explicitly.
+    #[serde(default, rename = "_version")]
+    pub version: InstrumentCodecVersion,
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "sink")]
+#[serde(deny_unknown_fields)]
+/// Sink to which the measurements are reported
+pub enum InstrumentSink {
+    /// Report every measurement as a `log` record
+    #[serde(rename = "log")]
+    Log {
+        /// The level of the log records
+        #[serde(default)]
+        level: InstrumentLevel,
+    },
+    /// Report every measurement as a `tracing` event
+    #[serde(rename = "tracing")]
+    Tracing {
+        /// The level of the tracing events
+        #[serde(default)]
+        level: InstrumentLevel,
+    },
+    /// Report every measurement to the measurement sink that has been
+    /// registered under the name
+    ///
+    /// Measurements are discarded while no sink is registered under the
+    /// name.
+    #[serde(rename = "registered")]
+    Registered {
+        /// The name of the registered sink
+        name: String,
+    },
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+/// Level at which measurements are logged or traced
+pub enum InstrumentLevel {
+    /// The "error" level
+    #[serde(rename = "error")]
+    Error,
+    /// The "warn" level
+    #[serde(rename = "warn")]
+    Warn,
+    /// The "info" level
+    #[serde(rename = "info")]
+    Info,
+    /// The "debug" level
+    #[serde(rename = "debug")]
+    #[default]
+    Debug,
+    /// The "trace" level
+    #[serde(rename = "trace")]
+    Trace,
+}
+
+impl Codec for InstrumentCodec {
+    type Error = InstrumentCodecError;
+
+    fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        let input = ArrayInfo::of(&data);
+
+        self.instrument(
+            MeasuredOperation::Encode,
+            input,
+            || self.codec.encode(data),
+            ArrayInfo::of,
+        )
+        .map_err(|err| InstrumentCodecError::InnerEncodeFailed { source: err })
+    }
+
+    fn decode(&self, encoded: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        let input = ArrayInfo::of(&encoded);
+
+        self.instrument(
+            MeasuredOperation::Decode,
+            input,
+            || self.codec.decode(encoded),
+            ArrayInfo::of,
+        )
+        .map_err(|err| InstrumentCodecError::InnerDecodeFailed { source: err })
+    }
+
+    fn decode_into(
+        &self,
+        encoded: AnyArrayView,
+        decoded: AnyArrayViewMut,
+    ) -> Result<(), Self::Error> {
+        let input = ArrayInfo::of(&encoded);
+        let output = ArrayInfo::of(&decoded);
+
+        self.instrument(
+            MeasuredOperation::DecodeInto,
+            input,
+            || self.codec.decode_into(encoded, decoded),
+            |()| output.clone(),
+        )
+        .map_err(|err| InstrumentCodecError::InnerDecodeFailed { source: err })
+    }
+}
+
+impl StaticCodec for InstrumentCodec {
+    const CODEC_ID: &'static str = "instrument.rs";
+
+    type Config<'de> = Self;
+
+    fn from_config(config: Self::Config<'_>) -> Self {
+        config
+    }
+
+    fn get_config(&self) -> StaticCodecConfig<'_, Self> {
+        StaticCodecConfig::from(self)
+    }
+}
+
+#[derive(Debug, Error)]
+/// Errors that may occur when applying the [`InstrumentCodec`].
+pub enum InstrumentCodecError {
+    /// [`InstrumentCodec`] failed to encode the data with the wrapped codec
+    #[error("Instrument failed to encode the data with the wrapped codec")]
+    InnerEncodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`InstrumentCodec`] failed to decode the data with the wrapped codec
+    #[error("Instrument failed to decode the data with the wrapped codec")]
+    InnerDecodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+}
+
+impl InstrumentCodec {
+    /// Measures the `operation` `f`, which maps the `input` to an output that
+    /// is described by `output`, and reports the measurement to the sink
+    fn instrument<T>(
+        &self,
+        operation: MeasuredOperation,
+        input: ArrayInfo,
+        f: impl FnOnce() -> Result<T, ErasedError>,
+        output: impl FnOnce(&T) -> ArrayInfo,
+    ) -> Result<T, ErasedError> {
+        let stage = self
+            .stage
+            .clone()
+            .unwrap_or_else(|| self.codec.ty().codec_id().to_owned());
+
+        let guard = StageGuard::enter(stage);
+        let start = Instant::now();
+        let result = f();
+        let duration = start.elapsed();
+        let (stages, nested_duration) = guard.exit(duration);
+
+        let measurement = Measurement {
+            stages,
+            operation,
+            duration,
+            self_duration: duration.saturating_sub(nested_duration),
+            output: result.as_ref().ok().map(output),
+            input,
+        };
+
+        match &self.sink {
+            InstrumentSink::Log { level } => LogSink { level: *level }.record(&measurement),
+            InstrumentSink::Tracing { level } => {
+                TracingSink { level: *level }.record(&measurement);
+            }
+            InstrumentSink::Registered { name } => {
+                let sink = SINKS
+                    .read()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .get(name)
+                    .cloned();
+
+                if let Some(sink) = sink {
+                    sink.record(&measurement);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// Measurement of a single encode or decode call of an instrumented codec
+pub struct Measurement {
+    /// The names of the enclosing instrumented stages on the current thread,
+    /// from the outermost to the measured stage
+    pub stages: Vec<String>,
+    /// The measured operation
+    pub operation: MeasuredOperation,
+    /// The wall time of the operation
+    pub duration: Duration,
+    /// The wall time of the operation, excluding the time spent in nested
+    /// instrumented stages
+    pub self_duration: Duration,
+    /// Information about the input array
+    pub input: ArrayInfo,
+    /// Information about the output array, or `None` if the operation failed
+    pub output: Option<ArrayInfo>,
+}
+
+impl Measurement {
+    #[must_use]
+    /// Returns the `/`-separated path of the measured stage
+    pub fn path(&self) -> String {
+        self.stages.join("/")
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Operation of a codec that is measured
+pub enum MeasuredOperation {
+    /// [`Codec::encode`]
+    Encode,
+    /// [`Codec::decode`]
+    Decode,
+    /// [`Codec::decode_into`]
+    DecodeInto,
+}
+
+impl fmt::Display for MeasuredOperation {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(match self {
+            Self::Encode => "encode",
+            Self::Decode => "decode",
+            Self::DecodeInto => "decode_into",
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// Information about an array that is passed into or out of a codec
+pub struct ArrayInfo {
+    /// The dtype of the array
+    pub dtype: AnyArrayDType,
+    /// The shape of the array
+    pub shape: Vec<usize>,
+    /// The size of the array's elements, in bytes
+    pub bytes: usize,
+}
+
+impl ArrayInfo {
+    fn of<T: AnyRawData>(array: &AnyArrayBase<T>) -> Self {
+        Self {
+            dtype: array.dtype(),
+            shape: array.shape().to_vec(),
+            bytes: array.len() * array.dtype().size(),
+        }
+    }
+}
+
+impl fmt::Display for ArrayInfo {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}{:?} ({} bytes)", self.dtype, self.shape, self.bytes)
+    }
+}
+
+/// Sink that receives the measurements of [`InstrumentCodec`]s
+pub trait MeasurementSink: Send + Sync {
+    /// Records the `measurement`
+    fn record(&self, measurement: &Measurement);
+}
+
+static SINKS: RwLock<BTreeMap<String, Arc<dyn MeasurementSink>>> = RwLock::new(BTreeMap::new());
+
+/// Registers the `sink` under the `name`, which can then be referenced by
+/// [`InstrumentSink::Registered`], and returns the sink that was previously
+/// registered under the same name, if any
+pub fn register_sink(
+    name: impl Into<String>,
+    sink: Arc<dyn MeasurementSink>,
+) -> Option<Arc<dyn MeasurementSink>> {
+    SINKS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(name.into(), sink)
+}
+
+/// Unregisters and returns the sink that is registered under the `name`, if
+/// any
+pub fn unregister_sink(name: &str) -> Option<Arc<dyn MeasurementSink>> {
+    SINKS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(name)
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+/// [`MeasurementSink`] which reports every measurement as a [`log`] record
+pub struct LogSink {
+    /// The level of the log records
+    pub level: InstrumentLevel,
+}
+
+impl MeasurementSink for LogSink {
+    fn record(&self, measurement: &Measurement) {
+        let level = match self.level {
+            InstrumentLevel::Error => log::Level::Error,
+            InstrumentLevel::Warn => log::Level::Warn,
+            InstrumentLevel::Info => log::Level::Info,
+            InstrumentLevel::Debug => log::Level::Debug,
+            InstrumentLevel::Trace => log::Level::Trace,
+        };
+
+        match &measurement.output {
+            Some(output) => log::log!(
+                level,
+                "{} {} took {:?} ({:?} self): {} -> {output}",
+                measurement.path(),
+                measurement.operation,
+                measurement.duration,
+                measurement.self_duration,
+                measurement.input,
+            ),
+            None => log::log!(
+                level,
+                "{} {} failed after {:?} ({:?} self): {}",
+                measurement.path(),
+                measurement.operation,
+                measurement.duration,
+                measurement.self_duration,
+                measurement.input,
+            ),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+/// [`MeasurementSink`] which reports every measurement as a [`tracing`] event
+pub struct TracingSink {
+    /// The level of the tracing events
+    pub level: InstrumentLevel,
+}
+
+impl MeasurementSink for TracingSink {
+    fn record(&self, measurement: &Measurement) {
+        macro_rules! event {
+            ($level:expr) => {
+                tracing::event!(
+                    target: module_path!(),
+                    $level,
+                    stage = measurement.path(),
+                    operation = %measurement.operation,
+                    duration_ns = saturating_nanos(measurement.duration),
+                    self_duration_ns = saturating_nanos(measurement.self_duration),
+                    input_dtype = %measurement.input.dtype,
+                    input_shape = ?measurement.input.shape,
+                    input_bytes = measurement.input.bytes,
+                    output_dtype = measurement.output.as_ref().map(|o| tracing::field::display(o.dtype)),
+                    output_shape = measurement.output.as_ref().map(|o| tracing::field::debug(&o.shape)),
+                    output_bytes = measurement.output.as_ref().map(|o| o.bytes),
+                    failed = measurement.output.is_none(),
+                )
+            };
+        }
+
+        match self.level {
+            InstrumentLevel::Error => event!(tracing::Level::ERROR),
+            InstrumentLevel::Warn => event!(tracing::Level::WARN),
+            InstrumentLevel::Info => event!(tracing::Level::INFO),
+            InstrumentLevel::Debug => event!(tracing::Level::DEBUG),
+            InstrumentLevel::Trace => event!(tracing::Level::TRACE),
+        }
+    }
+}
+
+#[derive(Default)]
+/// [`MeasurementSink`] which aggregates the measurements in memory into one
+/// [`Histogram`] per stage path and operation
+pub struct HistogramSink {
+    histograms: Mutex<BTreeMap<(String, MeasuredOperation), Histogram>>,
+}
+
+impl HistogramSink {
+    #[must_use]
+    /// Creates a new sink without any measurements
+    pub const fn new() -> Self {
+        Self {
+            histograms: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    #[must_use]
+    /// Returns a snapshot of the histograms, keyed by the stage path and
+    /// operation
+    pub fn snapshot(&self) -> BTreeMap<(String, MeasuredOperation), Histogram> {
+        self.histograms
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Removes all measurements
+    pub fn clear(&self) {
+        self.histograms
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+impl MeasurementSink for HistogramSink {
+    fn record(&self, measurement: &Measurement) {
+        let mut histograms = self
+            .histograms
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        histograms
+            .entry((measurement.path(), measurement.operation))
+            .or_default()
+            .record(measurement);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// Aggregated measurements of one stage and operation
+pub struct Histogram {
+    /// The number of calls
+    pub calls: u64,
+    /// The number of failed calls
+    pub failures: u64,
+    /// The total wall time of all calls
+    pub duration: Duration,
+    /// The total wall time of all calls, excluding the time spent in nested
+    /// instrumented stages
+    pub self_duration: Duration,
+    /// The total size of all inputs, in bytes
+    pub input_bytes: u64,
+    /// The total size of all successful outputs, in bytes
+    pub output_bytes: u64,
+    /// The number of calls by wall time, where the bucket `i` counts the calls
+    /// that took between `2^i` (inclusive) and `2^(i+1)` (exclusive)
+    /// nanoseconds, and the bucket zero also counts calls that took zero
+    /// nanoseconds
+    pub duration_buckets: [u64; 64],
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self {
+            calls: 0,
+            failures: 0,
+            duration: Duration::ZERO,
+            self_duration: Duration::ZERO,
+            input_bytes: 0,
+            output_bytes: 0,
+            duration_buckets: [0; 64],
+        }
+    }
+}
+
+impl Histogram {
+    fn record(&mut self, measurement: &Measurement) {
+        self.calls += 1;
+        self.duration += measurement.duration;
+        self.self_duration += measurement.self_duration;
+        self.input_bytes += measurement.input.bytes as u64;
+
+        match &measurement.output {
+            Some(output) => self.output_bytes += output.bytes as u64,
+            None => self.failures += 1,
+        }
+
+        let nanos = saturating_nanos(measurement.duration);
+        let bucket = (u64::BITS - nanos.leading_zeros()).saturating_sub(1);
+
+        if let Some(count) = self.duration_buckets.get_mut(bucket as usize) {
+            *count += 1;
+        }
+    }
+}
+
+fn saturating_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+thread_local! {
+    /// Stack of the instrumented stages that are currently running on this
+    /// thread, with the wall time spent in their nested stages
+    static STAGES: RefCell<Vec<(String, Duration)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Guard that keeps a stage on the thread-local stage stack, and removes it
+/// even if the instrumented operation panics
+struct StageGuard {
+    depth: usize,
+}
+
+impl StageGuard {
+    fn enter(stage: String) -> Self {
+        STAGES.with_borrow_mut(|stages| {
+            stages.push((stage, Duration::ZERO));
+            Self {
+                depth: stages.len(),
+            }
+        })
+    }
+
+    /// Leaves the stage after it ran for `duration` and returns the path of
+    /// stages and the wall time that was spent in nested stages
+    fn exit(self, duration: Duration) -> (Vec<String>, Duration) {
+        STAGES.with_borrow_mut(|stages| {
+            let path = stages.iter().map(|(stage, _)| stage.clone()).collect();
+            let nested = stages.last().map_or(Duration::ZERO, |(_, nested)| *nested);
+
+            stages.truncate(self.depth.saturating_sub(1));
+            if let Some((_, parent_nested)) = stages.last_mut() {
+                *parent_nested += duration;
+            }
+
+            std::mem::forget(self);
+
+            (path, nested)
+        })
+    }
+}
+
+impl Drop for StageGuard {
+    fn drop(&mut self) {
+        let _ = STAGES.try_with(|stages| {
+            if let Ok(mut stages) = stages.try_borrow_mut() {
+                stages.truncate(self.depth.saturating_sub(1));
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use numcodecs::AnyArray;
+    use numcodecs_identity::IdentityCodec;
+    use numcodecs_round::RoundCodec;
+    use serde_json::json;
+
+    use super::*;
+
+    numcodecs_registry::export_global! {
+        static REGISTRY: numcodecs_registry::EmptyRegistry = numcodecs_registry::EmptyRegistry;
+    }
+
+    fn instrument(codec: ErasedDynCodec, stage: Option<&str>, sink: &str) -> InstrumentCodec {
+        InstrumentCodec {
+            codec,
+            stage: stage.map(String::from),
+            sink: InstrumentSink::Registered {
+                name: String::from(sink),
+            },
+            version: StaticCodecVersion,
+        }
+    }
+
+    #[test]
+    fn nested_stages() {
+        let sink = Arc::new(HistogramSink::new());
+        register_sink("nested_stages", sink.clone());
+
+        let inner = instrument(
+            ErasedDynCodec::new(IdentityCodec {
+                version: StaticCodecVersion,
+            }),
+            None,
+            "nested_stages",
+        );
+        let codec = instrument(ErasedDynCodec::new(inner), Some("outer"), "nested_stages");
+
+        let data = AnyArray::F64(ndarray::Array::zeros((4, 8)).into_dyn());
+        let encoded = codec.encode(data.cow()).unwrap();
+        let mut decoded = AnyArray::F64(ndarray::Array::ones((4, 8)).into_dyn());
+        codec
+            .decode_into(encoded.view(), decoded.view_mut())
+            .unwrap();
+        assert_eq!(decoded, data);
+
+        let histograms = sink.snapshot();
+        assert_eq!(
+            histograms.keys().cloned().collect::<Vec<_>>(),
+            vec![
+                (String::from("outer"), MeasuredOperation::Encode),
+                (String::from("outer"), MeasuredOperation::DecodeInto),
+                (String::from("outer/identity.rs"), MeasuredOperation::Encode),
+                (
+                    String::from("outer/identity.rs"),
+                    MeasuredOperation::DecodeInto
+                ),
+            ]
+        );
+
+        for ((path, _), histogram) in &histograms {
+            assert_eq!(histogram.calls, 1);
+            assert_eq!(histogram.failures, 0);
+            assert_eq!(histogram.input_bytes, 4 * 8 * 8);
+            assert_eq!(histogram.output_bytes, 4 * 8 * 8);
+            assert_eq!(histogram.duration_buckets.iter().sum::<u64>(), 1);
+
+            if path == "outer" {
+                assert!(histogram.self_duration <= histogram.duration);
+            } else {
+                assert_eq!(histogram.self_duration, histogram.duration);
+            }
+        }
+
+        let outer = histograms
+            .get(&(String::from("outer"), MeasuredOperation::Encode))
+            .unwrap();
+        let inner = histograms
+            .get(&(String::from("outer/identity.rs"), MeasuredOperation::Encode))
+            .unwrap();
+        assert_eq!(outer.self_duration + inner.duration, outer.duration);
+
+        unregister_sink("nested_stages");
+    }
+
+    #[test]
+    fn failed_call() {
+        let sink = Arc::new(HistogramSink::new());
+        register_sink("failed_call", sink.clone());
+
+        let round: RoundCodec = serde_json::from_value(json!({
+            "precision": 1.0,
+        }))
+        .unwrap();
+        let codec = instrument(ErasedDynCodec::new(round), None, "failed_call");
+
+        let data = AnyArray::I32(ndarray::Array::zeros(16).into_dyn());
+        assert!(matches!(
+            codec.encode(data.cow()),
+            Err(InstrumentCodecError::InnerEncodeFailed { .. })
+        ));
+
+        let histograms = sink.snapshot();
+        let histogram = histograms
+            .get(&(String::from("round.rs"), MeasuredOperation::Encode))
+            .unwrap();
+        assert_eq!(histogram.calls, 1);
+        assert_eq!(histogram.failures, 1);
+        assert_eq!(histogram.input_bytes, 16 * 4);
+        assert_eq!(histogram.output_bytes, 0);
+
+        // the stage stack is empty again after the call
+        assert!(STAGES.with_borrow(Vec::is_empty));
+
+        unregister_sink("failed_call");
+    }
+
+    #[test]
+    fn sink_config() {
+        assert!(
+            serde_json::from_value::<InstrumentSink>(json!({ "sink": "log" })).unwrap()
+                == InstrumentSink::Log {
+                    level: InstrumentLevel::Debug
+                }
+        );
+        assert!(
+            serde_json::from_value::<InstrumentSink>(json!({
+                "sink": "tracing", "level": "trace",
+            }))
+            .unwrap()
+                == InstrumentSink::Tracing {
+                    level: InstrumentLevel::Trace
+                }
+        );
+
+        let codec = instrument(
+            ErasedDynCodec::new(IdentityCodec {
+                version: StaticCodecVersion,
+            }),
+            Some("stage"),
+            "sink_config",
+        );
+        assert_eq!(
+            serde_json::to_value(&codec).unwrap(),
+            json!({
+                "codec": { "id": "identity.rs", "_version": "1.0.0" },
+                "stage": "stage",
+                "sink": "registered",
+                "name": "sink_config",
+                "_version": "1.0.0",
+            })
+        );
+    }
+}
@@ -24,20 +24,22 @@ use ::serde_json as _;
 
 use std::borrow::Cow;
 use std::fmt;
+use std::num::NonZeroUsize;
 
-use ndarray::{Array, Array1, ArrayBase, Axis, Data, Dimension, IxDyn, ShapeError};
+use ndarray::{Array, Array1, ArrayBase, ArrayViewMut, Axis, Data, Dimension, IxDyn, ShapeError};
 use num_traits::identities::Zero;
 use numcodecs::{
     AnyArray, AnyArrayAssignError, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray,
     Codec, StaticCodec, StaticCodecConfig, StaticCodecVersion,
 };
+use numcodecs_utils::slices::{self, SliceFramingError, SlicesError};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 mod ffi;
 
-type Jpeg2000CodecVersion = StaticCodecVersion<0, 1, 0>;
+type Jpeg2000CodecVersion = StaticCodecVersion<0, 1, 1>;
 
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
 // serde cannot deny unknown fields because of the flatten
@@ -52,6 +54,13 @@ pub struct Jpeg2000Codec {
     /// JPEG 2000 compression mode
     #[serde(flatten)]
     pub mode: Jpeg2000CompressionMode,
+    /// The optional number of threads with which the 2D slices are encoded
+    /// and decoded in parallel
+    ///
+    /// If `None`, the slices are processed serially. The encoded bytes do not
+    /// depend on the number of threads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_threads: Option<NonZeroUsize>,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default, rename = "_version")]
     pub version: Jpeg2000CodecVersion,
@@ -84,28 +93,28 @@ impl Codec for Jpeg2000Codec {
     fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
         match data {
             AnyCowArray::I8(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, &self.mode)?).into_dyn(),
+                Array1::from(compress_with_threads(data, &self.mode, self.num_threads)?).into_dyn(),
             )),
             AnyCowArray::U8(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, &self.mode)?).into_dyn(),
+                Array1::from(compress_with_threads(data, &self.mode, self.num_threads)?).into_dyn(),
             )),
             AnyCowArray::I16(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, &self.mode)?).into_dyn(),
+                Array1::from(compress_with_threads(data, &self.mode, self.num_threads)?).into_dyn(),
             )),
             AnyCowArray::U16(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, &self.mode)?).into_dyn(),
+                Array1::from(compress_with_threads(data, &self.mode, self.num_threads)?).into_dyn(),
             )),
             AnyCowArray::I32(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, &self.mode)?).into_dyn(),
+                Array1::from(compress_with_threads(data, &self.mode, self.num_threads)?).into_dyn(),
             )),
             AnyCowArray::U32(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, &self.mode)?).into_dyn(),
+                Array1::from(compress_with_threads(data, &self.mode, self.num_threads)?).into_dyn(),
             )),
             AnyCowArray::I64(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, &self.mode)?).into_dyn(),
+                Array1::from(compress_with_threads(data, &self.mode, self.num_threads)?).into_dyn(),
             )),
             AnyCowArray::U64(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, &self.mode)?).into_dyn(),
+                Array1::from(compress_with_threads(data, &self.mode, self.num_threads)?).into_dyn(),
             )),
             encoded => Err(Jpeg2000CodecError::UnsupportedDtype(encoded.dtype())),
         }
@@ -124,7 +133,7 @@ impl Codec for Jpeg2000Codec {
             });
         }
 
-        decompress(&AnyCowArray::U8(encoded).as_bytes())
+        decompress_with_threads(&AnyCowArray::U8(encoded).as_bytes(), self.num_threads)
     }
 
     fn decode_into(
@@ -138,6 +147,45 @@ impl Codec for Jpeg2000Codec {
     }
 }
 
+impl Jpeg2000Codec {
+    /// Decodes only the slices at the `indices` along the first axis of the
+    /// `encoded` array into the `decoded` array.
+    ///
+    /// The `decoded` array must have the dtype and shape of the encoded data,
+    /// except that its first axis must have length `indices.len()`.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Jpeg2000CodecError`] if the `encoded` array is not a
+    /// one-dimensional byte array, or if decoding the selected slices with
+    /// [`decompress_slices_into`] fails.
+    pub fn decode_slices_into(
+        &self,
+        encoded: AnyArrayView,
+        indices: &[usize],
+        decoded: AnyArrayViewMut,
+    ) -> Result<(), Jpeg2000CodecError> {
+        let AnyArrayView::U8(encoded) = encoded else {
+            return Err(Jpeg2000CodecError::EncodedDataNotBytes {
+                dtype: encoded.dtype(),
+            });
+        };
+
+        if !matches!(encoded.shape(), [_]) {
+            return Err(Jpeg2000CodecError::EncodedDataNotOneDimensional {
+                shape: encoded.shape().to_vec(),
+            });
+        }
+
+        decompress_slices_into(
+            &AnyArrayView::U8(encoded).as_bytes(),
+            indices,
+            decoded,
+            self.num_threads,
+        )
+    }
+}
+
 impl StaticCodec for Jpeg2000Codec {
     const CODEC_ID: &'static str = "jpeg2000.rs";
 
@@ -209,6 +257,17 @@ pub enum Jpeg2000CodecError {
     /// [`Jpeg2000Codec`] failed to decode from an excessive number of slices
     #[error("Jpeg2000 failed to decode from an excessive number of slices")]
     DecodeTooManySlices,
+    /// [`Jpeg2000Codec`] failed to decode from a corrupted slice index
+    #[error("Jpeg2000 failed to decode from a corrupted slice index")]
+    DecodeCorruptedSliceIndex,
+    /// [`Jpeg2000Codec`] cannot decode a slice at an out-of-bounds index
+    #[error("Jpeg2000 cannot decode the slice at index {index} of an axis of length {len}")]
+    SliceIndexOutOfBounds {
+        /// The out-of-bounds slice index
+        index: usize,
+        /// The length of the first axis of the encoded array
+        len: usize,
+    },
     /// [`Jpeg2000Codec`] failed to decode the data
     #[error("Jpeg2000 failed to decode the data")]
     Jpeg2000DecodeFailed {
@@ -230,6 +289,27 @@ pub enum Jpeg2000CodecError {
     },
 }
 
+impl From<SlicesError> for Jpeg2000CodecError {
+    fn from(err: SlicesError) -> Self {
+        match err {
+            SlicesError::SliceEncodeFailed { source } => Self::SliceEncodeFailed {
+                source: Jpeg2000SliceError(source),
+            },
+            SlicesError::SliceDecodeFailed { source } => Self::SliceDecodeFailed {
+                source: Jpeg2000SliceError(source),
+            },
+            SlicesError::DecodeTooManySlices => Self::DecodeTooManySlices,
+            SlicesError::DecodeCorruptedSliceIndex => Self::DecodeCorruptedSliceIndex,
+            SlicesError::SliceIndexOutOfBounds { index, len } => {
+                Self::SliceIndexOutOfBounds { index, len }
+            }
+            SlicesError::MismatchedDecodeIntoArray { source } => {
+                Self::MismatchedDecodeIntoArray { source }
+            }
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 #[error(transparent)]
 /// Opaque error for when encoding or decoding the header fails
@@ -238,7 +318,7 @@ pub struct Jpeg2000HeaderError(postcard::Error);
 #[derive(Debug, Error)]
 #[error(transparent)]
 /// Opaque error for when encoding or decoding a slice fails
-pub struct Jpeg2000SliceError(postcard::Error);
+pub struct Jpeg2000SliceError(SliceFramingError);
 
 #[derive(Debug, Error)]
 #[error(transparent)]
@@ -247,6 +327,12 @@ pub struct Jpeg2000CodingError(ffi::Jpeg2000Error);
 
 /// Compress the `data` array using JPEG 2000 with the provided `mode`.
 ///
+/// The 2D slices are compressed serially. Use [`compress_with_threads`] to
+/// compress the slices in parallel.
+///
+/// The encoded data contains an index of the byte offsets of all slices,
+/// which allows [`decompress_slices_into`] to decode only selected slices.
+///
 /// # Errors
 ///
 /// Errors with
@@ -258,67 +344,76 @@ pub fn compress<T: Jpeg2000Element, S: Data<Elem = T>, D: Dimension>(
     data: ArrayBase<S, D>,
     mode: &Jpeg2000CompressionMode,
 ) -> Result<Vec<u8>, Jpeg2000CodecError> {
+    compress_with_threads(data, mode, None)
+}
+
+/// Compress the `data` array using JPEG 2000 with the provided `mode`.
+///
+/// The 2D slices are compressed in parallel using up to `num_threads` threads
+/// if provided, or serially otherwise. The output is the same in both cases.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`Jpeg2000CodecError::HeaderEncodeFailed`] if encoding the header failed
+/// - [`Jpeg2000CodecError::Jpeg2000EncodeFailed`] if encoding with JPEG 2000
+///   failed
+/// - [`Jpeg2000CodecError::SliceEncodeFailed`] if encoding a slice failed
+pub fn compress_with_threads<T: Jpeg2000Element, S: Data<Elem = T>, D: Dimension>(
+    data: ArrayBase<S, D>,
+    mode: &Jpeg2000CompressionMode,
+    num_threads: Option<NonZeroUsize>,
+) -> Result<Vec<u8>, Jpeg2000CodecError> {
+    let data = data.into_dyn();
+
+    // JPEG 2000 cannot handle zero-length dimensions, which have no slices
+    let (slice_offsets, slices) =
+        slices::encode_slices(&data, num_threads, |slice, [height, width]: [usize; 2]| {
+            let mut encoded_slice = Vec::new();
+
+            ffi::encode_into(
+                slice.iter().copied(),
+                width,
+                height,
+                match mode {
+                    Jpeg2000CompressionMode::PSNR { psnr } => {
+                        ffi::Jpeg2000CompressionMode::PSNR(*psnr)
+                    }
+                    Jpeg2000CompressionMode::Rate { rate } => {
+                        ffi::Jpeg2000CompressionMode::Rate(*rate)
+                    }
+                    Jpeg2000CompressionMode::Lossless => ffi::Jpeg2000CompressionMode::Lossless,
+                },
+                &mut encoded_slice,
+            )
+            .map_err(|err| Jpeg2000CodecError::Jpeg2000EncodeFailed {
+                source: Jpeg2000CodingError(err),
+            })
+            .map(|()| encoded_slice)
+        })?;
+
     let mut encoded = postcard::to_extend(
         &CompressionHeader {
             dtype: T::DTYPE,
             shape: Cow::Borrowed(data.shape()),
             version: StaticCodecVersion,
+            slice_offsets,
         },
         Vec::new(),
     )
     .map_err(|err| Jpeg2000CodecError::HeaderEncodeFailed {
         source: Jpeg2000HeaderError(err),
     })?;
-
-    // JPEG 2000 cannot handle zero-length dimensions
-    if data.is_empty() {
-        return Ok(encoded);
-    }
-
-    let mut encoded_slice = Vec::new();
-
-    let mut chunk_size = Vec::from(data.shape());
-    let (width, height) = match *chunk_size.as_mut_slice() {
-        [ref mut rest @ .., height, width] => {
-            for r in rest {
-                *r = 1;
-            }
-            (width, height)
-        }
-        [width] => (width, 1),
-        [] => (1, 1),
-    };
-
-    for slice in data.into_dyn().exact_chunks(chunk_size.as_slice()) {
-        encoded_slice.clear();
-
-        ffi::encode_into(
-            slice.iter().copied(),
-            width,
-            height,
-            match mode {
-                Jpeg2000CompressionMode::PSNR { psnr } => ffi::Jpeg2000CompressionMode::PSNR(*psnr),
-                Jpeg2000CompressionMode::Rate { rate } => ffi::Jpeg2000CompressionMode::Rate(*rate),
-                Jpeg2000CompressionMode::Lossless => ffi::Jpeg2000CompressionMode::Lossless,
-            },
-            &mut encoded_slice,
-        )
-        .map_err(|err| Jpeg2000CodecError::Jpeg2000EncodeFailed {
-            source: Jpeg2000CodingError(err),
-        })?;
-
-        encoded = postcard::to_extend(encoded_slice.as_slice(), encoded).map_err(|err| {
-            Jpeg2000CodecError::SliceEncodeFailed {
-                source: Jpeg2000SliceError(err),
-            }
-        })?;
-    }
+    encoded.extend_from_slice(&slices);
 
     Ok(encoded)
 }
 
 /// Decompress the `encoded` data into an array using JPEG 2000.
 ///
+/// The 2D slices are decompressed serially. Use [`decompress_with_threads`]
+/// to decompress the slices in parallel.
+///
 /// # Errors
 ///
 /// Errors with
@@ -331,92 +426,262 @@ pub fn compress<T: Jpeg2000Element, S: Data<Elem = T>, D: Dimension>(
 /// - [`Jpeg2000CodecError::DecodeTooManySlices`] if the encoded data contains
 ///   too many slices
 pub fn decompress(encoded: &[u8]) -> Result<AnyArray, Jpeg2000CodecError> {
+    decompress_with_threads(encoded, None)
+}
+
+/// Decompress the `encoded` data into an array using JPEG 2000.
+///
+/// The 2D slices are decompressed in parallel using up to `num_threads`
+/// threads if provided, or serially otherwise.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`Jpeg2000CodecError::HeaderDecodeFailed`] if decoding the header failed
+/// - [`Jpeg2000CodecError::SliceDecodeFailed`] if decoding a slice failed
+/// - [`Jpeg2000CodecError::Jpeg2000DecodeFailed`] if decoding with JPEG 2000
+///   failed
+/// - [`Jpeg2000CodecError::DecodeInvalidShape`] if the encoded data decodes to
+///   an unexpected shape
+/// - [`Jpeg2000CodecError::DecodeTooManySlices`] if the encoded data contains
+///   too many slices
+pub fn decompress_with_threads(
+    encoded: &[u8],
+    num_threads: Option<NonZeroUsize>,
+) -> Result<AnyArray, Jpeg2000CodecError> {
     fn decompress_typed<T: Jpeg2000Element>(
-        mut encoded: &[u8],
+        encoded: &[u8],
         shape: &[usize],
+        num_threads: Option<NonZeroUsize>,
     ) -> Result<Array<T, IxDyn>, Jpeg2000CodecError> {
         let mut decoded = Array::<T, _>::zeros(shape);
+        slices::decode_slices_into(
+            encoded,
+            decoded.view_mut(),
+            num_threads,
+            decompress_slice_into,
+        )?;
+        Ok(decoded)
+    }
 
-        let mut chunk_size = Vec::from(shape);
-        let (width, height) = match *chunk_size.as_mut_slice() {
-            [ref mut rest @ .., height, width] => {
-                for r in rest {
-                    *r = 1;
-                }
-                (width, height)
-            }
-            [width] => (width, 1),
-            [] => (1, 1),
-        };
+    let (header, encoded) = decode_header(encoded)?;
 
-        for mut slice in decoded.exact_chunks_mut(chunk_size.as_slice()) {
-            let (encoded_slice, rest) =
-                postcard::take_from_bytes::<Cow<[u8]>>(encoded).map_err(|err| {
-                    Jpeg2000CodecError::SliceDecodeFailed {
-                        source: Jpeg2000SliceError(err),
-                    }
-                })?;
-            encoded = rest;
+    match header.dtype {
+        Jpeg2000DType::I8 => Ok(AnyArray::I8(decompress_typed(
+            encoded,
+            &header.shape,
+            num_threads,
+        )?)),
+        Jpeg2000DType::U8 => Ok(AnyArray::U8(decompress_typed(
+            encoded,
+            &header.shape,
+            num_threads,
+        )?)),
+        Jpeg2000DType::I16 => Ok(AnyArray::I16(decompress_typed(
+            encoded,
+            &header.shape,
+            num_threads,
+        )?)),
+        Jpeg2000DType::U16 => Ok(AnyArray::U16(decompress_typed(
+            encoded,
+            &header.shape,
+            num_threads,
+        )?)),
+        Jpeg2000DType::I32 => Ok(AnyArray::I32(decompress_typed(
+            encoded,
+            &header.shape,
+            num_threads,
+        )?)),
+        Jpeg2000DType::U32 => Ok(AnyArray::U32(decompress_typed(
+            encoded,
+            &header.shape,
+            num_threads,
+        )?)),
+        Jpeg2000DType::I64 => Ok(AnyArray::I64(decompress_typed(
+            encoded,
+            &header.shape,
+            num_threads,
+        )?)),
+        Jpeg2000DType::U64 => Ok(AnyArray::U64(decompress_typed(
+            encoded,
+            &header.shape,
+            num_threads,
+        )?)),
+    }
+}
 
-            let (decoded_slice, (_width, _height)) =
-                ffi::decode::<T>(&encoded_slice).map_err(|err| {
-                    Jpeg2000CodecError::Jpeg2000DecodeFailed {
-                        source: Jpeg2000CodingError(err),
-                    }
-                })?;
-            let mut decoded_slice = Array::from_shape_vec((height, width), decoded_slice)
-                .map_err(|source| Jpeg2000CodecError::DecodeInvalidShape { source })?
-                .into_dyn();
+/// Decompress only the slices at the `indices` along the first axis of the
+/// `encoded` data into the `decoded` array using JPEG 2000.
+///
+/// The `decoded` array must have the dtype and shape of the encoded data,
+/// except that its first axis must have length `indices.len()`. For arrays
+/// with more than two dimensions, only the 2D slices that are selected by
+/// the `indices` are decoded, using up to `num_threads` threads if provided,
+/// or serially otherwise.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`Jpeg2000CodecError::HeaderDecodeFailed`] if decoding the header failed
+/// - [`Jpeg2000CodecError::SliceIndexOutOfBounds`] if any of the `indices`
+///   is out of bounds for the first axis of the encoded data
+/// - [`Jpeg2000CodecError::MismatchedDecodeIntoArray`] if the `decoded`
+///   array has the wrong dtype or shape
+/// - [`Jpeg2000CodecError::DecodeCorruptedSliceIndex`] if the slice index of
+///   the encoded data is corrupted
+/// - [`Jpeg2000CodecError::SliceDecodeFailed`] if decoding a slice failed
+/// - [`Jpeg2000CodecError::Jpeg2000DecodeFailed`] if decoding with JPEG 2000
+///   failed
+/// - [`Jpeg2000CodecError::DecodeInvalidShape`] if the encoded data decodes to
+///   an unexpected shape
+pub fn decompress_slices_into(
+    encoded: &[u8],
+    indices: &[usize],
+    decoded: AnyArrayViewMut,
+    num_threads: Option<NonZeroUsize>,
+) -> Result<(), Jpeg2000CodecError> {
+    let (header, encoded) = decode_header(encoded)?;
+
+    let dtype = match header.dtype {
+        Jpeg2000DType::I8 => AnyArrayDType::I8,
+        Jpeg2000DType::U8 => AnyArrayDType::U8,
+        Jpeg2000DType::I16 => AnyArrayDType::I16,
+        Jpeg2000DType::U16 => AnyArrayDType::U16,
+        Jpeg2000DType::I32 => AnyArrayDType::I32,
+        Jpeg2000DType::U32 => AnyArrayDType::U32,
+        Jpeg2000DType::I64 => AnyArrayDType::I64,
+        Jpeg2000DType::U64 => AnyArrayDType::U64,
+    };
+    slices::check_decode_selected_slices_into(dtype, &header.shape, indices, &decoded)?;
+
+    match decoded {
+        AnyArrayViewMut::I8(decoded) => slices::decode_selected_slices_into(
+            encoded,
+            &header.slice_offsets,
+            &header.shape,
+            indices,
+            decoded,
+            num_threads,
+            decompress_slice_into,
+        ),
+        AnyArrayViewMut::U8(decoded) => slices::decode_selected_slices_into(
+            encoded,
+            &header.slice_offsets,
+            &header.shape,
+            indices,
+            decoded,
+            num_threads,
+            decompress_slice_into,
+        ),
+        AnyArrayViewMut::I16(decoded) => slices::decode_selected_slices_into(
+            encoded,
+            &header.slice_offsets,
+            &header.shape,
+            indices,
+            decoded,
+            num_threads,
+            decompress_slice_into,
+        ),
+        AnyArrayViewMut::U16(decoded) => slices::decode_selected_slices_into(
+            encoded,
+            &header.slice_offsets,
+            &header.shape,
+            indices,
+            decoded,
+            num_threads,
+            decompress_slice_into,
+        ),
+        AnyArrayViewMut::I32(decoded) => slices::decode_selected_slices_into(
+            encoded,
+            &header.slice_offsets,
+            &header.shape,
+            indices,
+            decoded,
+            num_threads,
+            decompress_slice_into,
+        ),
+        AnyArrayViewMut::U32(decoded) => slices::decode_selected_slices_into(
+            encoded,
+            &header.slice_offsets,
+            &header.shape,
+            indices,
+            decoded,
+            num_threads,
+            decompress_slice_into,
+        ),
+        AnyArrayViewMut::I64(decoded) => slices::decode_selected_slices_into(
+            encoded,
+            &header.slice_offsets,
+            &header.shape,
+            indices,
+            decoded,
+            num_threads,
+            decompress_slice_into,
+        ),
+        AnyArrayViewMut::U64(decoded) => slices::decode_selected_slices_into(
+            encoded,
+            &header.slice_offsets,
+            &header.shape,
+            indices,
+            decoded,
+            num_threads,
+            decompress_slice_into,
+        ),
+        decoded => Err(Jpeg2000CodecError::UnsupportedDtype(decoded.dtype())),
+    }
+}
 
-            while decoded_slice.ndim() > shape.len() {
-                decoded_slice = decoded_slice.remove_axis(Axis(0));
-            }
+/// Decodes the header of the `encoded` data
+///
+/// The slice index of data that was encoded with the index-less format
+/// version 0.1.0 is reconstructed by walking over the slices.
+fn decode_header(encoded: &[u8]) -> Result<(CompressionHeader<'_>, &[u8]), Jpeg2000CodecError> {
+    if let Ok((header, encoded)) = postcard::take_from_bytes::<LegacyCompressionHeader>(encoded) {
+        let slice_offsets = slices::slice_offsets(encoded, slices::num_slices::<2>(&header.shape))?;
+
+        return Ok((
+            CompressionHeader {
+                dtype: header.dtype,
+                shape: header.shape,
+                version: StaticCodecVersion,
+                slice_offsets,
+            },
+            encoded,
+        ));
+    }
 
-            slice.assign(&decoded_slice);
+    postcard::take_from_bytes::<CompressionHeader>(encoded).map_err(|err| {
+        Jpeg2000CodecError::HeaderDecodeFailed {
+            source: Jpeg2000HeaderError(err),
         }
+    })
+}
 
-        if !encoded.is_empty() {
-            return Err(Jpeg2000CodecError::DecodeTooManySlices);
+fn decompress_slice_into<T: Jpeg2000Element>(
+    encoded_slice: &[u8],
+    mut slice: ArrayViewMut<T, IxDyn>,
+    slice_shape: [usize; 2],
+) -> Result<(), Jpeg2000CodecError> {
+    let (decoded_slice, (_width, _height)) = ffi::decode::<T>(encoded_slice).map_err(|err| {
+        Jpeg2000CodecError::Jpeg2000DecodeFailed {
+            source: Jpeg2000CodingError(err),
         }
+    })?;
+    let mut decoded_slice = Array::from_shape_vec(slice_shape, decoded_slice)
+        .map_err(|source| Jpeg2000CodecError::DecodeInvalidShape { source })?
+        .into_dyn();
 
-        Ok(decoded)
+    while decoded_slice.ndim() > slice.ndim() {
+        decoded_slice = decoded_slice.remove_axis(Axis(0));
     }
 
-    let (header, encoded) =
-        postcard::take_from_bytes::<CompressionHeader>(encoded).map_err(|err| {
-            Jpeg2000CodecError::HeaderDecodeFailed {
-                source: Jpeg2000HeaderError(err),
-            }
-        })?;
+    slice.assign(&decoded_slice);
 
-    // Return empty data for zero-size arrays
-    if header.shape.iter().copied().any(|s| s == 0) {
-        return match header.dtype {
-            Jpeg2000DType::I8 => Ok(AnyArray::I8(Array::zeros(&*header.shape))),
-            Jpeg2000DType::U8 => Ok(AnyArray::U8(Array::zeros(&*header.shape))),
-            Jpeg2000DType::I16 => Ok(AnyArray::I16(Array::zeros(&*header.shape))),
-            Jpeg2000DType::U16 => Ok(AnyArray::U16(Array::zeros(&*header.shape))),
-            Jpeg2000DType::I32 => Ok(AnyArray::I32(Array::zeros(&*header.shape))),
-            Jpeg2000DType::U32 => Ok(AnyArray::U32(Array::zeros(&*header.shape))),
-            Jpeg2000DType::I64 => Ok(AnyArray::I64(Array::zeros(&*header.shape))),
-            Jpeg2000DType::U64 => Ok(AnyArray::U64(Array::zeros(&*header.shape))),
-        };
-    }
-
-    match header.dtype {
-        Jpeg2000DType::I8 => Ok(AnyArray::I8(decompress_typed(encoded, &header.shape)?)),
-        Jpeg2000DType::U8 => Ok(AnyArray::U8(decompress_typed(encoded, &header.shape)?)),
-        Jpeg2000DType::I16 => Ok(AnyArray::I16(decompress_typed(encoded, &header.shape)?)),
-        Jpeg2000DType::U16 => Ok(AnyArray::U16(decompress_typed(encoded, &header.shape)?)),
-        Jpeg2000DType::I32 => Ok(AnyArray::I32(decompress_typed(encoded, &header.shape)?)),
-        Jpeg2000DType::U32 => Ok(AnyArray::U32(decompress_typed(encoded, &header.shape)?)),
-        Jpeg2000DType::I64 => Ok(AnyArray::I64(decompress_typed(encoded, &header.shape)?)),
-        Jpeg2000DType::U64 => Ok(AnyArray::U64(decompress_typed(encoded, &header.shape)?)),
-    }
+    Ok(())
 }
 
 /// Array element types which can be compressed with JPEG 2000.
-pub trait Jpeg2000Element: ffi::Jpeg2000Element + Zero {
+pub trait Jpeg2000Element: ffi::Jpeg2000Element + Zero + Send + Sync {
     /// The dtype representation of the type
     const DTYPE: Jpeg2000DType;
 }
@@ -452,6 +717,17 @@ struct CompressionHeader<'a> {
     #[serde(borrow)]
     shape: Cow<'a, [usize]>,
     version: Jpeg2000CodecVersion,
+    slice_offsets: Vec<usize>,
+}
+
+/// Header of the format version 0.1.0, which has no slice index
+#[derive(Deserialize)]
+struct LegacyCompressionHeader<'a> {
+    dtype: Jpeg2000DType,
+    #[serde(borrow)]
+    shape: Cow<'a, [usize]>,
+    #[expect(dead_code)]
+    version: StaticCodecVersion<0, 1, 0>,
 }
 
 /// Dtypes that JPEG 2000 can compress and decompress
@@ -652,4 +928,130 @@ mod tests {
             assert_eq!(decoded, AnyArray::I16(data));
         }
     }
+
+    #[test]
+    fn parallel_slices() {
+        std::mem::drop(simple_logger::init());
+
+        let data = Array::<i16, _>::from_shape_fn((5, 3, 16, 16), |(a, b, c, d)| {
+            i16::try_from(a * 256 + b * 16 + c * 4 + d).unwrap()
+        });
+        let mode = Jpeg2000CompressionMode::PSNR { psnr: 42.0 };
+
+        let encoded = compress(data.view(), &mode).unwrap();
+        let decoded = decompress(&encoded).unwrap();
+
+        for num_threads in [1, 2, 4, 64] {
+            let num_threads = NonZeroUsize::new(num_threads);
+
+            let encoded_parallel = compress_with_threads(data.view(), &mode, num_threads).unwrap();
+            assert_eq!(encoded_parallel, encoded);
+
+            let decoded_parallel = decompress_with_threads(&encoded, num_threads).unwrap();
+            assert_eq!(decoded_parallel, decoded);
+        }
+    }
+
+    #[test]
+    fn random_access_slices() {
+        std::mem::drop(simple_logger::init());
+
+        let data = Array::<i16, _>::from_shape_fn((5, 3, 16, 16), |(a, b, c, d)| {
+            i16::try_from(a * 256 + b * 16 + c * 4 + d).unwrap()
+        });
+        let mode = Jpeg2000CompressionMode::PSNR { psnr: 42.0 };
+
+        let encoded = compress(data.view(), &mode).unwrap();
+        let AnyArray::I16(decoded) = decompress(&encoded).unwrap() else {
+            panic!("unexpected decoded dtype");
+        };
+
+        for indices in [&[][..], &[0], &[4, 1], &[3, 0, 3]] {
+            for num_threads in [None, NonZeroUsize::new(2)] {
+                let mut slices = Array::<i16, _>::zeros((indices.len(), 3, 16, 16)).into_dyn();
+                decompress_slices_into(
+                    &encoded,
+                    indices,
+                    AnyArrayViewMut::I16(slices.view_mut()),
+                    num_threads,
+                )
+                .unwrap();
+
+                assert_eq!(slices, decoded.select(Axis(0), indices));
+            }
+        }
+
+        let data = data
+            .index_axis(Axis(0), 0)
+            .index_axis(Axis(0), 0)
+            .to_owned();
+        let encoded = compress(data.view(), &mode).unwrap();
+        let AnyArray::I16(decoded) = decompress(&encoded).unwrap() else {
+            panic!("unexpected decoded dtype");
+        };
+
+        let mut slices = Array::<i16, _>::zeros((2, 16)).into_dyn();
+        decompress_slices_into(
+            &encoded,
+            &[3, 1],
+            AnyArrayViewMut::I16(slices.view_mut()),
+            None,
+        )
+        .unwrap();
+        assert_eq!(slices, decoded.select(Axis(0), &[3, 1]));
+    }
+
+    #[test]
+    fn legacy_slices_without_index() {
+        let data = Array::<i16, _>::from_shape_fn((3, 2, 4, 4), |(a, b, c, d)| {
+            #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+            let x = (a * 32 + b * 16 + c * 4 + d) as i16;
+            x
+        });
+        let mode = Jpeg2000CompressionMode::Lossless;
+
+        let encoded = compress(data.view(), &mode).unwrap();
+        let decoded = decompress(&encoded).unwrap();
+
+        let (header, slices) = postcard::take_from_bytes::<CompressionHeader>(&encoded).unwrap();
+        let mut legacy = postcard::to_extend(
+            &(header.dtype, &header.shape, StaticCodecVersion::<0, 1, 0>),
+            Vec::new(),
+        )
+        .unwrap();
+        legacy.extend_from_slice(slices);
+
+        assert_eq!(decompress(&legacy).unwrap(), decoded);
+
+        let AnyArray::I16(decoded) = decoded else {
+            panic!("unexpected decoded dtype");
+        };
+        let mut slices = Array::<i16, _>::zeros((1, 2, 4, 4)).into_dyn();
+        decompress_slices_into(&legacy, &[1], AnyArrayViewMut::I16(slices.view_mut()), None)
+            .unwrap();
+        assert_eq!(slices, decoded.select(Axis(0), &[1]));
+    }
+
+    #[test]
+    fn random_access_slices_out_of_bounds() {
+        std::mem::drop(simple_logger::init());
+
+        let data = Array::<u8, _>::zeros((2, 4, 4));
+        let encoded = compress(data.view(), &Jpeg2000CompressionMode::Lossless).unwrap();
+
+        let mut slices = Array::<u8, _>::zeros((1, 4, 4)).into_dyn();
+        assert!(matches!(
+            decompress_slices_into(&encoded, &[2], AnyArrayViewMut::U8(slices.view_mut()), None),
+            Err(Jpeg2000CodecError::SliceIndexOutOfBounds { index: 2, len: 2 })
+        ));
+        assert!(matches!(
+            decompress_slices_into(
+                &encoded,
+                &[0, 1],
+                AnyArrayViewMut::U8(slices.view_mut()),
+                None
+            ),
+            Err(Jpeg2000CodecError::MismatchedDecodeIntoArray { .. })
+        ));
+    }
 }
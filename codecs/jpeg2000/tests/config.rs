@@ -2,7 +2,8 @@
 
 use ::{
     log as _, ndarray as _, num_traits as _, numcodecs_jpeg2000::Jpeg2000CompressionMode,
-    openjpeg_sys as _, postcard as _, schemars as _, simple_logger as _, thiserror as _,
+    numcodecs_utils as _, openjpeg_sys as _, postcard as _, schemars as _, simple_logger as _,
+    thiserror as _,
 };
 
 use numcodecs::StaticCodec;
@@ -59,3 +60,16 @@ fn lossless_config() {
 
     assert!(matches!(codec.mode, Jpeg2000CompressionMode::Lossless));
 }
+
+#[test]
+fn num_threads_config() {
+    let codec = Jpeg2000Codec::from_config(
+        Deserialize::deserialize(json!({
+            "mode": "lossless",
+            "num_threads": 4,
+        }))
+        .unwrap(),
+    );
+
+    assert_eq!(codec.num_threads.map(std::num::NonZeroUsize::get), Some(4));
+}
@@ -1,8 +1,8 @@
 #![expect(missing_docs)]
 
 use ::{
-    log as _, ndarray as _, num_traits as _, openjpeg_sys as _, postcard as _, schemars as _,
-    serde as _, serde_json as _, simple_logger as _, thiserror as _,
+    log as _, ndarray as _, num_traits as _, numcodecs_utils as _, openjpeg_sys as _,
+    postcard as _, schemars as _, serde as _, serde_json as _, simple_logger as _, thiserror as _,
 };
 
 use numcodecs::{DynCodecType, StaticCodecType};
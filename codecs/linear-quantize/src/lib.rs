@@ -27,30 +27,70 @@ use numcodecs::{
     AnyArray, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray, Codec, StaticCodec,
     StaticCodecConfig, StaticCodecVersion,
 };
-use schemars::{JsonSchema, JsonSchema_repr};
-use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use schemars::{JsonSchema, JsonSchema_repr, Schema, SchemaGenerator, json_schema};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::DeserializeOwned};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use thiserror::Error;
 use twofloat::TwoFloat;
 
-type LinearQuantizeCodecVersion = StaticCodecVersion<0, 1, 0>;
+type LinearQuantizeCodecVersion = StaticCodecVersion<0, 1, 1>;
 
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
-#[serde(deny_unknown_fields)]
+#[schemars(deny_unknown_fields)]
 /// Lossy codec to reduce the precision of floating point data.
 ///
 /// The data is quantized to unsigned integers of the best-fitting type.
 /// The range and shape of the input data is stored in-band.
+///
+/// By default, the quantization range is the range of the input data. An
+/// explicit `minimum` and `maximum` can be provided instead, e.g. such that
+/// all chunks of one variable share the same quantization grid.
 pub struct LinearQuantizeCodec {
     /// Dtype of the decoded data
     pub dtype: LinearQuantizeDType,
-    /// Binary precision of the encoded data where `$bits = \log_{2}(bins)$`
-    pub bits: LinearQuantizeBins,
+    /// Quantization mode
+    #[serde(flatten)]
+    pub mode: LinearQuantizeMode,
+    /// Optional explicit minimum of the quantization range, which must not
+    /// exceed the minimum of the data
+    #[serde(default)]
+    pub minimum: Option<f64>,
+    /// Optional explicit maximum of the quantization range, which must not
+    /// fall below the maximum of the data
+    #[serde(default)]
+    pub maximum: Option<f64>,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default, rename = "_version")]
     pub version: LinearQuantizeCodecVersion,
 }
 
+#[derive(Copy, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "mode")]
+#[serde(deny_unknown_fields)]
+/// Linear quantization mode
+pub enum LinearQuantizeMode {
+    /// Quantize using the smallest number of bins that guarantees an absolute
+    /// error bound.
+    ///
+    /// The number of bins is derived from the quantization range, which is
+    /// stored in-band, such that the absolute error is at most the error
+    /// bound, i.e. `$\left| x - \hat{x} \right| \leq eb_{abs}$`, up to
+    /// floating point roundoff errors.
+    #[serde(rename = "abs")]
+    AbsoluteError {
+        /// Non-negative maximum absolute error bound
+        eb_abs: NonNegative<f64>,
+    },
+    /// Quantize using a fixed number of bins.
+    ///
+    /// This is the default mode, which is used if no `mode` is specified.
+    #[serde(untagged)]
+    Bits {
+        /// Binary precision of the encoded data where `$bits = \log_{2}(bins)$`
+        bits: LinearQuantizeBins,
+    },
+}
+
 /// Data types which the [`LinearQuantizeCodec`] can quantize
 #[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema)]
 #[schemars(extend("enum" = ["f32", "float32", "f64", "float64"]))]
@@ -97,117 +137,129 @@ impl Codec for LinearQuantizeCodec {
     #[expect(clippy::too_many_lines)]
     fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
         let encoded = match (&data, self.dtype) {
-            (AnyCowArray::F32(data), LinearQuantizeDType::F32) => match self.bits as u8 {
-                bits @ ..=8 => AnyArray::U8(
-                    Array1::from_vec(quantize(data, |x| {
-                        let max = f32::from(u8::MAX >> (8 - bits));
-                        let x = x.mul_add(scale_for_bits::<f32>(bits), 0.5).clamp(0.0, max);
-                        #[expect(unsafe_code)]
-                        // Safety: x is clamped beforehand
-                        unsafe {
-                            x.to_int_unchecked::<u8>()
-                        }
-                    })?)
-                    .into_dyn(),
-                ),
-                bits @ 9..=16 => AnyArray::U16(
-                    Array1::from_vec(quantize(data, |x| {
-                        let max = f32::from(u16::MAX >> (16 - bits));
-                        let x = x.mul_add(scale_for_bits::<f32>(bits), 0.5).clamp(0.0, max);
-                        #[expect(unsafe_code)]
-                        // Safety: x is clamped beforehand
-                        unsafe {
-                            x.to_int_unchecked::<u16>()
-                        }
-                    })?)
-                    .into_dyn(),
-                ),
-                bits @ 17..=32 => AnyArray::U32(
-                    Array1::from_vec(quantize(data, |x| {
-                        // we need to use f64 here to have sufficient precision
-                        let max = f64::from(u32::MAX >> (32 - bits));
-                        let x = f64::from(x)
-                            .mul_add(scale_for_bits::<f64>(bits), 0.5)
-                            .clamp(0.0, max);
-                        #[expect(unsafe_code)]
-                        // Safety: x is clamped beforehand
-                        unsafe {
-                            x.to_int_unchecked::<u32>()
-                        }
-                    })?)
-                    .into_dyn(),
-                ),
-                bits @ 33.. => AnyArray::U64(
-                    Array1::from_vec(quantize(data, |x| {
-                        // we need to use TwoFloat here to have sufficient precision
-                        let max = TwoFloat::from(u64::MAX >> (64 - bits));
-                        let x = (TwoFloat::from(x) * scale_for_bits::<f64>(bits)
-                            + TwoFloat::from(0.5))
-                        .max(TwoFloat::from(0.0))
-                        .min(max);
-                        #[expect(unsafe_code)]
-                        // Safety: x is clamped beforehand
-                        unsafe {
-                            u64::try_from(x).unwrap_unchecked()
-                        }
-                    })?)
-                    .into_dyn(),
-                ),
-            },
-            (AnyCowArray::F64(data), LinearQuantizeDType::F64) => match self.bits as u8 {
-                bits @ ..=8 => AnyArray::U8(
-                    Array1::from_vec(quantize(data, |x| {
-                        let max = f64::from(u8::MAX >> (8 - bits));
-                        let x = x.mul_add(scale_for_bits::<f64>(bits), 0.5).clamp(0.0, max);
-                        #[expect(unsafe_code)]
-                        // Safety: x is clamped beforehand
-                        unsafe {
-                            x.to_int_unchecked::<u8>()
-                        }
-                    })?)
-                    .into_dyn(),
-                ),
-                bits @ 9..=16 => AnyArray::U16(
-                    Array1::from_vec(quantize(data, |x| {
-                        let max = f64::from(u16::MAX >> (16 - bits));
-                        let x = x.mul_add(scale_for_bits::<f64>(bits), 0.5).clamp(0.0, max);
-                        #[expect(unsafe_code)]
-                        // Safety: x is clamped beforehand
-                        unsafe {
-                            x.to_int_unchecked::<u16>()
-                        }
-                    })?)
-                    .into_dyn(),
-                ),
-                bits @ 17..=32 => AnyArray::U32(
-                    Array1::from_vec(quantize(data, |x| {
-                        let max = f64::from(u32::MAX >> (32 - bits));
-                        let x = x.mul_add(scale_for_bits::<f64>(bits), 0.5).clamp(0.0, max);
-                        #[expect(unsafe_code)]
-                        // Safety: x is clamped beforehand
-                        unsafe {
-                            x.to_int_unchecked::<u32>()
-                        }
-                    })?)
-                    .into_dyn(),
-                ),
-                bits @ 33.. => AnyArray::U64(
-                    Array1::from_vec(quantize(data, |x| {
-                        // we need to use TwoFloat here to have sufficient precision
-                        let max = TwoFloat::from(u64::MAX >> (64 - bits));
-                        let x = (TwoFloat::from(x) * scale_for_bits::<f64>(bits)
-                            + TwoFloat::from(0.5))
-                        .max(TwoFloat::from(0.0))
-                        .min(max);
-                        #[expect(unsafe_code)]
-                        // Safety: x is clamped beforehand
-                        unsafe {
-                            u64::try_from(x).unwrap_unchecked()
-                        }
-                    })?)
-                    .into_dyn(),
-                ),
-            },
+            (AnyCowArray::F32(data), LinearQuantizeDType::F32) => {
+                let (minimum, maximum) = quantization_range(
+                    data,
+                    self.minimum.map(f32_at_most),
+                    self.maximum.map(f32_at_least),
+                )?;
+
+                match self.bits_for_range(f64::from(minimum), f64::from(maximum))? {
+                    bits @ ..=8 => AnyArray::U8(
+                        Array1::from_vec(quantize(data, minimum, maximum, |x| {
+                            let max = f32::from(u8::MAX >> (8 - bits));
+                            let x = x.mul_add(scale_for_bits::<f32>(bits), 0.5).clamp(0.0, max);
+                            #[expect(unsafe_code)]
+                            // Safety: x is clamped beforehand
+                            unsafe {
+                                x.to_int_unchecked::<u8>()
+                            }
+                        })?)
+                        .into_dyn(),
+                    ),
+                    bits @ 9..=16 => AnyArray::U16(
+                        Array1::from_vec(quantize(data, minimum, maximum, |x| {
+                            let max = f32::from(u16::MAX >> (16 - bits));
+                            let x = x.mul_add(scale_for_bits::<f32>(bits), 0.5).clamp(0.0, max);
+                            #[expect(unsafe_code)]
+                            // Safety: x is clamped beforehand
+                            unsafe {
+                                x.to_int_unchecked::<u16>()
+                            }
+                        })?)
+                        .into_dyn(),
+                    ),
+                    bits @ 17..=32 => AnyArray::U32(
+                        Array1::from_vec(quantize(data, minimum, maximum, |x| {
+                            // we need to use f64 here to have sufficient precision
+                            let max = f64::from(u32::MAX >> (32 - bits));
+                            let x = f64::from(x)
+                                .mul_add(scale_for_bits::<f64>(bits), 0.5)
+                                .clamp(0.0, max);
+                            #[expect(unsafe_code)]
+                            // Safety: x is clamped beforehand
+                            unsafe {
+                                x.to_int_unchecked::<u32>()
+                            }
+                        })?)
+                        .into_dyn(),
+                    ),
+                    bits @ 33.. => AnyArray::U64(
+                        Array1::from_vec(quantize(data, minimum, maximum, |x| {
+                            // we need to use TwoFloat here to have sufficient precision
+                            let max = TwoFloat::from(u64::MAX >> (64 - bits));
+                            let x = (TwoFloat::from(x) * scale_for_bits::<f64>(bits)
+                                + TwoFloat::from(0.5))
+                            .max(TwoFloat::from(0.0))
+                            .min(max);
+                            #[expect(unsafe_code)]
+                            // Safety: x is clamped beforehand
+                            unsafe {
+                                u64::try_from(x).unwrap_unchecked()
+                            }
+                        })?)
+                        .into_dyn(),
+                    ),
+                }
+            }
+            (AnyCowArray::F64(data), LinearQuantizeDType::F64) => {
+                let (minimum, maximum) = quantization_range(data, self.minimum, self.maximum)?;
+
+                match self.bits_for_range(minimum, maximum)? {
+                    bits @ ..=8 => AnyArray::U8(
+                        Array1::from_vec(quantize(data, minimum, maximum, |x| {
+                            let max = f64::from(u8::MAX >> (8 - bits));
+                            let x = x.mul_add(scale_for_bits::<f64>(bits), 0.5).clamp(0.0, max);
+                            #[expect(unsafe_code)]
+                            // Safety: x is clamped beforehand
+                            unsafe {
+                                x.to_int_unchecked::<u8>()
+                            }
+                        })?)
+                        .into_dyn(),
+                    ),
+                    bits @ 9..=16 => AnyArray::U16(
+                        Array1::from_vec(quantize(data, minimum, maximum, |x| {
+                            let max = f64::from(u16::MAX >> (16 - bits));
+                            let x = x.mul_add(scale_for_bits::<f64>(bits), 0.5).clamp(0.0, max);
+                            #[expect(unsafe_code)]
+                            // Safety: x is clamped beforehand
+                            unsafe {
+                                x.to_int_unchecked::<u16>()
+                            }
+                        })?)
+                        .into_dyn(),
+                    ),
+                    bits @ 17..=32 => AnyArray::U32(
+                        Array1::from_vec(quantize(data, minimum, maximum, |x| {
+                            let max = f64::from(u32::MAX >> (32 - bits));
+                            let x = x.mul_add(scale_for_bits::<f64>(bits), 0.5).clamp(0.0, max);
+                            #[expect(unsafe_code)]
+                            // Safety: x is clamped beforehand
+                            unsafe {
+                                x.to_int_unchecked::<u32>()
+                            }
+                        })?)
+                        .into_dyn(),
+                    ),
+                    bits @ 33.. => AnyArray::U64(
+                        Array1::from_vec(quantize(data, minimum, maximum, |x| {
+                            // we need to use TwoFloat here to have sufficient precision
+                            let max = TwoFloat::from(u64::MAX >> (64 - bits));
+                            let x = (TwoFloat::from(x) * scale_for_bits::<f64>(bits)
+                                + TwoFloat::from(0.5))
+                            .max(TwoFloat::from(0.0))
+                            .min(max);
+                            #[expect(unsafe_code)]
+                            // Safety: x is clamped beforehand
+                            unsafe {
+                                u64::try_from(x).unwrap_unchecked()
+                            }
+                        })?)
+                        .into_dyn(),
+                    ),
+                }
+            }
             (data, dtype) => {
                 return Err(LinearQuantizeCodecError::MismatchedEncodeDType {
                     configured: dtype,
@@ -237,21 +289,23 @@ impl Codec for LinearQuantizeCodec {
             });
         }
 
+        let bits = self.bits_for_decoding(&encoded.view())?;
+
         let decoded = match (&encoded, self.dtype) {
             (AnyCowArray::U8(encoded), LinearQuantizeDType::F32) => {
                 AnyArray::F32(reconstruct(&as_standard_order(encoded), |x| {
-                    f32::from(x) / scale_for_bits::<f32>(self.bits as u8)
+                    f32::from(x) / scale_for_bits::<f32>(bits)
                 })?)
             }
             (AnyCowArray::U16(encoded), LinearQuantizeDType::F32) => {
                 AnyArray::F32(reconstruct(&as_standard_order(encoded), |x| {
-                    f32::from(x) / scale_for_bits::<f32>(self.bits as u8)
+                    f32::from(x) / scale_for_bits::<f32>(bits)
                 })?)
             }
             (AnyCowArray::U32(encoded), LinearQuantizeDType::F32) => {
                 AnyArray::F32(reconstruct(&as_standard_order(encoded), |x| {
                     // we need to use f64 here to have sufficient precision
-                    let x = f64::from(x) / scale_for_bits::<f64>(self.bits as u8);
+                    let x = f64::from(x) / scale_for_bits::<f64>(bits);
                     #[expect(clippy::cast_possible_truncation)]
                     let x = x as f32;
                     x
@@ -260,29 +314,29 @@ impl Codec for LinearQuantizeCodec {
             (AnyCowArray::U64(encoded), LinearQuantizeDType::F32) => {
                 AnyArray::F32(reconstruct(&as_standard_order(encoded), |x| {
                     // we need to use TwoFloat here to have sufficient precision
-                    let x = TwoFloat::from(x) / scale_for_bits::<f64>(self.bits as u8);
+                    let x = TwoFloat::from(x) / scale_for_bits::<f64>(bits);
                     f32::from(x)
                 })?)
             }
             (AnyCowArray::U8(encoded), LinearQuantizeDType::F64) => {
                 AnyArray::F64(reconstruct(&as_standard_order(encoded), |x| {
-                    f64::from(x) / scale_for_bits::<f64>(self.bits as u8)
+                    f64::from(x) / scale_for_bits::<f64>(bits)
                 })?)
             }
             (AnyCowArray::U16(encoded), LinearQuantizeDType::F64) => {
                 AnyArray::F64(reconstruct(&as_standard_order(encoded), |x| {
-                    f64::from(x) / scale_for_bits::<f64>(self.bits as u8)
+                    f64::from(x) / scale_for_bits::<f64>(bits)
                 })?)
             }
             (AnyCowArray::U32(encoded), LinearQuantizeDType::F64) => {
                 AnyArray::F64(reconstruct(&as_standard_order(encoded), |x| {
-                    f64::from(x) / scale_for_bits::<f64>(self.bits as u8)
+                    f64::from(x) / scale_for_bits::<f64>(bits)
                 })?)
             }
             (AnyCowArray::U64(encoded), LinearQuantizeDType::F64) => {
                 AnyArray::F64(reconstruct(&as_standard_order(encoded), |x| {
                     // we need to use TwoFloat here to have sufficient precision
-                    let x = TwoFloat::from(x) / scale_for_bits::<f64>(self.bits as u8);
+                    let x = TwoFloat::from(x) / scale_for_bits::<f64>(bits);
                     f64::from(x)
                 })?)
             }
@@ -318,23 +372,25 @@ impl Codec for LinearQuantizeCodec {
             });
         }
 
+        let bits = self.bits_for_decoding(&encoded)?;
+
         match (decoded, self.dtype) {
             (AnyArrayViewMut::F32(decoded), LinearQuantizeDType::F32) => {
                 match &encoded {
                     AnyArrayView::U8(encoded) => {
                         reconstruct_into(&as_standard_order(encoded), decoded, |x| {
-                            f32::from(x) / scale_for_bits::<f32>(self.bits as u8)
+                            f32::from(x) / scale_for_bits::<f32>(bits)
                         })
                     }
                     AnyArrayView::U16(encoded) => {
                         reconstruct_into(&as_standard_order(encoded), decoded, |x| {
-                            f32::from(x) / scale_for_bits::<f32>(self.bits as u8)
+                            f32::from(x) / scale_for_bits::<f32>(bits)
                         })
                     }
                     AnyArrayView::U32(encoded) => {
                         reconstruct_into(&as_standard_order(encoded), decoded, |x| {
                             // we need to use f64 here to have sufficient precision
-                            let x = f64::from(x) / scale_for_bits::<f64>(self.bits as u8);
+                            let x = f64::from(x) / scale_for_bits::<f64>(bits);
                             #[expect(clippy::cast_possible_truncation)]
                             let x = x as f32;
                             x
@@ -343,7 +399,7 @@ impl Codec for LinearQuantizeCodec {
                     AnyArrayView::U64(encoded) => {
                         reconstruct_into(&as_standard_order(encoded), decoded, |x| {
                             // we need to use TwoFloat here to have sufficient precision
-                            let x = TwoFloat::from(x) / scale_for_bits::<f64>(self.bits as u8);
+                            let x = TwoFloat::from(x) / scale_for_bits::<f64>(bits);
                             f32::from(x)
                         })
                     }
@@ -358,23 +414,23 @@ impl Codec for LinearQuantizeCodec {
                 match &encoded {
                     AnyArrayView::U8(encoded) => {
                         reconstruct_into(&as_standard_order(encoded), decoded, |x| {
-                            f64::from(x) / scale_for_bits::<f64>(self.bits as u8)
+                            f64::from(x) / scale_for_bits::<f64>(bits)
                         })
                     }
                     AnyArrayView::U16(encoded) => {
                         reconstruct_into(&as_standard_order(encoded), decoded, |x| {
-                            f64::from(x) / scale_for_bits::<f64>(self.bits as u8)
+                            f64::from(x) / scale_for_bits::<f64>(bits)
                         })
                     }
                     AnyArrayView::U32(encoded) => {
                         reconstruct_into(&as_standard_order(encoded), decoded, |x| {
-                            f64::from(x) / scale_for_bits::<f64>(self.bits as u8)
+                            f64::from(x) / scale_for_bits::<f64>(bits)
                         })
                     }
                     AnyArrayView::U64(encoded) => {
                         reconstruct_into(&as_standard_order(encoded), decoded, |x| {
                             // we need to use TwoFloat here to have sufficient precision
-                            let x = TwoFloat::from(x) / scale_for_bits::<f64>(self.bits as u8);
+                            let x = TwoFloat::from(x) / scale_for_bits::<f64>(bits);
                             f64::from(x)
                         })
                     }
@@ -397,6 +453,42 @@ impl Codec for LinearQuantizeCodec {
     }
 }
 
+impl LinearQuantizeCodec {
+    /// Computes the number of bits used to quantize data in the range
+    /// `$[minimum, maximum]$`.
+    fn bits_for_range(&self, minimum: f64, maximum: f64) -> Result<u8, LinearQuantizeCodecError> {
+        match self.mode {
+            LinearQuantizeMode::Bits { bits } => Ok(bits as u8),
+            LinearQuantizeMode::AbsoluteError { eb_abs } => {
+                bits_for_error_bound(maximum - minimum, eb_abs)
+            }
+        }
+    }
+
+    /// Computes the number of bits with which the `encoded` data was
+    /// quantized, using the quantization range from its header.
+    fn bits_for_decoding(&self, encoded: &AnyArrayView) -> Result<u8, LinearQuantizeCodecError> {
+        if let LinearQuantizeMode::Bits { bits } = self.mode {
+            return Ok(bits as u8);
+        }
+
+        let encoded = encoded.as_bytes();
+
+        let (minimum, maximum) = match self.dtype {
+            LinearQuantizeDType::F32 => {
+                let (header, _) = decode_header::<f32>(&encoded)?;
+                (f64::from(header.minimum), f64::from(header.maximum))
+            }
+            LinearQuantizeDType::F64 => {
+                let (header, _) = decode_header::<f64>(&encoded)?;
+                (header.minimum, header.maximum)
+            }
+        };
+
+        self.bits_for_range(minimum, maximum)
+    }
+}
+
 impl StaticCodec for LinearQuantizeCodec {
     const CODEC_ID: &'static str = "linear-quantize.rs";
 
@@ -411,6 +503,49 @@ impl StaticCodec for LinearQuantizeCodec {
     }
 }
 
+#[expect(clippy::derive_partial_eq_without_eq)] // floats are not Eq
+#[derive(Copy, Clone, PartialEq, PartialOrd, Hash)]
+/// Non-negative floating point number
+pub struct NonNegative<T: Float>(T);
+
+impl Serialize for NonNegative<f64> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for NonNegative<f64> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let x = f64::deserialize(deserializer)?;
+
+        if x >= 0.0 {
+            Ok(Self(x))
+        } else {
+            Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Float(x),
+                &"a non-negative value",
+            ))
+        }
+    }
+}
+
+impl JsonSchema for NonNegative<f64> {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("NonNegativeF64")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed(concat!(module_path!(), "::", "NonNegative<f64>"))
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "minimum": 0.0
+        })
+    }
+}
+
 #[derive(Debug, Error)]
 /// Errors that may occur when applying the [`LinearQuantizeCodec`].
 pub enum LinearQuantizeCodecError {
@@ -429,6 +564,41 @@ pub enum LinearQuantizeCodecError {
     /// point data
     #[error("LinearQuantize does not support non-finite (infinite or NaN) floating point data")]
     NonFiniteData,
+    /// [`LinearQuantizeCodec`] cannot quantize using an invalid quantization
+    /// range that is non-finite or where the `minimum` exceeds the `maximum`
+    #[error("LinearQuantize cannot quantize using the invalid range [{minimum}, {maximum}]")]
+    InvalidQuantizationRange {
+        /// The minimum of the quantization range
+        minimum: f64,
+        /// The maximum of the quantization range
+        maximum: f64,
+    },
+    /// [`LinearQuantizeCodec`] cannot quantize data that lies outside the
+    /// explicitly provided quantization range
+    #[error(
+        "LinearQuantize cannot quantize data in [{data_minimum}, {data_maximum}] that lies outside the provided range [{minimum}, {maximum}]"
+    )]
+    DataOutsideQuantizationRange {
+        /// The minimum of the quantization range
+        minimum: f64,
+        /// The maximum of the quantization range
+        maximum: f64,
+        /// The minimum of the data
+        data_minimum: f64,
+        /// The maximum of the data
+        data_maximum: f64,
+    },
+    /// [`LinearQuantizeCodec`] cannot guarantee the absolute error bound for
+    /// the quantization range with at most 64 bits
+    #[error(
+        "LinearQuantize cannot guarantee the absolute error bound {eb_abs} for the quantization range {range} with at most 64 bits"
+    )]
+    ExcessiveErrorBoundBits {
+        /// The absolute error bound
+        eb_abs: f64,
+        /// The width of the quantization range
+        range: f64,
+    },
     /// [`LinearQuantizeCodec`] failed to encode the header
     #[error("LinearQuantize failed to encode the header")]
     HeaderEncodeFailed {
@@ -495,36 +665,83 @@ pub enum LinearQuantizeCodecError {
 /// Opaque error for when encoding or decoding the header fails
 pub struct LinearQuantizeHeaderError(postcard::Error);
 
-/// Linear-quantize the elements in the `data` array using the `quantize`
-/// closure.
+/// Computes the quantization range of the `data` array, optionally using the
+/// explicitly provided `minimum` and `maximum` instead of the data's range.
 ///
 /// # Errors
 ///
 /// Errors with
 /// - [`LinearQuantizeCodecError::NonFiniteData`] if any data element is non-
 ///   finite (infinite or NaN)
-/// - [`LinearQuantizeCodecError::HeaderEncodeFailed`] if encoding the header
-///   failed
-pub fn quantize<
-    T: Float + ConstZero + ConstOne + Serialize,
-    Q: Unsigned,
+/// - [`LinearQuantizeCodecError::InvalidQuantizationRange`] if the range is
+///   non-finite or the `minimum` exceeds the `maximum`
+/// - [`LinearQuantizeCodecError::DataOutsideQuantizationRange`] if any data
+///   element lies outside the explicitly provided range
+pub fn quantization_range<
+    T: Float + ConstZero + ConstOne + Into<f64>,
     S: Data<Elem = T>,
     D: Dimension,
 >(
     data: &ArrayBase<S, D>,
-    quantize: impl Fn(T) -> Q,
-) -> Result<Vec<Q>, LinearQuantizeCodecError> {
+    minimum: Option<T>,
+    maximum: Option<T>,
+) -> Result<(T, T), LinearQuantizeCodecError> {
     if !Zip::from(data).all(|x| x.is_finite()) {
         return Err(LinearQuantizeCodecError::NonFiniteData);
     }
 
-    let (minimum, maximum) = data.first().map_or((T::ZERO, T::ONE), |first| {
+    let (data_minimum, data_maximum) = data.first().map_or((T::ZERO, T::ONE), |first| {
         (
             Zip::from(data).fold(*first, |a, b| a.min(*b)),
             Zip::from(data).fold(*first, |a, b| a.max(*b)),
         )
     });
 
+    let (minimum, maximum) = (
+        minimum.unwrap_or(data_minimum),
+        maximum.unwrap_or(data_maximum),
+    );
+
+    if !minimum.is_finite() || !maximum.is_finite() || minimum > maximum {
+        return Err(LinearQuantizeCodecError::InvalidQuantizationRange {
+            minimum: minimum.into(),
+            maximum: maximum.into(),
+        });
+    }
+
+    if !data.is_empty() && (data_minimum < minimum || data_maximum > maximum) {
+        return Err(LinearQuantizeCodecError::DataOutsideQuantizationRange {
+            minimum: minimum.into(),
+            maximum: maximum.into(),
+            data_minimum: data_minimum.into(),
+            data_maximum: data_maximum.into(),
+        });
+    }
+
+    Ok((minimum, maximum))
+}
+
+/// Linear-quantize the elements in the `data` array, which lie in the
+/// quantization range `$[minimum, maximum]$`, using the `quantize` closure.
+///
+/// The quantization range should be computed using [`quantization_range`].
+///
+/// # Errors
+///
+/// Errors with
+/// - [`LinearQuantizeCodecError::HeaderEncodeFailed`] if encoding the header
+///   failed
+pub fn quantize<
+    T: Float + ConstZero + ConstOne + Serialize,
+    Q: Unsigned,
+    S: Data<Elem = T>,
+    D: Dimension,
+>(
+    data: &ArrayBase<S, D>,
+    minimum: T,
+    maximum: T,
+    quantize: impl Fn(T) -> Q,
+) -> Result<Vec<Q>, LinearQuantizeCodecError> {
     let header = postcard::to_extend(
         &CompressionHeader {
             shape: Cow::Borrowed(data.shape()),
@@ -572,11 +789,8 @@ pub fn reconstruct<T: Float + DeserializeOwned, Q: Unsigned>(
 ) -> Result<ArrayD<T>, LinearQuantizeCodecError> {
     #[expect(unsafe_code)]
     // Safety: data is data.len()*size_of::<Q> bytes long and properly aligned for Q
-    let (header, remaining) = postcard::take_from_bytes::<CompressionHeader<T>>(unsafe {
+    let (header, remaining) = decode_header::<T>(unsafe {
         std::slice::from_raw_parts(encoded.as_ptr().cast(), std::mem::size_of_val(encoded))
-    })
-    .map_err(|err| LinearQuantizeCodecError::HeaderDecodeFailed {
-        source: LinearQuantizeHeaderError(err),
     })?;
 
     let encoded = encoded
@@ -611,11 +825,8 @@ pub fn reconstruct_into<T: Float + DeserializeOwned, Q: Unsigned>(
 ) -> Result<(), LinearQuantizeCodecError> {
     #[expect(unsafe_code)]
     // Safety: data is data.len()*size_of::<Q> bytes long and properly aligned for Q
-    let (header, remaining) = postcard::take_from_bytes::<CompressionHeader<T>>(unsafe {
+    let (header, remaining) = decode_header::<T>(unsafe {
         std::slice::from_raw_parts(encoded.as_ptr().cast(), std::mem::size_of_val(encoded))
-    })
-    .map_err(|err| LinearQuantizeCodecError::HeaderDecodeFailed {
-        source: LinearQuantizeHeaderError(err),
     })?;
 
     let encoded = encoded
@@ -638,6 +849,72 @@ pub fn reconstruct_into<T: Float + DeserializeOwned, Q: Unsigned>(
     Ok(())
 }
 
+/// Decodes the header from the start of the `encoded` bytes, returning the
+/// header and the remaining bytes.
+fn decode_header<T: DeserializeOwned>(
+    encoded: &[u8],
+) -> Result<(CompressionHeader<'_, T>, &[u8]), LinearQuantizeCodecError> {
+    postcard::take_from_bytes::<CompressionHeader<T>>(encoded).map_err(|err| {
+        LinearQuantizeCodecError::HeaderDecodeFailed {
+            source: LinearQuantizeHeaderError(err),
+        }
+    })
+}
+
+/// Computes the smallest number of bits that guarantees the absolute error
+/// bound `eb_abs` when quantizing data in a quantization range of width
+/// `range`.
+///
+/// The quantization error is at most half of the bin width,
+/// `$\frac{range}{2^{bits} - 1}$`, such that
+/// `$bits = \left\lceil \log_{2} \left( \left\lceil \frac{range}{2 \cdot eb_{abs}} \right\rceil + 1 \right) \right\rceil$`.
+fn bits_for_error_bound(
+    range: f64,
+    eb_abs: NonNegative<f64>,
+) -> Result<u8, LinearQuantizeCodecError> {
+    // a constant quantization range is exact for any number of bits
+    if range <= 0.0 {
+        return Ok(1);
+    }
+
+    let levels = (range / (eb_abs.0 * 2.0)).ceil() + 1.0;
+    let bits = levels.log2().ceil().max(1.0);
+
+    if bits > 64.0 {
+        return Err(LinearQuantizeCodecError::ExcessiveErrorBoundBits {
+            eb_abs: eb_abs.0,
+            range,
+        });
+    }
+
+    #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Ok(bits as u8)
+}
+
+/// Converts the `minimum` to [`f32`] without exceeding its [`f64`] value.
+fn f32_at_most(minimum: f64) -> f32 {
+    #[expect(clippy::cast_possible_truncation)]
+    let minimum_f32 = minimum as f32;
+
+    if f64::from(minimum_f32) > minimum {
+        minimum_f32.next_down()
+    } else {
+        minimum_f32
+    }
+}
+
+/// Converts the `maximum` to [`f32`] without falling below its [`f64`] value.
+fn f32_at_least(maximum: f64) -> f32 {
+    #[expect(clippy::cast_possible_truncation)]
+    let maximum_f32 = maximum as f32;
+
+    if f64::from(maximum_f32) < maximum {
+        maximum_f32.next_up()
+    } else {
+        maximum_f32
+    }
+}
+
 /// Returns `${2.0}^{bits} - 1.0$`
 fn scale_for_bits<T: Float + From<u8> + ConstOne>(bits: u8) -> T {
     <T as From<u8>>::from(bits).exp2() - T::ONE
@@ -677,6 +954,7 @@ struct CompressionHeader<'a, T> {
 #[cfg(test)]
 mod tests {
     use ndarray::CowArray;
+    use serde_json::json;
 
     use super::*;
 
@@ -685,8 +963,12 @@ mod tests {
         for bits in 1..=16 {
             let codec = LinearQuantizeCodec {
                 dtype: LinearQuantizeDType::F32,
-                #[expect(unsafe_code)]
-                bits: unsafe { std::mem::transmute::<u8, LinearQuantizeBins>(bits) },
+                mode: LinearQuantizeMode::Bits {
+                    #[expect(unsafe_code)]
+                    bits: unsafe { std::mem::transmute::<u8, LinearQuantizeBins>(bits) },
+                },
+                minimum: None,
+                maximum: None,
                 version: StaticCodecVersion,
             };
 
@@ -719,8 +1001,12 @@ mod tests {
         for bits in 1..=64 {
             let codec = LinearQuantizeCodec {
                 dtype: LinearQuantizeDType::F32,
-                #[expect(unsafe_code)]
-                bits: unsafe { std::mem::transmute::<u8, LinearQuantizeBins>(bits) },
+                mode: LinearQuantizeMode::Bits {
+                    #[expect(unsafe_code)]
+                    bits: unsafe { std::mem::transmute::<u8, LinearQuantizeBins>(bits) },
+                },
+                minimum: None,
+                maximum: None,
                 version: StaticCodecVersion,
             };
 
@@ -755,8 +1041,12 @@ mod tests {
         for bits in 1..=32 {
             let codec = LinearQuantizeCodec {
                 dtype: LinearQuantizeDType::F64,
-                #[expect(unsafe_code)]
-                bits: unsafe { std::mem::transmute::<u8, LinearQuantizeBins>(bits) },
+                mode: LinearQuantizeMode::Bits {
+                    #[expect(unsafe_code)]
+                    bits: unsafe { std::mem::transmute::<u8, LinearQuantizeBins>(bits) },
+                },
+                minimum: None,
+                maximum: None,
                 version: StaticCodecVersion,
             };
 
@@ -789,8 +1079,12 @@ mod tests {
         for bits in 1..=64 {
             let codec = LinearQuantizeCodec {
                 dtype: LinearQuantizeDType::F64,
-                #[expect(unsafe_code)]
-                bits: unsafe { std::mem::transmute::<u8, LinearQuantizeBins>(bits) },
+                mode: LinearQuantizeMode::Bits {
+                    #[expect(unsafe_code)]
+                    bits: unsafe { std::mem::transmute::<u8, LinearQuantizeBins>(bits) },
+                },
+                minimum: None,
+                maximum: None,
                 version: StaticCodecVersion,
             };
 
@@ -827,8 +1121,12 @@ mod tests {
 
             let codec = LinearQuantizeCodec {
                 dtype: LinearQuantizeDType::F64,
-                #[expect(unsafe_code)]
-                bits: unsafe { std::mem::transmute::<u8, LinearQuantizeBins>(bits) },
+                mode: LinearQuantizeMode::Bits {
+                    #[expect(unsafe_code)]
+                    bits: unsafe { std::mem::transmute::<u8, LinearQuantizeBins>(bits) },
+                },
+                minimum: None,
+                maximum: None,
                 version: StaticCodecVersion,
             };
 
@@ -849,4 +1147,120 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn error_bound_roundtrip() -> Result<(), LinearQuantizeCodecError> {
+        let data = (0..1000)
+            .map(|i| (f64::from(i) * 0.1).sin() * 1000.0)
+            .collect::<Vec<_>>();
+
+        for eb_abs in [1e-9, 1e-6, 0.001, 0.1, 1.0, 10.0, 5000.0] {
+            let codec = LinearQuantizeCodec {
+                dtype: LinearQuantizeDType::F64,
+                mode: LinearQuantizeMode::AbsoluteError {
+                    eb_abs: NonNegative(eb_abs),
+                },
+                minimum: None,
+                maximum: None,
+                version: StaticCodecVersion,
+            };
+
+            let encoded = codec.encode(AnyCowArray::F64(CowArray::from(&data).into_dyn()))?;
+            let decoded = codec.decode(encoded.cow())?;
+
+            let AnyArray::F64(decoded) = decoded else {
+                return Err(LinearQuantizeCodecError::MismatchedDecodeIntoDtype {
+                    configured: LinearQuantizeDType::F64,
+                    provided: decoded.dtype(),
+                });
+            };
+
+            for (o, d) in data.iter().zip(decoded.iter()) {
+                assert!((o - d).abs() <= eb_abs, "{o} {d} {eb_abs}");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn error_bound_bits() {
+        assert_eq!(bits_for_error_bound(0.0, NonNegative(0.0)).ok(), Some(1));
+        assert_eq!(bits_for_error_bound(1.0, NonNegative(0.5)).ok(), Some(1));
+        assert_eq!(bits_for_error_bound(1.0, NonNegative(0.25)).ok(), Some(2));
+        assert_eq!(bits_for_error_bound(255.0, NonNegative(0.5)).ok(), Some(8));
+        assert_eq!(bits_for_error_bound(256.0, NonNegative(0.5)).ok(), Some(9));
+        assert!(matches!(
+            bits_for_error_bound(1.0, NonNegative(0.0)),
+            Err(LinearQuantizeCodecError::ExcessiveErrorBoundBits { .. })
+        ));
+    }
+
+    #[test]
+    fn shared_range_roundtrip() -> Result<(), LinearQuantizeCodecError> {
+        let codec = LinearQuantizeCodec {
+            dtype: LinearQuantizeDType::F32,
+            mode: LinearQuantizeMode::AbsoluteError {
+                eb_abs: NonNegative(0.01),
+            },
+            minimum: Some(-10.0),
+            maximum: Some(10.0),
+            version: StaticCodecVersion,
+        };
+
+        for chunk in [[-10.0_f32, 0.0, 1.5], [2.25, 2.5, 10.0]] {
+            let encoded = codec.encode(AnyCowArray::F32(CowArray::from(&chunk).into_dyn()))?;
+
+            // both chunks share the same quantization grid and thus dtype
+            assert_eq!(encoded.dtype(), AnyArrayDType::U16);
+
+            let mut decoded = AnyArray::F32(ArrayD::zeros(vec![3]));
+            codec.decode_into(encoded.view(), decoded.view_mut())?;
+
+            let AnyArray::F32(decoded) = decoded else {
+                return Err(LinearQuantizeCodecError::MismatchedDecodeIntoDtype {
+                    configured: LinearQuantizeDType::F32,
+                    provided: decoded.dtype(),
+                });
+            };
+
+            for (o, d) in chunk.iter().zip(decoded.iter()) {
+                assert!((o - d).abs() <= 0.01, "{o} {d}");
+            }
+        }
+
+        assert!(matches!(
+            codec.encode(AnyCowArray::F32(
+                CowArray::from(&[0.0_f32, 11.0]).into_dyn()
+            )),
+            Err(LinearQuantizeCodecError::DataOutsideQuantizationRange { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    #[expect(clippy::unwrap_used)]
+    fn bits_mode_is_default() {
+        for config in [
+            json!({ "dtype": "f64", "bits": 8 }),
+            json!({ "dtype": "f64", "bits": 8, "_version": "0.1.0" }),
+        ] {
+            let codec: LinearQuantizeCodec = serde_json::from_value(config).unwrap();
+            assert!(matches!(
+                codec.mode,
+                LinearQuantizeMode::Bits {
+                    bits: LinearQuantizeBins::_1B8
+                }
+            ));
+        }
+
+        let codec: LinearQuantizeCodec =
+            serde_json::from_value(json!({ "dtype": "f64", "mode": "abs", "eb_abs": 0.1 }))
+                .unwrap();
+        assert!(matches!(
+            codec.mode,
+            LinearQuantizeMode::AbsoluteError { .. }
+        ));
+    }
 }
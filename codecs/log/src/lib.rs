@@ -15,10 +15,10 @@
 //! [Rust Doc Main]: https://img.shields.io/badge/docs-main-blue
 //! [docs]: https://juntyr.github.io/numcodecs-rs/numcodecs_log
 //!
-//! `$\ln(x)$` codec implementation for the [`numcodecs`] API.
+//! `$\log_{b}(x)$` codec implementation for the [`numcodecs`] API.
 
-use ndarray::{Array, ArrayBase, ArrayView, ArrayViewMut, Data, Dimension, Zip};
-use num_traits::{Float, Signed};
+use ndarray::{Array, ArrayBase, ArrayView, ArrayViewMut, Data, Dimension};
+use num_traits::{Float, FloatConst, Signed};
 use numcodecs::{
     AnyArray, AnyArrayAssignError, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray,
     Codec, StaticCodec, StaticCodecConfig, StaticCodecVersion,
@@ -28,15 +28,78 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
-#[serde(deny_unknown_fields)]
-/// Log codec which calculates `$c = \ln(x)$` on encoding and `$d = {e}^{c}$`
-/// on decoding.
+#[schemars(deny_unknown_fields)]
+/// Log codec which calculates `$c = \log_{b}(x)$` on encoding and
+/// `$d = {b}^{c}$` on decoding.
+///
+/// By default, the codec calculates `$c = \log_{b}(x + offset)$` with an
+/// optional `offset`, which requires that `$x + offset > 0$`. The codec also
+/// supports the following `mode`s:
+/// - `signed`: `$c = \text{sign}(x) \cdot \log_{b}(1 + |x|)$`, which supports
+///   all finite floating point numbers
 ///
-/// The codec only supports finite positive floating point numbers.
+/// Non-finite (infinite or NaN) floating point numbers are handled according
+/// to the `non_finite` policy.
 pub struct LogCodec {
+    /// The base `$b$` of the logarithm
+    #[serde(default)]
+    pub base: LogBase,
+    /// The mode of the logarithm
+    #[serde(flatten)]
+    pub mode: LogMode,
+    /// The policy for handling non-finite (infinite or NaN) values
+    #[serde(default)]
+    pub non_finite: NonFinitePolicy,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default, rename = "_version")]
-    pub version: StaticCodecVersion<1, 0, 0>,
+    pub version: StaticCodecVersion<1, 1, 0>,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+/// Base of the logarithm
+pub enum LogBase {
+    /// Binary logarithm with base `$2$`
+    #[serde(rename = "2")]
+    Two,
+    /// Natural logarithm with base `$e$`
+    #[serde(rename = "e")]
+    #[default]
+    E,
+    /// Common logarithm with base `$10$`
+    #[serde(rename = "10")]
+    Ten,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "mode")]
+#[serde(deny_unknown_fields)]
+/// Mode of the logarithm
+pub enum LogMode {
+    /// Signed logarithm, `$c = \text{sign}(x) \cdot \log_{b}(1 + |x|)$`
+    #[serde(rename = "signed")]
+    Signed,
+    /// Logarithm with an additive offset, `$c = \log_{b}(x + offset)$`
+    ///
+    /// This is the default mode, which is used if no `mode` is specified.
+    #[serde(untagged)]
+    Offset {
+        /// Additive offset that is applied before taking the logarithm
+        #[serde(default)]
+        offset: f64,
+    },
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+/// Policy for handling non-finite (infinite or NaN) values
+pub enum NonFinitePolicy {
+    /// Non-finite values produce an error
+    #[serde(rename = "error")]
+    #[default]
+    Error,
+    /// Non-finite values are passed through unchanged during encoding and
+    /// decoding
+    #[serde(rename = "pass-through")]
+    PassThrough,
 }
 
 impl Codec for LogCodec {
@@ -44,16 +107,36 @@ impl Codec for LogCodec {
 
     fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
         match data {
-            AnyCowArray::F32(data) => Ok(AnyArray::F32(ln(data)?)),
-            AnyCowArray::F64(data) => Ok(AnyArray::F64(ln(data)?)),
+            AnyCowArray::F32(data) => Ok(AnyArray::F32(log(
+                data,
+                self.base,
+                self.mode,
+                self.non_finite,
+            )?)),
+            AnyCowArray::F64(data) => Ok(AnyArray::F64(log(
+                data,
+                self.base,
+                self.mode,
+                self.non_finite,
+            )?)),
             encoded => Err(LogCodecError::UnsupportedDtype(encoded.dtype())),
         }
     }
 
     fn decode(&self, encoded: AnyCowArray) -> Result<AnyArray, Self::Error> {
         match encoded {
-            AnyCowArray::F32(encoded) => Ok(AnyArray::F32(exp(encoded)?)),
-            AnyCowArray::F64(encoded) => Ok(AnyArray::F64(exp(encoded)?)),
+            AnyCowArray::F32(encoded) => Ok(AnyArray::F32(pow(
+                encoded,
+                self.base,
+                self.mode,
+                self.non_finite,
+            )?)),
+            AnyCowArray::F64(encoded) => Ok(AnyArray::F64(pow(
+                encoded,
+                self.base,
+                self.mode,
+                self.non_finite,
+            )?)),
             encoded => Err(LogCodecError::UnsupportedDtype(encoded.dtype())),
         }
     }
@@ -65,10 +148,10 @@ impl Codec for LogCodec {
     ) -> Result<(), Self::Error> {
         match (encoded, decoded) {
             (AnyArrayView::F32(encoded), AnyArrayViewMut::F32(decoded)) => {
-                exp_into(encoded, decoded)
+                pow_into(encoded, decoded, self.base, self.mode, self.non_finite)
             }
             (AnyArrayView::F64(encoded), AnyArrayViewMut::F64(decoded)) => {
-                exp_into(encoded, decoded)
+                pow_into(encoded, decoded, self.base, self.mode, self.non_finite)
             }
             (encoded @ (AnyArrayView::F32(_) | AnyArrayView::F64(_)), decoded) => {
                 Err(LogCodecError::MismatchedDecodeIntoArray {
@@ -104,11 +187,13 @@ pub enum LogCodecError {
     #[error("Log does not support the dtype {0}")]
     UnsupportedDtype(AnyArrayDType),
     /// [`LogCodec`] does not support non-positive (negative or zero) floating
-    /// point data
-    #[error("Log does not support non-positive (negative or zero) floating point data")]
+    /// point data after the offset has been applied
+    #[error(
+        "Log does not support non-positive (negative or zero) floating point data after the offset has been applied"
+    )]
     NonPositiveData,
     /// [`LogCodec`] does not support non-finite (infinite or NaN) floating
-    /// point data
+    /// point data with the error policy
     #[error("Log does not support non-finite (infinite or NaN) floating point data")]
     NonFiniteData,
     /// [`LogCodec`] cannot decode into the provided array
@@ -129,57 +214,133 @@ pub enum LogCodecError {
 ///   (negative or zero)
 /// - [`LogCodecError::NonFiniteData`] if any data element is non-finite
 ///   (infinite or NaN)
-pub fn ln<T: Float + Signed, S: Data<Elem = T>, D: Dimension>(
+pub fn ln<T: LogFloat, S: Data<Elem = T>, D: Dimension>(
     data: ArrayBase<S, D>,
 ) -> Result<Array<T, D>, LogCodecError> {
-    if !Zip::from(&data).all(T::is_positive) {
-        return Err(LogCodecError::NonPositiveData);
-    }
+    log(
+        data,
+        LogBase::E,
+        LogMode::Offset { offset: 0.0 },
+        NonFinitePolicy::Error,
+    )
+}
 
-    if !Zip::from(&data).all(|x| x.is_finite()) {
-        return Err(LogCodecError::NonFiniteData);
-    }
+/// Compute `${e}^{x}$` over the elements of the input `data` array.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`LogCodecError::NonFiniteData`] if any data element is non-finite
+///   (infinite or NaN)
+pub fn exp<T: LogFloat, S: Data<Elem = T>, D: Dimension>(
+    data: ArrayBase<S, D>,
+) -> Result<Array<T, D>, LogCodecError> {
+    pow(
+        data,
+        LogBase::E,
+        LogMode::Offset { offset: 0.0 },
+        NonFinitePolicy::Error,
+    )
+}
+
+/// Compute `${e}^{x}$` over the elements of the input `data` array and write
+/// them into the `out`put array.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`LogCodecError::NonFiniteData`] if any data element is non-finite
+///   (infinite or NaN)
+/// - [`LogCodecError::MismatchedDecodeIntoArray`] if the `data` array's shape
+///   does not match the `out`put array's shape
+pub fn exp_into<T: LogFloat, D: Dimension>(
+    data: ArrayView<T, D>,
+    out: ArrayViewMut<T, D>,
+) -> Result<(), LogCodecError> {
+    pow_into(
+        data,
+        out,
+        LogBase::E,
+        LogMode::Offset { offset: 0.0 },
+        NonFinitePolicy::Error,
+    )
+}
 
+/// Compute the logarithm with the given `base` and `mode` over the elements
+/// of the input `data` array.
+///
+/// Non-finite (infinite or NaN) data elements are handled according to the
+/// `non_finite` policy.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`LogCodecError::NonPositiveData`] if any data element is non-positive
+///   (negative or zero) after the [`LogMode::Offset`] has been applied
+/// - [`LogCodecError::NonFiniteData`] if any data element is non-finite
+///   (infinite or NaN) and the policy is [`NonFinitePolicy::Error`]
+pub fn log<T: LogFloat, S: Data<Elem = T>, D: Dimension>(
+    data: ArrayBase<S, D>,
+    base: LogBase,
+    mode: LogMode,
+    non_finite: NonFinitePolicy,
+) -> Result<Array<T, D>, LogCodecError> {
     let mut data = data.into_owned();
-    data.mapv_inplace(T::ln);
+
+    for x in &mut data {
+        *x = log_element(*x, base, mode, non_finite)?;
+    }
 
     Ok(data)
 }
 
-/// Compute `${e}^{x}$` over the elements of the input `data` array.
+/// Compute the inverse of the logarithm with the given `base` and `mode`
+/// over the elements of the input `data` array.
+///
+/// Non-finite (infinite or NaN) data elements are handled according to the
+/// `non_finite` policy.
 ///
 /// # Errors
 ///
 /// Errors with
 /// - [`LogCodecError::NonFiniteData`] if any data element is non-finite
-///   (infinite or NaN)
-pub fn exp<T: Float, S: Data<Elem = T>, D: Dimension>(
+///   (infinite or NaN) and the policy is [`NonFinitePolicy::Error`]
+pub fn pow<T: LogFloat, S: Data<Elem = T>, D: Dimension>(
     data: ArrayBase<S, D>,
+    base: LogBase,
+    mode: LogMode,
+    non_finite: NonFinitePolicy,
 ) -> Result<Array<T, D>, LogCodecError> {
-    if !Zip::from(&data).all(|x| x.is_finite()) {
-        return Err(LogCodecError::NonFiniteData);
-    }
-
     let mut data = data.into_owned();
-    data.mapv_inplace(T::exp);
+
+    for x in &mut data {
+        *x = pow_element(*x, base, mode, non_finite)?;
+    }
 
     Ok(data)
 }
 
 #[expect(clippy::needless_pass_by_value)]
-/// Compute `${e}^{x}$` over the elements of the input `data` array and write
-/// them into the `out`put array.
+/// Compute the inverse of the logarithm with the given `base` and `mode`
+/// over the elements of the input `data` array and write them into the
+/// `out`put array.
+///
+/// Non-finite (infinite or NaN) data elements are handled according to the
+/// `non_finite` policy.
 ///
 /// # Errors
 ///
 /// Errors with
 /// - [`LogCodecError::NonFiniteData`] if any data element is non-finite
-///   (infinite or NaN)
+///   (infinite or NaN) and the policy is [`NonFinitePolicy::Error`]
 /// - [`LogCodecError::MismatchedDecodeIntoArray`] if the `data` array's shape
 ///   does not match the `out`put array's shape
-pub fn exp_into<T: Float, D: Dimension>(
+pub fn pow_into<T: LogFloat, D: Dimension>(
     data: ArrayView<T, D>,
     mut out: ArrayViewMut<T, D>,
+    base: LogBase,
+    mode: LogMode,
+    non_finite: NonFinitePolicy,
 ) -> Result<(), LogCodecError> {
     if data.shape() != out.shape() {
         return Err(LogCodecError::MismatchedDecodeIntoArray {
@@ -190,20 +351,115 @@ pub fn exp_into<T: Float, D: Dimension>(
         });
     }
 
-    if !Zip::from(&data).all(|x| x.is_finite()) {
+    if non_finite == NonFinitePolicy::Error && !data.iter().all(|x| x.is_finite()) {
         return Err(LogCodecError::NonFiniteData);
     }
 
     // iteration must occur in synchronised (standard) order
     for (d, o) in data.iter().zip(out.iter_mut()) {
-        *o = T::exp(*d);
+        *o = pow_element(*d, base, mode, non_finite)?;
     }
 
     Ok(())
 }
 
+fn log_element<T: LogFloat>(
+    x: T,
+    base: LogBase,
+    mode: LogMode,
+    non_finite: NonFinitePolicy,
+) -> Result<T, LogCodecError> {
+    if !x.is_finite() {
+        return match non_finite {
+            NonFinitePolicy::Error => Err(LogCodecError::NonFiniteData),
+            NonFinitePolicy::PassThrough => Ok(x),
+        };
+    }
+
+    match mode {
+        LogMode::Offset { offset } => {
+            let x = x + T::from_f64(offset);
+
+            // log(0) = -inf would not be decodable
+            if x <= T::zero() {
+                return Err(LogCodecError::NonPositiveData);
+            }
+
+            Ok(match base {
+                LogBase::Two => x.log2(),
+                LogBase::E => x.ln(),
+                LogBase::Ten => x.log10(),
+            })
+        }
+        LogMode::Signed => {
+            let log = x.abs().ln_1p();
+            let log = match base {
+                LogBase::Two => log * T::LOG2_E(),
+                LogBase::E => log,
+                LogBase::Ten => log * T::LOG10_E(),
+            };
+            Ok(log.copysign(x))
+        }
+    }
+}
+
+fn pow_element<T: LogFloat>(
+    x: T,
+    base: LogBase,
+    mode: LogMode,
+    non_finite: NonFinitePolicy,
+) -> Result<T, LogCodecError> {
+    if !x.is_finite() {
+        return match non_finite {
+            NonFinitePolicy::Error => Err(LogCodecError::NonFiniteData),
+            NonFinitePolicy::PassThrough => Ok(x),
+        };
+    }
+
+    match mode {
+        LogMode::Offset { offset } => {
+            let pow = match base {
+                LogBase::Two => x.exp2(),
+                LogBase::E => x.exp(),
+                LogBase::Ten => T::from_f64(10.0).powf(x),
+            };
+            Ok(pow - T::from_f64(offset))
+        }
+        LogMode::Signed => {
+            let x_abs = match base {
+                LogBase::Two => x.abs() * T::LN_2(),
+                LogBase::E => x.abs(),
+                LogBase::Ten => x.abs() * T::LN_10(),
+            };
+            Ok(x_abs.exp_m1().copysign(x))
+        }
+    }
+}
+
+/// Floating point types
+pub trait LogFloat: Float + FloatConst + Signed {
+    /// Converts from a [`f64`] value
+    #[must_use]
+    fn from_f64(x: f64) -> Self;
+}
+
+impl LogFloat for f32 {
+    #[expect(clippy::cast_possible_truncation)]
+    fn from_f64(x: f64) -> Self {
+        x as Self
+    }
+}
+
+impl LogFloat for f64 {
+    fn from_f64(x: f64) -> Self {
+        x
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use super::*;
 
     #[test]
@@ -225,4 +481,116 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn roundtrip_bases_and_modes() -> Result<(), LogCodecError> {
+        let data = (-500..500).map(|x| f64::from(x) * 0.5).collect::<Vec<_>>();
+        let data = Array::from_vec(data);
+
+        for base in [LogBase::Two, LogBase::E, LogBase::Ten] {
+            for mode in [LogMode::Offset { offset: 251.0 }, LogMode::Signed] {
+                let encoded = log(data.view(), base, mode, NonFinitePolicy::Error)?;
+                let decoded = pow(encoded.view(), base, mode, NonFinitePolicy::Error)?;
+
+                for (r, d) in data.iter().zip(decoded.iter()) {
+                    assert!(((*r) - (*d)).abs() < 1e-10, "{r} {d}");
+                }
+            }
+        }
+
+        let encoded = log(
+            data.view(),
+            LogBase::Ten,
+            LogMode::Signed,
+            NonFinitePolicy::Error,
+        )?;
+        for (r, e) in data.iter().zip(encoded.iter()) {
+            let expected = r.signum() * (1.0 + r.abs()).log10();
+            assert!((e - expected).abs() < 1e-12, "{e} {expected}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn non_positive_after_offset() {
+        let data = Array::from_vec(vec![1.0_f64, 0.0, 2.0]);
+
+        assert!(matches!(
+            log(
+                data.view(),
+                LogBase::E,
+                LogMode::Offset { offset: 0.0 },
+                NonFinitePolicy::Error
+            ),
+            Err(LogCodecError::NonPositiveData)
+        ));
+        assert!(
+            log(
+                data.view(),
+                LogBase::E,
+                LogMode::Offset { offset: 1.0 },
+                NonFinitePolicy::Error
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn non_finite_policy() -> Result<(), LogCodecError> {
+        let data = Array::from_vec(vec![f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 4.0]);
+
+        assert!(matches!(
+            log(
+                data.view(),
+                LogBase::Two,
+                LogMode::Signed,
+                NonFinitePolicy::Error
+            ),
+            Err(LogCodecError::NonFiniteData)
+        ));
+
+        let encoded = log(
+            data.view(),
+            LogBase::Two,
+            LogMode::Offset { offset: 0.0 },
+            NonFinitePolicy::PassThrough,
+        )?;
+        let expected = [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 2.0];
+        for (e, x) in encoded.iter().zip(expected) {
+            assert_eq!(e.to_bits(), x.to_bits());
+        }
+
+        let decoded = pow(
+            encoded.view(),
+            LogBase::Two,
+            LogMode::Offset { offset: 0.0 },
+            NonFinitePolicy::PassThrough,
+        )?;
+        for (d, x) in decoded.iter().zip(data.iter()) {
+            assert_eq!(d.to_bits(), x.to_bits());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[expect(clippy::unwrap_used)]
+    fn natural_log_without_offset_is_default() {
+        for config in [json!({}), json!({ "_version": "1.0.0" })] {
+            let codec: LogCodec = serde_json::from_value(config).unwrap();
+            assert_eq!(codec.base, LogBase::E);
+            assert!(matches!(
+                codec.mode,
+                LogMode::Offset { offset } if offset.to_bits() == 0.0_f64.to_bits()
+            ));
+            assert_eq!(codec.non_finite, NonFinitePolicy::Error);
+        }
+
+        let codec: LogCodec = serde_json::from_value(json!({ "offset": 1.0 })).unwrap();
+        assert!(matches!(
+            codec.mode,
+            LogMode::Offset { offset } if offset.to_bits() == 1.0_f64.to_bits()
+        ));
+    }
 }
@@ -0,0 +1,654 @@
+//! [![CI Status]][workflow] [![MSRV]][repo] [![Latest Version]][crates.io] [![Rust Doc Crate]][docs.rs] [![Rust Doc Main]][docs]
+//!
+//! [CI Status]: https://img.shields.io/github/actions/workflow/status/juntyr/numcodecs-rs/ci.yml?branch=main
+//! [workflow]: https://github.com/juntyr/numcodecs-rs/actions/workflows/ci.yml?query=branch%3Amain
+//!
+//! [MSRV]: https://img.shields.io/badge/MSRV-1.87.0-blue
+//! [repo]: https://github.com/juntyr/numcodecs-rs
+//!
+//! [Latest Version]: https://img.shields.io/crates/v/numcodecs-mask
+//! [crates.io]: https://crates.io/crates/numcodecs-mask
+//!
+//! [Rust Doc Crate]: https://img.shields.io/docsrs/numcodecs-mask
+//! [docs.rs]: https://docs.rs/numcodecs-mask/
+//!
+//! [Rust Doc Main]: https://img.shields.io/badge/docs-main-blue
+//! [docs]: https://juntyr.github.io/numcodecs-rs/numcodecs_mask
+//!
+//! Non-finite and fill value masking meta-codec implementation for the
+//! [`numcodecs`] API.
+
+use std::{borrow::Cow, collections::VecDeque};
+
+use ndarray::{ArrayViewMut, CowArray, IxDyn};
+use numcodecs::{
+    AnyArray, AnyArrayAssignError, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray,
+    ArrayDType, ArrayDataMutExt, Codec, DynCodec, ErasedDynCodec, ErasedError, StaticCodec,
+    StaticCodecConfig, StaticCodecVersion,
+};
+use numcodecs_registry::GlobalRegistry;
+use numcodecs_utils::framing::{
+    FramingError, decode_framed, encode_framed, framed_array, framed_bytes,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use thiserror::Error;
+
+type MaskCodecVersion = StaticCodecVersion<1, 0, 0>;
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+/// Masking meta-codec which wraps an existing codec and masks out non-finite
+/// and fill values before encoding the data with this codec.
+///
+/// During encoding, the positions and exact values of all NaN, infinite, and
+/// fill values are extracted into a run-length encoded mask, and the masked
+/// values are replaced with finite values before the data is passed to the
+/// wrapped codec. During decoding, the masked values are restored exactly.
+///
+/// The codec only supports floating point data.
+pub struct MaskCodec {
+    /// The configuration of the wrapped codec.
+    #[serde(serialize_with = "DynCodec::get_config")]
+    #[serde(deserialize_with = "GlobalRegistry::codec_from_config")]
+    #[schemars(schema_with = "ErasedDynCodec::codec_config_schema")]
+    pub codec: ErasedDynCodec,
+    /// Optional fill value, e.g. the `_FillValue` of a netCDF variable, which
+    /// is masked out in addition to all non-finite values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fill_value: Option<f64>,
+    /// The strategy with which masked values are filled before encoding
+    #[serde(default = "default_fill")]
+    pub fill: MaskFill,
+    /// The codec's encoding format version. Do not provide this parameter explicitly.
+    #[serde(default, rename = "_version")]
+    pub version: MaskCodecVersion,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+/// Strategy with which masked values are filled before encoding
+pub enum MaskFill {
+    /// Fill masked values with zero
+    #[serde(rename = "zero")]
+    Zero,
+    /// Fill masked values with the mean of all unmasked values
+    #[serde(rename = "mean")]
+    Mean,
+    /// Fill masked values with their nearest unmasked neighbour, where the
+    /// distance is the number of steps along the axes of the array
+    #[serde(rename = "nearest")]
+    Nearest,
+}
+
+const fn default_fill() -> MaskFill {
+    MaskFill::Mean
+}
+
+impl Codec for MaskCodec {
+    type Error = MaskCodecError;
+
+    fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        match data {
+            AnyCowArray::F32(data) => {
+                let (runs, data) = mask_and_fill(data, self.fill_value, self.fill);
+                encode_masked(&self.codec, &AnyCowArray::F32(data), &runs)
+            }
+            AnyCowArray::F64(data) => {
+                let (runs, data) = mask_and_fill(data, self.fill_value, self.fill);
+                encode_masked(&self.codec, &AnyCowArray::F64(data), &runs)
+            }
+            encoded => Err(MaskCodecError::UnsupportedDtype(encoded.dtype())),
+        }
+    }
+
+    fn decode(&self, encoded: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        let encoded = encoded.view();
+        let encoded = framed_bytes(&encoded)?;
+        let (header, inner_encoded, runs) = decode_framed::<CompressionHeader>(&encoded)?;
+
+        let mut decoded = AnyArray::zeros(header.dtype, &header.shape);
+        decode_into(
+            &self.codec,
+            &header,
+            &inner_encoded,
+            runs,
+            decoded.view_mut(),
+        )?;
+
+        Ok(decoded)
+    }
+
+    fn decode_into(
+        &self,
+        encoded: AnyArrayView,
+        decoded: AnyArrayViewMut,
+    ) -> Result<(), Self::Error> {
+        let encoded = framed_bytes(&encoded)?;
+        let (header, inner_encoded, runs) = decode_framed::<CompressionHeader>(&encoded)?;
+
+        decode_into(&self.codec, &header, &inner_encoded, runs, decoded)
+    }
+}
+
+impl StaticCodec for MaskCodec {
+    const CODEC_ID: &'static str = "mask.rs";
+
+    type Config<'de> = Self;
+
+    fn from_config(config: Self::Config<'_>) -> Self {
+        config
+    }
+
+    fn get_config(&self) -> StaticCodecConfig<'_, Self> {
+        StaticCodecConfig::from(self)
+    }
+}
+
+#[derive(Debug, Error)]
+/// Errors that may occur when applying the [`MaskCodec`].
+pub enum MaskCodecError {
+    /// [`MaskCodec`] does not support the dtype
+    #[error("Mask does not support the dtype {0}")]
+    UnsupportedDtype(AnyArrayDType),
+    /// [`MaskCodec`] failed to encode the data with the wrapped codec
+    #[error("Mask failed to encode the data with the wrapped codec")]
+    InnerEncodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`MaskCodec`] failed to decode the data with the wrapped codec
+    #[error("Mask failed to decode the data with the wrapped codec")]
+    InnerDecodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`MaskCodec`] failed to frame or unframe the wrapped codec's encoding
+    #[error("Mask failed to frame or unframe the wrapped codec's encoding")]
+    FramingFailed {
+        /// The source of the error
+        #[from]
+        source: FramingError,
+    },
+    /// [`MaskCodec`] failed to encode the mask
+    #[error("Mask failed to encode the mask")]
+    MaskEncodeFailed {
+        /// Opaque source error
+        source: MaskCodingError,
+    },
+    /// [`MaskCodec`] failed to decode the mask
+    #[error("Mask failed to decode the mask")]
+    MaskDecodeFailed {
+        /// Opaque source error
+        source: MaskCodingError,
+    },
+    /// [`MaskCodec`] decoded a mask that does not fit the decoded array
+    #[error("Mask decoded a mask that does not fit the decoded array of length {len}")]
+    DecodeInvalidMask {
+        /// The length of the decoded array
+        len: usize,
+    },
+    /// [`MaskCodec`] cannot decode into the provided array
+    #[error("Mask cannot decode into the provided array")]
+    MismatchedDecodeIntoArray {
+        /// The source of the error
+        #[from]
+        source: AnyArrayAssignError,
+    },
+}
+
+#[derive(Debug, Error)]
+#[error(transparent)]
+/// Opaque error for when encoding or decoding the mask fails
+pub struct MaskCodingError(postcard::Error);
+
+#[derive(Serialize, Deserialize)]
+/// Run of consecutive masked values that all share the same bit pattern
+struct MaskedRun<T> {
+    /// Number of unmasked values since the end of the previous run
+    skip: usize,
+    /// Number of masked values in the run
+    len: usize,
+    /// The exact masked value
+    value: T,
+}
+
+/// Extracts the run-length encoded mask of all non-finite and `fill_value`
+/// values in the `data` and replaces them according to the `fill` strategy
+fn mask_and_fill<T: Float>(
+    data: CowArray<T, IxDyn>,
+    fill_value: Option<f64>,
+    fill: MaskFill,
+) -> (Vec<MaskedRun<T>>, CowArray<T, IxDyn>) {
+    let fill_value = fill_value.map(T::from_f64);
+
+    let mask = data
+        .iter()
+        .map(|x| !x.is_finite() || fill_value.is_some_and(|fill_value| *x == fill_value))
+        .collect::<Vec<_>>();
+
+    let mut runs: Vec<MaskedRun<T>> = Vec::new();
+    let mut run_end = 0;
+
+    for (index, (&x, masked)) in data.iter().zip(mask.iter()).enumerate() {
+        if !masked {
+            continue;
+        }
+
+        match runs.last_mut() {
+            Some(run) if run_end == index && run.value.to_bits_u64() == x.to_bits_u64() => {
+                run.len += 1;
+            }
+            _ => runs.push(MaskedRun {
+                skip: index - run_end,
+                len: 1,
+                value: x,
+            }),
+        }
+
+        run_end = index + 1;
+    }
+
+    if runs.is_empty() {
+        return (runs, data);
+    }
+
+    let mut filled = data.into_owned();
+    let shape = filled.shape().to_vec();
+    let () = filled.with_slice_mut(|values| fill_masked(values, &mask, &shape, fill));
+
+    (runs, filled.into())
+}
+
+/// Replaces all masked `values`, which are in standard order for an array of
+/// the given `shape`, according to the `fill` strategy
+fn fill_masked<T: Float>(values: &mut [T], mask: &[bool], shape: &[usize], fill: MaskFill) {
+    match fill {
+        MaskFill::Zero => {
+            for (x, masked) in values.iter_mut().zip(mask) {
+                if *masked {
+                    *x = T::ZERO;
+                }
+            }
+        }
+        MaskFill::Mean => {
+            let mut mean = 0.0;
+            let mut count = 0_usize;
+
+            // running mean that cannot overflow, unlike summing all values
+            for (x, _) in values.iter().zip(mask).filter(|(_, masked)| !**masked) {
+                count += 1;
+                #[expect(clippy::cast_precision_loss)]
+                let count = count as f64;
+                mean += x.into_f64() / count - mean / count;
+            }
+
+            let mean = T::from_f64(mean);
+
+            for (x, masked) in values.iter_mut().zip(mask) {
+                if *masked {
+                    *x = mean;
+                }
+            }
+        }
+        MaskFill::Nearest => fill_nearest(values, mask, shape),
+    }
+}
+
+/// Replaces all masked `values`, which are in standard order for an array of
+/// the given `shape`, with their nearest unmasked value, where the distance is
+/// the number of steps along the axes of the array
+fn fill_nearest<T: Float>(values: &mut [T], mask: &[bool], shape: &[usize]) {
+    // breadth-first search outwards from all unmasked values at once
+    let mut queue = mask
+        .iter()
+        .enumerate()
+        .filter_map(|(index, masked)| (!masked).then_some(index))
+        .collect::<VecDeque<_>>();
+
+    if queue.is_empty() {
+        values.fill(T::ZERO);
+        return;
+    }
+
+    let mut filled = mask.iter().map(|masked| !masked).collect::<Vec<_>>();
+
+    while let Some(index) = queue.pop_front() {
+        let Some(&x) = values.get(index) else {
+            continue;
+        };
+
+        let mut stride = 1;
+
+        for &len in shape.iter().rev() {
+            let i = (index / stride) % len;
+
+            let neighbours = [
+                (i > 0).then(|| index - stride),
+                (i + 1 < len).then(|| index + stride),
+            ];
+
+            for neighbour in neighbours.into_iter().flatten() {
+                match (filled.get_mut(neighbour), values.get_mut(neighbour)) {
+                    (Some(is_filled), Some(y)) if !*is_filled => {
+                        *is_filled = true;
+                        *y = x;
+                        queue.push_back(neighbour);
+                    }
+                    _ => (),
+                }
+            }
+
+            stride *= len;
+        }
+    }
+}
+
+/// Encodes the masked and filled `data` with the wrapped `codec` and appends
+/// the run-length encoded mask `runs`
+fn encode_masked<T: Float>(
+    codec: &ErasedDynCodec,
+    data: &AnyCowArray,
+    runs: &[MaskedRun<T>],
+) -> Result<AnyArray, MaskCodecError> {
+    let encoded = codec
+        .encode(data.cow())
+        .map_err(|err| MaskCodecError::InnerEncodeFailed { source: err })?;
+
+    let encoded_bytes = encode_framed(
+        &CompressionHeader {
+            dtype: data.dtype(),
+            shape: Cow::Borrowed(data.shape()),
+            version: StaticCodecVersion,
+        },
+        &encoded,
+    )?;
+
+    let encoded_bytes = postcard::to_extend(runs, encoded_bytes).map_err(|err| {
+        MaskCodecError::MaskEncodeFailed {
+            source: MaskCodingError(err),
+        }
+    })?;
+
+    Ok(framed_array(encoded_bytes))
+}
+
+fn decode_into(
+    codec: &ErasedDynCodec,
+    header: &CompressionHeader,
+    inner_encoded: &AnyArray,
+    runs: &[u8],
+    mut decoded: AnyArrayViewMut,
+) -> Result<(), MaskCodecError> {
+    if decoded.dtype() != header.dtype {
+        return Err(MaskCodecError::MismatchedDecodeIntoArray {
+            source: AnyArrayAssignError::DTypeMismatch {
+                src: header.dtype,
+                dst: decoded.dtype(),
+            },
+        });
+    }
+
+    if decoded.shape() != &*header.shape {
+        return Err(MaskCodecError::MismatchedDecodeIntoArray {
+            source: AnyArrayAssignError::ShapeMismatch {
+                src: header.shape.to_vec(),
+                dst: decoded.shape().to_vec(),
+            },
+        });
+    }
+
+    codec
+        .decode_into(inner_encoded.view(), decoded.view_mut())
+        .map_err(|err| MaskCodecError::InnerDecodeFailed { source: err })?;
+
+    match decoded {
+        AnyArrayViewMut::F32(decoded) => unmask(decoded, runs),
+        AnyArrayViewMut::F64(decoded) => unmask(decoded, runs),
+        decoded => Err(MaskCodecError::UnsupportedDtype(decoded.dtype())),
+    }
+}
+
+/// Restores the masked values from the encoded `runs` in the `decoded` data
+fn unmask<T: Float>(
+    mut decoded: ArrayViewMut<T, IxDyn>,
+    runs: &[u8],
+) -> Result<(), MaskCodecError> {
+    let runs = postcard::from_bytes::<Vec<MaskedRun<T>>>(runs).map_err(|err| {
+        MaskCodecError::MaskDecodeFailed {
+            source: MaskCodingError(err),
+        }
+    })?;
+
+    decoded.with_slice_mut(|decoded| {
+        let len = decoded.len();
+        let mut run_end = 0_usize;
+
+        for run in runs {
+            let run_start = run_end
+                .checked_add(run.skip)
+                .ok_or(MaskCodecError::DecodeInvalidMask { len })?;
+            run_end = run_start
+                .checked_add(run.len)
+                .ok_or(MaskCodecError::DecodeInvalidMask { len })?;
+
+            decoded
+                .get_mut(run_start..run_end)
+                .ok_or(MaskCodecError::DecodeInvalidMask { len })?
+                .fill(run.value);
+        }
+
+        Ok(())
+    })
+}
+
+/// Floating point types.
+pub trait Float: Copy + PartialEq + ArrayDType + Serialize + DeserializeOwned {
+    /// Zero
+    const ZERO: Self;
+
+    /// Returns `true` if the value is neither infinite nor NaN
+    #[must_use]
+    fn is_finite(self) -> bool;
+
+    /// Returns the raw bit pattern of the value
+    #[must_use]
+    fn to_bits_u64(self) -> u64;
+
+    /// Converts the value into a [`f64`] without loss of precision
+    #[must_use]
+    fn into_f64(self) -> f64;
+
+    /// Converts from a [`f64`], rounding to the nearest value
+    #[must_use]
+    fn from_f64(x: f64) -> Self;
+}
+
+impl Float for f32 {
+    const ZERO: Self = 0.0;
+
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
+
+    fn to_bits_u64(self) -> u64 {
+        u64::from(self.to_bits())
+    }
+
+    fn into_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn from_f64(x: f64) -> Self {
+        x as Self
+    }
+}
+
+impl Float for f64 {
+    const ZERO: Self = 0.0;
+
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
+
+    fn to_bits_u64(self) -> u64 {
+        self.to_bits()
+    }
+
+    fn into_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(x: f64) -> Self {
+        x
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CompressionHeader<'a> {
+    dtype: AnyArrayDType,
+    #[serde(borrow)]
+    shape: Cow<'a, [usize]>,
+    version: MaskCodecVersion,
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use ndarray::Array;
+    use numcodecs_asinh::AsinhCodec;
+    use serde_json::json;
+
+    use super::*;
+
+    numcodecs_registry::export_global! {
+        static REGISTRY: numcodecs_registry::EmptyRegistry = numcodecs_registry::EmptyRegistry;
+    }
+
+    fn mask(fill_value: Option<f64>, fill: MaskFill) -> MaskCodec {
+        let asinh: AsinhCodec = serde_json::from_value(json!({
+            "linear_width": 1.0,
+        }))
+        .unwrap();
+
+        MaskCodec {
+            codec: ErasedDynCodec::new(asinh),
+            fill_value,
+            fill,
+            version: StaticCodecVersion,
+        }
+    }
+
+    #[test]
+    fn restore_masked_values() {
+        let data = Array::from_vec(vec![
+            f64::NAN,
+            1.0,
+            -9999.0,
+            -9999.0,
+            2.0,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            3.0,
+            f64::from_bits(f64::NAN.to_bits() | 0x42),
+        ])
+        .into_dyn();
+
+        for fill in [MaskFill::Zero, MaskFill::Mean, MaskFill::Nearest] {
+            let codec = mask(Some(-9999.0), fill);
+
+            let encoded = codec.encode(AnyCowArray::F64(data.view().into())).unwrap();
+            let AnyArray::F64(decoded) = codec.decode(encoded.cow()).unwrap() else {
+                panic!("decoded data has the wrong dtype");
+            };
+
+            let mut decoded_into = Array::zeros(data.shape());
+            codec
+                .decode_into(
+                    encoded.view(),
+                    AnyArrayViewMut::F64(decoded_into.view_mut()),
+                )
+                .unwrap();
+
+            for ((x, y), z) in data.iter().zip(decoded.iter()).zip(decoded_into.iter()) {
+                if x.is_finite() && x.to_bits() != (-9999.0_f64).to_bits() {
+                    assert!((x - y).abs() < 1e-12, "{x} vs {y}");
+                } else {
+                    assert_eq!(x.to_bits(), y.to_bits());
+                }
+                assert_eq!(y.to_bits(), z.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn fill_strategies() {
+        let mask = [true, false, true, true, true, false, true];
+        let values = [0.0, 1.0, 0.0, 0.0, 0.0, 5.0, 0.0];
+
+        let mut zero = values;
+        fill_masked(&mut zero, &mask, &[7], MaskFill::Zero);
+        assert_eq!(zero, [0.0, 1.0, 0.0, 0.0, 0.0, 5.0, 0.0]);
+
+        let mut mean = values;
+        fill_masked(&mut mean, &mask, &[7], MaskFill::Mean);
+        assert_eq!(mean, [3.0, 1.0, 3.0, 3.0, 3.0, 5.0, 3.0]);
+
+        let mut nearest = values;
+        fill_masked(&mut nearest, &mask, &[7], MaskFill::Nearest);
+        assert_eq!(nearest, [1.0, 1.0, 1.0, 1.0, 5.0, 5.0, 5.0]);
+    }
+
+    #[test]
+    fn fill_nearest_along_axes() {
+        // in the flattened array, all masked values would be filled with 3.0
+        let mask = [false, false, false, true, true, true];
+        let values = [1.0, 2.0, 3.0, 0.0, 0.0, 0.0];
+
+        let mut nearest = values;
+        fill_masked(&mut nearest, &mask, &[2, 3], MaskFill::Nearest);
+        assert_eq!(nearest, [1.0, 2.0, 3.0, 1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn fill_mean_without_overflow() {
+        let mask = [false, false, true];
+        let values = [f64::MAX, f64::MAX, 0.0];
+
+        let mut mean = values;
+        fill_masked(&mut mean, &mask, &[3], MaskFill::Mean);
+        assert_eq!(mean, [f64::MAX; 3]);
+    }
+
+    #[test]
+    fn mask_runs() {
+        let data = Array::from_vec(vec![f32::NAN, f32::NAN, 1.0, 2.0, -1.0, -1.0, f32::NAN]);
+
+        let (runs, filled) = mask_and_fill(
+            CowArray::from(data.view().into_dyn()),
+            Some(-1.0),
+            MaskFill::Zero,
+        );
+
+        assert_eq!(
+            runs.iter()
+                .map(|run| (run.skip, run.len))
+                .collect::<Vec<_>>(),
+            [(0, 2), (2, 2), (0, 1)]
+        );
+        assert_eq!(
+            filled.iter().copied().collect::<Vec<_>>(),
+            [0.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn unsupported_dtype() {
+        let codec = mask(None, MaskFill::Mean);
+
+        assert!(matches!(
+            codec.encode(AnyCowArray::I32(Array::zeros(4).into_dyn().into())),
+            Err(MaskCodecError::UnsupportedDtype(AnyArrayDType::I32))
+        ));
+    }
+}
@@ -28,17 +28,18 @@ use ::serde_json as _;
 
 use std::{borrow::Cow, fmt, num::NonZeroUsize};
 
-use ndarray::{Array, Array1, ArrayBase, Axis, Data, Dimension, IxDyn, ShapeError};
+use ndarray::{Array, Array1, ArrayBase, ArrayViewMut, Axis, Data, Dimension, IxDyn, ShapeError};
 use num_traits::{Float, identities::Zero};
 use numcodecs::{
     AnyArray, AnyArrayAssignError, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray,
     Codec, StaticCodec, StaticCodecConfig, StaticCodecVersion,
 };
+use numcodecs_utils::slices::{self, SliceFramingError, SlicesError};
 use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 
-type QpetSperrCodecVersion = StaticCodecVersion<0, 2, 0>;
+type QpetSperrCodecVersion = StaticCodecVersion<0, 2, 1>;
 
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
 // serde cannot deny unknown fields because of the flatten
@@ -54,6 +55,13 @@ pub struct QpetSperrCodec {
     /// QPET-SPERR compression mode
     #[serde(flatten)]
     pub mode: QpetSperrCompressionMode,
+    /// The optional number of threads with which the 3D slices are encoded
+    /// and decoded in parallel
+    ///
+    /// If `None`, the slices are processed serially. The encoded bytes do not
+    /// depend on the number of threads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_threads: Option<NonZeroUsize>,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default, rename = "_version")]
     pub version: QpetSperrCodecVersion,
@@ -112,10 +120,10 @@ impl Codec for QpetSperrCodec {
     fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
         match data {
             AnyCowArray::F32(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, &self.mode)?).into_dyn(),
+                Array1::from(compress_with_threads(data, &self.mode, self.num_threads)?).into_dyn(),
             )),
             AnyCowArray::F64(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, &self.mode)?).into_dyn(),
+                Array1::from(compress_with_threads(data, &self.mode, self.num_threads)?).into_dyn(),
             )),
             encoded => Err(QpetSperrCodecError::UnsupportedDtype(encoded.dtype())),
         }
@@ -134,7 +142,7 @@ impl Codec for QpetSperrCodec {
             });
         }
 
-        decompress(&AnyCowArray::U8(encoded).as_bytes())
+        decompress_with_threads(&AnyCowArray::U8(encoded).as_bytes(), self.num_threads)
     }
 
     fn decode_into(
@@ -148,6 +156,45 @@ impl Codec for QpetSperrCodec {
     }
 }
 
+impl QpetSperrCodec {
+    /// Decodes only the slices at the `indices` along the first axis of the
+    /// `encoded` array into the `decoded` array.
+    ///
+    /// The `decoded` array must have the dtype and shape of the encoded data,
+    /// except that its first axis must have length `indices.len()`.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`QpetSperrCodecError`] if the `encoded` array is not a
+    /// one-dimensional byte array, or if decoding the selected slices with
+    /// [`decompress_slices_into`] fails.
+    pub fn decode_slices_into(
+        &self,
+        encoded: AnyArrayView,
+        indices: &[usize],
+        decoded: AnyArrayViewMut,
+    ) -> Result<(), QpetSperrCodecError> {
+        let AnyArrayView::U8(encoded) = encoded else {
+            return Err(QpetSperrCodecError::EncodedDataNotBytes {
+                dtype: encoded.dtype(),
+            });
+        };
+
+        if !matches!(encoded.shape(), [_]) {
+            return Err(QpetSperrCodecError::EncodedDataNotOneDimensional {
+                shape: encoded.shape().to_vec(),
+            });
+        }
+
+        decompress_slices_into(
+            &AnyArrayView::U8(encoded).as_bytes(),
+            indices,
+            decoded,
+            self.num_threads,
+        )
+    }
+}
+
 impl StaticCodec for QpetSperrCodec {
     const CODEC_ID: &'static str = "qpet-sperr.rs";
 
@@ -219,6 +266,17 @@ pub enum QpetSperrCodecError {
     /// [`QpetSperrCodec`] failed to decode from an excessive number of slices
     #[error("QpetSperr failed to decode from an excessive number of slices")]
     DecodeTooManySlices,
+    /// [`QpetSperrCodec`] failed to decode from a corrupted slice index
+    #[error("QpetSperr failed to decode from a corrupted slice index")]
+    DecodeCorruptedSliceIndex,
+    /// [`QpetSperrCodec`] cannot decode a slice at an out-of-bounds index
+    #[error("QpetSperr cannot decode the slice at index {index} of an axis of length {len}")]
+    SliceIndexOutOfBounds {
+        /// The out-of-bounds slice index
+        index: usize,
+        /// The length of the first axis of the encoded array
+        len: usize,
+    },
     /// [`QpetSperrCodec`] failed to decode the data
     #[error("QpetSperr failed to decode the data")]
     SperrDecodeFailed {
@@ -240,6 +298,27 @@ pub enum QpetSperrCodecError {
     },
 }
 
+impl From<SlicesError> for QpetSperrCodecError {
+    fn from(err: SlicesError) -> Self {
+        match err {
+            SlicesError::SliceEncodeFailed { source } => Self::SliceEncodeFailed {
+                source: QpetSperrSliceError(source),
+            },
+            SlicesError::SliceDecodeFailed { source } => Self::SliceDecodeFailed {
+                source: QpetSperrSliceError(source),
+            },
+            SlicesError::DecodeTooManySlices => Self::DecodeTooManySlices,
+            SlicesError::DecodeCorruptedSliceIndex => Self::DecodeCorruptedSliceIndex,
+            SlicesError::SliceIndexOutOfBounds { index, len } => {
+                Self::SliceIndexOutOfBounds { index, len }
+            }
+            SlicesError::MismatchedDecodeIntoArray { source } => {
+                Self::MismatchedDecodeIntoArray { source }
+            }
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 #[error(transparent)]
 /// Opaque error for when encoding or decoding the header fails
@@ -248,7 +327,7 @@ pub struct QpetSperrHeaderError(postcard::Error);
 #[derive(Debug, Error)]
 #[error(transparent)]
 /// Opaque error for when encoding or decoding a slice fails
-pub struct QpetSperrSliceError(postcard::Error);
+pub struct QpetSperrSliceError(SliceFramingError);
 
 #[derive(Debug, Error)]
 #[error(transparent)]
@@ -259,6 +338,12 @@ pub struct QpetSperrCodingError(qpet_sperr::Error);
 ///
 /// The compressed data can be decompressed using SPERR or QPET-SPERR.
 ///
+/// The 3D slices are compressed serially. Use [`compress_with_threads`] to
+/// compress the slices in parallel.
+///
+/// The encoded data contains an index of the byte offsets of all slices,
+/// which allows [`decompress_slices_into`] to decode only selected slices.
+///
 /// # Errors
 ///
 /// Errors with
@@ -266,176 +351,265 @@ pub struct QpetSperrCodingError(qpet_sperr::Error);
 /// - [`QpetSperrCodecError::QpetSperrEncodeFailed`] if encoding with
 ///   QPET-SPERR failed
 /// - [`QpetSperrCodecError::SliceEncodeFailed`] if encoding a slice failed
-#[allow(clippy::missing_panics_doc)]
 pub fn compress<T: QpetSperrElement, S: Data<Elem = T>, D: Dimension>(
     data: ArrayBase<S, D>,
     mode: &QpetSperrCompressionMode,
 ) -> Result<Vec<u8>, QpetSperrCodecError> {
+    compress_with_threads(data, mode, None)
+}
+
+/// Compress the `data` array using QPET-SPERR with the provided `mode`.
+///
+/// The compressed data can be decompressed using SPERR or QPET-SPERR.
+///
+/// The 3D slices are compressed in parallel using up to `num_threads` threads
+/// if provided, or serially otherwise. The output is the same in both cases.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`QpetSperrCodecError::HeaderEncodeFailed`] if encoding the header failed
+/// - [`QpetSperrCodecError::QpetSperrEncodeFailed`] if encoding with
+///   QPET-SPERR failed
+/// - [`QpetSperrCodecError::SliceEncodeFailed`] if encoding a slice failed
+#[allow(clippy::missing_panics_doc)]
+pub fn compress_with_threads<T: QpetSperrElement, S: Data<Elem = T>, D: Dimension>(
+    data: ArrayBase<S, D>,
+    mode: &QpetSperrCompressionMode,
+    num_threads: Option<NonZeroUsize>,
+) -> Result<Vec<u8>, QpetSperrCodecError> {
+    let data = data.into_dyn();
+
+    // SPERR cannot handle zero-length dimensions, which have no slices
+    let (slice_offsets, slices) =
+        slices::encode_slices(&data, num_threads, |mut slice, slice_shape: [usize; 3]| {
+            while slice.ndim() < 3 {
+                slice = slice.insert_axis(Axis(0));
+            }
+            #[allow(clippy::unwrap_used)]
+            // slice must now have at least three axes, and all but the last three
+            //  must be of size 1
+            let slice = slice.into_shape_with_order(slice_shape).unwrap();
+
+            let QpetSperrCompressionMode::SymbolicQuantityOfInterest {
+                qoi,
+                qoi_block_size,
+                qoi_pwe,
+                sperr_chunks,
+                data_pwe,
+                qoi_k,
+                high_prec,
+            } = mode;
+
+            qpet_sperr::compress_3d(
+                slice,
+                qpet_sperr::CompressionMode::SymbolicQuantityOfInterest {
+                    qoi: qoi.as_str(),
+                    qoi_block_size: *qoi_block_size,
+                    qoi_pwe: qoi_pwe.0,
+                    data_pwe: data_pwe.map(|data_pwe| data_pwe.0),
+                    qoi_k: qoi_k.0,
+                    high_prec: *high_prec,
+                },
+                (
+                    sperr_chunks.0.get(),
+                    sperr_chunks.1.get(),
+                    sperr_chunks.2.get(),
+                ),
+            )
+            .map_err(|err| QpetSperrCodecError::QpetSperrEncodeFailed {
+                source: QpetSperrCodingError(err),
+            })
+        })?;
+
     let mut encoded = postcard::to_extend(
         &CompressionHeader {
             dtype: T::DTYPE,
             shape: Cow::Borrowed(data.shape()),
             version: StaticCodecVersion,
+            slice_offsets,
         },
         Vec::new(),
     )
     .map_err(|err| QpetSperrCodecError::HeaderEncodeFailed {
         source: QpetSperrHeaderError(err),
     })?;
-
-    // SPERR cannot handle zero-length dimensions
-    if data.is_empty() {
-        return Ok(encoded);
-    }
-
-    let mut chunk_size = Vec::from(data.shape());
-    let (width, height, depth) = match *chunk_size.as_mut_slice() {
-        [ref mut rest @ .., depth, height, width] => {
-            for r in rest {
-                *r = 1;
-            }
-            (width, height, depth)
-        }
-        [height, width] => (width, height, 1),
-        [width] => (width, 1, 1),
-        [] => (1, 1, 1),
-    };
-
-    for mut slice in data.into_dyn().exact_chunks(chunk_size.as_slice()) {
-        while slice.ndim() < 3 {
-            slice = slice.insert_axis(Axis(0));
-        }
-        #[allow(clippy::unwrap_used)]
-        // slice must now have at least three axes, and all but the last three
-        //  must be of size 1
-        let slice = slice.into_shape_with_order((depth, height, width)).unwrap();
-
-        let QpetSperrCompressionMode::SymbolicQuantityOfInterest {
-            qoi,
-            qoi_block_size,
-            qoi_pwe,
-            sperr_chunks,
-            data_pwe,
-            qoi_k,
-            high_prec,
-        } = mode;
-
-        let encoded_slice = qpet_sperr::compress_3d(
-            slice,
-            qpet_sperr::CompressionMode::SymbolicQuantityOfInterest {
-                qoi: qoi.as_str(),
-                qoi_block_size: *qoi_block_size,
-                qoi_pwe: qoi_pwe.0,
-                data_pwe: data_pwe.map(|data_pwe| data_pwe.0),
-                qoi_k: qoi_k.0,
-                high_prec: *high_prec,
-            },
-            (
-                sperr_chunks.0.get(),
-                sperr_chunks.1.get(),
-                sperr_chunks.2.get(),
-            ),
-        )
-        .map_err(|err| QpetSperrCodecError::QpetSperrEncodeFailed {
-            source: QpetSperrCodingError(err),
-        })?;
-
-        encoded = postcard::to_extend(encoded_slice.as_slice(), encoded).map_err(|err| {
-            QpetSperrCodecError::SliceEncodeFailed {
-                source: QpetSperrSliceError(err),
-            }
-        })?;
-    }
+    encoded.extend_from_slice(&slices);
 
     Ok(encoded)
 }
 
 /// Decompress the `encoded` data into an array using SPERR.
 ///
+/// The 3D slices are decompressed serially. Use [`decompress_with_threads`]
+/// to decompress the slices in parallel.
+///
 /// # Errors
 ///
 /// Errors with
 /// - [`QpetSperrCodecError::HeaderDecodeFailed`] if decoding the header failed
 /// - [`QpetSperrCodecError::SliceDecodeFailed`] if decoding a slice failed
 /// - [`QpetSperrCodecError::SperrDecodeFailed`] if decoding with SPERR failed
-/// - [`QpetSperrCodecError::DecodeInvalidShape`] if the encoded data decodes
-///   to an unexpected shape
 /// - [`QpetSperrCodecError::DecodeTooManySlices`] if the encoded data contains
 ///   too many slices
 pub fn decompress(encoded: &[u8]) -> Result<AnyArray, QpetSperrCodecError> {
+    decompress_with_threads(encoded, None)
+}
+
+/// Decompress the `encoded` data into an array using SPERR.
+///
+/// The 3D slices are decompressed in parallel using up to `num_threads`
+/// threads if provided, or serially otherwise.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`QpetSperrCodecError::HeaderDecodeFailed`] if decoding the header failed
+/// - [`QpetSperrCodecError::SliceDecodeFailed`] if decoding a slice failed
+/// - [`QpetSperrCodecError::SperrDecodeFailed`] if decoding with SPERR failed
+/// - [`QpetSperrCodecError::DecodeTooManySlices`] if the encoded data contains
+///   too many slices
+pub fn decompress_with_threads(
+    encoded: &[u8],
+    num_threads: Option<NonZeroUsize>,
+) -> Result<AnyArray, QpetSperrCodecError> {
     fn decompress_typed<T: QpetSperrElement>(
-        mut encoded: &[u8],
+        encoded: &[u8],
         shape: &[usize],
+        num_threads: Option<NonZeroUsize>,
     ) -> Result<Array<T, IxDyn>, QpetSperrCodecError> {
         let mut decoded = Array::<T, _>::zeros(shape);
+        slices::decode_slices_into(
+            encoded,
+            decoded.view_mut(),
+            num_threads,
+            decompress_slice_into,
+        )?;
+        Ok(decoded)
+    }
 
-        let mut chunk_size = Vec::from(shape);
-        let (width, height, depth) = match *chunk_size.as_mut_slice() {
-            [ref mut rest @ .., depth, height, width] => {
-                for r in rest {
-                    *r = 1;
-                }
-                (width, height, depth)
-            }
-            [height, width] => (width, height, 1),
-            [width] => (width, 1, 1),
-            [] => (1, 1, 1),
-        };
-
-        for mut slice in decoded.exact_chunks_mut(chunk_size.as_slice()) {
-            let (encoded_slice, rest) =
-                postcard::take_from_bytes::<Cow<[u8]>>(encoded).map_err(|err| {
-                    QpetSperrCodecError::SliceDecodeFailed {
-                        source: QpetSperrSliceError(err),
-                    }
-                })?;
-            encoded = rest;
+    let (header, encoded) = decode_header(encoded)?;
 
-            while slice.ndim() < 3 {
-                slice = slice.insert_axis(Axis(0));
-            }
-            #[allow(clippy::unwrap_used)]
-            // slice must now have at least three axes, and all but the last
-            //  three must be of size 1
-            let slice = slice.into_shape_with_order((depth, height, width)).unwrap();
-
-            qpet_sperr::decompress_into_3d(&encoded_slice, slice).map_err(|err| {
-                QpetSperrCodecError::SperrDecodeFailed {
-                    source: QpetSperrCodingError(err),
-                }
-            })?;
-        }
+    match header.dtype {
+        QpetSperrDType::F32 => Ok(AnyArray::F32(decompress_typed(
+            encoded,
+            &header.shape,
+            num_threads,
+        )?)),
+        QpetSperrDType::F64 => Ok(AnyArray::F64(decompress_typed(
+            encoded,
+            &header.shape,
+            num_threads,
+        )?)),
+    }
+}
 
-        if !encoded.is_empty() {
-            return Err(QpetSperrCodecError::DecodeTooManySlices);
-        }
+/// Decompress only the slices at the `indices` along the first axis of the
+/// `encoded` data into the `decoded` array using SPERR.
+///
+/// The `decoded` array must have the dtype and shape of the encoded data,
+/// except that its first axis must have length `indices.len()`. For arrays
+/// with more than three dimensions, only the 3D slices that are selected by
+/// the `indices` are decoded, using up to `num_threads` threads if provided,
+/// or serially otherwise.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`QpetSperrCodecError::HeaderDecodeFailed`] if decoding the header failed
+/// - [`QpetSperrCodecError::SliceIndexOutOfBounds`] if any of the `indices` is
+///   out of bounds for the first axis of the encoded data
+/// - [`QpetSperrCodecError::MismatchedDecodeIntoArray`] if the `decoded` array
+///   has the wrong dtype or shape
+/// - [`QpetSperrCodecError::DecodeCorruptedSliceIndex`] if the slice index of the
+///   encoded data is corrupted
+/// - [`QpetSperrCodecError::SliceDecodeFailed`] if decoding a slice failed
+/// - [`QpetSperrCodecError::SperrDecodeFailed`] if decoding with SPERR failed
+pub fn decompress_slices_into(
+    encoded: &[u8],
+    indices: &[usize],
+    decoded: AnyArrayViewMut,
+    num_threads: Option<NonZeroUsize>,
+) -> Result<(), QpetSperrCodecError> {
+    let (header, encoded) = decode_header(encoded)?;
+
+    let dtype = match header.dtype {
+        QpetSperrDType::F32 => AnyArrayDType::F32,
+        QpetSperrDType::F64 => AnyArrayDType::F64,
+    };
+    slices::check_decode_selected_slices_into(dtype, &header.shape, indices, &decoded)?;
+
+    match decoded {
+        AnyArrayViewMut::F32(decoded) => slices::decode_selected_slices_into(
+            encoded,
+            &header.slice_offsets,
+            &header.shape,
+            indices,
+            decoded,
+            num_threads,
+            decompress_slice_into,
+        ),
+        AnyArrayViewMut::F64(decoded) => slices::decode_selected_slices_into(
+            encoded,
+            &header.slice_offsets,
+            &header.shape,
+            indices,
+            decoded,
+            num_threads,
+            decompress_slice_into,
+        ),
+        decoded => Err(QpetSperrCodecError::UnsupportedDtype(decoded.dtype())),
+    }
+}
 
-        Ok(decoded)
+/// Decodes the header of the `encoded` data
+///
+/// The slice index of data that was encoded with the index-less format
+/// version 0.2.0 is reconstructed by walking over the slices.
+fn decode_header(encoded: &[u8]) -> Result<(CompressionHeader<'_>, &[u8]), QpetSperrCodecError> {
+    if let Ok((header, encoded)) = postcard::take_from_bytes::<LegacyCompressionHeader>(encoded) {
+        let slice_offsets = slices::slice_offsets(encoded, slices::num_slices::<3>(&header.shape))?;
+
+        return Ok((
+            CompressionHeader {
+                dtype: header.dtype,
+                shape: header.shape,
+                version: StaticCodecVersion,
+                slice_offsets,
+            },
+            encoded,
+        ));
     }
 
-    let (header, encoded) =
-        postcard::take_from_bytes::<CompressionHeader>(encoded).map_err(|err| {
-            QpetSperrCodecError::HeaderDecodeFailed {
-                source: QpetSperrHeaderError(err),
-            }
-        })?;
+    postcard::take_from_bytes::<CompressionHeader>(encoded).map_err(|err| {
+        QpetSperrCodecError::HeaderDecodeFailed {
+            source: QpetSperrHeaderError(err),
+        }
+    })
+}
 
-    // Return empty data for zero-size arrays
-    if header.shape.iter().copied().any(|s| s == 0) {
-        return match header.dtype {
-            QpetSperrDType::F32 => Ok(AnyArray::F32(Array::zeros(&*header.shape))),
-            QpetSperrDType::F64 => Ok(AnyArray::F64(Array::zeros(&*header.shape))),
-        };
+fn decompress_slice_into<T: QpetSperrElement>(
+    encoded_slice: &[u8],
+    mut slice: ArrayViewMut<T, IxDyn>,
+    slice_shape: [usize; 3],
+) -> Result<(), QpetSperrCodecError> {
+    while slice.ndim() < 3 {
+        slice = slice.insert_axis(Axis(0));
     }
+    #[allow(clippy::unwrap_used)]
+    // slice must now have at least three axes, and all but the last
+    //  three must be of size 1
+    let slice = slice.into_shape_with_order(slice_shape).unwrap();
 
-    match header.dtype {
-        QpetSperrDType::F32 => Ok(AnyArray::F32(decompress_typed(encoded, &header.shape)?)),
-        QpetSperrDType::F64 => Ok(AnyArray::F64(decompress_typed(encoded, &header.shape)?)),
-    }
+    qpet_sperr::decompress_into_3d(encoded_slice, slice).map_err(|err| {
+        QpetSperrCodecError::SperrDecodeFailed {
+            source: QpetSperrCodingError(err),
+        }
+    })
 }
 
 /// Array element types which can be compressed with QPET-SPERR.
-pub trait QpetSperrElement: qpet_sperr::Element + Zero {
+pub trait QpetSperrElement: qpet_sperr::Element + Zero + Send + Sync {
     /// The dtype representation of the type
     const DTYPE: QpetSperrDType;
 }
@@ -504,6 +678,17 @@ struct CompressionHeader<'a> {
     #[serde(borrow)]
     shape: Cow<'a, [usize]>,
     version: QpetSperrCodecVersion,
+    slice_offsets: Vec<usize>,
+}
+
+/// Header of the format version 0.2.0, which has no slice index
+#[derive(Deserialize)]
+struct LegacyCompressionHeader<'a> {
+    dtype: QpetSperrDType,
+    #[serde(borrow)]
+    shape: Cow<'a, [usize]>,
+    #[expect(dead_code)]
+    version: StaticCodecVersion<0, 2, 0>,
 }
 
 /// Dtypes that QPET-SPERR can compress and decompress
@@ -688,4 +873,165 @@ mod tests {
         assert_eq!(decoded.len(), 64 * 64 * 1);
         assert_eq!(decoded.shape(), &[64, 64, 1]);
     }
+
+    #[test]
+    fn parallel_slices() {
+        let data = Array::<f64, _>::from_shape_fn((5, 3, 8, 8, 8), |(a, b, c, d, e)| {
+            #[expect(clippy::cast_precision_loss)]
+            let x = (a * 512 + b * 64 + c * 8 + d + e) as f64;
+            x.sin()
+        });
+        let mode = QpetSperrCompressionMode::SymbolicQuantityOfInterest {
+            qoi: String::from("x^2"),
+            qoi_block_size: default_qoi_block_size(),
+            qoi_pwe: Positive(0.1),
+            sperr_chunks: default_sperr_chunks(),
+            data_pwe: None,
+            qoi_k: default_qoi_k(),
+            high_prec: false,
+        };
+
+        let encoded = compress(data.view(), &mode).unwrap();
+        let decoded = decompress(&encoded).unwrap();
+
+        for num_threads in [1, 2, 4, 64] {
+            let num_threads = NonZeroUsize::new(num_threads);
+
+            let encoded_parallel = compress_with_threads(data.view(), &mode, num_threads).unwrap();
+            assert_eq!(encoded_parallel, encoded);
+
+            let decoded_parallel = decompress_with_threads(&encoded, num_threads).unwrap();
+            assert_eq!(decoded_parallel, decoded);
+        }
+    }
+    #[test]
+    fn random_access_slices() {
+        let data = Array::<f64, _>::from_shape_fn((5, 3, 4, 8, 8), |(a, b, c, d, e)| {
+            #[expect(clippy::cast_precision_loss)]
+            let x = (a * 256 + b * 64 + c * 8 + d + e) as f64;
+            x.sin()
+        });
+        let mode = QpetSperrCompressionMode::SymbolicQuantityOfInterest {
+            qoi: String::from("x^2"),
+            qoi_block_size: default_qoi_block_size(),
+            qoi_pwe: Positive(0.1),
+            sperr_chunks: default_sperr_chunks(),
+            data_pwe: None,
+            qoi_k: default_qoi_k(),
+            high_prec: false,
+        };
+
+        let encoded = compress(data.view(), &mode).unwrap();
+        let AnyArray::F64(decoded) = decompress(&encoded).unwrap() else {
+            panic!("unexpected decoded dtype");
+        };
+
+        for indices in [&[][..], &[0], &[4, 1], &[3, 0, 3]] {
+            for num_threads in [None, NonZeroUsize::new(2)] {
+                let mut slices = Array::<f64, _>::zeros((indices.len(), 3, 4, 8, 8)).into_dyn();
+                decompress_slices_into(
+                    &encoded,
+                    indices,
+                    AnyArrayViewMut::F64(slices.view_mut()),
+                    num_threads,
+                )
+                .unwrap();
+
+                assert_eq!(slices, decoded.select(Axis(0), indices));
+            }
+        }
+
+        let data = data
+            .index_axis(Axis(0), 0)
+            .index_axis(Axis(0), 0)
+            .to_owned();
+        let encoded = compress(data.view(), &mode).unwrap();
+        let AnyArray::F64(decoded) = decompress(&encoded).unwrap() else {
+            panic!("unexpected decoded dtype");
+        };
+
+        let mut slices = Array::<f64, _>::zeros((2, 8, 8)).into_dyn();
+        decompress_slices_into(
+            &encoded,
+            &[3, 1],
+            AnyArrayViewMut::F64(slices.view_mut()),
+            None,
+        )
+        .unwrap();
+        assert_eq!(slices, decoded.select(Axis(0), &[3, 1]));
+    }
+
+    #[test]
+    fn legacy_slices_without_index() {
+        let data = Array::<f32, _>::from_shape_fn((3, 2, 4, 4), |(a, b, c, d)| {
+            #[expect(clippy::cast_precision_loss)]
+            let x = (a * 32 + b * 16 + c * 4 + d) as f32;
+            x.sin()
+        });
+        let mode = QpetSperrCompressionMode::SymbolicQuantityOfInterest {
+            qoi: String::from("x^2"),
+            qoi_block_size: default_qoi_block_size(),
+            qoi_pwe: Positive(0.1),
+            sperr_chunks: default_sperr_chunks(),
+            data_pwe: None,
+            qoi_k: default_qoi_k(),
+            high_prec: false,
+        };
+
+        let encoded = compress(data.view(), &mode).unwrap();
+        let decoded = decompress(&encoded).unwrap();
+
+        let (header, slices) = postcard::take_from_bytes::<CompressionHeader>(&encoded).unwrap();
+        let mut legacy = postcard::to_extend(
+            &(header.dtype, &header.shape, StaticCodecVersion::<0, 2, 0>),
+            Vec::new(),
+        )
+        .unwrap();
+        legacy.extend_from_slice(slices);
+
+        assert_eq!(decompress(&legacy).unwrap(), decoded);
+
+        let AnyArray::F32(decoded) = decoded else {
+            panic!("unexpected decoded dtype");
+        };
+        let mut slices = Array::<f32, _>::zeros((1, 2, 4, 4)).into_dyn();
+        decompress_slices_into(&legacy, &[1], AnyArrayViewMut::F32(slices.view_mut()), None)
+            .unwrap();
+        assert_eq!(slices, decoded.select(Axis(0), &[1]));
+    }
+
+    #[test]
+    fn random_access_slices_out_of_bounds() {
+        let data = Array::<f32, _>::zeros((2, 1, 4, 4));
+        let mode = QpetSperrCompressionMode::SymbolicQuantityOfInterest {
+            qoi: String::from("x^2"),
+            qoi_block_size: default_qoi_block_size(),
+            qoi_pwe: Positive(0.1),
+            sperr_chunks: default_sperr_chunks(),
+            data_pwe: None,
+            qoi_k: default_qoi_k(),
+            high_prec: false,
+        };
+        let encoded = compress(data.view(), &mode).unwrap();
+
+        let mut slices = Array::<f32, _>::zeros((1, 1, 4, 4)).into_dyn();
+        assert!(matches!(
+            decompress_slices_into(
+                &encoded,
+                &[2],
+                AnyArrayViewMut::F32(slices.view_mut()),
+                None
+            ),
+            Err(QpetSperrCodecError::SliceIndexOutOfBounds { index: 2, len: 2 })
+        ));
+        assert!(matches!(
+            decompress_slices_into(
+                &encoded,
+                &[0, 1],
+                AnyArrayViewMut::F32(slices.view_mut()),
+                None
+            ),
+            Err(QpetSperrCodecError::MismatchedDecodeIntoArray { .. })
+        ));
+    }
 }
@@ -1,8 +1,8 @@
 #![expect(missing_docs)]
 
 use ::{
-    ndarray as _, num_traits as _, postcard as _, qpet_sperr as _, schemars as _, thiserror as _,
-    zstd_sys as _,
+    ndarray as _, num_traits as _, numcodecs_utils as _, postcard as _, qpet_sperr as _,
+    schemars as _, thiserror as _, zstd_sys as _,
 };
 
 use numcodecs::StaticCodec;
@@ -32,3 +32,18 @@ fn symbolic_qoi_config() {
         QpetSperrCompressionMode::SymbolicQuantityOfInterest { qoi, .. } if qoi == "x^2"
     ));
 }
+
+#[test]
+fn num_threads_config() {
+    let codec = QpetSperrCodec::from_config(
+        Deserialize::deserialize(json!({
+            "mode": "qoi-symbolic",
+            "qoi": "x^2",
+            "qoi_pwe": 0.1,
+            "num_threads": 4,
+        }))
+        .unwrap(),
+    );
+
+    assert_eq!(codec.num_threads.map(std::num::NonZeroUsize::get), Some(4));
+}
@@ -1,8 +1,8 @@
 #![expect(missing_docs)]
 
 use ::{
-    ndarray as _, num_traits as _, postcard as _, qpet_sperr as _, schemars as _, serde as _,
-    serde_json as _, thiserror as _, zstd_sys as _,
+    ndarray as _, num_traits as _, numcodecs_utils as _, postcard as _, qpet_sperr as _,
+    schemars as _, serde as _, serde_json as _, thiserror as _, zstd_sys as _,
 };
 
 use numcodecs::{DynCodecType, StaticCodecType};
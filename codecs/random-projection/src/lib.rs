@@ -60,7 +60,7 @@ pub struct RandomProjectionCodec {
     pub projection: RandomProjectionKind,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default, rename = "_version")]
-    pub version: StaticCodecVersion<0, 1, 0>,
+    pub version: StaticCodecVersion<0, 1, 1>,
 }
 
 /// Method with which the reduced dimensionality `$K$` is selected
@@ -123,6 +123,26 @@ pub enum RandomProjectionKind {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         density: Option<OpenClosedUnit<f64>>,
     },
+    /// The random projection is a subsampled randomized Hadamard transform
+    /// (SRHT), also known as the fast Johnson-Lindenstrauss transform [^3],
+    /// which never materializes the projection matrix.
+    ///
+    /// The `$D$` features are multiplied by random signs, zero-padded to the
+    /// next power of two `$M$`, and mixed using the fast Walsh-Hadamard
+    /// transform. Then, `$K$` of the `$M$` transformed components are sampled
+    /// uniformly at random (with replacement) and scaled by
+    /// `$\frac{1}{\sqrt{k}}$`. The implied matrix's components are thus
+    /// `$\pm \sqrt{\frac{1}{k}}$`.
+    ///
+    /// Projecting `$N$` samples costs `$O(N \cdot M \cdot \log M)$` time and
+    /// only `$O(M + K)$` additional memory.
+    ///
+    /// [^3]: Ailon, N., and Chazelle, B. (2009). The fast
+    ///       Johnson-Lindenstrauss transform and approximate nearest
+    ///       neighbors. *SIAM Journal on Computing*, 39(1), 302-322.
+    ///       Available from:
+    ///       [doi:10.1137/060673096](https://doi.org/10.1137/060673096).
+    SubsampledHadamard,
 }
 
 impl Codec for RandomProjectionCodec {
@@ -227,9 +247,11 @@ pub enum RandomProjectionCodecError {
         /// Number of samples `N` in the output array
         output: usize,
     },
-    /// [`RandomProjectionCodec`] cannot decode from an array with zero
-    /// dimensionality `$K$`
-    #[error("RandomProjection cannot decode from an array with zero dimensionality `K`")]
+    /// [`RandomProjectionCodec`] cannot encode into or decode from an array
+    /// with zero dimensionality `$K$`
+    #[error(
+        "RandomProjection cannot encode into or decode from an array with zero dimensionality `K`"
+    )]
     ProjectedArrayZeroComponents,
     /// [`RandomProjectionCodec`] cannot decode from an array with corrupted
     /// dimensionality metadata
@@ -276,17 +298,65 @@ pub fn project_with_projection<T: FloatExt, S: Data<Elem = T>, D: Dimension>(
         .into_dimensionality()
         .map_err(|err| RandomProjectionCodecError::NonMatrixData { source: err })?;
 
-    let (n, d) = data.dim();
+    let (n, _d) = data.dim();
 
-    let k = match reduction {
-        RandomProjectionReduction::JohnsonLindenstrauss { epsilon } => {
-            johnson_lindenstrauss_min_k(n, *epsilon)
-        }
-        RandomProjectionReduction::Explicit { k } => k.get(),
-    };
+    let k = reduced_dimensionality(n, reduction);
 
     let mut projected = Array::<T, Ix2>::from_elem((n, k + 1), T::ZERO);
 
+    project_batch_into_with_projection(data, projected.view_mut(), seed, projection)?;
+
+    Ok(projected)
+}
+
+/// Applies random projection to one `batch` of rows of a larger dataset with
+/// the given `seed` and `projection` kind and outputs into the `projected`
+/// array.
+///
+/// The `projected` array must have the shape `$(B, K + 1)$`, where `$B$` is
+/// the number of rows in the `batch` and `$K$` is the reduced dimensionality,
+/// which should be computed once for the full dataset using
+/// [`reduced_dimensionality`]. The last column stores the dimensionality
+/// metadata.
+///
+/// Since the projection matrix is regenerated from the `seed`, the batches
+/// can be projected independently, in any order, and in parallel. Stacking
+/// the projected batches produces exactly the same output as projecting the
+/// full dataset with [`project_with_projection`]. Each projected batch (or
+/// any other subset of projected rows) can then be reconstructed using
+/// [`reconstruct_with_projection`] or [`reconstruct_into_with_projection`].
+///
+/// # Errors
+///
+/// Errors with
+/// - [`RandomProjectionCodecError::NumberOfSamplesMismatch`] if the `batch`'s
+///   number of samples doesn't match the `projected` array's number of
+///   samples
+/// - [`RandomProjectionCodecError::ProjectedArrayZeroComponents`] if the
+///   `projected` array is of shape `$(B, 0)$` and thus has no space for the
+///   dimensionality metadata
+/// - [`RandomProjectionCodecError::NonFiniteData`] if the input `batch` or
+///   projected output contains non-finite data
+pub fn project_batch_into_with_projection<T: FloatExt, S: Data<Elem = T>>(
+    batch: ArrayBase<S, Ix2>,
+    mut projected: ArrayViewMut<T, Ix2>,
+    seed: u64,
+    projection: &RandomProjectionKind,
+) -> Result<(), RandomProjectionCodecError> {
+    let (n, d) = batch.dim();
+    let (n2, k) = projected.dim();
+
+    if n2 != n {
+        return Err(RandomProjectionCodecError::NumberOfSamplesMismatch {
+            input: n,
+            output: n2,
+        });
+    }
+
+    let Some(k) = k.checked_sub(1) else {
+        return Err(RandomProjectionCodecError::ProjectedArrayZeroComponents);
+    };
+
     // stash the number of features `$d$` in an extra column
     // this is quite inefficient but works for now
     for p in projected.slice_mut(s!(.., k)) {
@@ -295,7 +365,7 @@ pub fn project_with_projection<T: FloatExt, S: Data<Elem = T>, D: Dimension>(
 
     match projection {
         RandomProjectionKind::Gaussian => project_into(
-            data,
+            batch,
             projected.slice_mut(s!(.., ..k)),
             |x, y| gaussian_project(x, y, seed),
             gaussian_normaliser(k),
@@ -303,15 +373,16 @@ pub fn project_with_projection<T: FloatExt, S: Data<Elem = T>, D: Dimension>(
         RandomProjectionKind::Sparse { density } => {
             let density = density_or_ping_li_minimum(*density, d);
             project_into(
-                data,
+                batch,
                 projected.slice_mut(s!(.., ..k)),
                 |x, y| sparse_project(x, y, density, seed),
                 sparse_normaliser(k, density),
             )
         }
-    }?;
-
-    Ok(projected)
+        RandomProjectionKind::SubsampledHadamard => {
+            srht_project_into(batch, projected.slice_mut(s!(.., ..k)), seed)
+        }
+    }
 }
 
 #[expect(clippy::needless_pass_by_value)]
@@ -445,6 +516,12 @@ pub fn reconstruct_with_projection<T: FloatExt, S: Data<Elem = T>, D: Dimension>
                 sparse_normaliser(k, density),
             )
         }
+        RandomProjectionKind::SubsampledHadamard => {
+            let (n, _k) = projected.dim();
+            let mut reconstructed = Array::<T, Ix2>::from_elem((n, d), T::ZERO);
+            srht_reconstruct_into(projected, reconstructed.view_mut(), seed)?;
+            Ok(reconstructed)
+        }
     }
 }
 
@@ -595,6 +672,9 @@ pub fn reconstruct_into_with_projection<T: FloatExt, S: Data<Elem = T>, D: Dimen
                 sparse_normaliser(k, density),
             )
         }
+        RandomProjectionKind::SubsampledHadamard => {
+            srht_reconstruct_into(projected, reconstructed, seed)
+        }
     }
 }
 
@@ -664,6 +744,156 @@ pub fn reconstruct_into<T: FloatExt, S: Data<Elem = T>>(
     Ok(())
 }
 
+#[expect(clippy::needless_pass_by_value)]
+/// Applies the subsampled randomized Hadamard transform (SRHT) with the given
+/// `seed` to the input `data` and outputs into the `projected` array.
+///
+/// Unlike [`project_into`], the random projection matrix is never
+/// materialized. Instead, every sample is transformed using the fast
+/// Walsh-Hadamard transform, from which `$K$` components are sampled.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`RandomProjectionCodecError::NumberOfSamplesMismatch`] if the input
+///   `data`'s number of samples doesn't match the `projected` array's number
+///   of samples
+/// - [`RandomProjectionCodecError::NonFiniteData`] if the input `data` or
+///   projected output contains non-finite data
+pub fn srht_project_into<T: FloatExt, S: Data<Elem = T>>(
+    data: ArrayBase<S, Ix2>,
+    mut projected: ArrayViewMut<T, Ix2>,
+    seed: u64,
+) -> Result<(), RandomProjectionCodecError> {
+    let (n, d) = data.dim();
+    let (n2, k) = projected.dim();
+
+    if n2 != n {
+        return Err(RandomProjectionCodecError::NumberOfSamplesMismatch {
+            input: n,
+            output: n2,
+        });
+    }
+
+    let d_padded = d.next_power_of_two();
+    let signs = (0..d).map(|l| srht_sign(l, seed)).collect::<Vec<T>>();
+    let rows = (0..k)
+        .map(|j| srht_row(j, d_padded, seed))
+        .collect::<Vec<_>>();
+    let normalizer = srht_normaliser(k);
+
+    let mut buffer = vec![T::ZERO; d_padded];
+
+    for (data_i, projected_i) in data.rows().into_iter().zip(projected.rows_mut()) {
+        // randomly flip the signs of the features and zero-pad them to d_padded
+        buffer.fill(T::ZERO);
+        for ((b, &x), &sign) in buffer.iter_mut().zip(data_i).zip(&signs) {
+            *b = x * sign;
+        }
+
+        fast_walsh_hadamard_transform(&mut buffer);
+
+        // subsample k of the d_padded transformed components
+        for (projected_ij, &r) in projected_i.into_iter().zip(&rows) {
+            #[expect(clippy::indexing_slicing)]
+            // buffer is of shape (d_padded) and all r's are in 0..d_padded
+            let b = buffer[r];
+            *projected_ij = b * normalizer;
+        }
+    }
+
+    if !Zip::from(projected).all(|x| x.is_finite()) {
+        return Err(RandomProjectionCodecError::NonFiniteData);
+    }
+
+    Ok(())
+}
+
+#[expect(clippy::needless_pass_by_value)]
+/// Applies the (approximate) inverse of the subsampled randomized Hadamard
+/// transform (SRHT) with the given `seed` to the `projected` array.
+///
+/// The reconstructed data is output into the `reconstructed` array.
+///
+/// Unlike [`reconstruct_into`], the random projection matrix is never
+/// materialized. Instead, every sample is transformed using the fast
+/// Walsh-Hadamard transform.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`RandomProjectionCodecError::NumberOfSamplesMismatch`] if the number of
+///   samples `$N$` of the `projected` array don't match the number of samples
+///   of the `reconstructed` array
+/// - [`RandomProjectionCodecError::NonFiniteData`] if the `projected` array or
+///   the reconstructed output contains non-finite data
+pub fn srht_reconstruct_into<T: FloatExt, S: Data<Elem = T>>(
+    projected: ArrayBase<S, Ix2>,
+    mut reconstructed: ArrayViewMut<T, Ix2>,
+    seed: u64,
+) -> Result<(), RandomProjectionCodecError> {
+    let (n, k) = projected.dim();
+    let (n2, d) = reconstructed.dim();
+
+    if n2 != n {
+        return Err(RandomProjectionCodecError::NumberOfSamplesMismatch {
+            input: n,
+            output: n2,
+        });
+    }
+
+    let d_padded = d.next_power_of_two();
+    let signs = (0..d).map(|l| srht_sign(l, seed)).collect::<Vec<T>>();
+    let rows = (0..k)
+        .map(|j| srht_row(j, d_padded, seed))
+        .collect::<Vec<_>>();
+    let normalizer = srht_normaliser(k);
+
+    let mut buffer = vec![T::ZERO; d_padded];
+
+    for (projected_i, reconstructed_i) in projected.rows().into_iter().zip(reconstructed.rows_mut())
+    {
+        // scatter the k components back to the d_padded sampled positions
+        buffer.fill(T::ZERO);
+        for (&p, &r) in projected_i.into_iter().zip(&rows) {
+            #[expect(clippy::indexing_slicing)]
+            // buffer is of shape (d_padded) and all r's are in 0..d_padded
+            let b = &mut buffer[r];
+            *b += p;
+        }
+
+        // the Walsh-Hadamard matrix is symmetric
+        fast_walsh_hadamard_transform(&mut buffer);
+
+        // undo the random sign flips and drop the zero-padding
+        for ((reconstructed_il, &b), &sign) in reconstructed_i.into_iter().zip(&buffer).zip(&signs)
+        {
+            *reconstructed_il = b * sign * normalizer;
+        }
+    }
+
+    if !Zip::from(reconstructed).all(|x| x.is_finite()) {
+        return Err(RandomProjectionCodecError::NonFiniteData);
+    }
+
+    Ok(())
+}
+
+/// Computes the reduced dimensionality `$K$` for a dataset with `n_samples`
+/// samples using the `reduction` method.
+///
+/// When projecting a dataset in batches, `$K$` must be computed once for the
+/// full dataset, not for every batch.
+#[must_use]
+pub fn reduced_dimensionality(n_samples: usize, reduction: &RandomProjectionReduction) -> usize {
+    match reduction {
+        RandomProjectionReduction::JohnsonLindenstrauss { epsilon } => {
+            johnson_lindenstrauss_min_k(n_samples, *epsilon)
+        }
+        RandomProjectionReduction::Explicit { k } => k.get(),
+    }
+}
+
 /// Find a 'safe' number of components `$K$` to randomly project to.
 ///
 /// The minimum number of components to guarantee the `$\epsilon$`-embedding is
@@ -741,6 +971,49 @@ fn sparse_normaliser<T: FloatExt>(k: usize, density: T) -> T {
     (T::from_usize(k) * density).recip().sqrt()
 }
 
+/// Sample the random sign `$\pm 1$` of the `l`-th feature with the random
+/// `seed`
+fn srht_sign<T: FloatExt>(l: usize, seed: u64) -> T {
+    let (ClosedOpenUnit(u0), _u1) = T::u01x2(hash_matrix_index(l, usize::MAX, seed));
+
+    if u0 < T::HALF { -T::ONE } else { T::ONE }
+}
+
+/// Sample the row in `$[0, m)$` of the Walsh-Hadamard matrix that the `j`-th
+/// projected component is drawn from with the random `seed`
+///
+/// `m` must be a power of two.
+const fn srht_row(j: usize, m: usize, seed: u64) -> usize {
+    // since m is a power of two, masking the hash is unbiased
+    #[expect(clippy::cast_possible_truncation)] // the result is less than m
+    let r = (hash_matrix_index(usize::MAX, j, seed) & ((m as u64) - 1)) as usize;
+    r
+}
+
+fn srht_normaliser<T: FloatExt>(k: usize) -> T {
+    T::from_usize(k).sqrt().recip()
+}
+
+/// Applies the unnormalized fast Walsh-Hadamard transform to the `values` in
+/// place, whose length must be a power of two
+fn fast_walsh_hadamard_transform<T: FloatExt>(values: &mut [T]) {
+    let mut h = 1;
+
+    while h < values.len() {
+        for block in values.chunks_exact_mut(h * 2) {
+            let (low, high) = block.split_at_mut(h);
+
+            for (a, b) in low.iter_mut().zip(high) {
+                let (x, y) = (*a, *b);
+                *a = x + y;
+                *b = x - y;
+            }
+        }
+
+        h *= 2;
+    }
+}
+
 const fn hash_matrix_index(x: usize, y: usize, seed: u64) -> u64 {
     seahash_diffuse(seahash_diffuse(x as u64) ^ seed ^ (y as u64))
 }
@@ -924,6 +1197,7 @@ impl FloatExt for f64 {
 #[cfg(test)]
 #[expect(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
+    use ndarray::Axis;
     use ndarray_rand::RandomExt;
     use ndarray_rand::rand_distr::{Distribution, Normal};
 
@@ -993,6 +1267,128 @@ mod tests {
         );
     }
 
+    #[test]
+    fn subsampled_hadamard_f32() {
+        test_error_decline::<f32>(
+            (100, 100),
+            Normal::new(42.0, 24.0).unwrap(),
+            42,
+            RandomProjectionKind::SubsampledHadamard,
+        );
+    }
+
+    #[test]
+    fn subsampled_hadamard_f64() {
+        test_error_decline::<f64>(
+            (100, 100),
+            Normal::new(42.0, 24.0).unwrap(),
+            42,
+            RandomProjectionKind::SubsampledHadamard,
+        );
+    }
+
+    #[test]
+    fn subsampled_hadamard_matches_materialized_matrix() {
+        let data = Array::<f64, Ix2>::random((10, 37), Normal::new(42.0, 24.0).unwrap());
+        let (n, d, k, m, seed) = (10, 37, 24, 64, 42);
+
+        // the SRHT matrix component at (l, j), with l in 0..d and j in 0..k
+        let projection = |l: usize, j: usize| {
+            let parity = (srht_row(j, m, seed) & l).count_ones() % 2;
+            let sign: f64 = srht_sign(l, seed);
+            if parity == 0 { sign } else { -sign }
+        };
+
+        let mut fast = Array::<f64, Ix2>::zeros((n, k));
+        let mut materialized = Array::<f64, Ix2>::zeros((n, k));
+        srht_project_into(data.view(), fast.view_mut(), seed).unwrap();
+        project_into(
+            data.view(),
+            materialized.view_mut(),
+            projection,
+            srht_normaliser(k),
+        )
+        .unwrap();
+        assert_close(&fast, &materialized);
+
+        let mut fast_reconstructed = Array::<f64, Ix2>::zeros((n, d));
+        let mut materialized_reconstructed = Array::<f64, Ix2>::zeros((n, d));
+        srht_reconstruct_into(fast.view(), fast_reconstructed.view_mut(), seed).unwrap();
+        reconstruct_into(
+            fast.view(),
+            materialized_reconstructed.view_mut(),
+            projection,
+            srht_normaliser(k),
+        )
+        .unwrap();
+        assert_close(&fast_reconstructed, &materialized_reconstructed);
+    }
+
+    #[test]
+    fn batched_matches_full() {
+        let data = Array::<f64, Ix2>::random((100, 50), Normal::new(42.0, 24.0).unwrap());
+        let seed = 42;
+        let reduction = RandomProjectionReduction::JohnsonLindenstrauss {
+            epsilon: OpenClosedUnit(0.5),
+        };
+
+        for projection in [
+            RandomProjectionKind::Gaussian,
+            RandomProjectionKind::Sparse { density: None },
+            RandomProjectionKind::SubsampledHadamard,
+        ] {
+            let projected = project_with_projection(data.view(), seed, &reduction, &projection)
+                .expect("projecting must not fail");
+            let reconstructed = reconstruct_with_projection(projected.view(), seed, &projection)
+                .expect("reconstruction must not fail");
+
+            let k = reduced_dimensionality(data.nrows(), &reduction);
+            let mut projected_batched = Array::<f64, Ix2>::zeros((data.nrows(), k + 1));
+            let mut reconstructed_batched = Array::<f64, Ix2>::zeros(data.dim());
+
+            for (batch, projected_batch) in data
+                .axis_chunks_iter(Axis(0), 7)
+                .zip(projected_batched.axis_chunks_iter_mut(Axis(0), 7))
+            {
+                project_batch_into_with_projection(batch, projected_batch, seed, &projection)
+                    .expect("projecting a batch must not fail");
+            }
+
+            for (projected_batch, reconstructed_batch) in projected_batched
+                .axis_chunks_iter(Axis(0), 13)
+                .zip(reconstructed_batched.axis_chunks_iter_mut(Axis(0), 13))
+            {
+                reconstruct_into_with_projection(
+                    projected_batch,
+                    reconstructed_batch,
+                    seed,
+                    &projection,
+                )
+                .expect("reconstructing a batch must not fail");
+            }
+
+            assert!(
+                projected
+                    .iter()
+                    .zip(&projected_batched)
+                    .all(|(a, b)| a.to_bits() == b.to_bits())
+            );
+            assert!(
+                reconstructed
+                    .iter()
+                    .zip(&reconstructed_batched)
+                    .all(|(a, b)| a.to_bits() == b.to_bits())
+            );
+        }
+    }
+
+    fn assert_close(a: &Array<f64, Ix2>, b: &Array<f64, Ix2>) {
+        assert_eq!(a.dim(), b.dim());
+        for (&a, &b) in a.iter().zip(b) {
+            assert!((a - b).abs() <= 1e-9 * a.abs().max(1.0), "{a} != {b}");
+        }
+    }
+
     #[expect(clippy::needless_pass_by_value)]
     fn test_error_decline<T: FloatExt + std::fmt::Display>(
         shape: (usize, usize),
@@ -59,3 +59,26 @@ fn config_sparse_projection() {
         .unwrap(),
     );
 }
+
+#[test]
+fn config_subsampled_hadamard_projection() {
+    let _ = RandomProjectionCodec::from_config(
+        Deserialize::deserialize(json!({
+            "seed": 42,
+            "reduction": "johnson-lindenstrauss",
+            "epsilon": 0.5,
+            "projection": "subsampled-hadamard",
+        }))
+        .unwrap(),
+    );
+
+    let _ = RandomProjectionCodec::from_config(
+        Deserialize::deserialize(json!({
+            "seed": 42,
+            "reduction": "explicit",
+            "k": 24,
+            "projection": "subsampled-hadamard",
+        }))
+        .unwrap(),
+    );
+}
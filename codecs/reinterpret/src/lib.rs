@@ -30,18 +30,76 @@ use thiserror::Error;
 #[serde(deny_unknown_fields)]
 /// Codec to reinterpret data between different compatible types.
 ///
-/// Note that no conversion happens, only the meaning of the bits changes.
-///
+/// By default, no conversion happens, only the meaning of the bits changes.
 /// Reinterpreting to bytes, or to a same-sized unsigned integer type, or
 /// without the changing the dtype are supported.
+///
+/// If a `cast` policy is provided, the values are instead converted between
+/// any two dtypes, similar to `numcodecs.AsType`. The policy determines how
+/// values that cannot be represented exactly in the target dtype are handled.
+///
+/// The `byte_order` of the encoded data can be configured, in which case the
+/// bytes of every element are swapped during encoding and decoding if the
+/// byte order differs from the native byte order.
 pub struct ReinterpretCodec {
     /// Dtype of the encoded data.
     encode_dtype: AnyArrayDType,
     /// Dtype of the decoded data
     decode_dtype: AnyArrayDType,
+    /// Byte order of the encoded data
+    #[serde(default)]
+    byte_order: ByteOrder,
+    /// Policy for converting values between the dtypes. If no policy is
+    /// provided, the bits are reinterpreted instead.
+    #[serde(default)]
+    cast: Option<CastPolicy>,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default)]
-    _version: StaticCodecVersion<1, 0, 0>,
+    _version: StaticCodecVersion<1, 1, 0>,
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+/// Byte order of the encoded data
+pub enum ByteOrder {
+    /// The native byte order of the platform
+    #[serde(rename = "native")]
+    #[default]
+    Native,
+    /// Little-endian byte order
+    #[serde(rename = "little")]
+    Little,
+    /// Big-endian byte order
+    #[serde(rename = "big")]
+    Big,
+}
+
+impl ByteOrder {
+    #[must_use]
+    /// Returns `true` if the bytes of every element need to be swapped to
+    /// convert between the native byte order and this byte order.
+    pub const fn needs_swap(self) -> bool {
+        match self {
+            Self::Native => false,
+            Self::Little => cfg!(target_endian = "big"),
+            Self::Big => cfg!(target_endian = "little"),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+/// Policy for converting values between dtypes
+pub enum CastPolicy {
+    /// Every value must be represented exactly in the target dtype
+    #[serde(rename = "exact")]
+    Exact,
+    /// Values are rounded to the nearest value of the target dtype, but must
+    /// be within its range
+    #[serde(rename = "round")]
+    Round,
+    /// Values are rounded to the nearest value of the target dtype, and
+    /// out-of-range values are clamped to its minimum or maximum
+    #[serde(rename = "saturate")]
+    Saturate,
 }
 
 impl ReinterpretCodec {
@@ -78,6 +136,8 @@ impl ReinterpretCodec {
         Ok(Self {
             encode_dtype,
             decode_dtype,
+            byte_order: ByteOrder::Native,
+            cast: None,
             _version: StaticCodecVersion,
         })
     }
@@ -88,6 +148,8 @@ impl ReinterpretCodec {
         Self {
             encode_dtype: dtype,
             decode_dtype: dtype,
+            byte_order: ByteOrder::Native,
+            cast: None,
             _version: StaticCodecVersion,
         }
     }
@@ -99,6 +161,8 @@ impl ReinterpretCodec {
         Self {
             encode_dtype: AnyArrayDType::U8,
             decode_dtype: dtype,
+            byte_order: ByteOrder::Native,
+            cast: None,
             _version: StaticCodecVersion,
         }
     }
@@ -110,9 +174,37 @@ impl ReinterpretCodec {
         Self {
             encode_dtype: dtype.to_binary(),
             decode_dtype: dtype,
+            byte_order: ByteOrder::Native,
+            cast: None,
             _version: StaticCodecVersion,
         }
     }
+
+    #[must_use]
+    /// Create a [`ReinterpretCodec`] that converts the values from
+    /// `decode_dtype` to `encode_dtype` on encoding, and from `encode_dtype`
+    /// back to `decode_dtype` on decoding, using the cast `policy`.
+    pub const fn cast(
+        encode_dtype: AnyArrayDType,
+        decode_dtype: AnyArrayDType,
+        policy: CastPolicy,
+    ) -> Self {
+        Self {
+            encode_dtype,
+            decode_dtype,
+            byte_order: ByteOrder::Native,
+            cast: Some(policy),
+            _version: StaticCodecVersion,
+        }
+    }
+
+    #[must_use]
+    /// Configure the [`ReinterpretCodec`] to use the `byte_order` for the
+    /// encoded data.
+    pub const fn with_byte_order(mut self, byte_order: ByteOrder) -> Self {
+        self.byte_order = byte_order;
+        self
+    }
 }
 
 impl Codec for ReinterpretCodec {
@@ -126,49 +218,21 @@ impl Codec for ReinterpretCodec {
             });
         }
 
-        let encoded = match (data, self.encode_dtype) {
-            (data, dtype) if data.dtype() == dtype => data.into_owned(),
-            (data, AnyArrayDType::U8) => {
-                let mut shape = data.shape().to_vec();
-                if let Some(last) = shape.last_mut() {
-                    *last *= data.dtype().size();
-                }
-                #[expect(unsafe_code)]
-                // Safety: the shape is extended to match the expansion into bytes
-                let encoded =
-                    unsafe { Array::from_shape_vec_unchecked(shape, data.as_bytes().into_owned()) };
-                AnyArray::U8(encoded)
-            }
-            (AnyCowArray::I16(data), AnyArrayDType::U16) => {
-                AnyArray::U16(reinterpret_array(data, |x| {
-                    u16::from_ne_bytes(x.to_ne_bytes())
-                }))
-            }
-            (AnyCowArray::I32(data), AnyArrayDType::U32) => {
-                AnyArray::U32(reinterpret_array(data, |x| {
-                    u32::from_ne_bytes(x.to_ne_bytes())
-                }))
-            }
-            (AnyCowArray::F32(data), AnyArrayDType::U32) => {
-                AnyArray::U32(reinterpret_array(data, f32::to_bits))
-            }
-            (AnyCowArray::I64(data), AnyArrayDType::U64) => {
-                AnyArray::U64(reinterpret_array(data, |x| {
-                    u64::from_ne_bytes(x.to_ne_bytes())
-                }))
-            }
-            (AnyCowArray::F64(data), AnyArrayDType::U64) => {
-                AnyArray::U64(reinterpret_array(data, f64::to_bits))
-            }
-            (data, dtype) => {
-                return Err(ReinterpretCodecError::InvalidReinterpret {
-                    decode_dtype: data.dtype(),
-                    encode_dtype: dtype,
-                });
+        if let Some(policy) = self.cast {
+            let mut encoded = cast_any_array(data, self.encode_dtype, policy)?;
+            if self.byte_order.needs_swap() {
+                swap_byte_order(encoded.view_mut());
             }
-        };
+            return Ok(encoded);
+        }
+
+        if self.byte_order.needs_swap() {
+            let mut data = data.into_owned();
+            swap_byte_order(data.view_mut());
+            return reinterpret_any_array(data.into_cow(), self.encode_dtype);
+        }
 
-        Ok(encoded)
+        reinterpret_any_array(data, self.encode_dtype)
     }
 
     fn decode(&self, encoded: AnyCowArray) -> Result<AnyArray, Self::Error> {
@@ -179,7 +243,16 @@ impl Codec for ReinterpretCodec {
             });
         }
 
-        let decoded = match (encoded, self.decode_dtype) {
+        if let Some(policy) = self.cast {
+            if self.byte_order.needs_swap() {
+                let mut encoded = encoded.into_owned();
+                swap_byte_order(encoded.view_mut());
+                return cast_any_array(encoded.into_cow(), self.decode_dtype, policy);
+            }
+            return cast_any_array(encoded, self.decode_dtype, policy);
+        }
+
+        let mut decoded = match (encoded, self.decode_dtype) {
             (encoded, dtype) if encoded.dtype() == dtype => encoded.into_owned(),
             (AnyCowArray::U8(encoded), dtype) => {
                 let mut shape = encoded.shape().to_vec();
@@ -211,7 +284,7 @@ impl Codec for ReinterpretCodec {
             (AnyCowArray::U32(encoded), AnyArrayDType::F32) => {
                 AnyArray::F32(reinterpret_array(encoded, f32::from_bits))
             }
-            (AnyCowArray::U64(encoded), AnyArrayDType::U64) => {
+            (AnyCowArray::U64(encoded), AnyArrayDType::I64) => {
                 AnyArray::I64(reinterpret_array(encoded, |x| {
                     i64::from_ne_bytes(x.to_ne_bytes())
                 }))
@@ -227,6 +300,10 @@ impl Codec for ReinterpretCodec {
             }
         };
 
+        if self.byte_order.needs_swap() {
+            swap_byte_order(decoded.view_mut());
+        }
+
         Ok(decoded)
     }
 
@@ -242,6 +319,11 @@ impl Codec for ReinterpretCodec {
             });
         }
 
+        if self.cast.is_some() || self.byte_order.needs_swap() {
+            let decoded_in = self.decode(encoded.cow())?;
+            return Ok(decoded.assign(&decoded_in)?);
+        }
+
         match (encoded, self.decode_dtype) {
             (encoded, dtype) if encoded.dtype() == dtype => Ok(decoded.assign(&encoded)?),
             (AnyArrayView::U8(encoded), dtype) => {
@@ -288,7 +370,7 @@ impl Codec for ReinterpretCodec {
             (AnyArrayView::U32(encoded), AnyArrayDType::F32) => {
                 reinterpret_array_into(encoded, f32::from_bits, decoded)
             }
-            (AnyArrayView::U64(encoded), AnyArrayDType::U64) => {
+            (AnyArrayView::U64(encoded), AnyArrayDType::I64) => {
                 reinterpret_array_into(encoded, |x| i64::from_ne_bytes(x.to_ne_bytes()), decoded)
             }
             (AnyArrayView::U64(encoded), AnyArrayDType::F64) => {
@@ -323,6 +405,8 @@ impl Serialize for ReinterpretCodec {
         ReinterpretCodecConfig {
             encode_dtype: self.encode_dtype,
             decode_dtype: self.decode_dtype,
+            byte_order: self.byte_order,
+            cast: self.cast,
         }
         .serialize(serializer)
     }
@@ -332,7 +416,13 @@ impl<'de> Deserialize<'de> for ReinterpretCodec {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let config = ReinterpretCodecConfig::deserialize(deserializer)?;
 
-        Self::try_new(config.encode_dtype, config.decode_dtype).map_err(serde::de::Error::custom)
+        let codec = match config.cast {
+            Some(policy) => Self::cast(config.encode_dtype, config.decode_dtype, policy),
+            None => Self::try_new(config.encode_dtype, config.decode_dtype)
+                .map_err(serde::de::Error::custom)?,
+        };
+
+        Ok(codec.with_byte_order(config.byte_order))
     }
 }
 
@@ -341,6 +431,10 @@ impl<'de> Deserialize<'de> for ReinterpretCodec {
 struct ReinterpretCodecConfig {
     encode_dtype: AnyArrayDType,
     decode_dtype: AnyArrayDType,
+    #[serde(default)]
+    byte_order: ByteOrder,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cast: Option<CastPolicy>,
 }
 
 #[derive(Debug, Error)]
@@ -385,6 +479,27 @@ pub enum ReinterpretCodecError {
         /// Dtype of the array into which the encoded data is to be decoded
         dtype: AnyArrayDType,
     },
+    /// [`ReinterpretCodec`] cannot cast a value that is out of range for the
+    /// target dtype
+    #[error("Reinterpret cannot cast a {src} value that is out of range for {dst}")]
+    CastOutOfRange {
+        /// Dtype of the value that is cast
+        src: AnyArrayDType,
+        /// Dtype to which the value is cast
+        dst: AnyArrayDType,
+    },
+    /// [`ReinterpretCodec`] cannot cast a value without losing precision with
+    /// the [`CastPolicy::Exact`] policy
+    #[error("Reinterpret cannot cast a {src} value to {dst} without losing precision")]
+    CastPrecisionLoss {
+        /// Dtype of the value that is cast
+        src: AnyArrayDType,
+        /// Dtype to which the value is cast
+        dst: AnyArrayDType,
+    },
+    /// [`ReinterpretCodec`] does not support the dtype
+    #[error("Reinterpret does not support the dtype {0}")]
+    UnsupportedDtype(AnyArrayDType),
     /// [`ReinterpretCodec`] cannot decode into the provided array
     #[error("Reinterpret cannot decode into the provided array")]
     MismatchedDecodeIntoArray {
@@ -457,3 +572,398 @@ where
 
     Ok(())
 }
+
+/// Reinterpret the bits of the `data` array as the `dtype`.
+fn reinterpret_any_array(
+    data: AnyCowArray,
+    dtype: AnyArrayDType,
+) -> Result<AnyArray, ReinterpretCodecError> {
+    let encoded = match (data, dtype) {
+        (data, dtype) if data.dtype() == dtype => data.into_owned(),
+        (data, AnyArrayDType::U8) => {
+            let mut shape = data.shape().to_vec();
+            if let Some(last) = shape.last_mut() {
+                *last *= data.dtype().size();
+            }
+            #[expect(unsafe_code)]
+            // Safety: the shape is extended to match the expansion into bytes
+            let encoded =
+                unsafe { Array::from_shape_vec_unchecked(shape, data.as_bytes().into_owned()) };
+            AnyArray::U8(encoded)
+        }
+        (AnyCowArray::I16(data), AnyArrayDType::U16) => {
+            AnyArray::U16(reinterpret_array(data, |x| {
+                u16::from_ne_bytes(x.to_ne_bytes())
+            }))
+        }
+        (AnyCowArray::I32(data), AnyArrayDType::U32) => {
+            AnyArray::U32(reinterpret_array(data, |x| {
+                u32::from_ne_bytes(x.to_ne_bytes())
+            }))
+        }
+        (AnyCowArray::F32(data), AnyArrayDType::U32) => {
+            AnyArray::U32(reinterpret_array(data, f32::to_bits))
+        }
+        (AnyCowArray::I64(data), AnyArrayDType::U64) => {
+            AnyArray::U64(reinterpret_array(data, |x| {
+                u64::from_ne_bytes(x.to_ne_bytes())
+            }))
+        }
+        (AnyCowArray::F64(data), AnyArrayDType::U64) => {
+            AnyArray::U64(reinterpret_array(data, f64::to_bits))
+        }
+        (data, dtype) => {
+            return Err(ReinterpretCodecError::InvalidReinterpret {
+                decode_dtype: data.dtype(),
+                encode_dtype: dtype,
+            });
+        }
+    };
+
+    Ok(encoded)
+}
+
+/// Swap the bytes of every element of the `array` in place.
+pub fn swap_byte_order(array: AnyArrayViewMut) {
+    match array {
+        AnyArrayViewMut::U16(mut array) => array.mapv_inplace(u16::swap_bytes),
+        AnyArrayViewMut::U32(mut array) => array.mapv_inplace(u32::swap_bytes),
+        AnyArrayViewMut::U64(mut array) => array.mapv_inplace(u64::swap_bytes),
+        AnyArrayViewMut::I16(mut array) => array.mapv_inplace(i16::swap_bytes),
+        AnyArrayViewMut::I32(mut array) => array.mapv_inplace(i32::swap_bytes),
+        AnyArrayViewMut::I64(mut array) => array.mapv_inplace(i64::swap_bytes),
+        AnyArrayViewMut::F32(mut array) => {
+            array.mapv_inplace(|x| f32::from_bits(x.to_bits().swap_bytes()));
+        }
+        AnyArrayViewMut::F64(mut array) => {
+            array.mapv_inplace(|x| f64::from_bits(x.to_bits().swap_bytes()));
+        }
+        // single-byte elements have no byte order
+        _ => (),
+    }
+}
+
+/// Convert the values of the `array` to the `dtype` using the cast `policy`.
+fn cast_any_array(
+    array: AnyCowArray,
+    dtype: AnyArrayDType,
+    policy: CastPolicy,
+) -> Result<AnyArray, ReinterpretCodecError> {
+    match array {
+        AnyCowArray::U8(array) => cast_array_to_dtype(array, dtype, policy),
+        AnyCowArray::U16(array) => cast_array_to_dtype(array, dtype, policy),
+        AnyCowArray::U32(array) => cast_array_to_dtype(array, dtype, policy),
+        AnyCowArray::U64(array) => cast_array_to_dtype(array, dtype, policy),
+        AnyCowArray::I8(array) => cast_array_to_dtype(array, dtype, policy),
+        AnyCowArray::I16(array) => cast_array_to_dtype(array, dtype, policy),
+        AnyCowArray::I32(array) => cast_array_to_dtype(array, dtype, policy),
+        AnyCowArray::I64(array) => cast_array_to_dtype(array, dtype, policy),
+        AnyCowArray::F32(array) => cast_array_to_dtype(array, dtype, policy),
+        AnyCowArray::F64(array) => cast_array_to_dtype(array, dtype, policy),
+        array => Err(ReinterpretCodecError::UnsupportedDtype(array.dtype())),
+    }
+}
+
+fn cast_array_to_dtype<T: CastElement, S: Data<Elem = T>, D: Dimension>(
+    array: ArrayBase<S, D>,
+    dtype: AnyArrayDType,
+    policy: CastPolicy,
+) -> Result<AnyArray, ReinterpretCodecError> {
+    let array = array.into_dyn();
+
+    let array = match dtype {
+        AnyArrayDType::U8 => AnyArray::U8(cast_array(&array, policy)?),
+        AnyArrayDType::U16 => AnyArray::U16(cast_array(&array, policy)?),
+        AnyArrayDType::U32 => AnyArray::U32(cast_array(&array, policy)?),
+        AnyArrayDType::U64 => AnyArray::U64(cast_array(&array, policy)?),
+        AnyArrayDType::I8 => AnyArray::I8(cast_array(&array, policy)?),
+        AnyArrayDType::I16 => AnyArray::I16(cast_array(&array, policy)?),
+        AnyArrayDType::I32 => AnyArray::I32(cast_array(&array, policy)?),
+        AnyArrayDType::I64 => AnyArray::I64(cast_array(&array, policy)?),
+        AnyArrayDType::F32 => AnyArray::F32(cast_array(&array, policy)?),
+        AnyArrayDType::F64 => AnyArray::F64(cast_array(&array, policy)?),
+        dtype => return Err(ReinterpretCodecError::UnsupportedDtype(dtype)),
+    };
+
+    Ok(array)
+}
+
+fn cast_array<T: CastElement, U: CastElement, S: Data<Elem = T>, D: Dimension>(
+    array: &ArrayBase<S, D>,
+    policy: CastPolicy,
+) -> Result<Array<U, D>, ReinterpretCodecError> {
+    // iterate over the elements in standard order
+    let data = array
+        .iter()
+        .map(|x| {
+            U::from_cast_value(x.to_cast_value(), policy).map_err(|err| match err {
+                CastFailure::OutOfRange => ReinterpretCodecError::CastOutOfRange {
+                    src: T::DTYPE,
+                    dst: U::DTYPE,
+                },
+                CastFailure::PrecisionLoss => ReinterpretCodecError::CastPrecisionLoss {
+                    src: T::DTYPE,
+                    dst: U::DTYPE,
+                },
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    #[expect(unsafe_code)]
+    // Safety: the data has the same length as the array and is in standard order
+    let array = unsafe { Array::from_shape_vec_unchecked(array.raw_dim(), data) };
+
+    Ok(array)
+}
+
+/// Lossless intermediate representation of a value that is cast
+#[derive(Copy, Clone)]
+enum CastValue {
+    Int(i128),
+    Float(f64),
+}
+
+/// Reason why a value cannot be cast
+enum CastFailure {
+    OutOfRange,
+    PrecisionLoss,
+}
+
+trait CastElement: ArrayDType + Copy {
+    fn to_cast_value(self) -> CastValue;
+
+    fn from_cast_value(value: CastValue, policy: CastPolicy) -> Result<Self, CastFailure>;
+}
+
+macro_rules! impl_cast_element_for_int {
+    ($($ty:ty),*) => {
+        $(
+            impl CastElement for $ty {
+                fn to_cast_value(self) -> CastValue {
+                    CastValue::Int(i128::from(self))
+                }
+
+                fn from_cast_value(value: CastValue, policy: CastPolicy) -> Result<Self, CastFailure> {
+                    let value = match value {
+                        CastValue::Int(value) => value,
+                        CastValue::Float(value) => {
+                            if value.is_nan() {
+                                return Err(CastFailure::OutOfRange);
+                            }
+
+                            let rounded = value.round();
+                            if policy == CastPolicy::Exact && rounded.to_bits() != value.to_bits() {
+                                return Err(CastFailure::PrecisionLoss);
+                            }
+
+                            // the cast saturates, which preserves out-of-range values
+                            #[expect(clippy::cast_possible_truncation)]
+                            let rounded = rounded as i128;
+                            rounded
+                        }
+                    };
+
+                    match Self::try_from(value) {
+                        Ok(value) => Ok(value),
+                        Err(_) if policy == CastPolicy::Saturate => {
+                            Ok(if value < 0 { Self::MIN } else { Self::MAX })
+                        }
+                        Err(_) => Err(CastFailure::OutOfRange),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_cast_element_for_int! { u8, u16, u32, u64, i8, i16, i32, i64 }
+
+impl CastElement for f32 {
+    fn to_cast_value(self) -> CastValue {
+        CastValue::Float(f64::from(self))
+    }
+
+    fn from_cast_value(value: CastValue, policy: CastPolicy) -> Result<Self, CastFailure> {
+        let (cast, exact) = match value {
+            CastValue::Int(value) => {
+                #[expect(clippy::cast_precision_loss)]
+                let cast = value as Self;
+                #[expect(clippy::cast_possible_truncation)]
+                let exact = (cast as i128) == value;
+                (cast, exact)
+            }
+            CastValue::Float(value) => {
+                #[expect(clippy::cast_possible_truncation)]
+                let cast = value as Self;
+
+                if value.is_finite() && cast.is_infinite() {
+                    return match policy {
+                        CastPolicy::Saturate => Ok(Self::MAX.copysign(cast)),
+                        CastPolicy::Exact | CastPolicy::Round => Err(CastFailure::OutOfRange),
+                    };
+                }
+
+                let exact = value.is_nan() || f64::from(cast).to_bits() == value.to_bits();
+                (cast, exact)
+            }
+        };
+
+        if policy == CastPolicy::Exact && !exact {
+            return Err(CastFailure::PrecisionLoss);
+        }
+
+        Ok(cast)
+    }
+}
+
+impl CastElement for f64 {
+    fn to_cast_value(self) -> CastValue {
+        CastValue::Float(self)
+    }
+
+    fn from_cast_value(value: CastValue, policy: CastPolicy) -> Result<Self, CastFailure> {
+        match value {
+            CastValue::Int(value) => {
+                #[expect(clippy::cast_precision_loss)]
+                let cast = value as Self;
+                #[expect(clippy::cast_possible_truncation)]
+                let exact = (cast as i128) == value;
+
+                if policy == CastPolicy::Exact && !exact {
+                    return Err(CastFailure::PrecisionLoss);
+                }
+
+                Ok(cast)
+            }
+            CastValue::Float(value) => Ok(value),
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use ndarray::Array1;
+
+    use super::*;
+
+    #[test]
+    fn u64_to_i64_roundtrip() -> Result<(), ReinterpretCodecError> {
+        let data = Array1::from_vec(vec![-1_i64, 0, 42, i64::MIN, i64::MAX]).into_dyn();
+
+        let codec = ReinterpretCodec::to_binary(AnyArrayDType::I64);
+
+        let encoded = codec.encode(AnyCowArray::I64(data.view().into()))?;
+        let AnyArray::U64(encoded) = encoded else {
+            return Err(ReinterpretCodecError::UnsupportedDtype(encoded.dtype()));
+        };
+
+        let decoded = codec.decode(AnyCowArray::U64(encoded.view().into()))?;
+        assert_eq!(decoded, AnyArray::I64(data.clone()));
+
+        let mut decoded_into = Array1::<i64>::zeros(data.len()).into_dyn();
+        codec.decode_into(
+            AnyArrayView::U64(encoded.view()),
+            AnyArrayViewMut::I64(decoded_into.view_mut()),
+        )?;
+        assert_eq!(decoded_into, data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn byte_order_roundtrip() -> Result<(), ReinterpretCodecError> {
+        let data = Array1::from_vec(vec![1.0_f32, -2.5, 1024.0]).into_dyn();
+
+        let codec = ReinterpretCodec::to_bytes(AnyArrayDType::F32).with_byte_order(ByteOrder::Big);
+
+        let encoded = codec.encode(AnyCowArray::F32(data.view().into()))?;
+        let AnyArray::U8(encoded) = encoded else {
+            return Err(ReinterpretCodecError::UnsupportedDtype(encoded.dtype()));
+        };
+        let expected = data
+            .iter()
+            .flat_map(|x| x.to_be_bytes())
+            .collect::<Vec<_>>();
+        assert_eq!(encoded.iter().copied().collect::<Vec<_>>(), expected);
+
+        let decoded = codec.decode(AnyCowArray::U8(encoded.view().into()))?;
+        let AnyArray::F32(decoded) = decoded else {
+            return Err(ReinterpretCodecError::UnsupportedDtype(decoded.dtype()));
+        };
+        assert_eq!(decoded.mapv(f32::to_bits), data.mapv(f32::to_bits));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cast_roundtrip() -> Result<(), ReinterpretCodecError> {
+        let data = Array1::from_vec(vec![i16::MIN, -1, 0, 1, i16::MAX]).into_dyn();
+
+        let codec =
+            ReinterpretCodec::cast(AnyArrayDType::F32, AnyArrayDType::I16, CastPolicy::Exact);
+
+        let encoded = codec.encode(AnyCowArray::I16(data.view().into()))?;
+        let AnyArray::F32(encoded) = encoded else {
+            return Err(ReinterpretCodecError::UnsupportedDtype(encoded.dtype()));
+        };
+        assert_eq!(
+            encoded.mapv(f32::to_bits),
+            data.mapv(|x| f32::from(x).to_bits())
+        );
+
+        let mut decoded = Array::zeros(data.raw_dim());
+        codec.decode_into(
+            AnyArrayView::F32(encoded.view()),
+            AnyArrayViewMut::I16(decoded.view_mut()),
+        )?;
+        assert_eq!(decoded, data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cast_policies() {
+        let data = Array1::from_vec(vec![0.5_f64, 1e300]).into_dyn();
+
+        let exact =
+            ReinterpretCodec::cast(AnyArrayDType::F32, AnyArrayDType::F64, CastPolicy::Exact);
+        assert!(matches!(
+            exact.encode(AnyCowArray::F64(data.view().into())),
+            Err(ReinterpretCodecError::CastOutOfRange { .. })
+        ));
+
+        let saturate =
+            ReinterpretCodec::cast(AnyArrayDType::F32, AnyArrayDType::F64, CastPolicy::Saturate);
+        let encoded = saturate
+            .encode(AnyCowArray::F64(data.view().into()))
+            .unwrap();
+        assert!(matches!(
+            encoded,
+            AnyArray::F32(encoded) if encoded.iter().map(|x| x.to_bits()).eq([0.5_f32.to_bits(), f32::MAX.to_bits()])
+        ));
+
+        let data = Array1::from_vec(vec![1.5_f32, 300.0]).into_dyn();
+
+        let exact =
+            ReinterpretCodec::cast(AnyArrayDType::U8, AnyArrayDType::F32, CastPolicy::Exact);
+        assert!(matches!(
+            exact.encode(AnyCowArray::F32(data.view().into())),
+            Err(ReinterpretCodecError::CastPrecisionLoss { .. })
+        ));
+
+        let round =
+            ReinterpretCodec::cast(AnyArrayDType::U8, AnyArrayDType::F32, CastPolicy::Round);
+        assert!(matches!(
+            round.encode(AnyCowArray::F32(data.view().into())),
+            Err(ReinterpretCodecError::CastOutOfRange { .. })
+        ));
+
+        let saturate =
+            ReinterpretCodec::cast(AnyArrayDType::U8, AnyArrayDType::F32, CastPolicy::Saturate);
+        let encoded = saturate
+            .encode(AnyCowArray::F32(data.view().into()))
+            .unwrap();
+        assert!(matches!(encoded, AnyArray::U8(encoded) if encoded.iter().eq(&[2, 255])));
+    }
+}
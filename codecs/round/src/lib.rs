@@ -17,7 +17,7 @@
 //!
 //! Rounding codec implementation for the [`numcodecs`] API.
 
-use std::borrow::Cow;
+use std::{borrow::Cow, num::NonZeroU8};
 
 use ndarray::{Array, ArrayBase, Data, Dimension};
 use num_traits::Float;
@@ -30,17 +30,58 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
-#[serde(deny_unknown_fields)]
+#[schemars(deny_unknown_fields)]
 /// Codec that rounds the data on encoding and passes through the input
 /// unchanged during decoding.
 ///
 /// The codec only supports floating point data.
 pub struct RoundCodec {
-    /// Precision of the rounding operation
-    pub precision: NonNegative<f64>,
+    /// Rounding mode
+    #[serde(flatten)]
+    pub mode: RoundMode,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default, rename = "_version")]
-    pub version: StaticCodecVersion<1, 0, 0>,
+    pub version: StaticCodecVersion<1, 1, 0>,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "mode")]
+#[serde(deny_unknown_fields)]
+/// Rounding mode
+pub enum RoundMode {
+    /// Round to the specified number of significant decimal `digits`.
+    ///
+    /// Each value is rounded to the nearest multiple of a power of two that
+    /// is chosen such that the absolute error is guaranteed to be at most
+    /// half a unit in the last significant decimal digit, i.e.
+    /// `$\left| x - c \right| \leq \frac{1}{2} \cdot 10^{\lfloor \log_{10} |x| \rfloor - digits + 1}$`.
+    #[serde(rename = "digits")]
+    SignificantDigits {
+        /// Number of significant decimal digits to keep
+        digits: NonZeroU8,
+    },
+    /// Round relative to the magnitude of each value.
+    ///
+    /// Each value is rounded to the nearest multiple of a power of two that
+    /// is chosen such that the relative error is guaranteed to be at most
+    /// the precision, i.e. `$\left| x - c \right| \leq precision \cdot |x|$`.
+    #[serde(rename = "rel")]
+    Relative {
+        /// Relative precision of the rounding operation
+        precision: NonNegative<f64>,
+    },
+    /// Round to the nearest multiple of an absolute `precision`.
+    ///
+    /// The absolute error is guaranteed to be at most half of the precision,
+    /// i.e. `$\left| x - c \right| \leq \frac{precision}{2}$`, up to
+    /// floating point roundoff errors.
+    ///
+    /// This is the default mode, which is used if no `mode` is specified.
+    #[serde(untagged)]
+    Absolute {
+        /// Precision of the rounding operation
+        precision: NonNegative<f64>,
+    },
 }
 
 impl Codec for RoundCodec {
@@ -48,12 +89,17 @@ impl Codec for RoundCodec {
 
     fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
         match data {
-            #[expect(clippy::cast_possible_truncation)]
-            AnyCowArray::F32(data) => Ok(AnyArray::F32(round(
-                data,
-                NonNegative(self.precision.0 as f32),
-            ))),
-            AnyCowArray::F64(data) => Ok(AnyArray::F64(round(data, self.precision))),
+            AnyCowArray::F32(data) => Ok(AnyArray::F32(match self.mode {
+                #[expect(clippy::cast_possible_truncation)]
+                RoundMode::Absolute { precision } => round(data, NonNegative(precision.0 as f32)),
+                RoundMode::SignificantDigits { digits } => round_significant_digits(data, digits),
+                RoundMode::Relative { precision } => round_relative(data, precision),
+            })),
+            AnyCowArray::F64(data) => Ok(AnyArray::F64(match self.mode {
+                RoundMode::Absolute { precision } => round(data, precision),
+                RoundMode::SignificantDigits { digits } => round_significant_digits(data, digits),
+                RoundMode::Relative { precision } => round_relative(data, precision),
+            })),
             encoded => Err(RoundCodecError::UnsupportedDtype(encoded.dtype())),
         }
     }
@@ -182,9 +228,135 @@ pub fn round<T: Float, S: Data<Elem = T>, D: Dimension>(
     encoded
 }
 
+#[must_use]
+/// Rounds the input `data` to the specified number of significant decimal
+/// `digits`.
+///
+/// Each value `$x$` is rounded to the nearest multiple of a power of two,
+/// `$c = \text{round}\left( \frac{x}{2^k} \right) \cdot 2^k$`, where `$k$`
+/// is chosen such that the error is guaranteed to be at most half a unit in
+/// the last significant decimal digit, i.e.
+/// `$\left| x - c \right| \leq \frac{1}{2} \cdot 10^{\lfloor \log_{10} |x| \rfloor - digits + 1}$`.
+///
+/// Zero, subnormal, infinite, and NaN values are returned unchanged.
+pub fn round_significant_digits<T: Float, S: Data<Elem = T>, D: Dimension>(
+    data: ArrayBase<S, D>,
+    digits: NonZeroU8,
+) -> Array<T, D> {
+    let mut encoded = data.into_owned();
+
+    encoded.mapv_inplace(|x| {
+        significant_digits_exponent(x, digits).map_or(x, |k| round_to_power_of_two(x, k))
+    });
+
+    encoded
+}
+
+#[must_use]
+/// Rounds the input `data` relative to the magnitude of each value.
+///
+/// Each value `$x$` is rounded to the nearest multiple of a power of two,
+/// `$c = \text{round}\left( \frac{x}{2^k} \right) \cdot 2^k$`, where `$k$`
+/// is chosen such that the relative error is guaranteed to be at most the
+/// precision, i.e. `$\left| x - c \right| \leq precision \cdot |x|$`.
+///
+/// If the precision is zero, subnormal, or infinite, the `data` is returned
+/// unchanged. Zero, subnormal, infinite, and NaN values are also returned
+/// unchanged.
+pub fn round_relative<T: Float, S: Data<Elem = T>, D: Dimension>(
+    data: ArrayBase<S, D>,
+    precision: NonNegative<f64>,
+) -> Array<T, D> {
+    let mut encoded = data.into_owned();
+
+    // the error is at most 2^(k-1) <= precision * |x| / 2, so the quantum
+    //  may be up to twice the relative precision
+    let precision = precision.0 + precision.0;
+
+    encoded.mapv_inplace(|x| {
+        relative_exponent(x, precision).map_or(x, |k| round_to_power_of_two(x, k))
+    });
+
+    encoded
+}
+
+#[must_use]
+/// Computes the exponent `$k$` of the largest power of two, `$2^k$`, that
+/// does not exceed one unit in the last significant decimal digit of `x`.
+///
+/// Specifically, `$2^k \leq 10^{\lfloor \log_{10} |x| \rfloor - digits + 1}$`.
+/// Returns `None` for zero, subnormal, infinite, and NaN values.
+pub fn significant_digits_exponent<T: Float>(x: T, digits: NonZeroU8) -> Option<i32> {
+    let log2_floor = normal_log2_floor(x)?;
+
+    // conservative estimate of floor(log10(|x|)) that never exceeds it
+    let log10_floor = (f64::from(log2_floor) * std::f64::consts::LOG10_2).floor();
+    // the largest power of two that does not exceed the value of the last
+    //  significant decimal digit
+    let last_digit_log10 = log10_floor - f64::from(digits.get()) + 1.0;
+    #[expect(clippy::cast_possible_truncation)]
+    let k = (last_digit_log10 * std::f64::consts::LOG2_10).floor() as i32;
+
+    Some(k)
+}
+
+#[must_use]
+/// Computes the exponent `$k$` of the largest power of two, `$2^k$`, that
+/// does not exceed `$precision \cdot |x|$`.
+///
+/// Returns `None` if `x` or the `precision` are zero, subnormal, infinite, or
+/// NaN.
+pub fn relative_exponent<T: Float>(x: T, precision: f64) -> Option<i32> {
+    let precision_log2_floor = normal_log2_floor(precision)?;
+    let log2_floor = normal_log2_floor(x)?;
+
+    Some(precision_log2_floor.saturating_add(log2_floor))
+}
+
+/// Rounds `x` to the nearest multiple of `$2^k$`.
+fn round_to_power_of_two<T: Float>(x: T, k: i32) -> T {
+    let quantum = (T::one() + T::one()).powi(k);
+
+    // the value is rounded to zero if the quantum is infinite, which is
+    //  within the error bound since the quantum is then far larger than x
+    if quantum.is_infinite() {
+        return x * T::zero();
+    }
+
+    let n = x / quantum;
+
+    // if x / quantum is not finite, don't try to round
+    //  e.g. when the quantum is zero
+    if !n.is_finite() {
+        return x;
+    }
+
+    let rounded = n.round() * quantum;
+
+    // rounding up the largest finite values may overflow
+    if rounded.is_finite() { rounded } else { x }
+}
+
+/// Computes `$\lfloor \log_{2} |x| \rfloor$` exactly for normal `x`, and
+/// returns `None` for zero, subnormal, infinite, and NaN values.
+fn normal_log2_floor<T: Float>(x: T) -> Option<i32> {
+    if !x.is_normal() {
+        return None;
+    }
+
+    let (mantissa, exponent, _sign) = x.integer_decode();
+
+    #[expect(clippy::cast_possible_wrap)]
+    let mantissa_log2_floor = (u64::BITS - 1 - mantissa.leading_zeros()) as i32;
+
+    Some(i32::from(exponent) + mantissa_log2_floor)
+}
+
 #[cfg(test)]
+#[expect(clippy::unwrap_used)]
 mod tests {
     use ndarray::array;
+    use serde_json::json;
 
     use super::*;
 
@@ -254,4 +426,142 @@ mod tests {
             assert!(d == r || d.to_bits() == r.to_bits());
         }
     }
+
+    #[test]
+    fn round_significant_digits_error_bound() {
+        let data = Array::from_iter((-300..300).flat_map(|e| {
+            [1.0, 1.234_567_89, 7.654_321, 9.999_999_9]
+                .map(|f: f64| f * 10.0_f64.powi(e))
+                .into_iter()
+                .flat_map(|x| [x, -x])
+        }));
+
+        for digits in 1..=15 {
+            let rounded = round_significant_digits(data.view(), NonZeroU8::new(digits).unwrap());
+
+            for (x, c) in data.iter().zip(&rounded) {
+                let bound = 0.5 * 10.0_f64.powf(x.abs().log10().floor() - f64::from(digits) + 1.0);
+                assert!((x - c).abs() <= bound, "{x} {c} {digits}");
+            }
+        }
+    }
+
+    #[test]
+    fn round_significant_digits_quantizes() {
+        let data = array![1.234_567_f32, -98_765.43_f32, 0.000_123_456_f32];
+
+        let rounded = round_significant_digits(data.view(), NonZeroU8::new(3).unwrap());
+
+        for (x, c) in data.iter().zip(&rounded) {
+            assert_ne!(x.to_bits(), c.to_bits());
+            assert!(c.to_bits().trailing_zeros() > 8, "{c}");
+        }
+    }
+
+    #[test]
+    fn round_relative_error_bound() {
+        let data = Array::from_iter((-300..300).flat_map(|e| {
+            [1.0, 1.234_567_89, 7.654_321, 9.999_999_9]
+                .map(|f: f64| f * 10.0_f64.powi(e))
+                .into_iter()
+                .flat_map(|x| [x, -x])
+        }));
+
+        for precision in [1e-12, 1e-6, 0.001, 0.01, 0.1, 0.5, 1.0, 2.0, 1e300] {
+            let rounded = round_relative(data.view(), NonNegative(precision));
+
+            for (x, c) in data.iter().zip(&rounded) {
+                assert!((x - c).abs() <= precision * x.abs(), "{x} {c} {precision}");
+            }
+        }
+
+        let rounded = round_relative(data.view(), NonNegative(0.0));
+        assert_eq!(data, rounded);
+    }
+
+    #[test]
+    fn round_modes_edge_cases() {
+        let data = array![
+            -f64::NAN,
+            -f64::INFINITY,
+            -f64::MAX,
+            -f64::MIN_POSITIVE,
+            -0.0,
+            0.0,
+            f64::MIN_POSITIVE / 2.0,
+            f64::MAX,
+            f64::INFINITY,
+            f64::NAN
+        ];
+
+        for rounded in [
+            round_significant_digits(data.view(), NonZeroU8::new(1).unwrap()),
+            round_relative(data.view(), NonNegative(0.1)),
+        ] {
+            for (d, r) in data.iter().zip(rounded) {
+                assert!(
+                    (d - r).abs() <= 0.5 * d.abs()
+                        || (d.is_infinite() && d.to_bits() == r.to_bits())
+                        || d.to_bits() == r.to_bits()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn round_relative_f32_error_bound() {
+        let data = Array::from_iter((-30..30).flat_map(|e| {
+            [1.0, 1.234_567_8, 7.654_321, 9.999_999]
+                .map(|f: f32| f * 10.0_f32.powi(e))
+                .into_iter()
+                .flat_map(|x| [x, -x])
+        }));
+
+        for precision in [1e-10, 0.1, 0.3, 1.0 / 3.0, 0.999_999_999] {
+            let rounded = round_relative(data.view(), NonNegative(precision));
+
+            for (x, c) in data.iter().zip(&rounded) {
+                assert!(
+                    f64::from((x - c).abs()) <= precision * f64::from(x.abs()),
+                    "{x} {c} {precision}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn absolute_mode_is_default() {
+        for config in [
+            json!({ "precision": 0.5 }),
+            json!({ "precision": 0.5, "_version": "1.0.0" }),
+        ] {
+            let codec: RoundCodec = serde_json::from_value(config).unwrap();
+            assert!(
+                codec.mode
+                    == RoundMode::Absolute {
+                        precision: NonNegative(0.5)
+                    }
+            );
+        }
+
+        let codec: RoundCodec =
+            serde_json::from_value(json!({ "mode": "rel", "precision": 0.5 })).unwrap();
+        assert!(
+            codec.mode
+                == RoundMode::Relative {
+                    precision: NonNegative(0.5)
+                }
+        );
+
+        assert_eq!(
+            serde_json::to_value(RoundCodec {
+                mode: RoundMode::Absolute {
+                    precision: NonNegative(0.5)
+                },
+                version: StaticCodecVersion,
+            })
+            .unwrap(),
+            json!({ "precision": 0.5, "_version": "1.1.0" })
+        );
+    }
 }
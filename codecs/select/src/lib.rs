@@ -0,0 +1,694 @@
+//! [![CI Status]][workflow] [![MSRV]][repo] [![Latest Version]][crates.io] [![Rust Doc Crate]][docs.rs] [![Rust Doc Main]][docs]
+//!
+//! [CI Status]: https://img.shields.io/github/actions/workflow/status/juntyr/numcodecs-rs/ci.yml?branch=main
+//! [workflow]: https://github.com/juntyr/numcodecs-rs/actions/workflows/ci.yml?query=branch%3Amain
+//!
+//! [MSRV]: https://img.shields.io/badge/MSRV-1.87.0-blue
+//! [repo]: https://github.com/juntyr/numcodecs-rs
+//!
+//! [Latest Version]: https://img.shields.io/crates/v/numcodecs-select
+//! [crates.io]: https://crates.io/crates/numcodecs-select
+//!
+//! [Rust Doc Crate]: https://img.shields.io/docsrs/numcodecs-select
+//! [docs.rs]: https://docs.rs/numcodecs-select/
+//!
+//! [Rust Doc Main]: https://img.shields.io/badge/docs-main-blue
+//! [docs]: https://juntyr.github.io/numcodecs-rs/numcodecs_select
+//!
+//! Best-of-N selection meta-codec implementation for the [`numcodecs`] API.
+
+use std::{borrow::Cow, num::NonZeroUsize};
+
+use ndarray::{ArrayBase, Data, IxDyn};
+use num_traits::AsPrimitive;
+use numcodecs::{
+    AnyArray, AnyArrayAssignError, AnyArrayView, AnyArrayViewMut, AnyCowArray, Codec, DynCodec,
+    ErasedDynCodec, ErasedError, StaticCodec, StaticCodecConfig, StaticCodecVersion,
+};
+use numcodecs_registry::GlobalRegistry;
+use numcodecs_utils::{
+    framing::{FramingError, decode_framed, encode_framed, framed_array, framed_bytes},
+    parallel,
+};
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+type SelectCodecVersion = StaticCodecVersion<1, 0, 0>;
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+// serde cannot deny unknown fields because of the flatten
+#[schemars(deny_unknown_fields)]
+/// Select meta-codec which encodes the data with several candidate codecs and
+/// keeps the encoding of the best candidate.
+///
+/// Candidates that fail to encode the data, or to decode it when checking the
+/// error budget, are skipped.
+///
+/// The index of the selected candidate is recorded in the encoding such that
+/// decoding is dispatched to the same codec.
+pub struct SelectCodec {
+    /// The ordered list of candidate codecs
+    pub candidates: Vec<SelectCandidate>,
+    /// The strategy with which the candidate is selected
+    #[serde(flatten)]
+    pub strategy: SelectStrategy,
+    /// The optional number of threads with which the candidates are tried in
+    /// parallel
+    ///
+    /// If `None`, the candidates are tried serially. The selected candidate
+    /// does not depend on the number of threads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_threads: Option<NonZeroUsize>,
+    /// The codec's encoding format version. Do not provide this parameter explicitly.
+    #[serde(default, rename = "_version")]
+    pub version: SelectCodecVersion,
+}
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+/// Candidate codec that is tried by the select codec
+pub struct SelectCandidate {
+    /// The configuration of the candidate codec
+    #[serde(serialize_with = "DynCodec::get_config")]
+    #[serde(deserialize_with = "GlobalRegistry::codec_from_config")]
+    #[schemars(schema_with = "ErasedDynCodec::codec_config_schema")]
+    pub codec: ErasedDynCodec,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "select")]
+#[serde(deny_unknown_fields)]
+/// Strategy with which the candidate is selected
+pub enum SelectStrategy {
+    /// Select the candidate with the smallest encoding, preferring earlier
+    /// candidates in case of a tie
+    #[serde(rename = "smallest")]
+    Smallest,
+    /// Select the first candidate whose encoding fits within the budget
+    ///
+    /// Checking the error budget requires decoding the encoding of every
+    /// tried candidate.
+    #[serde(rename = "first-fit")]
+    FirstFit {
+        /// The optional maximum size of the candidate's encoding, in bytes
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_bytes: Option<usize>,
+        /// The optional maximum pointwise absolute error of the candidate's
+        /// decoding
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_abs_error: Option<NonNegative<f64>>,
+    },
+}
+
+impl SelectCodec {
+    /// Encodes the `data` with the candidate codecs and returns the index of
+    /// the selected candidate together with the encoding
+    ///
+    /// Candidates that fail are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Errors with
+    /// - [`SelectCodecError::NoCandidateSelected`] if no candidate succeeded
+    ///   and fit the budget
+    /// - [`SelectCodecError::FramingFailed`] if framing the encoding of the
+    ///   selected candidate failed
+    #[expect(clippy::needless_pass_by_value)]
+    pub fn encode_and_select(
+        &self,
+        data: AnyCowArray,
+    ) -> Result<(usize, AnyArray), SelectCodecError> {
+        let candidates = self.candidates.iter().enumerate().collect();
+        let mut failed = Vec::new();
+
+        let selected = match self.strategy {
+            SelectStrategy::Smallest => {
+                parallel::map(candidates, self.num_threads, |(index, candidate)| {
+                    let encoded = encode_candidate(&candidate.codec, index, &data)?;
+                    Ok((index, encoded))
+                })
+                .into_iter()
+                .filter_map(|attempt| attempt.map_err(|err| failed.push(err)).ok())
+                .min_by_key(|(_, encoded)| encoded_size(encoded))
+            }
+            SelectStrategy::FirstFit {
+                max_bytes,
+                max_abs_error,
+            } => {
+                // the first fitting candidate is returned like an error, such
+                // that no further candidates are tried when trying serially
+                let attempts = parallel::try_map(
+                    candidates,
+                    self.num_threads,
+                    |(index, candidate): (usize, &SelectCandidate)| {
+                        let attempt =
+                            encode_candidate(&candidate.codec, index, &data).and_then(|encoded| {
+                                let fits = fits_budget(
+                                    &candidate.codec,
+                                    index,
+                                    &data,
+                                    &encoded,
+                                    max_bytes,
+                                    max_abs_error,
+                                )?;
+                                Ok(fits.then_some(encoded))
+                            });
+
+                        match attempt {
+                            Ok(Some(encoded)) => Err(Box::new((index, encoded))),
+                            Ok(None) => Ok(None),
+                            Err(err) => Ok(Some(err)),
+                        }
+                    },
+                );
+
+                match attempts {
+                    Ok(attempts) => {
+                        failed.extend(attempts.into_iter().flatten());
+                        None
+                    }
+                    Err(selected) => Some(*selected),
+                }
+            }
+        };
+
+        let Some((candidate, encoded)) = selected else {
+            return Err(SelectCodecError::NoCandidateSelected { failed });
+        };
+
+        let encoded_bytes = encode_framed(
+            &CompressionHeader {
+                candidate,
+                version: StaticCodecVersion,
+            },
+            &encoded,
+        )?;
+
+        Ok((candidate, framed_array(encoded_bytes)))
+    }
+
+    /// Returns the index of the candidate that was selected to produce the
+    /// `encoded` data
+    ///
+    /// # Errors
+    ///
+    /// Errors with
+    /// - [`SelectCodecError::FramingFailed`] if unframing the `encoded` data
+    ///   failed
+    /// - [`SelectCodecError::DecodeInvalidCandidate`] if the recorded
+    ///   candidate index is out of bounds
+    #[expect(clippy::needless_pass_by_value)]
+    pub fn selected_candidate(&self, encoded: AnyArrayView) -> Result<usize, SelectCodecError> {
+        let (candidate, _, _) = self.split_encoded(&encoded)?;
+        Ok(candidate)
+    }
+}
+
+impl Codec for SelectCodec {
+    type Error = SelectCodecError;
+
+    fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        let (_, encoded) = self.encode_and_select(data)?;
+        Ok(encoded)
+    }
+
+    fn decode(&self, encoded: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        let (index, candidate, inner_encoded) = self.split_encoded(&encoded.view())?;
+
+        candidate
+            .codec
+            .decode(inner_encoded.into_cow())
+            .map_err(|err| SelectCodecError::InnerDecodeFailed {
+                candidate: index,
+                source: err,
+            })
+    }
+
+    fn decode_into(
+        &self,
+        encoded: AnyArrayView,
+        decoded: AnyArrayViewMut,
+    ) -> Result<(), Self::Error> {
+        let (index, candidate, inner_encoded) = self.split_encoded(&encoded)?;
+
+        candidate
+            .codec
+            .decode_into(inner_encoded.view(), decoded)
+            .map_err(|err| SelectCodecError::InnerDecodeFailed {
+                candidate: index,
+                source: err,
+            })
+    }
+}
+
+impl StaticCodec for SelectCodec {
+    const CODEC_ID: &'static str = "select.rs";
+
+    type Config<'de> = Self;
+
+    fn from_config(config: Self::Config<'_>) -> Self {
+        config
+    }
+
+    fn get_config(&self) -> StaticCodecConfig<'_, Self> {
+        StaticCodecConfig::from(self)
+    }
+}
+
+#[derive(Debug, Error)]
+/// Errors that may occur when applying the [`SelectCodec`].
+pub enum SelectCodecError {
+    /// [`SelectCodec`] failed to encode the data with a candidate codec
+    #[error("Select failed to encode the data with the candidate codec {candidate}")]
+    InnerEncodeFailed {
+        /// The index of the candidate
+        candidate: usize,
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`SelectCodec`] failed to decode the data with a candidate codec
+    #[error("Select failed to decode the data with the candidate codec {candidate}")]
+    InnerDecodeFailed {
+        /// The index of the candidate
+        candidate: usize,
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`SelectCodec`] received decoded data from a candidate codec that does
+    /// not match the encoded data
+    #[error(
+        "Select received decoded data from the candidate codec {candidate} that does not match the encoded data"
+    )]
+    MismatchedInnerDecode {
+        /// The index of the candidate
+        candidate: usize,
+        /// The source of the error
+        source: AnyArrayAssignError,
+    },
+    /// [`SelectCodec`] could not select any candidate since there are none,
+    /// all failed, or none fit the budget
+    #[error(
+        "Select could not select any candidate since there are none, all failed, or none fit the budget"
+    )]
+    NoCandidateSelected {
+        /// The errors of the candidates that failed, in candidate order
+        failed: Vec<Self>,
+    },
+    /// [`SelectCodec`] failed to frame or unframe the candidate codec's
+    /// encoding
+    #[error("Select failed to frame or unframe the candidate codec's encoding")]
+    FramingFailed {
+        /// The source of the error
+        #[from]
+        source: FramingError,
+    },
+    /// [`SelectCodec`] decoded a candidate index that is out of bounds for
+    /// its candidates
+    #[error("Select decoded the candidate index {candidate} but only has {candidates} candidates")]
+    DecodeInvalidCandidate {
+        /// The decoded candidate index
+        candidate: usize,
+        /// The number of candidates of the codec
+        candidates: usize,
+    },
+}
+
+impl SelectCodec {
+    /// Splits the `encoded` data into the index of the recorded candidate, the
+    /// candidate, and the encoding that was produced by its codec
+    fn split_encoded(
+        &self,
+        encoded: &AnyArrayView,
+    ) -> Result<(usize, &SelectCandidate, AnyArray), SelectCodecError> {
+        let (header, inner_encoded, _) =
+            decode_framed::<CompressionHeader>(&framed_bytes(encoded)?)?;
+
+        let Some(candidate) = self.candidates.get(header.candidate) else {
+            return Err(SelectCodecError::DecodeInvalidCandidate {
+                candidate: header.candidate,
+                candidates: self.candidates.len(),
+            });
+        };
+
+        Ok((header.candidate, candidate, inner_encoded))
+    }
+}
+
+fn encode_candidate(
+    codec: &ErasedDynCodec,
+    candidate: usize,
+    data: &AnyCowArray,
+) -> Result<AnyArray, SelectCodecError> {
+    codec
+        .encode(data.cow())
+        .map_err(|err| SelectCodecError::InnerEncodeFailed {
+            candidate,
+            source: err,
+        })
+}
+
+fn encoded_size(encoded: &AnyArray) -> usize {
+    encoded.len() * encoded.dtype().size()
+}
+
+/// Checks if the `encoded` data of the `candidate` fits within the
+/// `max_bytes` size budget and the `max_abs_error` error budget
+fn fits_budget(
+    codec: &ErasedDynCodec,
+    candidate: usize,
+    data: &AnyCowArray,
+    encoded: &AnyArray,
+    max_bytes: Option<usize>,
+    max_abs_error: Option<NonNegative<f64>>,
+) -> Result<bool, SelectCodecError> {
+    if max_bytes.is_some_and(|max_bytes| encoded_size(encoded) > max_bytes) {
+        return Ok(false);
+    }
+
+    let Some(max_abs_error) = max_abs_error else {
+        return Ok(true);
+    };
+
+    let decoded =
+        codec
+            .decode(encoded.cow())
+            .map_err(|err| SelectCodecError::InnerDecodeFailed {
+                candidate,
+                source: err,
+            })?;
+
+    let error = match (data, &decoded) {
+        (AnyCowArray::U8(data), AnyArray::U8(decoded)) => abs_error(data, decoded),
+        (AnyCowArray::U16(data), AnyArray::U16(decoded)) => abs_error(data, decoded),
+        (AnyCowArray::U32(data), AnyArray::U32(decoded)) => abs_error(data, decoded),
+        (AnyCowArray::U64(data), AnyArray::U64(decoded)) => abs_error(data, decoded),
+        (AnyCowArray::I8(data), AnyArray::I8(decoded)) => abs_error(data, decoded),
+        (AnyCowArray::I16(data), AnyArray::I16(decoded)) => abs_error(data, decoded),
+        (AnyCowArray::I32(data), AnyArray::I32(decoded)) => abs_error(data, decoded),
+        (AnyCowArray::I64(data), AnyArray::I64(decoded)) => abs_error(data, decoded),
+        (AnyCowArray::F32(data), AnyArray::F32(decoded)) => abs_error(data, decoded),
+        (AnyCowArray::F64(data), AnyArray::F64(decoded)) => abs_error(data, decoded),
+        (data, decoded) => Err(AnyArrayAssignError::DTypeMismatch {
+            src: decoded.dtype(),
+            dst: data.dtype(),
+        }),
+    }
+    .map_err(|source| SelectCodecError::MismatchedInnerDecode { candidate, source })?;
+
+    Ok(error <= max_abs_error.0)
+}
+
+/// Computes the maximum pointwise absolute error between the `data` and the
+/// `decoded` data
+///
+/// Non-finite values must be decoded exactly, otherwise the error is infinite.
+fn abs_error<T: Copy + AsPrimitive<f64>>(
+    data: &ArrayBase<impl Data<Elem = T>, IxDyn>,
+    decoded: &ArrayBase<impl Data<Elem = T>, IxDyn>,
+) -> Result<f64, AnyArrayAssignError> {
+    if data.shape() != decoded.shape() {
+        return Err(AnyArrayAssignError::ShapeMismatch {
+            src: decoded.shape().to_vec(),
+            dst: data.shape().to_vec(),
+        });
+    }
+
+    let error = data
+        .iter()
+        .zip(decoded.iter())
+        .map(|(&x, &y)| {
+            let (x, y): (f64, f64) = (x.as_(), y.as_());
+
+            if x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan()) {
+                0.0
+            } else if x.is_finite() && y.is_finite() {
+                (x - y).abs()
+            } else {
+                f64::INFINITY
+            }
+        })
+        .fold(0.0, f64::max);
+
+    Ok(error)
+}
+
+#[expect(clippy::derive_partial_eq_without_eq)] // floats are not Eq
+#[derive(Copy, Clone, PartialEq, PartialOrd, Hash)]
+/// Non-negative floating point number
+pub struct NonNegative<T>(T);
+
+impl Serialize for NonNegative<f64> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for NonNegative<f64> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let x = f64::deserialize(deserializer)?;
+
+        if x >= 0.0 {
+            Ok(Self(x))
+        } else {
+            Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Float(x),
+                &"a non-negative value",
+            ))
+        }
+    }
+}
+
+impl JsonSchema for NonNegative<f64> {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("NonNegativeF64")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed(concat!(module_path!(), "::", "NonNegative<f64>"))
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "minimum": 0.0
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CompressionHeader {
+    candidate: usize,
+    version: SelectCodecVersion,
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use ndarray::Array;
+    use numcodecs_identity::IdentityCodec;
+    use numcodecs_round::RoundCodec;
+    use numcodecs_zlib::ZlibCodec;
+    use serde_json::json;
+
+    use super::*;
+
+    numcodecs_registry::export_global! {
+        static REGISTRY: numcodecs_registry::EmptyRegistry = numcodecs_registry::EmptyRegistry;
+    }
+
+    fn identity() -> SelectCandidate {
+        SelectCandidate {
+            codec: ErasedDynCodec::new(IdentityCodec {
+                version: StaticCodecVersion,
+            }),
+        }
+    }
+
+    fn round() -> SelectCandidate {
+        let round: RoundCodec = serde_json::from_value(json!({
+            "precision": 1.0,
+        }))
+        .unwrap();
+
+        SelectCandidate {
+            codec: ErasedDynCodec::new(round),
+        }
+    }
+
+    fn zlib() -> SelectCandidate {
+        let zlib: ZlibCodec = serde_json::from_value(json!({ "level": 9 })).unwrap();
+
+        SelectCandidate {
+            codec: ErasedDynCodec::new(zlib),
+        }
+    }
+
+    fn select(
+        candidates: Vec<SelectCandidate>,
+        strategy: &serde_json::Value,
+        num_threads: Option<usize>,
+    ) -> SelectCodec {
+        SelectCodec {
+            candidates,
+            strategy: serde_json::from_value(strategy.clone()).unwrap(),
+            num_threads: num_threads.and_then(NonZeroUsize::new),
+            version: StaticCodecVersion,
+        }
+    }
+
+    #[test]
+    fn smallest() {
+        for num_threads in [None, Some(2)] {
+            let codec = select(
+                vec![identity(), zlib()],
+                &json!({ "select": "smallest" }),
+                num_threads,
+            );
+
+            let data = Array::<f64, _>::zeros((16, 16)).into_dyn();
+            let (candidate, encoded) = codec
+                .encode_and_select(AnyCowArray::F64(data.view().into()))
+                .unwrap();
+            assert_eq!(candidate, 1);
+            assert_eq!(codec.selected_candidate(encoded.view()).unwrap(), 1);
+            assert_eq!(
+                codec.decode(encoded.cow()).unwrap(),
+                AnyArray::F64(data.clone())
+            );
+
+            // zlib's header and checksum outweigh any savings on tiny data
+            let data = Array::from_vec(vec![42_u8]).into_dyn();
+            let (candidate, encoded) = codec
+                .encode_and_select(AnyCowArray::U8(data.view().into()))
+                .unwrap();
+            assert_eq!(candidate, 0);
+
+            let mut decoded = Array::zeros(data.shape());
+            codec
+                .decode_into(encoded.view(), AnyArrayViewMut::U8(decoded.view_mut()))
+                .unwrap();
+            assert_eq!(decoded, data);
+        }
+    }
+
+    #[test]
+    fn first_fit_error_budget() {
+        let data = Array::from_vec(vec![0.25_f64, 1.75, -3.5, 42.0]).into_dyn();
+
+        for num_threads in [None, Some(2)] {
+            let codec = select(
+                vec![round(), identity()],
+                &json!({ "select": "first-fit", "max_abs_error": 0.5 }),
+                num_threads,
+            );
+            let (candidate, encoded) = codec
+                .encode_and_select(AnyCowArray::F64(data.view().into()))
+                .unwrap();
+            assert_eq!(candidate, 0);
+            assert_eq!(
+                codec.decode(encoded.cow()).unwrap(),
+                AnyArray::F64(Array::from_vec(vec![0.0, 2.0, -4.0, 42.0]).into_dyn())
+            );
+
+            let codec = select(
+                vec![round(), identity()],
+                &json!({ "select": "first-fit", "max_abs_error": 0.1 }),
+                num_threads,
+            );
+            let (candidate, encoded) = codec
+                .encode_and_select(AnyCowArray::F64(data.view().into()))
+                .unwrap();
+            assert_eq!(candidate, 1);
+            assert_eq!(
+                codec.decode(encoded.cow()).unwrap(),
+                AnyArray::F64(data.clone())
+            );
+        }
+    }
+
+    #[test]
+    fn first_fit_size_budget() {
+        let data = Array::<u16, _>::zeros(1024).into_dyn();
+
+        let codec = select(
+            vec![identity(), zlib()],
+            &json!({ "select": "first-fit", "max_bytes": 1024 }),
+            None,
+        );
+        let (candidate, _) = codec
+            .encode_and_select(AnyCowArray::U16(data.view().into()))
+            .unwrap();
+        assert_eq!(candidate, 1);
+
+        let codec = select(
+            vec![identity(), zlib()],
+            &json!({ "select": "first-fit", "max_bytes": 8 }),
+            None,
+        );
+        assert!(matches!(
+            codec.encode_and_select(AnyCowArray::U16(data.view().into())),
+            Err(SelectCodecError::NoCandidateSelected { .. })
+        ));
+    }
+
+    #[test]
+    fn skip_failed_candidates() {
+        // round only supports floating point data
+        let data = Array::from_vec(vec![1_u8, 2, 3]).into_dyn();
+
+        for strategy in [
+            json!({ "select": "smallest" }),
+            json!({ "select": "first-fit", "max_abs_error": 0.0 }),
+        ] {
+            for num_threads in [None, Some(2)] {
+                let codec = select(vec![round(), identity()], &strategy, num_threads);
+                let (candidate, _) = codec
+                    .encode_and_select(AnyCowArray::U8(data.view().into()))
+                    .unwrap();
+                assert_eq!(candidate, 1);
+
+                let codec = select(vec![round(), round()], &strategy, num_threads);
+                let Err(SelectCodecError::NoCandidateSelected { failed }) =
+                    codec.encode_and_select(AnyCowArray::U8(data.view().into()))
+                else {
+                    panic!("all candidates should have failed");
+                };
+                assert!(matches!(
+                    failed.as_slice(),
+                    [
+                        SelectCodecError::InnerEncodeFailed { candidate: 0, .. },
+                        SelectCodecError::InnerEncodeFailed { candidate: 1, .. },
+                    ]
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_candidate() {
+        let codec = select(
+            vec![identity(), identity()],
+            &json!({ "select": "first-fit", "max_bytes": 0 }),
+            None,
+        );
+        let data = Array::<u8, _>::zeros(0).into_dyn();
+        let (candidate, encoded) = codec
+            .encode_and_select(AnyCowArray::U8(data.view().into()))
+            .unwrap();
+        assert_eq!(candidate, 0);
+
+        let codec = select(Vec::new(), &json!({ "select": "smallest" }), None);
+        assert!(matches!(
+            codec.decode(encoded.cow()),
+            Err(SelectCodecError::DecodeInvalidCandidate {
+                candidate: 0,
+                candidates: 0
+            })
+        ));
+        assert!(matches!(
+            codec.encode(AnyCowArray::U8(data.view().into())),
+            Err(SelectCodecError::NoCandidateSelected { .. })
+        ));
+    }
+}
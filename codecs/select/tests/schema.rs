@@ -0,0 +1,28 @@
+#![expect(missing_docs)]
+
+use ::{
+    ndarray as _, num_traits as _, numcodecs_identity as _, numcodecs_round as _,
+    numcodecs_utils as _, numcodecs_zlib as _, schemars as _, serde as _, serde_json as _,
+    thiserror as _,
+};
+
+use numcodecs::{DynCodecType, StaticCodecType};
+use numcodecs_select::SelectCodec;
+
+#[test]
+fn schema() {
+    let schema = format!(
+        "{:#}",
+        StaticCodecType::<SelectCodec>::of()
+            .codec_config_schema()
+            .to_value()
+    );
+
+    if schema != include_str!("schema.json") {
+        panic!("Select schema has changed\n===\n{schema}\n===");
+    }
+}
+
+numcodecs_registry::export_global! {
+    static REGISTRY: numcodecs_registry::EmptyRegistry = numcodecs_registry::EmptyRegistry;
+}
@@ -0,0 +1,457 @@
+//! [![CI Status]][workflow] [![MSRV]][repo] [![Latest Version]][crates.io] [![Rust Doc Crate]][docs.rs] [![Rust Doc Main]][docs]
+//!
+//! [CI Status]: https://img.shields.io/github/actions/workflow/status/juntyr/numcodecs-rs/ci.yml?branch=main
+//! [workflow]: https://github.com/juntyr/numcodecs-rs/actions/workflows/ci.yml?query=branch%3Amain
+//!
+//! [MSRV]: https://img.shields.io/badge/MSRV-1.87.0-blue
+//! [repo]: https://github.com/juntyr/numcodecs-rs
+//!
+//! [Latest Version]: https://img.shields.io/crates/v/numcodecs-shuffle
+//! [crates.io]: https://crates.io/crates/numcodecs-shuffle
+//!
+//! [Rust Doc Crate]: https://img.shields.io/docsrs/numcodecs-shuffle
+//! [docs.rs]: https://docs.rs/numcodecs-shuffle/
+//!
+//! [Rust Doc Main]: https://img.shields.io/badge/docs-main-blue
+//! [docs]: https://juntyr.github.io/numcodecs-rs/numcodecs_shuffle
+//!
+//! Byte and bit shuffle codec implementation for the [`numcodecs`] API.
+
+use std::num::NonZeroUsize;
+
+use numcodecs::{
+    AnyArray, AnyArrayAssignError, AnyArrayView, AnyArrayViewMut, AnyCowArray, Codec, StaticCodec,
+    StaticCodecConfig, StaticCodecVersion,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+/// Codec that shuffles the bytes or bits of the array elements such that
+/// bytes or bits of the same significance are stored next to each other.
+///
+/// Shuffling does not compress the data by itself, but often makes it more
+/// amenable to compression with a subsequent lossless codec, e.g. Zstandard.
+///
+/// The encoded array has the same dtype and shape as the input array, but its
+/// elements contain the shuffled bytes. The bytes of the encoded array, in
+/// standard order, are the same as those produced by `numcodecs.Shuffle` (for
+/// the byte mode) and the bitshuffle algorithm (for the bit mode), where the
+/// element size is the size of the array's dtype.
+pub struct ShuffleCodec {
+    /// The shuffle mode.
+    pub mode: ShuffleMode,
+    /// The number of elements per block, within which the bytes or bits are
+    /// shuffled.
+    ///
+    /// If no block size is provided, the entire array is shuffled as one
+    /// block.
+    #[serde(default)]
+    pub block_size: Option<NonZeroUsize>,
+    /// The codec's encoding format version. Do not provide this parameter explicitly.
+    #[serde(default, rename = "_version")]
+    pub version: StaticCodecVersion<1, 0, 0>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+/// Shuffle mode
+pub enum ShuffleMode {
+    /// Shuffle the bytes of the elements, i.e. first store the first byte of
+    /// every element, then the second, and so on.
+    #[serde(rename = "byte")]
+    Byte,
+    /// Shuffle the bits of the elements, i.e. first store the first bit of
+    /// every element, then the second, and so on.
+    ///
+    /// Only groups of eight elements are bit-shuffled. If the number of
+    /// elements in a block is not a multiple of eight, the bytes of the
+    /// remaining trailing elements are copied without shuffling.
+    #[serde(rename = "bit")]
+    Bit,
+}
+
+impl Codec for ShuffleCodec {
+    type Error = ShuffleCodecError;
+
+    fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        let (encoded, ()) = AnyArray::with_zeros_bytes(data.dtype(), data.shape(), |encoded| {
+            shuffle(
+                &data.as_bytes(),
+                encoded,
+                data.dtype().size(),
+                self.block_size,
+                self.mode,
+            );
+        });
+
+        Ok(encoded)
+    }
+
+    fn decode(&self, encoded: AnyCowArray) -> Result<AnyArray, Self::Error> {
+        let (decoded, ()) =
+            AnyArray::with_zeros_bytes(encoded.dtype(), encoded.shape(), |decoded| {
+                unshuffle(
+                    &encoded.as_bytes(),
+                    decoded,
+                    encoded.dtype().size(),
+                    self.block_size,
+                    self.mode,
+                );
+            });
+
+        Ok(decoded)
+    }
+
+    fn decode_into(
+        &self,
+        encoded: AnyArrayView,
+        mut decoded: AnyArrayViewMut,
+    ) -> Result<(), Self::Error> {
+        if encoded.dtype() != decoded.dtype() {
+            return Err(ShuffleCodecError::MismatchedDecodeIntoArray {
+                source: AnyArrayAssignError::DTypeMismatch {
+                    src: encoded.dtype(),
+                    dst: decoded.dtype(),
+                },
+            });
+        }
+
+        if encoded.shape() != decoded.shape() {
+            return Err(ShuffleCodecError::MismatchedDecodeIntoArray {
+                source: AnyArrayAssignError::ShapeMismatch {
+                    src: encoded.shape().to_vec(),
+                    dst: decoded.shape().to_vec(),
+                },
+            });
+        }
+
+        let () = decoded.with_bytes_mut(|decoded| {
+            unshuffle(
+                &encoded.as_bytes(),
+                decoded,
+                encoded.dtype().size(),
+                self.block_size,
+                self.mode,
+            );
+        });
+
+        Ok(())
+    }
+}
+
+impl StaticCodec for ShuffleCodec {
+    const CODEC_ID: &'static str = "shuffle.rs";
+
+    type Config<'de> = Self;
+
+    fn from_config(config: Self::Config<'_>) -> Self {
+        config
+    }
+
+    fn get_config(&self) -> StaticCodecConfig<'_, Self> {
+        StaticCodecConfig::from(self)
+    }
+}
+
+#[derive(Debug, Error)]
+/// Errors that may occur when applying the [`ShuffleCodec`].
+pub enum ShuffleCodecError {
+    /// [`ShuffleCodec`] cannot decode into the provided array
+    #[error("Shuffle cannot decode into the provided array")]
+    MismatchedDecodeIntoArray {
+        /// The source of the error
+        #[from]
+        source: AnyArrayAssignError,
+    },
+}
+
+/// Shuffle the bytes or bits, depending on the `mode`, of the elements of
+/// `element_size` bytes in the `data` byte slice and write them into the
+/// `shuffled` byte slice.
+///
+/// The elements are shuffled in blocks of `block_size` elements, or as one
+/// block if no `block_size` is provided.
+///
+/// # Panics
+///
+/// Panics if `data` and `shuffled` have different lengths, or if their length
+/// is not a multiple of the `element_size`.
+pub fn shuffle(
+    data: &[u8],
+    shuffled: &mut [u8],
+    element_size: usize,
+    block_size: Option<NonZeroUsize>,
+    mode: ShuffleMode,
+) {
+    assert_eq!(
+        data.len(),
+        shuffled.len(),
+        "data and shuffled must have the same length"
+    );
+    assert_eq!(
+        data.len() % element_size,
+        0,
+        "data length must be a multiple of the element size"
+    );
+
+    let block_bytes = block_size.map_or(data.len(), |block_size| block_size.get() * element_size);
+
+    for (data, shuffled) in data
+        .chunks(block_bytes.max(1))
+        .zip(shuffled.chunks_mut(block_bytes.max(1)))
+    {
+        match mode {
+            ShuffleMode::Byte => byte_shuffle_block(data, shuffled, element_size),
+            ShuffleMode::Bit => bit_shuffle_block(data, shuffled, element_size),
+        }
+    }
+}
+
+/// Unshuffle the bytes or bits, depending on the `mode`, of the elements of
+/// `element_size` bytes in the `shuffled` byte slice and write them into the
+/// `data` byte slice.
+///
+/// The elements must have been [`shuffle`]d with the same `block_size` and
+/// `mode`.
+///
+/// # Panics
+///
+/// Panics if `shuffled` and `data` have different lengths, or if their length
+/// is not a multiple of the `element_size`.
+pub fn unshuffle(
+    shuffled: &[u8],
+    data: &mut [u8],
+    element_size: usize,
+    block_size: Option<NonZeroUsize>,
+    mode: ShuffleMode,
+) {
+    assert_eq!(
+        shuffled.len(),
+        data.len(),
+        "shuffled and data must have the same length"
+    );
+    assert_eq!(
+        shuffled.len() % element_size,
+        0,
+        "shuffled length must be a multiple of the element size"
+    );
+
+    let block_bytes =
+        block_size.map_or(shuffled.len(), |block_size| block_size.get() * element_size);
+
+    for (shuffled, data) in shuffled
+        .chunks(block_bytes.max(1))
+        .zip(data.chunks_mut(block_bytes.max(1)))
+    {
+        match mode {
+            ShuffleMode::Byte => byte_unshuffle_block(shuffled, data, element_size),
+            ShuffleMode::Bit => bit_unshuffle_block(shuffled, data, element_size),
+        }
+    }
+}
+
+fn byte_shuffle_block(data: &[u8], shuffled: &mut [u8], element_size: usize) {
+    let num_elements = data.len() / element_size;
+
+    if num_elements == 0 {
+        return;
+    }
+
+    // the j-th plane contains the j-th byte of every element
+    for (j, plane) in shuffled.chunks_exact_mut(num_elements).enumerate() {
+        for (s, d) in plane
+            .iter_mut()
+            .zip(data.iter().skip(j).step_by(element_size))
+        {
+            *s = *d;
+        }
+    }
+}
+
+fn byte_unshuffle_block(shuffled: &[u8], data: &mut [u8], element_size: usize) {
+    let num_elements = data.len() / element_size;
+
+    if num_elements == 0 {
+        return;
+    }
+
+    for (j, plane) in shuffled.chunks_exact(num_elements).enumerate() {
+        for (d, s) in data.iter_mut().skip(j).step_by(element_size).zip(plane) {
+            *d = *s;
+        }
+    }
+}
+
+fn bit_shuffle_block(data: &[u8], shuffled: &mut [u8], element_size: usize) {
+    // only groups of eight elements are bit-shuffled, the rest is copied
+    let num_shuffled_bytes = (data.len() / (element_size * 8)) * (element_size * 8);
+    let (data, data_rest) = data.split_at(num_shuffled_bytes);
+    let (shuffled, shuffled_rest) = shuffled.split_at_mut(num_shuffled_bytes);
+    shuffled_rest.copy_from_slice(data_rest);
+
+    let plane_size = data.len() / (element_size * 8);
+
+    if plane_size == 0 {
+        return;
+    }
+
+    // the (8*j + k)-th plane contains the k-th bit of the j-th byte of every
+    //  element, packed with eight elements per byte
+    for (p, plane) in shuffled.chunks_exact_mut(plane_size).enumerate() {
+        let (j, k) = (p / 8, p % 8);
+
+        for (s, elements) in plane.iter_mut().zip(data.chunks_exact(element_size * 8)) {
+            *s = elements
+                .iter()
+                .skip(j)
+                .step_by(element_size)
+                .enumerate()
+                .fold(0, |acc, (i, d)| acc | (((d >> k) & 1) << i));
+        }
+    }
+}
+
+fn bit_unshuffle_block(shuffled: &[u8], data: &mut [u8], element_size: usize) {
+    let num_shuffled_bytes = (data.len() / (element_size * 8)) * (element_size * 8);
+    let (shuffled, shuffled_rest) = shuffled.split_at(num_shuffled_bytes);
+    let (data, data_rest) = data.split_at_mut(num_shuffled_bytes);
+    data_rest.copy_from_slice(shuffled_rest);
+
+    let plane_size = data.len() / (element_size * 8);
+
+    if plane_size == 0 {
+        return;
+    }
+
+    for (g, elements) in data.chunks_exact_mut(element_size * 8).enumerate() {
+        for (i, element) in elements.chunks_exact_mut(element_size).enumerate() {
+            for (j, d) in element.iter_mut().enumerate() {
+                *d = shuffled
+                    .chunks_exact(plane_size)
+                    .skip(j * 8)
+                    .take(8)
+                    .enumerate()
+                    .fold(0, |acc, (k, plane)| {
+                        acc | (((plane.get(g).copied().unwrap_or(0) >> i) & 1) << k)
+                    });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use ndarray::{Array1, ArrayD};
+
+    use super::*;
+
+    fn roundtrip(data: &AnyArray, mode: ShuffleMode, block_size: Option<NonZeroUsize>) {
+        let codec = ShuffleCodec {
+            mode,
+            block_size,
+            version: StaticCodecVersion,
+        };
+
+        let encoded = codec.encode(data.cow()).unwrap();
+        assert_eq!(encoded.dtype(), data.dtype());
+        assert_eq!(encoded.shape(), data.shape());
+
+        let decoded = codec.decode(encoded.cow()).unwrap();
+        assert_eq!(&decoded, data);
+
+        let mut decoded_into = AnyArray::zeros(data.dtype(), data.shape());
+        codec
+            .decode_into(encoded.view(), decoded_into.view_mut())
+            .unwrap();
+        assert_eq!(&decoded_into, data);
+    }
+
+    #[test]
+    fn byte_shuffle_matches_numcodecs() {
+        let mut shuffled = [0_u8; 8];
+        shuffle(
+            &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08],
+            &mut shuffled,
+            4,
+            None,
+            ShuffleMode::Byte,
+        );
+        assert_eq!(shuffled, [0x01, 0x05, 0x02, 0x06, 0x03, 0x07, 0x04, 0x08]);
+
+        let mut shuffled = [0_u8; 8];
+        shuffle(
+            &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08],
+            &mut shuffled,
+            2,
+            NonZeroUsize::new(2),
+            ShuffleMode::Byte,
+        );
+        assert_eq!(shuffled, [0x01, 0x03, 0x02, 0x04, 0x05, 0x07, 0x06, 0x08]);
+    }
+
+    #[test]
+    fn bit_shuffle_planes() {
+        let mut shuffled = [0_u8; 9];
+        shuffle(
+            &[0xFF, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x80, 0x42],
+            &mut shuffled,
+            1,
+            None,
+            ShuffleMode::Bit,
+        );
+        assert_eq!(
+            shuffled,
+            [0x05, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x81, 0x42]
+        );
+    }
+
+    #[test]
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_possible_wrap,
+        clippy::cast_precision_loss
+    )]
+    fn roundtrip_all_dtypes() {
+        let data = (0..1000_u64)
+            .map(|x| x.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+            .collect::<Vec<_>>();
+
+        let arrays = [
+            AnyArray::U8(Array1::from_iter(data.iter().map(|x| *x as u8)).into_dyn()),
+            AnyArray::U16(Array1::from_iter(data.iter().map(|x| *x as u16)).into_dyn()),
+            AnyArray::U32(Array1::from_iter(data.iter().map(|x| *x as u32)).into_dyn()),
+            AnyArray::U64(Array1::from_iter(data.iter().copied()).into_dyn()),
+            AnyArray::I8(Array1::from_iter(data.iter().map(|x| *x as i8)).into_dyn()),
+            AnyArray::I16(Array1::from_iter(data.iter().map(|x| *x as i16)).into_dyn()),
+            AnyArray::I32(Array1::from_iter(data.iter().map(|x| *x as i32)).into_dyn()),
+            AnyArray::I64(Array1::from_iter(data.iter().map(|x| *x as i64)).into_dyn()),
+            AnyArray::F32(Array1::from_iter(data.iter().map(|x| *x as f32)).into_dyn()),
+            AnyArray::F64(Array1::from_iter(data.iter().map(|x| *x as f64)).into_dyn()),
+        ];
+
+        for data in arrays {
+            for mode in [ShuffleMode::Byte, ShuffleMode::Bit] {
+                for block_size in [None, NonZeroUsize::new(1), NonZeroUsize::new(13)] {
+                    roundtrip(&data, mode, block_size);
+                    roundtrip(&AnyArray::zeros(data.dtype(), &[0]), mode, block_size);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn roundtrip_non_standard_order() {
+        let data = ArrayD::from_shape_fn(vec![7, 5, 3], |idx| {
+            #[expect(clippy::cast_precision_loss)]
+            let x = (idx[0] * 100 + idx[1] * 10 + idx[2]) as f32;
+            x.sqrt()
+        });
+        let data = AnyArray::F32(data.reversed_axes());
+
+        roundtrip(&data, ShuffleMode::Byte, NonZeroUsize::new(8));
+        roundtrip(&data, ShuffleMode::Bit, NonZeroUsize::new(16));
+    }
+}
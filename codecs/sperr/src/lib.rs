@@ -24,18 +24,20 @@ use ::serde_json as _;
 
 use std::borrow::Cow;
 use std::fmt;
+use std::num::NonZeroUsize;
 
-use ndarray::{Array, Array1, ArrayBase, Axis, Data, Dimension, IxDyn, ShapeError};
+use ndarray::{Array, Array1, ArrayBase, ArrayViewMut, Axis, Data, Dimension, IxDyn, ShapeError};
 use num_traits::{Float, identities::Zero};
 use numcodecs::{
     AnyArray, AnyArrayAssignError, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray,
     Codec, StaticCodec, StaticCodecConfig, StaticCodecVersion,
 };
+use numcodecs_utils::slices::{self, SliceFramingError, SlicesError};
 use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 
-type SperrCodecVersion = StaticCodecVersion<0, 2, 0>;
+type SperrCodecVersion = StaticCodecVersion<0, 2, 1>;
 
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
 // serde cannot deny unknown fields because of the flatten
@@ -51,11 +53,26 @@ pub struct SperrCodec {
     /// SPERR compression mode
     #[serde(flatten)]
     pub mode: SperrCompressionMode,
+    /// 3D size of the chunks (z,y,x) that SPERR uses internally
+    #[serde(default = "default_chunks")]
+    pub chunks: (NonZeroUsize, NonZeroUsize, NonZeroUsize),
+    /// The optional number of threads with which the 3D slices are encoded
+    /// and decoded in parallel
+    ///
+    /// If `None`, the slices are processed serially. The encoded bytes do not
+    /// depend on the number of threads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_threads: Option<NonZeroUsize>,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default, rename = "_version")]
     pub version: SperrCodecVersion,
 }
 
+const fn default_chunks() -> (NonZeroUsize, NonZeroUsize, NonZeroUsize) {
+    const NON_ZERO_256: NonZeroUsize = NonZeroUsize::MIN.saturating_add(255);
+    (NON_ZERO_256, NON_ZERO_256, NON_ZERO_256)
+}
+
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
 /// SPERR compression mode
 #[serde(tag = "mode")]
@@ -92,10 +109,22 @@ impl Codec for SperrCodec {
     fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
         match data {
             AnyCowArray::F32(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, &self.mode)?).into_dyn(),
+                Array1::from(compress_with_threads(
+                    data,
+                    &self.mode,
+                    self.chunks,
+                    self.num_threads,
+                )?)
+                .into_dyn(),
             )),
             AnyCowArray::F64(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, &self.mode)?).into_dyn(),
+                Array1::from(compress_with_threads(
+                    data,
+                    &self.mode,
+                    self.chunks,
+                    self.num_threads,
+                )?)
+                .into_dyn(),
             )),
             encoded => Err(SperrCodecError::UnsupportedDtype(encoded.dtype())),
         }
@@ -114,7 +143,7 @@ impl Codec for SperrCodec {
             });
         }
 
-        decompress(&AnyCowArray::U8(encoded).as_bytes())
+        decompress_with_threads(&AnyCowArray::U8(encoded).as_bytes(), self.num_threads)
     }
 
     fn decode_into(
@@ -128,6 +157,45 @@ impl Codec for SperrCodec {
     }
 }
 
+impl SperrCodec {
+    /// Decodes only the slices at the `indices` along the first axis of the
+    /// `encoded` array into the `decoded` array.
+    ///
+    /// The `decoded` array must have the dtype and shape of the encoded data,
+    /// except that its first axis must have length `indices.len()`.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`SperrCodecError`] if the `encoded` array is not a
+    /// one-dimensional byte array, or if decoding the selected slices with
+    /// [`decompress_slices_into`] fails.
+    pub fn decode_slices_into(
+        &self,
+        encoded: AnyArrayView,
+        indices: &[usize],
+        decoded: AnyArrayViewMut,
+    ) -> Result<(), SperrCodecError> {
+        let AnyArrayView::U8(encoded) = encoded else {
+            return Err(SperrCodecError::EncodedDataNotBytes {
+                dtype: encoded.dtype(),
+            });
+        };
+
+        if !matches!(encoded.shape(), [_]) {
+            return Err(SperrCodecError::EncodedDataNotOneDimensional {
+                shape: encoded.shape().to_vec(),
+            });
+        }
+
+        decompress_slices_into(
+            &AnyArrayView::U8(encoded).as_bytes(),
+            indices,
+            decoded,
+            self.num_threads,
+        )
+    }
+}
+
 impl StaticCodec for SperrCodec {
     const CODEC_ID: &'static str = "sperr.rs";
 
@@ -199,6 +267,17 @@ pub enum SperrCodecError {
     /// [`SperrCodec`] failed to decode from an excessive number of slices
     #[error("Sperr failed to decode from an excessive number of slices")]
     DecodeTooManySlices,
+    /// [`SperrCodec`] failed to decode from a corrupted slice index
+    #[error("Sperr failed to decode from a corrupted slice index")]
+    DecodeCorruptedSliceIndex,
+    /// [`SperrCodec`] cannot decode a slice at an out-of-bounds index
+    #[error("Sperr cannot decode the slice at index {index} of an axis of length {len}")]
+    SliceIndexOutOfBounds {
+        /// The out-of-bounds slice index
+        index: usize,
+        /// The length of the first axis of the encoded array
+        len: usize,
+    },
     /// [`SperrCodec`] failed to decode the data
     #[error("Sperr failed to decode the data")]
     SperrDecodeFailed {
@@ -220,6 +299,27 @@ pub enum SperrCodecError {
     },
 }
 
+impl From<SlicesError> for SperrCodecError {
+    fn from(err: SlicesError) -> Self {
+        match err {
+            SlicesError::SliceEncodeFailed { source } => Self::SliceEncodeFailed {
+                source: SperrSliceError(source),
+            },
+            SlicesError::SliceDecodeFailed { source } => Self::SliceDecodeFailed {
+                source: SperrSliceError(source),
+            },
+            SlicesError::DecodeTooManySlices => Self::DecodeTooManySlices,
+            SlicesError::DecodeCorruptedSliceIndex => Self::DecodeCorruptedSliceIndex,
+            SlicesError::SliceIndexOutOfBounds { index, len } => {
+                Self::SliceIndexOutOfBounds { index, len }
+            }
+            SlicesError::MismatchedDecodeIntoArray { source } => {
+                Self::MismatchedDecodeIntoArray { source }
+            }
+        }
+    }
+}
+
 #[derive(Debug, Error)]
 #[error(transparent)]
 /// Opaque error for when encoding or decoding the header fails
@@ -228,7 +328,7 @@ pub struct SperrHeaderError(postcard::Error);
 #[derive(Debug, Error)]
 #[error(transparent)]
 /// Opaque error for when encoding or decoding a slice fails
-pub struct SperrSliceError(postcard::Error);
+pub struct SperrSliceError(SliceFramingError);
 
 #[derive(Debug, Error)]
 #[error(transparent)]
@@ -237,174 +337,272 @@ pub struct SperrCodingError(sperr::Error);
 
 /// Compress the `data` array using SPERR with the provided `mode`.
 ///
+/// Each 3D slice is internally split by SPERR into chunks of size
+/// 256x256x256, and the 3D slices are compressed serially. Use
+/// [`compress_with_threads`] to configure the chunk size and to compress the
+/// slices in parallel.
+///
+/// The encoded data contains an index of the byte offsets of all slices,
+/// which allows [`decompress_slices_into`] to decode only selected slices.
+///
 /// # Errors
 ///
 /// Errors with
 /// - [`SperrCodecError::HeaderEncodeFailed`] if encoding the header failed
 /// - [`SperrCodecError::SperrEncodeFailed`] if encoding with SPERR failed
 /// - [`SperrCodecError::SliceEncodeFailed`] if encoding a slice failed
-#[allow(clippy::missing_panics_doc)]
 pub fn compress<T: SperrElement, S: Data<Elem = T>, D: Dimension>(
     data: ArrayBase<S, D>,
     mode: &SperrCompressionMode,
 ) -> Result<Vec<u8>, SperrCodecError> {
+    compress_with_threads(data, mode, default_chunks(), None)
+}
+
+/// Compress the `data` array using SPERR with the provided `mode`.
+///
+/// Each 3D slice is internally split by SPERR into chunks of size `chunks`
+/// (z,y,x).
+///
+/// The 3D slices are compressed in parallel using up to `num_threads` threads
+/// if provided, or serially otherwise. The output is the same in both cases.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`SperrCodecError::HeaderEncodeFailed`] if encoding the header failed
+/// - [`SperrCodecError::SperrEncodeFailed`] if encoding with SPERR failed
+/// - [`SperrCodecError::SliceEncodeFailed`] if encoding a slice failed
+#[allow(clippy::missing_panics_doc)]
+pub fn compress_with_threads<T: SperrElement, S: Data<Elem = T>, D: Dimension>(
+    data: ArrayBase<S, D>,
+    mode: &SperrCompressionMode,
+    chunks: (NonZeroUsize, NonZeroUsize, NonZeroUsize),
+    num_threads: Option<NonZeroUsize>,
+) -> Result<Vec<u8>, SperrCodecError> {
+    let data = data.into_dyn();
+
+    // SPERR cannot handle zero-length dimensions, which have no slices
+    let (slice_offsets, slices) =
+        slices::encode_slices(&data, num_threads, |mut slice, slice_shape: [usize; 3]| {
+            while slice.ndim() < 3 {
+                slice = slice.insert_axis(Axis(0));
+            }
+            #[allow(clippy::unwrap_used)]
+            // slice must now have at least three axes, and all but the last
+            //  three must be of size 1
+            let slice = slice.into_shape_with_order(slice_shape).unwrap();
+
+            sperr::compress_3d(
+                slice,
+                match mode {
+                    SperrCompressionMode::BitsPerPixel { bpp } => {
+                        sperr::CompressionMode::BitsPerPixel { bpp: bpp.0 }
+                    }
+                    SperrCompressionMode::PeakSignalToNoiseRatio { psnr } => {
+                        sperr::CompressionMode::PeakSignalToNoiseRatio { psnr: psnr.0 }
+                    }
+                    SperrCompressionMode::PointwiseError { pwe } => {
+                        sperr::CompressionMode::PointwiseError { pwe: pwe.0 }
+                    }
+                    SperrCompressionMode::QuantisationStep { q } => {
+                        sperr::CompressionMode::QuantisationStep { q: q.0 }
+                    }
+                },
+                (chunks.0.get(), chunks.1.get(), chunks.2.get()),
+            )
+            .map_err(|err| SperrCodecError::SperrEncodeFailed {
+                source: SperrCodingError(err),
+            })
+        })?;
+
     let mut encoded = postcard::to_extend(
         &CompressionHeader {
             dtype: T::DTYPE,
             shape: Cow::Borrowed(data.shape()),
             version: StaticCodecVersion,
+            slice_offsets,
         },
         Vec::new(),
     )
     .map_err(|err| SperrCodecError::HeaderEncodeFailed {
         source: SperrHeaderError(err),
     })?;
-
-    // SPERR cannot handle zero-length dimensions
-    if data.is_empty() {
-        return Ok(encoded);
-    }
-
-    let mut chunk_size = Vec::from(data.shape());
-    let (width, height, depth) = match *chunk_size.as_mut_slice() {
-        [ref mut rest @ .., depth, height, width] => {
-            for r in rest {
-                *r = 1;
-            }
-            (width, height, depth)
-        }
-        [height, width] => (width, height, 1),
-        [width] => (width, 1, 1),
-        [] => (1, 1, 1),
-    };
-
-    for mut slice in data.into_dyn().exact_chunks(chunk_size.as_slice()) {
-        while slice.ndim() < 3 {
-            slice = slice.insert_axis(Axis(0));
-        }
-        #[allow(clippy::unwrap_used)]
-        // slice must now have at least three axes, and all but the last three
-        //  must be of size 1
-        let slice = slice.into_shape_with_order((depth, height, width)).unwrap();
-
-        let encoded_slice = sperr::compress_3d(
-            slice,
-            match mode {
-                SperrCompressionMode::BitsPerPixel { bpp } => {
-                    sperr::CompressionMode::BitsPerPixel { bpp: bpp.0 }
-                }
-                SperrCompressionMode::PeakSignalToNoiseRatio { psnr } => {
-                    sperr::CompressionMode::PeakSignalToNoiseRatio { psnr: psnr.0 }
-                }
-                SperrCompressionMode::PointwiseError { pwe } => {
-                    sperr::CompressionMode::PointwiseError { pwe: pwe.0 }
-                }
-                SperrCompressionMode::QuantisationStep { q } => {
-                    sperr::CompressionMode::QuantisationStep { q: q.0 }
-                }
-            },
-            (256, 256, 256),
-        )
-        .map_err(|err| SperrCodecError::SperrEncodeFailed {
-            source: SperrCodingError(err),
-        })?;
-
-        encoded = postcard::to_extend(encoded_slice.as_slice(), encoded).map_err(|err| {
-            SperrCodecError::SliceEncodeFailed {
-                source: SperrSliceError(err),
-            }
-        })?;
-    }
+    encoded.extend_from_slice(&slices);
 
     Ok(encoded)
 }
 
 /// Decompress the `encoded` data into an array using SPERR.
 ///
+/// The 3D slices are decompressed serially. Use [`decompress_with_threads`]
+/// to decompress the slices in parallel.
+///
 /// # Errors
 ///
 /// Errors with
 /// - [`SperrCodecError::HeaderDecodeFailed`] if decoding the header failed
 /// - [`SperrCodecError::SliceDecodeFailed`] if decoding a slice failed
 /// - [`SperrCodecError::SperrDecodeFailed`] if decoding with SPERR failed
-/// - [`SperrCodecError::DecodeInvalidShape`] if the encoded data decodes to
-///   an unexpected shape
 /// - [`SperrCodecError::DecodeTooManySlices`] if the encoded data contains
 ///   too many slices
 pub fn decompress(encoded: &[u8]) -> Result<AnyArray, SperrCodecError> {
+    decompress_with_threads(encoded, None)
+}
+
+/// Decompress the `encoded` data into an array using SPERR.
+///
+/// The 3D slices are decompressed in parallel using up to `num_threads`
+/// threads if provided, or serially otherwise.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`SperrCodecError::HeaderDecodeFailed`] if decoding the header failed
+/// - [`SperrCodecError::SliceDecodeFailed`] if decoding a slice failed
+/// - [`SperrCodecError::SperrDecodeFailed`] if decoding with SPERR failed
+/// - [`SperrCodecError::DecodeTooManySlices`] if the encoded data contains
+///   too many slices
+pub fn decompress_with_threads(
+    encoded: &[u8],
+    num_threads: Option<NonZeroUsize>,
+) -> Result<AnyArray, SperrCodecError> {
     fn decompress_typed<T: SperrElement>(
-        mut encoded: &[u8],
+        encoded: &[u8],
         shape: &[usize],
+        num_threads: Option<NonZeroUsize>,
     ) -> Result<Array<T, IxDyn>, SperrCodecError> {
         let mut decoded = Array::<T, _>::zeros(shape);
+        slices::decode_slices_into(
+            encoded,
+            decoded.view_mut(),
+            num_threads,
+            decompress_slice_into,
+        )?;
+        Ok(decoded)
+    }
 
-        let mut chunk_size = Vec::from(shape);
-        let (width, height, depth) = match *chunk_size.as_mut_slice() {
-            [ref mut rest @ .., depth, height, width] => {
-                for r in rest {
-                    *r = 1;
-                }
-                (width, height, depth)
-            }
-            [height, width] => (width, height, 1),
-            [width] => (width, 1, 1),
-            [] => (1, 1, 1),
-        };
-
-        for mut slice in decoded.exact_chunks_mut(chunk_size.as_slice()) {
-            let (encoded_slice, rest) =
-                postcard::take_from_bytes::<Cow<[u8]>>(encoded).map_err(|err| {
-                    SperrCodecError::SliceDecodeFailed {
-                        source: SperrSliceError(err),
-                    }
-                })?;
-            encoded = rest;
-
-            while slice.ndim() < 3 {
-                slice = slice.insert_axis(Axis(0));
-            }
-            #[allow(clippy::unwrap_used)]
-            // slice must now have at least three axes, and all but the last
-            //  three must be of size 1
-            let slice = slice.into_shape_with_order((depth, height, width)).unwrap();
+    let (header, encoded) = decode_header(encoded)?;
 
-            sperr::decompress_into_3d(&encoded_slice, slice).map_err(|err| {
-                SperrCodecError::SperrDecodeFailed {
-                    source: SperrCodingError(err),
-                }
-            })?;
-        }
+    match header.dtype {
+        SperrDType::F32 => Ok(AnyArray::F32(decompress_typed(
+            encoded,
+            &header.shape,
+            num_threads,
+        )?)),
+        SperrDType::F64 => Ok(AnyArray::F64(decompress_typed(
+            encoded,
+            &header.shape,
+            num_threads,
+        )?)),
+    }
+}
 
-        if !encoded.is_empty() {
-            return Err(SperrCodecError::DecodeTooManySlices);
-        }
+/// Decompress only the slices at the `indices` along the first axis of the
+/// `encoded` data into the `decoded` array using SPERR.
+///
+/// The `decoded` array must have the dtype and shape of the encoded data,
+/// except that its first axis must have length `indices.len()`. For arrays
+/// with more than three dimensions, only the 3D slices that are selected by
+/// the `indices` are decoded, using up to `num_threads` threads if provided,
+/// or serially otherwise.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`SperrCodecError::HeaderDecodeFailed`] if decoding the header failed
+/// - [`SperrCodecError::SliceIndexOutOfBounds`] if any of the `indices` is
+///   out of bounds for the first axis of the encoded data
+/// - [`SperrCodecError::MismatchedDecodeIntoArray`] if the `decoded` array
+///   has the wrong dtype or shape
+/// - [`SperrCodecError::DecodeCorruptedSliceIndex`] if the slice index of the
+///   encoded data is corrupted
+/// - [`SperrCodecError::SliceDecodeFailed`] if decoding a slice failed
+/// - [`SperrCodecError::SperrDecodeFailed`] if decoding with SPERR failed
+pub fn decompress_slices_into(
+    encoded: &[u8],
+    indices: &[usize],
+    decoded: AnyArrayViewMut,
+    num_threads: Option<NonZeroUsize>,
+) -> Result<(), SperrCodecError> {
+    let (header, encoded) = decode_header(encoded)?;
+
+    let dtype = match header.dtype {
+        SperrDType::F32 => AnyArrayDType::F32,
+        SperrDType::F64 => AnyArrayDType::F64,
+    };
+    slices::check_decode_selected_slices_into(dtype, &header.shape, indices, &decoded)?;
+
+    match decoded {
+        AnyArrayViewMut::F32(decoded) => slices::decode_selected_slices_into(
+            encoded,
+            &header.slice_offsets,
+            &header.shape,
+            indices,
+            decoded,
+            num_threads,
+            decompress_slice_into,
+        ),
+        AnyArrayViewMut::F64(decoded) => slices::decode_selected_slices_into(
+            encoded,
+            &header.slice_offsets,
+            &header.shape,
+            indices,
+            decoded,
+            num_threads,
+            decompress_slice_into,
+        ),
+        decoded => Err(SperrCodecError::UnsupportedDtype(decoded.dtype())),
+    }
+}
 
-        Ok(decoded)
+/// Decodes the header of the `encoded` data
+///
+/// The slice index of data that was encoded with the index-less format
+/// version 0.2.0 is reconstructed by walking over the slices.
+fn decode_header(encoded: &[u8]) -> Result<(CompressionHeader<'_>, &[u8]), SperrCodecError> {
+    if let Ok((header, encoded)) = postcard::take_from_bytes::<LegacyCompressionHeader>(encoded) {
+        let slice_offsets = slices::slice_offsets(encoded, slices::num_slices::<3>(&header.shape))?;
+
+        return Ok((
+            CompressionHeader {
+                dtype: header.dtype,
+                shape: header.shape,
+                version: StaticCodecVersion,
+                slice_offsets,
+            },
+            encoded,
+        ));
     }
 
-    let (header, encoded) =
-        postcard::take_from_bytes::<CompressionHeader>(encoded).map_err(|err| {
-            SperrCodecError::HeaderDecodeFailed {
-                source: SperrHeaderError(err),
-            }
-        })?;
+    postcard::take_from_bytes::<CompressionHeader>(encoded).map_err(|err| {
+        SperrCodecError::HeaderDecodeFailed {
+            source: SperrHeaderError(err),
+        }
+    })
+}
 
-    // Return empty data for zero-size arrays
-    if header.shape.iter().copied().any(|s| s == 0) {
-        return match header.dtype {
-            SperrDType::F32 => Ok(AnyArray::F32(Array::zeros(&*header.shape))),
-            SperrDType::F64 => Ok(AnyArray::F64(Array::zeros(&*header.shape))),
-        };
+fn decompress_slice_into<T: SperrElement>(
+    encoded_slice: &[u8],
+    mut slice: ArrayViewMut<T, IxDyn>,
+    slice_shape: [usize; 3],
+) -> Result<(), SperrCodecError> {
+    while slice.ndim() < 3 {
+        slice = slice.insert_axis(Axis(0));
     }
+    #[allow(clippy::unwrap_used)]
+    // slice must now have at least three axes, and all but the last
+    //  three must be of size 1
+    let slice = slice.into_shape_with_order(slice_shape).unwrap();
 
-    match header.dtype {
-        SperrDType::F32 => Ok(AnyArray::F32(decompress_typed(encoded, &header.shape)?)),
-        SperrDType::F64 => Ok(AnyArray::F64(decompress_typed(encoded, &header.shape)?)),
-    }
+    sperr::decompress_into_3d(encoded_slice, slice).map_err(|err| {
+        SperrCodecError::SperrDecodeFailed {
+            source: SperrCodingError(err),
+        }
+    })
 }
 
 /// Array element types which can be compressed with SPERR.
-pub trait SperrElement: sperr::Element + Zero {
+pub trait SperrElement: sperr::Element + Zero + Send + Sync {
     /// The dtype representation of the type
     const DTYPE: SperrDType;
 }
@@ -473,6 +671,17 @@ struct CompressionHeader<'a> {
     #[serde(borrow)]
     shape: Cow<'a, [usize]>,
     version: SperrCodecVersion,
+    slice_offsets: Vec<usize>,
+}
+
+/// Header of the format version 0.2.0, which has no slice index
+#[derive(Deserialize)]
+struct LegacyCompressionHeader<'a> {
+    dtype: SperrDType,
+    #[serde(borrow)]
+    shape: Cow<'a, [usize]>,
+    #[expect(dead_code)]
+    version: StaticCodecVersion<0, 2, 0>,
 }
 
 /// Dtypes that SPERR can compress and decompress
@@ -608,4 +817,170 @@ mod tests {
             assert_eq!(decoded, AnyArray::F32(data));
         }
     }
+
+    #[test]
+    fn parallel_slices() {
+        let data = Array::<f64, _>::from_shape_fn((5, 3, 8, 8, 8), |(a, b, c, d, e)| {
+            #[expect(clippy::cast_precision_loss)]
+            let x = (a * 512 + b * 64 + c * 8 + d + e) as f64;
+            x.sin()
+        });
+        let mode = SperrCompressionMode::PointwiseError {
+            pwe: Positive(0.01),
+        };
+
+        let encoded = compress(data.view(), &mode).unwrap();
+        let decoded = decompress(&encoded).unwrap();
+
+        for num_threads in [1, 2, 4, 64] {
+            let num_threads = NonZeroUsize::new(num_threads);
+
+            let encoded_parallel =
+                compress_with_threads(data.view(), &mode, default_chunks(), num_threads).unwrap();
+            assert_eq!(encoded_parallel, encoded);
+
+            let decoded_parallel = decompress_with_threads(&encoded, num_threads).unwrap();
+            assert_eq!(decoded_parallel, decoded);
+        }
+    }
+
+    #[test]
+    fn random_access_slices() {
+        let data = Array::<f64, _>::from_shape_fn((5, 3, 4, 8, 8), |(a, b, c, d, e)| {
+            #[expect(clippy::cast_precision_loss)]
+            let x = (a * 256 + b * 64 + c * 8 + d + e) as f64;
+            x.sin()
+        });
+        let mode = SperrCompressionMode::PointwiseError {
+            pwe: Positive(0.01),
+        };
+
+        let encoded = compress(data.view(), &mode).unwrap();
+        let AnyArray::F64(decoded) = decompress(&encoded).unwrap() else {
+            panic!("unexpected decoded dtype");
+        };
+
+        for indices in [&[][..], &[0], &[4, 1], &[3, 0, 3]] {
+            for num_threads in [None, NonZeroUsize::new(2)] {
+                let mut slices = Array::<f64, _>::zeros((indices.len(), 3, 4, 8, 8)).into_dyn();
+                decompress_slices_into(
+                    &encoded,
+                    indices,
+                    AnyArrayViewMut::F64(slices.view_mut()),
+                    num_threads,
+                )
+                .unwrap();
+
+                assert_eq!(slices, decoded.select(Axis(0), indices));
+            }
+        }
+
+        let data = data
+            .index_axis(Axis(0), 0)
+            .index_axis(Axis(0), 0)
+            .to_owned();
+        let encoded = compress(data.view(), &mode).unwrap();
+        let AnyArray::F64(decoded) = decompress(&encoded).unwrap() else {
+            panic!("unexpected decoded dtype");
+        };
+
+        let mut slices = Array::<f64, _>::zeros((2, 8, 8)).into_dyn();
+        decompress_slices_into(
+            &encoded,
+            &[3, 1],
+            AnyArrayViewMut::F64(slices.view_mut()),
+            None,
+        )
+        .unwrap();
+        assert_eq!(slices, decoded.select(Axis(0), &[3, 1]));
+    }
+
+    #[test]
+    fn random_access_slices_out_of_bounds() {
+        let data = Array::<f32, _>::zeros((2, 1, 4, 4));
+        let mode = SperrCompressionMode::PointwiseError {
+            pwe: Positive(0.01),
+        };
+        let encoded = compress(data.view(), &mode).unwrap();
+
+        let mut slices = Array::<f32, _>::zeros((1, 1, 4, 4)).into_dyn();
+        assert!(matches!(
+            decompress_slices_into(
+                &encoded,
+                &[2],
+                AnyArrayViewMut::F32(slices.view_mut()),
+                None
+            ),
+            Err(SperrCodecError::SliceIndexOutOfBounds { index: 2, len: 2 })
+        ));
+        assert!(matches!(
+            decompress_slices_into(
+                &encoded,
+                &[0, 1],
+                AnyArrayViewMut::F32(slices.view_mut()),
+                None
+            ),
+            Err(SperrCodecError::MismatchedDecodeIntoArray { .. })
+        ));
+    }
+
+    #[test]
+    fn legacy_slices_without_index() {
+        let data = Array::<f32, _>::from_shape_fn((3, 2, 4, 4), |(a, b, c, d)| {
+            #[expect(clippy::cast_precision_loss)]
+            let x = (a * 32 + b * 16 + c * 4 + d) as f32;
+            x.sin()
+        });
+        let mode = SperrCompressionMode::PointwiseError {
+            pwe: Positive(0.01),
+        };
+
+        let encoded = compress(data.view(), &mode).unwrap();
+        let decoded = decompress(&encoded).unwrap();
+
+        let (header, slices) = postcard::take_from_bytes::<CompressionHeader>(&encoded).unwrap();
+        let mut legacy = postcard::to_extend(
+            &(header.dtype, &header.shape, StaticCodecVersion::<0, 2, 0>),
+            Vec::new(),
+        )
+        .unwrap();
+        legacy.extend_from_slice(slices);
+
+        assert_eq!(decompress(&legacy).unwrap(), decoded);
+
+        let AnyArray::F32(decoded) = decoded else {
+            panic!("unexpected decoded dtype");
+        };
+        let mut slices = Array::<f32, _>::zeros((1, 2, 4, 4)).into_dyn();
+        decompress_slices_into(&legacy, &[1], AnyArrayViewMut::F32(slices.view_mut()), None)
+            .unwrap();
+        assert_eq!(slices, decoded.select(Axis(0), &[1]));
+    }
+
+    #[test]
+    fn small_chunks() {
+        let data = Array::<f64, _>::from_shape_fn((2, 24, 24, 24), |(a, b, c, d)| {
+            #[expect(clippy::cast_precision_loss)]
+            let x = (a * 4096 + b * 256 + c * 16 + d) as f64;
+            x.cos()
+        });
+        let mode = SperrCompressionMode::PointwiseError {
+            pwe: Positive(0.01),
+        };
+        let chunks = (
+            NonZeroUsize::new(8).unwrap(),
+            NonZeroUsize::new(16).unwrap(),
+            NonZeroUsize::new(24).unwrap(),
+        );
+
+        let encoded = compress_with_threads(data.view(), &mode, chunks, None).unwrap();
+        let AnyArray::F64(decoded) = decompress(&encoded).unwrap() else {
+            panic!("unexpected decoded dtype");
+        };
+
+        assert_eq!(decoded.shape(), data.shape());
+        for (d, o) in decoded.iter().zip(data.iter()) {
+            assert!((d - o).abs() <= 0.01);
+        }
+    }
 }
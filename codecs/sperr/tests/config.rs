@@ -1,6 +1,9 @@
 #![expect(missing_docs)]
 
-use ::{ndarray as _, num_traits as _, postcard as _, schemars as _, sperr as _, thiserror as _};
+use ::{
+    ndarray as _, num_traits as _, numcodecs_utils as _, postcard as _, schemars as _, sperr as _,
+    thiserror as _,
+};
 
 use numcodecs::StaticCodec;
 use numcodecs_sperr::{SperrCodec, SperrCompressionMode};
@@ -76,3 +79,38 @@ fn q_config() {
         SperrCompressionMode::QuantisationStep { q } if q.get() == 1.5
     ));
 }
+
+#[test]
+fn num_threads_config() {
+    let codec = SperrCodec::from_config(
+        Deserialize::deserialize(json!({
+            "mode": "pwe",
+            "pwe": 0.1,
+            "num_threads": 4,
+        }))
+        .unwrap(),
+    );
+
+    assert_eq!(codec.num_threads.map(std::num::NonZeroUsize::get), Some(4));
+}
+
+#[test]
+fn chunks_config() {
+    let codec = SperrCodec::from_config(
+        Deserialize::deserialize(json!({
+            "mode": "pwe",
+            "pwe": 0.1,
+            "chunks": [64, 128, 256],
+        }))
+        .unwrap(),
+    );
+
+    assert_eq!(
+        (
+            codec.chunks.0.get(),
+            codec.chunks.1.get(),
+            codec.chunks.2.get()
+        ),
+        (64, 128, 256)
+    );
+}
@@ -1,8 +1,8 @@
 #![expect(missing_docs)]
 
 use ::{
-    ndarray as _, num_traits as _, postcard as _, schemars as _, serde as _, serde_json as _,
-    sperr as _, thiserror as _,
+    ndarray as _, num_traits as _, numcodecs_utils as _, postcard as _, schemars as _, serde as _,
+    serde_json as _, sperr as _, thiserror as _,
 };
 
 use numcodecs::{DynCodecType, StaticCodecType};
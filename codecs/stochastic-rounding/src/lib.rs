@@ -19,6 +19,7 @@
 
 use std::borrow::Cow;
 use std::hash::{Hash, Hasher};
+use std::num::NonZeroU8;
 
 use ndarray::{Array, ArrayBase, Data, Dimension};
 use num_traits::Float;
@@ -26,6 +27,7 @@ use numcodecs::{
     AnyArray, AnyArrayAssignError, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray,
     Codec, StaticCodec, StaticCodecConfig, StaticCodecVersion,
 };
+use numcodecs_round::{relative_exponent, significant_digits_exponent};
 use rand::{
     SeedableRng,
     distr::{Distribution, Open01},
@@ -36,27 +38,69 @@ use thiserror::Error;
 use wyhash::{WyHash, WyRng};
 
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
-#[serde(deny_unknown_fields)]
-/// Codec that stochastically rounds the data to the nearest multiple of
-/// `precision` on encoding and passes through the input unchanged during
-/// decoding.
+#[schemars(deny_unknown_fields)]
+/// Codec that stochastically rounds the data on encoding and passes through
+/// the input unchanged during decoding.
 ///
-/// The nearest representable multiple is chosen such that the absolute
-/// difference between the original value and the rounded value do not exceed
-/// the precision. Therefore, the rounded value may have a non-zero remainder.
+/// The data is rounded to the nearest multiple of an absolute or of a
+/// per-value precision, depending on the rounding mode, such that the
+/// absolute difference between the original value and the rounded value does
+/// not exceed this precision. Therefore, the rounded value may have a
+/// non-zero remainder.
 ///
 /// This codec first hashes the input array data and shape to then `seed` a
 /// pseudo-random number generator that is used to sample the stochasticity for
 /// rounding. Therefore, passing in the same input with the same `seed` will
 /// produce the same stochasticity and thus the same encoded output.
 pub struct StochasticRoundingCodec {
-    /// The precision of the rounding operation
-    pub precision: NonNegative<f64>,
+    /// Stochastic rounding mode
+    #[serde(flatten)]
+    pub mode: StochasticRoundingMode,
     /// Seed for the random generator
     pub seed: u64,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default, rename = "_version")]
-    pub version: StaticCodecVersion<1, 0, 0>,
+    pub version: StaticCodecVersion<1, 1, 0>,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "mode")]
+#[serde(deny_unknown_fields)]
+/// Stochastic rounding mode
+pub enum StochasticRoundingMode {
+    /// Stochastically round to the specified number of significant decimal
+    /// `digits`.
+    ///
+    /// Each value is rounded to a nearest multiple of a power of two that is
+    /// chosen such that the absolute error is guaranteed to be at most one
+    /// unit in the last significant decimal digit, i.e.
+    /// `|x - c| <= 10^(floor(log10(|x|)) - digits + 1)`.
+    #[serde(rename = "digits")]
+    SignificantDigits {
+        /// Number of significant decimal digits to keep
+        digits: NonZeroU8,
+    },
+    /// Stochastically round relative to the magnitude of each value.
+    ///
+    /// Each value is rounded to a nearest multiple of a power of two that is
+    /// chosen such that the relative error is guaranteed to be at most the
+    /// precision, i.e. `|x - c| <= precision * |x|`.
+    #[serde(rename = "rel")]
+    Relative {
+        /// The relative precision of the rounding operation
+        precision: NonNegative<f64>,
+    },
+    /// Stochastically round to a nearest multiple of an absolute `precision`.
+    ///
+    /// The absolute error is guaranteed to be at most the precision, i.e.
+    /// `|x - c| <= precision`.
+    ///
+    /// This is the default mode, which is used if no `mode` is specified.
+    #[serde(untagged)]
+    Absolute {
+        /// The precision of the rounding operation
+        precision: NonNegative<f64>,
+    },
 }
 
 impl Codec for StochasticRoundingCodec {
@@ -64,17 +108,29 @@ impl Codec for StochasticRoundingCodec {
 
     fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
         match data {
-            #[expect(clippy::cast_possible_truncation)]
-            AnyCowArray::F32(data) => Ok(AnyArray::F32(stochastic_rounding(
-                data,
-                NonNegative(self.precision.0 as f32),
-                self.seed,
-            ))),
-            AnyCowArray::F64(data) => Ok(AnyArray::F64(stochastic_rounding(
-                data,
-                self.precision,
-                self.seed,
-            ))),
+            AnyCowArray::F32(data) => Ok(AnyArray::F32(match self.mode {
+                #[expect(clippy::cast_possible_truncation)]
+                StochasticRoundingMode::Absolute { precision } => {
+                    stochastic_rounding(data, NonNegative(precision.0 as f32), self.seed)
+                }
+                StochasticRoundingMode::SignificantDigits { digits } => {
+                    stochastic_rounding_significant_digits(data, digits, self.seed)
+                }
+                StochasticRoundingMode::Relative { precision } => {
+                    stochastic_rounding_relative(data, precision, self.seed)
+                }
+            })),
+            AnyCowArray::F64(data) => Ok(AnyArray::F64(match self.mode {
+                StochasticRoundingMode::Absolute { precision } => {
+                    stochastic_rounding(data, precision, self.seed)
+                }
+                StochasticRoundingMode::SignificantDigits { digits } => {
+                    stochastic_rounding_significant_digits(data, digits, self.seed)
+                }
+                StochasticRoundingMode::Relative { precision } => {
+                    stochastic_rounding_relative(data, precision, self.seed)
+                }
+            })),
             encoded => Err(StochasticRoundingCodecError::UnsupportedDtype(
                 encoded.dtype(),
             )),
@@ -197,12 +253,86 @@ pub fn stochastic_rounding<T: FloatExt, S: Data<Elem = T>, D: Dimension>(
 where
     Open01: Distribution<T>,
 {
-    let mut encoded = data.into_owned();
-
     if precision.0.is_zero() {
-        return encoded;
+        return data.into_owned();
     }
 
+    stochastic_rounding_with_precision(data, seed, |_| Some(precision.0))
+}
+
+/// Stochastically rounds the `data` to the specified number of significant
+/// decimal `digits`.
+///
+/// Each value is rounded to a nearest multiple of a power of two that is
+/// chosen such that the absolute error is guaranteed to be at most one unit in
+/// the last significant decimal digit, i.e.
+/// `|x - c| <= 10^(floor(log10(|x|)) - digits + 1)`.
+///
+/// Zero, subnormal, infinite, and NaN values are returned unchanged.
+///
+/// This function first hashes the input array data and shape to then `seed` a
+/// pseudo-random number generator that is used to sample the stochasticity for
+/// rounding. Therefore, passing in the same input with the same `seed` will
+/// produce the same stochasticity and thus the same encoded output.
+#[must_use]
+pub fn stochastic_rounding_significant_digits<T: FloatExt, S: Data<Elem = T>, D: Dimension>(
+    data: ArrayBase<S, D>,
+    digits: NonZeroU8,
+    seed: u64,
+) -> Array<T, D>
+where
+    Open01: Distribution<T>,
+{
+    stochastic_rounding_with_precision(data, seed, |x| {
+        let k = significant_digits_exponent(x, digits)?;
+
+        Some((T::one() + T::one()).powi(k))
+    })
+}
+
+/// Stochastically rounds the `data` relative to the magnitude of each value.
+///
+/// Each value is rounded to a nearest multiple of a power of two that is
+/// chosen such that the relative error is guaranteed to be at most the
+/// precision, i.e. `|x - c| <= precision * |x|`.
+///
+/// If the precision is zero, subnormal, or infinite, the `data` is returned
+/// unchanged. Zero, subnormal, infinite, and NaN values are also returned
+/// unchanged.
+///
+/// This function first hashes the input array data and shape to then `seed` a
+/// pseudo-random number generator that is used to sample the stochasticity for
+/// rounding. Therefore, passing in the same input with the same `seed` will
+/// produce the same stochasticity and thus the same encoded output.
+#[must_use]
+pub fn stochastic_rounding_relative<T: FloatExt, S: Data<Elem = T>, D: Dimension>(
+    data: ArrayBase<S, D>,
+    precision: NonNegative<f64>,
+    seed: u64,
+) -> Array<T, D>
+where
+    Open01: Distribution<T>,
+{
+    stochastic_rounding_with_precision(data, seed, |x| {
+        let k = relative_exponent(x, precision.0)?;
+
+        Some((T::one() + T::one()).powi(k))
+    })
+}
+
+/// Stochastically rounds every finite value `x` in the `data` to a nearest
+/// multiple of its `precision(x)`, or leaves it unchanged if the `precision`
+/// is `None` or zero.
+fn stochastic_rounding_with_precision<T: FloatExt, S: Data<Elem = T>, D: Dimension>(
+    data: ArrayBase<S, D>,
+    seed: u64,
+    precision: impl Fn(T) -> Option<T>,
+) -> Array<T, D>
+where
+    Open01: Distribution<T>,
+{
+    let mut encoded = data.into_owned();
+
     let mut hasher = WyHash::with_seed(seed);
     // hashing the shape provides a prefix for the flattened data
     encoded.shape().hash(&mut hasher);
@@ -221,21 +351,37 @@ where
             continue;
         }
 
-        let remainder = x.rem_euclid(precision.0);
+        let Some(precision) = precision(*x) else {
+            continue;
+        };
+
+        // the precision may underflow to zero for tiny values, which cannot
+        //  be rounded
+        if precision.is_zero() {
+            continue;
+        }
+
+        // an infinite precision rounds every finite value to zero
+        if precision.is_infinite() {
+            *x = *x * T::zero();
+            continue;
+        }
+
+        let remainder = x.rem_euclid(precision);
 
         // compute the nearest multiples of precision based on the remainder
         // correct max 1 ULP rounding errors to ensure that the nearest
         //  multiples are at most precision away from the original value
         let mut lower = *x - remainder;
-        if (*x - lower) > precision.0 {
+        if (*x - lower) > precision {
             lower = lower.next_up();
         }
-        let mut upper = *x + (precision.0 - remainder);
-        if (upper - *x) > precision.0 {
+        let mut upper = *x + (precision - remainder);
+        if (upper - *x) > precision {
             upper = upper.next_down();
         }
 
-        let threshold = remainder / precision.0;
+        let threshold = remainder / precision;
 
         let u01: T = Open01.sample(&mut rng);
 
@@ -309,8 +455,10 @@ impl FloatExt for f64 {
 }
 
 #[cfg(test)]
+#[expect(clippy::unwrap_used)]
 mod tests {
     use ndarray::{array, linspace};
+    use serde_json::json;
 
     use super::*;
 
@@ -411,4 +559,105 @@ mod tests {
             assert!((r - d).abs() <= precision);
         }
     }
+
+    #[test]
+    fn round_significant_digits_error_bound() {
+        let data = Array::from_iter((-300..300).flat_map(|e| {
+            [1.0, 1.234_567_89, 7.654_321, 9.999_999_9]
+                .map(|f: f64| f * 10.0_f64.powi(e))
+                .into_iter()
+                .flat_map(|x| [x, -x])
+        }));
+
+        for digits in 1..=15 {
+            let rounded = stochastic_rounding_significant_digits(
+                data.view(),
+                NonZeroU8::new(digits).unwrap(),
+                42,
+            );
+
+            for (x, c) in data.iter().zip(&rounded) {
+                let bound = 10.0_f64.powf(x.abs().log10().floor() - f64::from(digits) + 1.0);
+                assert!((x - c).abs() <= bound, "{x} {c} {digits}");
+            }
+        }
+    }
+
+    #[test]
+    fn round_relative_error_bound() {
+        let data = Array::from_iter((-300..300).flat_map(|e| {
+            [1.0, 1.234_567_89, 7.654_321, 9.999_999_9]
+                .map(|f: f64| f * 10.0_f64.powi(e))
+                .into_iter()
+                .flat_map(|x| [x, -x])
+        }));
+
+        for precision in [1e-12, 1e-6, 0.001, 0.01, 0.1, 0.5, 1.0, 2.0, 1e300] {
+            let rounded = stochastic_rounding_relative(data.view(), NonNegative(precision), 42);
+
+            for (x, c) in data.iter().zip(&rounded) {
+                assert!((x - c).abs() <= precision * x.abs(), "{x} {c} {precision}");
+            }
+        }
+
+        let rounded = stochastic_rounding_relative(data.view(), NonNegative(0.0), 42);
+        assert_eq!(data, rounded);
+    }
+
+    #[test]
+    fn round_modes_edge_cases() {
+        let data = array![
+            -f64::NAN,
+            -f64::INFINITY,
+            -f64::MAX,
+            -f64::MIN_POSITIVE,
+            -0.0,
+            0.0,
+            f64::MIN_POSITIVE / 2.0,
+            f64::MAX,
+            f64::INFINITY,
+            f64::NAN
+        ];
+
+        for rounded in [
+            stochastic_rounding_significant_digits(data.view(), NonZeroU8::new(1).unwrap(), 42),
+            stochastic_rounding_relative(data.view(), NonNegative(0.1), 42),
+        ] {
+            for (d, r) in data.iter().zip(rounded) {
+                assert!(
+                    (d - r).abs() <= d.abs()
+                        || (d.is_infinite() && d.to_bits() == r.to_bits())
+                        || d.to_bits() == r.to_bits()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn absolute_mode_is_default() {
+        for config in [
+            json!({ "precision": 0.5, "seed": 42 }),
+            json!({ "precision": 0.5, "seed": 42, "_version": "1.0.0" }),
+        ] {
+            let codec: StochasticRoundingCodec = serde_json::from_value(config).unwrap();
+            assert!(
+                codec.mode
+                    == StochasticRoundingMode::Absolute {
+                        precision: NonNegative(0.5)
+                    }
+            );
+        }
+
+        assert_eq!(
+            serde_json::to_value(StochasticRoundingCodec {
+                mode: StochasticRoundingMode::Absolute {
+                    precision: NonNegative(0.5)
+                },
+                seed: 42,
+                version: StaticCodecVersion,
+            })
+            .unwrap(),
+            json!({ "precision": 0.5, "seed": 42, "_version": "1.1.0" })
+        );
+    }
 }
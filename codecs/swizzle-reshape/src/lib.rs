@@ -26,7 +26,12 @@ use std::{
 use ndarray::{Array, ArrayBase, ArrayView, ArrayViewMut, Data, IxDyn};
 use numcodecs::{
     AnyArray, AnyArrayAssignError, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray,
-    Codec, StaticCodec, StaticCodecConfig, StaticCodecVersion,
+    Codec, DynCodec, ErasedDynCodec, ErasedError, StaticCodec, StaticCodecConfig,
+    StaticCodecVersion,
+};
+use numcodecs_registry::GlobalRegistry;
+use numcodecs_utils::framing::{
+    FramingError, decode_framed, encode_framed, framed_array, framed_bytes,
 };
 use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
 use serde::{
@@ -40,12 +45,21 @@ use thiserror::Error;
 #[serde(deny_unknown_fields)]
 /// Codec to swizzle/swap the axes of an array and reshape it.
 ///
-/// This codec does not store metadata about the original shape of the array.
-/// Since axes that have been combined during encoding cannot be split without
-/// further information, decoding may fail if an output array is not provided.
+/// By default, this codec does not store metadata about the original shape of
+/// the array. Since axes that have been combined during encoding cannot be
+/// split without further information, decoding may fail if an output array is
+/// not provided.
+///
+/// Swizzling and splitting axes is always supported since no additional
+/// information about the array's shape is required to reconstruct it.
 ///
-/// Swizzling axes is always supported since no additional information about the
-/// array's shape is required to reconstruct it.
+/// If a wrapped `codec` is provided, the swizzled and reshaped array is encoded
+/// with it, and the original shape of the array is stored next to the wrapped
+/// codec's encoding such that merged axes can always be decoded. Since the
+/// shape is stored outside of the wrapped codec's encoding, it is preserved
+/// even if the wrapped codec is lossy. The encoded array is then a
+/// one-dimensional byte array, and must only be compressed further with
+/// lossless codecs.
 pub struct SwizzleReshapeCodec {
     /// The permutation of the axes that is applied on encoding.
     ///
@@ -62,12 +76,26 @@ pub struct SwizzleReshapeCodec {
     ///   of axes as the input array
     /// - `[[0], [{}]]` in contrast collapses all other axes into one, i.e.
     ///   the encoded array is two-dimensional
+    ///
+    /// A single axis can also be split into several axes with explicit sizes,
+    /// e.g. `[{"split": 0, "sizes": [2, 3]}, {}]` splits the first axis of
+    /// length six into two axes of lengths two and three.
     pub axes: Vec<AxisGroup>,
+    /// The configuration of the optional wrapped codec, which encodes the
+    /// swizzled and reshaped array while the original shape of the array is
+    /// stored alongside its encoding.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "serialize_optional_codec")]
+    #[serde(deserialize_with = "deserialize_optional_codec")]
+    #[schemars(schema_with = "ErasedDynCodec::codec_config_schema")]
+    pub codec: Option<ErasedDynCodec>,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default, rename = "_version")]
-    pub version: StaticCodecVersion<1, 0, 0>,
+    pub version: SwizzleReshapeCodecVersion,
 }
 
+type SwizzleReshapeCodecVersion = StaticCodecVersion<1, 1, 0>;
+
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 #[serde(deny_unknown_fields)]
@@ -77,6 +105,19 @@ pub enum AxisGroup {
     Group(Vec<Axis>),
     /// All remaining axes, each in a separate single-axis group
     AllRest(Rest),
+    /// A single input axis, which is split into multiple axes
+    Split(AxisSplit),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+/// A single input axis that is split into multiple axes with explicit sizes
+pub struct AxisSplit {
+    /// The index of the input axis that is split
+    pub split: usize,
+    /// The sizes of the split axes, whose product must equal the length of the
+    /// input axis
+    pub sizes: Vec<usize>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
@@ -94,55 +135,55 @@ impl Codec for SwizzleReshapeCodec {
     type Error = SwizzleReshapeCodecError;
 
     fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
-        match data {
-            AnyCowArray::U8(data) => Ok(AnyArray::U8(swizzle_reshape(data, &self.axes)?)),
-            AnyCowArray::U16(data) => Ok(AnyArray::U16(swizzle_reshape(data, &self.axes)?)),
-            AnyCowArray::U32(data) => Ok(AnyArray::U32(swizzle_reshape(data, &self.axes)?)),
-            AnyCowArray::U64(data) => Ok(AnyArray::U64(swizzle_reshape(data, &self.axes)?)),
-            AnyCowArray::I8(data) => Ok(AnyArray::I8(swizzle_reshape(data, &self.axes)?)),
-            AnyCowArray::I16(data) => Ok(AnyArray::I16(swizzle_reshape(data, &self.axes)?)),
-            AnyCowArray::I32(data) => Ok(AnyArray::I32(swizzle_reshape(data, &self.axes)?)),
-            AnyCowArray::I64(data) => Ok(AnyArray::I64(swizzle_reshape(data, &self.axes)?)),
-            AnyCowArray::F32(data) => Ok(AnyArray::F32(swizzle_reshape(data, &self.axes)?)),
-            AnyCowArray::F64(data) => Ok(AnyArray::F64(swizzle_reshape(data, &self.axes)?)),
-            data => Err(SwizzleReshapeCodecError::UnsupportedDtype(data.dtype())),
-        }
+        let shape = data.shape().to_vec();
+
+        let encoded = match data {
+            AnyCowArray::U8(data) => AnyArray::U8(swizzle_reshape(data, &self.axes)?),
+            AnyCowArray::U16(data) => AnyArray::U16(swizzle_reshape(data, &self.axes)?),
+            AnyCowArray::U32(data) => AnyArray::U32(swizzle_reshape(data, &self.axes)?),
+            AnyCowArray::U64(data) => AnyArray::U64(swizzle_reshape(data, &self.axes)?),
+            AnyCowArray::I8(data) => AnyArray::I8(swizzle_reshape(data, &self.axes)?),
+            AnyCowArray::I16(data) => AnyArray::I16(swizzle_reshape(data, &self.axes)?),
+            AnyCowArray::I32(data) => AnyArray::I32(swizzle_reshape(data, &self.axes)?),
+            AnyCowArray::I64(data) => AnyArray::I64(swizzle_reshape(data, &self.axes)?),
+            AnyCowArray::F32(data) => AnyArray::F32(swizzle_reshape(data, &self.axes)?),
+            AnyCowArray::F64(data) => AnyArray::F64(swizzle_reshape(data, &self.axes)?),
+            data => return Err(SwizzleReshapeCodecError::UnsupportedDtype(data.dtype())),
+        };
+
+        let Some(codec) = &self.codec else {
+            return Ok(encoded);
+        };
+
+        let encoded = codec
+            .encode(encoded.into_cow())
+            .map_err(|err| SwizzleReshapeCodecError::InnerEncodeFailed { source: err })?;
+
+        let encoded = encode_framed(
+            &ShapeHeader {
+                shape: Cow::Borrowed(&shape),
+                version: StaticCodecVersion,
+            },
+            &encoded,
+        )?;
+
+        Ok(framed_array(encoded))
     }
 
     fn decode(&self, encoded: AnyCowArray) -> Result<AnyArray, Self::Error> {
-        match encoded {
-            AnyCowArray::U8(encoded) => {
-                Ok(AnyArray::U8(undo_swizzle_reshape(encoded, &self.axes)?))
-            }
-            AnyCowArray::U16(encoded) => {
-                Ok(AnyArray::U16(undo_swizzle_reshape(encoded, &self.axes)?))
-            }
-            AnyCowArray::U32(encoded) => {
-                Ok(AnyArray::U32(undo_swizzle_reshape(encoded, &self.axes)?))
-            }
-            AnyCowArray::U64(encoded) => {
-                Ok(AnyArray::U64(undo_swizzle_reshape(encoded, &self.axes)?))
-            }
-            AnyCowArray::I8(encoded) => {
-                Ok(AnyArray::I8(undo_swizzle_reshape(encoded, &self.axes)?))
-            }
-            AnyCowArray::I16(encoded) => {
-                Ok(AnyArray::I16(undo_swizzle_reshape(encoded, &self.axes)?))
-            }
-            AnyCowArray::I32(encoded) => {
-                Ok(AnyArray::I32(undo_swizzle_reshape(encoded, &self.axes)?))
-            }
-            AnyCowArray::I64(encoded) => {
-                Ok(AnyArray::I64(undo_swizzle_reshape(encoded, &self.axes)?))
-            }
-            AnyCowArray::F32(encoded) => {
-                Ok(AnyArray::F32(undo_swizzle_reshape(encoded, &self.axes)?))
-            }
-            AnyCowArray::F64(encoded) => {
-                Ok(AnyArray::F64(undo_swizzle_reshape(encoded, &self.axes)?))
-            }
-            encoded => Err(SwizzleReshapeCodecError::UnsupportedDtype(encoded.dtype())),
-        }
+        let Some(codec) = &self.codec else {
+            return undo_swizzle_reshape_any(encoded, None, &self.axes);
+        };
+
+        let encoded = encoded.view();
+        let framed = framed_bytes(&encoded)?;
+        let (header, encoded, _) = decode_framed::<ShapeHeader>(&framed)?;
+
+        let decoded = codec
+            .decode(encoded.into_cow())
+            .map_err(|err| SwizzleReshapeCodecError::InnerDecodeFailed { source: err })?;
+
+        undo_swizzle_reshape_any(decoded.into_cow(), Some(&header.shape), &self.axes)
     }
 
     fn decode_into(
@@ -150,47 +191,27 @@ impl Codec for SwizzleReshapeCodec {
         encoded: AnyArrayView,
         decoded: AnyArrayViewMut,
     ) -> Result<(), Self::Error> {
-        match (encoded, decoded) {
-            (AnyArrayView::U8(encoded), AnyArrayViewMut::U8(decoded)) => {
-                undo_swizzle_reshape_into(encoded, decoded, &self.axes)
-            }
-            (AnyArrayView::U16(encoded), AnyArrayViewMut::U16(decoded)) => {
-                undo_swizzle_reshape_into(encoded, decoded, &self.axes)
-            }
-            (AnyArrayView::U32(encoded), AnyArrayViewMut::U32(decoded)) => {
-                undo_swizzle_reshape_into(encoded, decoded, &self.axes)
-            }
-            (AnyArrayView::U64(encoded), AnyArrayViewMut::U64(decoded)) => {
-                undo_swizzle_reshape_into(encoded, decoded, &self.axes)
-            }
-            (AnyArrayView::I8(encoded), AnyArrayViewMut::I8(decoded)) => {
-                undo_swizzle_reshape_into(encoded, decoded, &self.axes)
-            }
-            (AnyArrayView::I16(encoded), AnyArrayViewMut::I16(decoded)) => {
-                undo_swizzle_reshape_into(encoded, decoded, &self.axes)
-            }
-            (AnyArrayView::I32(encoded), AnyArrayViewMut::I32(decoded)) => {
-                undo_swizzle_reshape_into(encoded, decoded, &self.axes)
-            }
-            (AnyArrayView::I64(encoded), AnyArrayViewMut::I64(decoded)) => {
-                undo_swizzle_reshape_into(encoded, decoded, &self.axes)
-            }
-            (AnyArrayView::F32(encoded), AnyArrayViewMut::F32(decoded)) => {
-                undo_swizzle_reshape_into(encoded, decoded, &self.axes)
-            }
-            (AnyArrayView::F64(encoded), AnyArrayViewMut::F64(decoded)) => {
-                undo_swizzle_reshape_into(encoded, decoded, &self.axes)
-            }
-            (encoded, decoded) if encoded.dtype() != decoded.dtype() => {
-                Err(SwizzleReshapeCodecError::MismatchedDecodeIntoArray {
-                    source: AnyArrayAssignError::DTypeMismatch {
-                        src: encoded.dtype(),
-                        dst: decoded.dtype(),
-                    },
-                })
-            }
-            (encoded, _decoded) => Err(SwizzleReshapeCodecError::UnsupportedDtype(encoded.dtype())),
+        let Some(codec) = &self.codec else {
+            return undo_swizzle_reshape_into_any(encoded, decoded, &self.axes);
+        };
+
+        let framed = framed_bytes(&encoded)?;
+        let (header, encoded, _) = decode_framed::<ShapeHeader>(&framed)?;
+
+        if decoded.shape() != &*header.shape {
+            return Err(SwizzleReshapeCodecError::MismatchedDecodeIntoArray {
+                source: AnyArrayAssignError::ShapeMismatch {
+                    src: header.shape.into_owned(),
+                    dst: decoded.shape().to_vec(),
+                },
+            });
         }
+
+        let encoded = codec
+            .decode(encoded.into_cow())
+            .map_err(|err| SwizzleReshapeCodecError::InnerDecodeFailed { source: err })?;
+
+        undo_swizzle_reshape_into_any(encoded.view(), decoded, &self.axes)
     }
 }
 
@@ -249,6 +270,54 @@ pub enum SwizzleReshapeCodecError {
         "SwizzleReshape cannot encode or decode with an axis permutation that contains multiple rest-axes markers"
     )]
     MultipleRestAxes,
+    /// [`SwizzleReshapeCodec`] cannot split an axis of length `len` into axes
+    /// with `sizes` whose product differs from the length
+    #[error(
+        "SwizzleReshape cannot split the axis {index} of length {len} into axes with sizes {sizes:?} whose product differs from the length"
+    )]
+    InvalidAxisSplit {
+        /// The index of the axis that is split
+        index: usize,
+        /// The length of the axis that is split
+        len: usize,
+        /// The sizes of the split axes
+        sizes: Vec<usize>,
+    },
+    /// [`SwizzleReshapeCodec`] cannot decode an encoded array whose shape does
+    /// not match the axis permutation
+    #[error(
+        "SwizzleReshape cannot decode an encoded array of shape {shape:?} that does not match the axis permutation"
+    )]
+    MismatchedEncodedShape {
+        /// The shape of the encoded array
+        shape: Vec<usize>,
+    },
+    /// [`SwizzleReshapeCodec`] failed to encode the swizzled and reshaped
+    /// array with the wrapped codec
+    #[error(
+        "SwizzleReshape failed to encode the swizzled and reshaped array with the wrapped codec"
+    )]
+    InnerEncodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`SwizzleReshapeCodec`] failed to decode the swizzled and reshaped
+    /// array with the wrapped codec
+    #[error(
+        "SwizzleReshape failed to decode the swizzled and reshaped array with the wrapped codec"
+    )]
+    InnerDecodeFailed {
+        /// Opaque source error
+        source: ErasedError,
+    },
+    /// [`SwizzleReshapeCodec`] failed to frame or unframe the wrapped codec's
+    /// encoding together with the original shape of the array
+    #[error("SwizzleReshape failed to frame or unframe the wrapped codec's encoding")]
+    FramingFailed {
+        /// The source of the error
+        #[from]
+        source: FramingError,
+    },
     /// [`SwizzleReshapeCodec`] cannot decode into the provided array
     #[error("SwizzleReshape cannot decode into the provided array")]
     MismatchedDecodeIntoArray {
@@ -270,6 +339,8 @@ pub enum SwizzleReshapeCodecError {
 ///   permutation does not contain every axis index exactly once
 /// - [`SwizzleReshapeCodecError::MultipleRestAxes`] if the `axes` permutation
 ///   contains more than one [`Rest`]-axes marker
+/// - [`SwizzleReshapeCodecError::InvalidAxisSplit`] if the sizes of an
+///   [`AxisSplit`] do not match the length of the split axis
 pub fn swizzle_reshape<T: Copy, S: Data<Elem = T>>(
     data: ArrayBase<S, IxDyn>,
     axes: &[AxisGroup],
@@ -278,7 +349,7 @@ pub fn swizzle_reshape<T: Copy, S: Data<Elem = T>>(
         permutation,
         swizzled_shape,
         new_shape,
-    } = validate_into_axes_shape(&data, axes)?;
+    } = validate_into_axes_shape(data.shape(), axes)?;
 
     let swizzled: ArrayBase<S, ndarray::Dim<ndarray::IxDynImpl>> = data.permuted_axes(permutation);
     assert_eq!(swizzled.shape(), swizzled_shape, "incorrect swizzled shape");
@@ -295,8 +366,8 @@ pub fn swizzle_reshape<T: Copy, S: Data<Elem = T>>(
 /// Reverts the swizzle and reshape of the `encoded` array with the `axes` and
 /// returns the original array.
 ///
-/// Since the shape of the original array is not known, only permutations of
-/// axes are supported.
+/// Since the shape of the original array is not known, only permutations and
+/// splits of axes are supported.
 ///
 /// # Errors
 ///
@@ -309,18 +380,59 @@ pub fn swizzle_reshape<T: Copy, S: Data<Elem = T>>(
 ///   permutation does not contain every axis index exactly once
 /// - [`SwizzleReshapeCodecError::MultipleRestAxes`] if the `axes` permutation
 ///   contains more than one [`Rest`]-axes marker
+/// - [`SwizzleReshapeCodecError::MismatchedEncodedShape`] if the `encoded`
+///   array's shape does not match the `axes`
 pub fn undo_swizzle_reshape<T: Copy, S: Data<Elem = T>>(
     encoded: ArrayBase<S, IxDyn>,
     axes: &[AxisGroup],
 ) -> Result<Array<T, IxDyn>, SwizzleReshapeCodecError> {
     if !axes.iter().all(|axis| match axis {
         AxisGroup::Group(axes) => matches!(axes.as_slice(), [Axis::Index(_)]),
-        AxisGroup::AllRest(Rest) => true,
+        AxisGroup::AllRest(Rest) | AxisGroup::Split(_) => true,
     }) {
         return Err(SwizzleReshapeCodecError::CannotDecodeMergedAxes);
     }
 
-    let SwizzleReshapeAxes { permutation, .. } = validate_into_axes_shape(&encoded, axes)?;
+    let shape = unmerged_decoded_shape(encoded.shape(), axes)?;
+
+    undo_swizzle_reshape_with_shape(encoded, &shape, axes)
+}
+
+#[expect(clippy::missing_panics_doc)]
+#[expect(clippy::needless_pass_by_value)]
+/// Reverts the swizzle and reshape of the `encoded` array with the `axes`
+/// and returns the original array of the known original `shape`.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`SwizzleReshapeCodecError::InvalidAxisIndex`] if any axis is out of
+///   bounds
+/// - [`SwizzleReshapeCodecError::InvalidAxisPermutation`] if the `axes`
+///   permutation does not contain every axis index exactly once
+/// - [`SwizzleReshapeCodecError::MultipleRestAxes`] if the `axes` permutation
+///   contains more than one [`Rest`]-axes marker
+/// - [`SwizzleReshapeCodecError::InvalidAxisSplit`] if the sizes of an
+///   [`AxisSplit`] do not match the length of the split axis
+/// - [`SwizzleReshapeCodecError::MismatchedEncodedShape`] if the `encoded`
+///   array's shape does not match the shape that swizzling and reshaping an
+///   array of the original `shape` would have produced
+pub fn undo_swizzle_reshape_with_shape<T: Copy, S: Data<Elem = T>>(
+    encoded: ArrayBase<S, IxDyn>,
+    shape: &[usize],
+    axes: &[AxisGroup],
+) -> Result<Array<T, IxDyn>, SwizzleReshapeCodecError> {
+    let SwizzleReshapeAxes {
+        permutation,
+        swizzled_shape,
+        new_shape,
+    } = validate_into_axes_shape(shape, axes)?;
+
+    if encoded.shape() != new_shape {
+        return Err(SwizzleReshapeCodecError::MismatchedEncodedShape {
+            shape: encoded.shape().to_vec(),
+        });
+    }
 
     let mut inverse_permutation = vec![0; permutation.len()];
     #[expect(clippy::indexing_slicing)] // all are guaranteed to be in range
@@ -328,8 +440,10 @@ pub fn undo_swizzle_reshape<T: Copy, S: Data<Elem = T>>(
         inverse_permutation[p] = i;
     }
 
-    // since no axes were merged, no reshape is needed
-    let unshaped = encoded;
+    #[expect(clippy::expect_used)] // only panics on an implementation bug
+    let unshaped = encoded
+        .to_shape(swizzled_shape)
+        .expect("new decoding shape should have the correct number of elements");
     let unswizzled = unshaped.permuted_axes(inverse_permutation);
 
     Ok(unswizzled.into_owned())
@@ -349,6 +463,8 @@ pub fn undo_swizzle_reshape<T: Copy, S: Data<Elem = T>>(
 ///   permutation does not contain every axis index exactly once
 /// - [`SwizzleReshapeCodecError::MultipleRestAxes`] if the `axes` permutation
 ///   contains more than one [`Rest`]-axes marker
+/// - [`SwizzleReshapeCodecError::InvalidAxisSplit`] if the sizes of an
+///   [`AxisSplit`] do not match the length of the split axis
 /// - [`SwizzleReshapeCodecError::MismatchedDecodeIntoArray`] if the `encoded`
 ///   array's shape does not match the shape that swizzling and reshaping an
 ///   array of the `decoded` array's shape would have produced
@@ -361,7 +477,7 @@ pub fn undo_swizzle_reshape_into<T: Copy>(
         permutation,
         swizzled_shape,
         new_shape,
-    } = validate_into_axes_shape(&decoded, axes)?;
+    } = validate_into_axes_shape(decoded.shape(), axes)?;
 
     if encoded.shape() != new_shape {
         return Err(SwizzleReshapeCodecError::MismatchedDecodeIntoArray {
@@ -395,13 +511,14 @@ struct SwizzleReshapeAxes {
     new_shape: Vec<usize>,
 }
 
-fn validate_into_axes_shape<T, S: Data<Elem = T>>(
-    array: &ArrayBase<S, IxDyn>,
+#[expect(clippy::too_many_lines)]
+fn validate_into_axes_shape(
+    shape: &[usize],
     axes: &[AxisGroup],
 ) -> Result<SwizzleReshapeAxes, SwizzleReshapeCodecError> {
     // counts of each axis index, used to check for missing or duplicate axes,
     //  and for knowing which axes are caught by the rest catch-all
-    let mut axis_index_counts = vec![0_usize; array.ndim()];
+    let mut axis_index_counts = vec![0_usize; shape.len()];
 
     let mut has_rest = false;
 
@@ -418,7 +535,7 @@ fn validate_into_axes_shape<T, S: Data<Elem = T>>(
                             } else {
                                 return Err(SwizzleReshapeCodecError::InvalidAxisIndex {
                                     index: *index,
-                                    ndim: array.ndim(),
+                                    ndim: shape.len(),
                                 });
                             }
                         }
@@ -435,6 +552,25 @@ fn validate_into_axes_shape<T, S: Data<Elem = T>>(
                     return Err(SwizzleReshapeCodecError::MultipleRestAxes);
                 }
             }
+            AxisGroup::Split(AxisSplit { split, sizes }) => {
+                let Some(c) = axis_index_counts.get_mut(*split) else {
+                    return Err(SwizzleReshapeCodecError::InvalidAxisIndex {
+                        index: *split,
+                        ndim: shape.len(),
+                    });
+                };
+                *c += 1;
+
+                #[expect(clippy::indexing_slicing)] // index is checked above
+                let len = shape[*split];
+                if sizes.iter().product::<usize>() != len {
+                    return Err(SwizzleReshapeCodecError::InvalidAxisSplit {
+                        index: *split,
+                        len,
+                        sizes: sizes.clone(),
+                    });
+                }
+            }
         }
     }
 
@@ -447,17 +583,18 @@ fn validate_into_axes_shape<T, S: Data<Elem = T>>(
     {
         return Err(SwizzleReshapeCodecError::InvalidAxisPermutation {
             axes: axes.to_vec(),
-            ndim: array.ndim(),
+            ndim: shape.len(),
         });
     }
 
     // the permutation to apply to the input axes
-    let mut axis_permutation = Vec::with_capacity(array.len());
+    let mut axis_permutation = Vec::with_capacity(shape.len());
     // the shape of the already permuted intermediary array
-    let mut permuted_shape = Vec::with_capacity(array.len());
+    let mut permuted_shape = Vec::with_capacity(shape.len());
     // the shape of the already permuted and grouped output array
     let mut grouped_shape = Vec::with_capacity(axes.len());
 
+    #[expect(clippy::indexing_slicing)] // all axes are validated to be in range
     for axis in axes {
         match axis {
             // a group merged all of its axes
@@ -468,15 +605,15 @@ fn validate_into_axes_shape<T, S: Data<Elem = T>>(
                     match axis {
                         Axis::Index(index) => {
                             axis_permutation.push(*index);
-                            permuted_shape.push(array.len_of(ndarray::Axis(*index)));
-                            new_len *= array.len_of(ndarray::Axis(*index));
+                            permuted_shape.push(shape[*index]);
+                            new_len *= shape[*index];
                         }
                         Axis::MergedRest(Rest) => {
                             for (index, count) in axis_index_counts.iter().enumerate() {
                                 if *count == 0 {
                                     axis_permutation.push(index);
-                                    permuted_shape.push(array.len_of(ndarray::Axis(index)));
-                                    new_len *= array.len_of(ndarray::Axis(index));
+                                    permuted_shape.push(shape[index]);
+                                    new_len *= shape[index];
                                 }
                             }
                         }
@@ -488,11 +625,17 @@ fn validate_into_axes_shape<T, S: Data<Elem = T>>(
                 for (index, count) in axis_index_counts.iter().enumerate() {
                     if *count == 0 {
                         axis_permutation.push(index);
-                        permuted_shape.push(array.len_of(ndarray::Axis(index)));
-                        grouped_shape.push(array.len_of(ndarray::Axis(index)));
+                        permuted_shape.push(shape[index]);
+                        grouped_shape.push(shape[index]);
                     }
                 }
             }
+            // a split axis is reshaped into several axes
+            AxisGroup::Split(AxisSplit { split, sizes }) => {
+                axis_permutation.push(*split);
+                permuted_shape.push(shape[*split]);
+                grouped_shape.extend_from_slice(sizes);
+            }
         }
     }
 
@@ -503,6 +646,188 @@ fn validate_into_axes_shape<T, S: Data<Elem = T>>(
     })
 }
 
+/// Reconstruct the shape of the decoded array from the `encoded_shape` and
+/// the `axes`, which must not merge any axes.
+fn unmerged_decoded_shape(
+    encoded_shape: &[usize],
+    axes: &[AxisGroup],
+) -> Result<Vec<usize>, SwizzleReshapeCodecError> {
+    let mismatched_shape = || SwizzleReshapeCodecError::MismatchedEncodedShape {
+        shape: encoded_shape.to_vec(),
+    };
+
+    // the number of encoded axes that are produced by explicit groups
+    let explicit_ndim = axes
+        .iter()
+        .map(|axis| match axis {
+            AxisGroup::Group(_) => 1,
+            AxisGroup::AllRest(Rest) => 0,
+            AxisGroup::Split(AxisSplit { sizes, .. }) => sizes.len(),
+        })
+        .sum::<usize>();
+    let has_rest = axes
+        .iter()
+        .any(|axis| matches!(axis, AxisGroup::AllRest(Rest)));
+
+    let rest_ndim = match encoded_shape.len().checked_sub(explicit_ndim) {
+        Some(rest_ndim) if has_rest || rest_ndim == 0 => rest_ndim,
+        _ => return Err(mismatched_shape()),
+    };
+    let ndim = axes
+        .iter()
+        .filter(|axis| !matches!(axis, AxisGroup::AllRest(Rest)))
+        .count()
+        + rest_ndim;
+
+    let mut shape = vec![None; ndim];
+    let mut rest_shape = &[][..];
+    let mut encoded_shape_iter = encoded_shape;
+
+    for axis in axes {
+        let (index, len, ndim) = match axis {
+            AxisGroup::Group(group) => match group.as_slice() {
+                [Axis::Index(index)] => (*index, encoded_shape_iter.first().copied(), 1),
+                _ => return Err(SwizzleReshapeCodecError::CannotDecodeMergedAxes),
+            },
+            AxisGroup::AllRest(Rest) => {
+                let Some((rest, remaining)) = encoded_shape_iter.split_at_checked(rest_ndim) else {
+                    return Err(mismatched_shape());
+                };
+                rest_shape = rest;
+                encoded_shape_iter = remaining;
+                continue;
+            }
+            AxisGroup::Split(AxisSplit { split, sizes }) => {
+                (*split, Some(sizes.iter().product()), sizes.len())
+            }
+        };
+
+        let Some(entry) = shape.get_mut(index) else {
+            return Err(SwizzleReshapeCodecError::InvalidAxisIndex { index, ndim });
+        };
+        *entry = len;
+
+        encoded_shape_iter = encoded_shape_iter
+            .get(ndim..)
+            .ok_or_else(mismatched_shape)?;
+    }
+
+    // the remaining axes are filled in order, duplicate or missing axes are
+    //  detected later during validation
+    let mut rest_shape = rest_shape.iter().copied();
+    Ok(shape
+        .into_iter()
+        .map(|len| len.or_else(|| rest_shape.next()).unwrap_or(0))
+        .collect())
+}
+
+/// Reverts the swizzle and reshape of the `encoded` array with the `axes`,
+/// using the original `shape` of the array if it is known.
+fn undo_swizzle_reshape_any(
+    encoded: AnyCowArray,
+    shape: Option<&[usize]>,
+    axes: &[AxisGroup],
+) -> Result<AnyArray, SwizzleReshapeCodecError> {
+    fn undo_typed<T: Copy, S: Data<Elem = T>>(
+        encoded: ArrayBase<S, IxDyn>,
+        shape: Option<&[usize]>,
+        axes: &[AxisGroup],
+    ) -> Result<Array<T, IxDyn>, SwizzleReshapeCodecError> {
+        match shape {
+            Some(shape) => undo_swizzle_reshape_with_shape(encoded, shape, axes),
+            None => undo_swizzle_reshape(encoded, axes),
+        }
+    }
+
+    match encoded {
+        AnyCowArray::U8(encoded) => Ok(AnyArray::U8(undo_typed(encoded, shape, axes)?)),
+        AnyCowArray::U16(encoded) => Ok(AnyArray::U16(undo_typed(encoded, shape, axes)?)),
+        AnyCowArray::U32(encoded) => Ok(AnyArray::U32(undo_typed(encoded, shape, axes)?)),
+        AnyCowArray::U64(encoded) => Ok(AnyArray::U64(undo_typed(encoded, shape, axes)?)),
+        AnyCowArray::I8(encoded) => Ok(AnyArray::I8(undo_typed(encoded, shape, axes)?)),
+        AnyCowArray::I16(encoded) => Ok(AnyArray::I16(undo_typed(encoded, shape, axes)?)),
+        AnyCowArray::I32(encoded) => Ok(AnyArray::I32(undo_typed(encoded, shape, axes)?)),
+        AnyCowArray::I64(encoded) => Ok(AnyArray::I64(undo_typed(encoded, shape, axes)?)),
+        AnyCowArray::F32(encoded) => Ok(AnyArray::F32(undo_typed(encoded, shape, axes)?)),
+        AnyCowArray::F64(encoded) => Ok(AnyArray::F64(undo_typed(encoded, shape, axes)?)),
+        encoded => Err(SwizzleReshapeCodecError::UnsupportedDtype(encoded.dtype())),
+    }
+}
+
+/// Reverts the swizzle and reshape of the `encoded` array with the `axes`
+/// into the `decoded` array.
+fn undo_swizzle_reshape_into_any(
+    encoded: AnyArrayView,
+    decoded: AnyArrayViewMut,
+    axes: &[AxisGroup],
+) -> Result<(), SwizzleReshapeCodecError> {
+    match (encoded, decoded) {
+        (AnyArrayView::U8(encoded), AnyArrayViewMut::U8(decoded)) => {
+            undo_swizzle_reshape_into(encoded, decoded, axes)
+        }
+        (AnyArrayView::U16(encoded), AnyArrayViewMut::U16(decoded)) => {
+            undo_swizzle_reshape_into(encoded, decoded, axes)
+        }
+        (AnyArrayView::U32(encoded), AnyArrayViewMut::U32(decoded)) => {
+            undo_swizzle_reshape_into(encoded, decoded, axes)
+        }
+        (AnyArrayView::U64(encoded), AnyArrayViewMut::U64(decoded)) => {
+            undo_swizzle_reshape_into(encoded, decoded, axes)
+        }
+        (AnyArrayView::I8(encoded), AnyArrayViewMut::I8(decoded)) => {
+            undo_swizzle_reshape_into(encoded, decoded, axes)
+        }
+        (AnyArrayView::I16(encoded), AnyArrayViewMut::I16(decoded)) => {
+            undo_swizzle_reshape_into(encoded, decoded, axes)
+        }
+        (AnyArrayView::I32(encoded), AnyArrayViewMut::I32(decoded)) => {
+            undo_swizzle_reshape_into(encoded, decoded, axes)
+        }
+        (AnyArrayView::I64(encoded), AnyArrayViewMut::I64(decoded)) => {
+            undo_swizzle_reshape_into(encoded, decoded, axes)
+        }
+        (AnyArrayView::F32(encoded), AnyArrayViewMut::F32(decoded)) => {
+            undo_swizzle_reshape_into(encoded, decoded, axes)
+        }
+        (AnyArrayView::F64(encoded), AnyArrayViewMut::F64(decoded)) => {
+            undo_swizzle_reshape_into(encoded, decoded, axes)
+        }
+        (encoded, decoded) if encoded.dtype() != decoded.dtype() => {
+            Err(SwizzleReshapeCodecError::MismatchedDecodeIntoArray {
+                source: AnyArrayAssignError::DTypeMismatch {
+                    src: encoded.dtype(),
+                    dst: decoded.dtype(),
+                },
+            })
+        }
+        (encoded, _decoded) => Err(SwizzleReshapeCodecError::UnsupportedDtype(encoded.dtype())),
+    }
+}
+
+#[expect(clippy::ref_option)] // serde passes the field by reference
+fn serialize_optional_codec<S: Serializer>(
+    codec: &Option<ErasedDynCodec>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match codec {
+        Some(codec) => codec.get_config(serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn deserialize_optional_codec<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<ErasedDynCodec>, D::Error> {
+    GlobalRegistry::codec_from_config(deserializer).map(Some)
+}
+
+#[derive(Serialize, Deserialize)]
+struct ShapeHeader<'a> {
+    #[serde(borrow)]
+    shape: Cow<'a, [usize]>,
+    version: SwizzleReshapeCodecVersion,
+}
+
 #[derive(Copy, Clone, Debug)]
 /// Marker to signify all remaining (not explicitly named) axes
 pub struct Rest;
@@ -555,9 +880,15 @@ impl JsonSchema for Rest {
 #[expect(clippy::expect_used)]
 mod tests {
     use ndarray::array;
+    use numcodecs_round::RoundCodec;
+    use serde_json::json;
 
     use super::*;
 
+    numcodecs_registry::export_global! {
+        static REGISTRY: numcodecs_registry::EmptyRegistry = numcodecs_registry::EmptyRegistry;
+    }
+
     #[test]
     fn identity() {
         roundtrip(
@@ -800,6 +1131,128 @@ mod tests {
         );
     }
 
+    #[test]
+    fn split() {
+        let mut i = 0;
+        roundtrip(
+            Array::from_shape_fn([6, 4], |_| {
+                i += 1;
+                i
+            })
+            .into_dyn(),
+            &[
+                AxisGroup::Split(AxisSplit {
+                    split: 0,
+                    sizes: vec![2, 3],
+                }),
+                AxisGroup::AllRest(Rest),
+            ],
+            &[2, 3, 4],
+        );
+
+        let mut i = 0;
+        roundtrip(
+            Array::from_shape_fn([6, 4, 5], |_| {
+                i += 1;
+                i
+            })
+            .into_dyn(),
+            &[
+                AxisGroup::Group(vec![Axis::Index(2)]),
+                AxisGroup::Split(AxisSplit {
+                    split: 0,
+                    sizes: vec![3, 1, 2],
+                }),
+                AxisGroup::Group(vec![Axis::Index(1)]),
+            ],
+            &[5, 3, 1, 2, 4],
+        );
+
+        assert!(matches!(
+            swizzle_reshape(
+                Array::<i32, _>::zeros([6, 4]).into_dyn(),
+                &[
+                    AxisGroup::Split(AxisSplit {
+                        split: 0,
+                        sizes: vec![4, 2],
+                    }),
+                    AxisGroup::AllRest(Rest),
+                ],
+            ),
+            Err(SwizzleReshapeCodecError::InvalidAxisSplit {
+                index: 0,
+                len: 6,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    #[expect(clippy::unwrap_used)]
+    fn wrapped_codec_preserves_shape() -> Result<(), SwizzleReshapeCodecError> {
+        let data = Array::from_shape_fn([3, 4, 5, 6], |(a, b, c, d)| {
+            f64::from(u32::try_from((((a * 4) + b) * 5 + c) * 6 + d).unwrap_or(0)) / 7.0
+        })
+        .into_dyn();
+
+        // a lossy wrapped codec cannot alter the stored shape
+        let round: RoundCodec = serde_json::from_value(json!({ "precision": 0.5 })).unwrap();
+        let codec = SwizzleReshapeCodec {
+            axes: vec![
+                AxisGroup::Group(vec![Axis::Index(0), Axis::Index(2)]),
+                AxisGroup::Group(vec![Axis::MergedRest(Rest)]),
+            ],
+            codec: Some(ErasedDynCodec::new(round)),
+            version: StaticCodecVersion,
+        };
+
+        let encoded = codec.encode(AnyCowArray::F64(data.view().into()))?;
+
+        let encoded_bytes = encoded.view();
+        let encoded_bytes = framed_bytes(&encoded_bytes)?;
+        let (header, inner, _) = decode_framed::<ShapeHeader>(&encoded_bytes)?;
+        assert_eq!(&*header.shape, data.shape());
+        assert_eq!(inner.shape(), &[15, 24]);
+
+        let decoded = codec.decode(encoded.cow())?;
+        let AnyArray::F64(decoded) = decoded else {
+            return Err(SwizzleReshapeCodecError::UnsupportedDtype(decoded.dtype()));
+        };
+        assert_eq!(decoded.shape(), data.shape());
+        for (d, r) in data.iter().zip(decoded.iter()) {
+            assert!((d - r).abs() <= 0.25);
+        }
+
+        let mut decoded_into = Array::zeros(data.shape());
+        codec.decode_into(
+            encoded.view(),
+            AnyArrayViewMut::F64(decoded_into.view_mut()),
+        )?;
+        assert_eq!(decoded_into, decoded);
+
+        let mut decoded_into = Array::zeros([4, 3, 5, 6]).into_dyn();
+        assert!(matches!(
+            codec.decode_into(
+                encoded.view(),
+                AnyArrayViewMut::F64(decoded_into.view_mut()),
+            ),
+            Err(SwizzleReshapeCodecError::MismatchedDecodeIntoArray { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    #[expect(clippy::unwrap_used)]
+    fn wrapped_codec_is_optional() {
+        let config = json!({ "axes": [[0], [1, 2]], "_version": "1.1.0" });
+
+        let codec: SwizzleReshapeCodec = serde_json::from_value(config.clone()).unwrap();
+        assert!(codec.codec.is_none());
+
+        assert_eq!(serde_json::to_value(&codec).unwrap(), config);
+    }
+
     #[expect(clippy::needless_pass_by_value)]
     fn roundtrip(data: Array<i32, IxDyn>, axes: &[AxisGroup], swizzle_shape: &[usize]) {
         let swizzled = swizzle_reshape(data.view(), axes).expect("swizzle should not fail");
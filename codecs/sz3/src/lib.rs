@@ -26,8 +26,12 @@ use numcodecs::{
     AnyArray, AnyArrayAssignError, AnyArrayDType, AnyArrayView, AnyArrayViewMut, AnyCowArray,
     ArrayDType, ArrayDataMutExt, Codec, StaticCodec, StaticCodecConfig, StaticCodecVersion,
 };
+use numcodecs_utils::{
+    corrections::{self, Corrections, CorrectionsError},
+    regions::{self, ErrorBoundRegion, InvalidRegionError},
+};
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use thiserror::Error;
 
 // Only included to explicitly enable the `no_wasm_shim` feature for
@@ -37,7 +41,7 @@ use ::zstd_sys as _;
 #[cfg(test)]
 use ::serde_json as _;
 
-type Sz3CodecVersion = StaticCodecVersion<0, 2, 0>;
+type Sz3CodecVersion = StaticCodecVersion<0, 2, 1>;
 
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
 // serde cannot deny unknown fields because of the flatten
@@ -50,6 +54,14 @@ pub struct Sz3Codec {
     /// SZ3 error bound
     #[serde(flatten)]
     pub error_bound: Sz3ErrorBound,
+    /// Optional regions with spatially varying absolute error bounds
+    ///
+    /// After compressing with SZ3, every point inside a region whose error
+    /// exceeds the region's absolute error bound is stored exactly as a
+    /// residual correction. Where regions overlap, the strictest bound
+    /// applies.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub regions: Vec<ErrorBoundRegion>,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default, rename = "_version")]
     pub version: Sz3CodecVersion,
@@ -156,44 +168,94 @@ impl Codec for Sz3Codec {
     fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
         match data {
             AnyCowArray::U8(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, self.predictor.as_ref(), &self.error_bound)?)
-                    .into_dyn(),
+                Array1::from(compress_with_regions(
+                    data,
+                    self.predictor.as_ref(),
+                    &self.error_bound,
+                    &self.regions,
+                )?)
+                .into_dyn(),
             )),
             AnyCowArray::I8(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, self.predictor.as_ref(), &self.error_bound)?)
-                    .into_dyn(),
+                Array1::from(compress_with_regions(
+                    data,
+                    self.predictor.as_ref(),
+                    &self.error_bound,
+                    &self.regions,
+                )?)
+                .into_dyn(),
             )),
             AnyCowArray::U16(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, self.predictor.as_ref(), &self.error_bound)?)
-                    .into_dyn(),
+                Array1::from(compress_with_regions(
+                    data,
+                    self.predictor.as_ref(),
+                    &self.error_bound,
+                    &self.regions,
+                )?)
+                .into_dyn(),
             )),
             AnyCowArray::I16(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, self.predictor.as_ref(), &self.error_bound)?)
-                    .into_dyn(),
+                Array1::from(compress_with_regions(
+                    data,
+                    self.predictor.as_ref(),
+                    &self.error_bound,
+                    &self.regions,
+                )?)
+                .into_dyn(),
             )),
             AnyCowArray::U32(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, self.predictor.as_ref(), &self.error_bound)?)
-                    .into_dyn(),
+                Array1::from(compress_with_regions(
+                    data,
+                    self.predictor.as_ref(),
+                    &self.error_bound,
+                    &self.regions,
+                )?)
+                .into_dyn(),
             )),
             AnyCowArray::I32(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, self.predictor.as_ref(), &self.error_bound)?)
-                    .into_dyn(),
+                Array1::from(compress_with_regions(
+                    data,
+                    self.predictor.as_ref(),
+                    &self.error_bound,
+                    &self.regions,
+                )?)
+                .into_dyn(),
             )),
             AnyCowArray::U64(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, self.predictor.as_ref(), &self.error_bound)?)
-                    .into_dyn(),
+                Array1::from(compress_with_regions(
+                    data,
+                    self.predictor.as_ref(),
+                    &self.error_bound,
+                    &self.regions,
+                )?)
+                .into_dyn(),
             )),
             AnyCowArray::I64(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, self.predictor.as_ref(), &self.error_bound)?)
-                    .into_dyn(),
+                Array1::from(compress_with_regions(
+                    data,
+                    self.predictor.as_ref(),
+                    &self.error_bound,
+                    &self.regions,
+                )?)
+                .into_dyn(),
             )),
             AnyCowArray::F32(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, self.predictor.as_ref(), &self.error_bound)?)
-                    .into_dyn(),
+                Array1::from(compress_with_regions(
+                    data,
+                    self.predictor.as_ref(),
+                    &self.error_bound,
+                    &self.regions,
+                )?)
+                .into_dyn(),
             )),
             AnyCowArray::F64(data) => Ok(AnyArray::U8(
-                Array1::from(compress(data, self.predictor.as_ref(), &self.error_bound)?)
-                    .into_dyn(),
+                Array1::from(compress_with_regions(
+                    data,
+                    self.predictor.as_ref(),
+                    &self.error_bound,
+                    &self.regions,
+                )?)
+                .into_dyn(),
             )),
             encoded => Err(Sz3CodecError::UnsupportedDtype(encoded.dtype())),
         }
@@ -262,6 +324,14 @@ pub enum Sz3CodecError {
         /// Opaque source error
         source: Sz3HeaderError,
     },
+    /// [`Sz3Codec`] cannot encode with an error bound region that does not
+    /// fit the array's shape
+    #[error("Sz3 cannot encode with an error bound region that does not fit the array")]
+    InvalidErrorBoundRegion {
+        /// The source of the error
+        #[from]
+        source: InvalidRegionError,
+    },
     /// [`Sz3Codec`] cannot encode an array of `shape`
     #[error("Sz3 cannot encode an array of shape {shape:?}")]
     InvalidEncodeShape {
@@ -300,6 +370,14 @@ pub enum Sz3CodecError {
         /// Opaque source error
         source: Sz3HeaderError,
     },
+    /// [`Sz3Codec`] failed to encode, decode, or apply the residual
+    /// corrections
+    #[error("Sz3 failed to encode, decode, or apply the residual corrections")]
+    CorrectionsFailed {
+        /// The source of the error
+        #[from]
+        source: CorrectionsError,
+    },
     /// [`Sz3Codec`] failed to decode the data
     #[error("Sz3 failed to decode the data")]
     Sz3DecodeFailed {
@@ -333,11 +411,12 @@ pub struct Sz3HeaderError(postcard::Error);
 /// Opaque error for when encoding or decoding with SZ3 fails
 pub struct Sz3CodingError(sz3::SZ3Error);
 
-#[expect(clippy::needless_pass_by_value, clippy::too_many_lines)]
 /// Compresses the input `data` array using SZ3, which consists of an optional
 /// `predictor`, an `error_bound`, an optional `encoder`, and an optional
 /// `lossless` compressor.
 ///
+/// See [`compress_with_regions`] for spatially varying error bounds.
+///
 /// # Errors
 ///
 /// Errors with
@@ -349,11 +428,42 @@ pub fn compress<T: Sz3Element, S: Data<Elem = T>, D: Dimension>(
     predictor: Option<&Sz3Predictor>,
     error_bound: &Sz3ErrorBound,
 ) -> Result<Vec<u8>, Sz3CodecError> {
+    compress_with_regions(data, predictor, error_bound, &[])
+}
+
+#[expect(clippy::needless_pass_by_value, clippy::too_many_lines)]
+/// Compresses the input `data` array using SZ3, which consists of an optional
+/// `predictor`, an `error_bound`, an optional `encoder`, and an optional
+/// `lossless` compressor.
+///
+/// Every point inside one of the error bound `regions` whose SZ3 error
+/// exceeds the region's absolute error bound is additionally stored exactly
+/// as a residual correction.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`Sz3CodecError::InvalidErrorBoundRegion`] if any region does not fit
+///   the array shape
+/// - [`Sz3CodecError::HeaderEncodeFailed`] if encoding the header failed
+/// - [`Sz3CodecError::CorrectionsFailed`] if encoding the residual
+///   corrections failed
+/// - [`Sz3CodecError::InvalidEncodeShape`] if the array shape is invalid
+/// - [`Sz3CodecError::Sz3EncodeFailed`] if encoding failed with an opaque error
+pub fn compress_with_regions<T: Sz3Element, S: Data<Elem = T>, D: Dimension>(
+    data: ArrayBase<S, D>,
+    predictor: Option<&Sz3Predictor>,
+    error_bound: &Sz3ErrorBound,
+    regions: &[ErrorBoundRegion],
+) -> Result<Vec<u8>, Sz3CodecError> {
+    regions::check_regions_fit(regions, data.shape())?;
+
     let mut encoded_bytes = postcard::to_extend(
         &CompressionHeader {
             dtype: <T as Sz3Element>::DTYPE,
             shape: Cow::Borrowed(data.shape()),
             version: StaticCodecVersion,
+            corrections: !regions.is_empty(),
         },
         Vec::new(),
     )
@@ -398,7 +508,7 @@ pub fn compress<T: Sz3Element, S: Data<Elem = T>, D: Dimension>(
             })?;
     }
 
-    let data = builder
+    let sz3_data = builder
         .finish()
         .map_err(|err| Sz3CodecError::InvalidEncodeShape {
             source: Sz3CodingError(err),
@@ -471,15 +581,73 @@ pub fn compress<T: Sz3Element, S: Data<Elem = T>, D: Dimension>(
     };
     config = config.compression_algorithm(predictor);
 
-    sz3::compress_into_with_config(&data, &config, &mut encoded_bytes).map_err(|err| {
+    let mut sz3_bytes = Vec::new();
+    sz3::compress_into_with_config(&sz3_data, &config, &mut sz3_bytes).map_err(|err| {
         Sz3CodecError::Sz3EncodeFailed {
             source: Sz3CodingError(err),
         }
     })?;
 
+    if !regions.is_empty() {
+        let (_config, decompressed) =
+            sz3::decompress::<T>(&sz3_bytes).map_err(|err| Sz3CodecError::Sz3DecodeFailed {
+                source: Sz3CodingError(err),
+            })?;
+        let corrections = corrections::collect_corrections(
+            &data,
+            decompressed.into_data().iter().copied(),
+            |index, x, y| {
+                regions::local_error_bound(regions, index).is_some_and(|eb_abs| {
+                    let error = x.abs_error(y);
+                    error.is_nan() || error > eb_abs
+                })
+            },
+        );
+
+        encoded_bytes = corrections::encode_corrections(&corrections, encoded_bytes)?;
+    }
+    encoded_bytes.extend_from_slice(&sz3_bytes);
+
     Ok(encoded_bytes)
 }
 
+/// Decodes the header of the `encoded` data
+///
+/// Data that was encoded with the format version 0.2.0 has no residual
+/// corrections.
+fn decode_header(encoded: &[u8]) -> Result<(CompressionHeader<'_>, &[u8]), Sz3CodecError> {
+    if let Ok((header, encoded)) = postcard::take_from_bytes::<LegacyCompressionHeader>(encoded) {
+        return Ok((
+            CompressionHeader {
+                dtype: header.dtype,
+                shape: header.shape,
+                version: StaticCodecVersion,
+                corrections: false,
+            },
+            encoded,
+        ));
+    }
+
+    postcard::take_from_bytes::<CompressionHeader>(encoded).map_err(|err| {
+        Sz3CodecError::HeaderDecodeFailed {
+            source: Sz3HeaderError(err),
+        }
+    })
+}
+
+/// Decodes the residual corrections from the start of the `encoded` data if
+/// it `has_corrections`, and returns them together with the remaining bytes
+fn decode_corrections<T: Sz3Element>(
+    encoded: &[u8],
+    has_corrections: bool,
+) -> Result<(Corrections<T>, &[u8]), Sz3CodecError> {
+    if !has_corrections {
+        return Ok((Vec::new(), encoded));
+    }
+
+    Ok(corrections::decode_corrections(encoded)?)
+}
+
 /// Decompresses the `encoded` data into an array using SZ3.
 ///
 /// # Errors
@@ -487,41 +655,45 @@ pub fn compress<T: Sz3Element, S: Data<Elem = T>, D: Dimension>(
 /// Errors with
 /// - [`Sz3CodecError::HeaderDecodeFailed`] if decoding the header failed
 /// - [`Sz3CodecError::Sz3DecodeFailed`] if decoding failed with an opaque error
+/// - [`Sz3CodecError::CorrectionsFailed`] if decoding the residual corrections
+///   failed or a residual correction is out of bounds
 pub fn decompress(encoded: &[u8]) -> Result<AnyArray, Sz3CodecError> {
     fn decompress_typed<T: Sz3Element>(
         encoded: &[u8],
-        shape: &[usize],
+        header: &CompressionHeader,
     ) -> Result<Array<T, IxDyn>, Sz3CodecError> {
+        let shape = &*header.shape;
+
         if shape.iter().copied().any(|s| s == 0) {
             return Ok(Array::from_shape_vec(shape, Vec::new())?);
         }
 
+        let (corrections, encoded) = decode_corrections::<T>(encoded, header.corrections)?;
+
         let (_config, decompressed) =
             sz3::decompress(encoded).map_err(|err| Sz3CodecError::Sz3DecodeFailed {
                 source: Sz3CodingError(err),
             })?;
 
-        Ok(Array::from_shape_vec(shape, decompressed.into_data())?)
+        let mut decompressed = decompressed.into_data();
+        corrections::apply_corrections(&mut decompressed, corrections)?;
+
+        Ok(Array::from_shape_vec(shape, decompressed)?)
     }
 
-    let (header, data) =
-        postcard::take_from_bytes::<CompressionHeader>(encoded).map_err(|err| {
-            Sz3CodecError::HeaderDecodeFailed {
-                source: Sz3HeaderError(err),
-            }
-        })?;
+    let (header, data) = decode_header(encoded)?;
 
     let decoded = match header.dtype {
-        Sz3DType::U8 => AnyArray::U8(decompress_typed(data, &header.shape)?),
-        Sz3DType::I8 => AnyArray::I8(decompress_typed(data, &header.shape)?),
-        Sz3DType::U16 => AnyArray::U16(decompress_typed(data, &header.shape)?),
-        Sz3DType::I16 => AnyArray::I16(decompress_typed(data, &header.shape)?),
-        Sz3DType::U32 => AnyArray::U32(decompress_typed(data, &header.shape)?),
-        Sz3DType::I32 => AnyArray::I32(decompress_typed(data, &header.shape)?),
-        Sz3DType::U64 => AnyArray::U64(decompress_typed(data, &header.shape)?),
-        Sz3DType::I64 => AnyArray::I64(decompress_typed(data, &header.shape)?),
-        Sz3DType::F32 => AnyArray::F32(decompress_typed(data, &header.shape)?),
-        Sz3DType::F64 => AnyArray::F64(decompress_typed(data, &header.shape)?),
+        Sz3DType::U8 => AnyArray::U8(decompress_typed(data, &header)?),
+        Sz3DType::I8 => AnyArray::I8(decompress_typed(data, &header)?),
+        Sz3DType::U16 => AnyArray::U16(decompress_typed(data, &header)?),
+        Sz3DType::I16 => AnyArray::I16(decompress_typed(data, &header)?),
+        Sz3DType::U32 => AnyArray::U32(decompress_typed(data, &header)?),
+        Sz3DType::I32 => AnyArray::I32(decompress_typed(data, &header)?),
+        Sz3DType::U64 => AnyArray::U64(decompress_typed(data, &header)?),
+        Sz3DType::I64 => AnyArray::I64(decompress_typed(data, &header)?),
+        Sz3DType::F32 => AnyArray::F32(decompress_typed(data, &header)?),
+        Sz3DType::F64 => AnyArray::F64(decompress_typed(data, &header)?),
     };
 
     Ok(decoded)
@@ -536,15 +708,20 @@ pub fn decompress(encoded: &[u8]) -> Result<AnyArray, Sz3CodecError> {
 /// - [`Sz3CodecError::MismatchedDecodeIntoArray`] if the `decoded` array is of
 ///   the wrong dtype or shape
 /// - [`Sz3CodecError::Sz3DecodeFailed`] if decoding failed with an opaque error
+/// - [`Sz3CodecError::CorrectionsFailed`] if decoding the residual corrections
+///   failed or a residual correction is out of bounds
 pub fn decompress_into(encoded: &[u8], decoded: AnyArrayViewMut) -> Result<(), Sz3CodecError> {
     fn decompress_into_typed<T: Sz3Element>(
         encoded: &[u8],
+        has_corrections: bool,
         mut decoded: ArrayViewMut<T, IxDyn>,
     ) -> Result<(), Sz3CodecError> {
         if decoded.is_empty() {
             return Ok(());
         }
 
+        let (corrections, encoded) = decode_corrections::<T>(encoded, has_corrections)?;
+
         let decoded_shape = decoded.shape().to_vec();
 
         decoded.with_slice_mut(|mut decoded| {
@@ -579,7 +756,7 @@ pub fn decompress_into(encoded: &[u8], decoded: AnyArrayViewMut) -> Result<(), S
                     })?;
             }
 
-            let mut decoded = builder
+            let mut sz3_decoded = builder
                 .finish()
                 // FIXME: different error code
                 .map_err(|err| Sz3CodecError::InvalidEncodeShape {
@@ -587,22 +764,19 @@ pub fn decompress_into(encoded: &[u8], decoded: AnyArrayViewMut) -> Result<(), S
                     shape: decoded_shape,
                 })?;
 
-            sz3::decompress_into_dimensioned(encoded, &mut decoded).map_err(|err| {
+            sz3::decompress_into_dimensioned(encoded, &mut sz3_decoded).map_err(|err| {
                 Sz3CodecError::Sz3DecodeFailed {
                     source: Sz3CodingError(err),
                 }
-            })
+            })?;
+
+            corrections::apply_corrections(decoded, corrections).map_err(Sz3CodecError::from)
         })?;
 
         Ok(())
     }
 
-    let (header, data) =
-        postcard::take_from_bytes::<CompressionHeader>(encoded).map_err(|err| {
-            Sz3CodecError::HeaderDecodeFailed {
-                source: Sz3HeaderError(err),
-            }
-        })?;
+    let (header, data) = decode_header(encoded)?;
 
     if decoded.shape() != &*header.shape {
         return Err(Sz3CodecError::MismatchedDecodeIntoArray {
@@ -614,16 +788,36 @@ pub fn decompress_into(encoded: &[u8], decoded: AnyArrayViewMut) -> Result<(), S
     }
 
     match (decoded, header.dtype) {
-        (AnyArrayViewMut::U8(decoded), Sz3DType::U8) => decompress_into_typed(data, decoded),
-        (AnyArrayViewMut::I8(decoded), Sz3DType::I8) => decompress_into_typed(data, decoded),
-        (AnyArrayViewMut::U16(decoded), Sz3DType::U16) => decompress_into_typed(data, decoded),
-        (AnyArrayViewMut::I16(decoded), Sz3DType::I16) => decompress_into_typed(data, decoded),
-        (AnyArrayViewMut::U32(decoded), Sz3DType::U32) => decompress_into_typed(data, decoded),
-        (AnyArrayViewMut::I32(decoded), Sz3DType::I32) => decompress_into_typed(data, decoded),
-        (AnyArrayViewMut::U64(decoded), Sz3DType::U64) => decompress_into_typed(data, decoded),
-        (AnyArrayViewMut::I64(decoded), Sz3DType::I64) => decompress_into_typed(data, decoded),
-        (AnyArrayViewMut::F32(decoded), Sz3DType::F32) => decompress_into_typed(data, decoded),
-        (AnyArrayViewMut::F64(decoded), Sz3DType::F64) => decompress_into_typed(data, decoded),
+        (AnyArrayViewMut::U8(decoded), Sz3DType::U8) => {
+            decompress_into_typed(data, header.corrections, decoded)
+        }
+        (AnyArrayViewMut::I8(decoded), Sz3DType::I8) => {
+            decompress_into_typed(data, header.corrections, decoded)
+        }
+        (AnyArrayViewMut::U16(decoded), Sz3DType::U16) => {
+            decompress_into_typed(data, header.corrections, decoded)
+        }
+        (AnyArrayViewMut::I16(decoded), Sz3DType::I16) => {
+            decompress_into_typed(data, header.corrections, decoded)
+        }
+        (AnyArrayViewMut::U32(decoded), Sz3DType::U32) => {
+            decompress_into_typed(data, header.corrections, decoded)
+        }
+        (AnyArrayViewMut::I32(decoded), Sz3DType::I32) => {
+            decompress_into_typed(data, header.corrections, decoded)
+        }
+        (AnyArrayViewMut::U64(decoded), Sz3DType::U64) => {
+            decompress_into_typed(data, header.corrections, decoded)
+        }
+        (AnyArrayViewMut::I64(decoded), Sz3DType::I64) => {
+            decompress_into_typed(data, header.corrections, decoded)
+        }
+        (AnyArrayViewMut::F32(decoded), Sz3DType::F32) => {
+            decompress_into_typed(data, header.corrections, decoded)
+        }
+        (AnyArrayViewMut::F64(decoded), Sz3DType::F64) => {
+            decompress_into_typed(data, header.corrections, decoded)
+        }
         (decoded, dtype) => Err(Sz3CodecError::MismatchedDecodeIntoArray {
             source: AnyArrayAssignError::DTypeMismatch {
                 src: dtype.into_dtype(),
@@ -634,49 +828,99 @@ pub fn decompress_into(encoded: &[u8], decoded: AnyArrayViewMut) -> Result<(), S
 }
 
 /// Array element types which can be compressed with SZ3.
-pub trait Sz3Element: Copy + sz3::SZ3Compressible + ArrayDType {
+pub trait Sz3Element:
+    Copy + sz3::SZ3Compressible + ArrayDType + Serialize + DeserializeOwned
+{
     /// The dtype representation of the type
     const DTYPE: Sz3DType;
+
+    /// Compute the absolute error between `self` and its `approximation`.
+    #[must_use]
+    fn abs_error(self, approximation: Self) -> f64;
 }
 
 impl Sz3Element for u8 {
     const DTYPE: Sz3DType = Sz3DType::U8;
+
+    fn abs_error(self, approximation: Self) -> f64 {
+        f64::from(self.abs_diff(approximation))
+    }
 }
 
 impl Sz3Element for i8 {
     const DTYPE: Sz3DType = Sz3DType::I8;
+
+    fn abs_error(self, approximation: Self) -> f64 {
+        f64::from(self.abs_diff(approximation))
+    }
 }
 
 impl Sz3Element for u16 {
     const DTYPE: Sz3DType = Sz3DType::U16;
+
+    fn abs_error(self, approximation: Self) -> f64 {
+        f64::from(self.abs_diff(approximation))
+    }
 }
 
 impl Sz3Element for i16 {
     const DTYPE: Sz3DType = Sz3DType::I16;
+
+    fn abs_error(self, approximation: Self) -> f64 {
+        f64::from(self.abs_diff(approximation))
+    }
 }
 
 impl Sz3Element for u32 {
     const DTYPE: Sz3DType = Sz3DType::U32;
+
+    fn abs_error(self, approximation: Self) -> f64 {
+        f64::from(self.abs_diff(approximation))
+    }
 }
 
 impl Sz3Element for i32 {
     const DTYPE: Sz3DType = Sz3DType::I32;
+
+    fn abs_error(self, approximation: Self) -> f64 {
+        f64::from(self.abs_diff(approximation))
+    }
 }
 
 impl Sz3Element for u64 {
     const DTYPE: Sz3DType = Sz3DType::U64;
+
+    fn abs_error(self, approximation: Self) -> f64 {
+        #[expect(clippy::cast_precision_loss)]
+        let error = self.abs_diff(approximation) as f64;
+        error
+    }
 }
 
 impl Sz3Element for i64 {
     const DTYPE: Sz3DType = Sz3DType::I64;
+
+    fn abs_error(self, approximation: Self) -> f64 {
+        #[expect(clippy::cast_precision_loss)]
+        let error = self.abs_diff(approximation) as f64;
+        error
+    }
 }
 
 impl Sz3Element for f32 {
     const DTYPE: Sz3DType = Sz3DType::F32;
+
+    fn abs_error(self, approximation: Self) -> f64 {
+        f64::from((self - approximation).abs())
+    }
 }
 
 impl Sz3Element for f64 {
     const DTYPE: Sz3DType = Sz3DType::F64;
+
+    fn abs_error(self, approximation: Self) -> f64 {
+        (self - approximation).abs()
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -685,6 +929,17 @@ struct CompressionHeader<'a> {
     #[serde(borrow)]
     shape: Cow<'a, [usize]>,
     version: Sz3CodecVersion,
+    corrections: bool,
+}
+
+/// Header of the format version 0.2.0, which has no residual corrections
+#[derive(Deserialize)]
+struct LegacyCompressionHeader<'a> {
+    dtype: Sz3DType,
+    #[serde(borrow)]
+    shape: Cow<'a, [usize]>,
+    #[expect(dead_code)]
+    version: StaticCodecVersion<0, 2, 0>,
 }
 
 /// Dtypes that SZ3 can compress and decompress
@@ -890,4 +1145,123 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    #[expect(clippy::unwrap_used)]
+    fn error_bound_regions() -> Result<(), Sz3CodecError> {
+        #[expect(clippy::cast_precision_loss)]
+        let data = Array::from_shape_fn((16, 16), |(i, j)| {
+            ((i * 16 + j) as f64).mul_add(0.37, 1.0).sin() * 100.0
+        });
+
+        let regions = [
+            ErrorBoundRegion {
+                start: vec![0, 0],
+                stop: vec![8, 8],
+                eb_abs: regions::NonNegative::new(0.0).unwrap(),
+            },
+            ErrorBoundRegion {
+                start: vec![4, 4],
+                stop: vec![16, 12],
+                eb_abs: regions::NonNegative::new(1e-3).unwrap(),
+            },
+        ];
+
+        let encoded = compress_with_regions(
+            data.view(),
+            default_predictor().as_ref(),
+            &Sz3ErrorBound::Absolute { abs: 1.0 },
+            &regions,
+        )?;
+
+        let mut decoded_into = Array::zeros(data.dim());
+        decompress_into(
+            &encoded,
+            AnyArrayViewMut::F64(decoded_into.view_mut().into_dyn()),
+        )?;
+
+        let AnyArray::F64(decoded) = decompress(&encoded)? else {
+            return Err(Sz3CodecError::UnsupportedDtype(AnyArrayDType::F64));
+        };
+        assert_eq!(decoded, decoded_into.into_dyn());
+
+        for ((index, x), y) in data.indexed_iter().zip(decoded.iter()) {
+            let error = (x - y).abs();
+            assert!(error <= 1.0);
+
+            for region in &regions {
+                if (region.start[0]..region.stop[0]).contains(&index.0)
+                    && (region.start[1]..region.stop[1]).contains(&index.1)
+                {
+                    assert!(error <= region.eb_abs.get(), "{x} vs {y} at {index:?}");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[expect(clippy::unwrap_used)]
+    fn invalid_error_bound_region() {
+        let data = Array1::<f32>::zeros(4);
+
+        for (start, stop) in [
+            (vec![0], vec![5]),
+            (vec![3], vec![2]),
+            (vec![0, 0], vec![1, 1]),
+        ] {
+            assert!(matches!(
+                compress_with_regions(
+                    data.view(),
+                    default_predictor().as_ref(),
+                    &Sz3ErrorBound::Absolute { abs: 0.1 },
+                    &[ErrorBoundRegion {
+                        start,
+                        stop,
+                        eb_abs: regions::NonNegative::new(0.0).unwrap(),
+                    }],
+                ),
+                Err(Sz3CodecError::InvalidErrorBoundRegion { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn legacy_without_corrections() -> Result<(), Sz3CodecError> {
+        #[expect(clippy::cast_precision_loss)]
+        let data = Array::from_shape_fn((16, 16), |(i, j)| {
+            ((i * 16 + j) as f64).mul_add(0.37, 1.0).sin() * 100.0
+        });
+
+        let encoded = compress(
+            data.view(),
+            default_predictor().as_ref(),
+            &Sz3ErrorBound::Absolute { abs: 1.0 },
+        )?;
+        let decoded = decompress(&encoded)?;
+
+        let (header, sz3_bytes) = decode_header(&encoded)?;
+        assert!(!header.corrections);
+
+        let mut legacy = postcard::to_extend(
+            &(header.dtype, &header.shape, StaticCodecVersion::<0, 2, 0>),
+            Vec::new(),
+        )
+        .map_err(|err| Sz3CodecError::HeaderEncodeFailed {
+            source: Sz3HeaderError(err),
+        })?;
+        legacy.extend_from_slice(sz3_bytes);
+
+        assert_eq!(decompress(&legacy)?, decoded);
+
+        let mut decoded_into = Array::<f64, _>::zeros(data.dim());
+        decompress_into(
+            &legacy,
+            AnyArrayViewMut::F64(decoded_into.view_mut().into_dyn()),
+        )?;
+        assert_eq!(AnyArray::F64(decoded_into.into_dyn()), decoded);
+
+        Ok(())
+    }
 }
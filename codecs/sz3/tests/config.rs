@@ -1,8 +1,8 @@
 #![expect(missing_docs)]
 
 use ::{
-    ndarray as _, num_traits as _, postcard as _, schemars as _, sz3 as _, thiserror as _,
-    zstd_sys as _,
+    ndarray as _, num_traits as _, numcodecs_utils as _, postcard as _, schemars as _, sz3 as _,
+    thiserror as _, zstd_sys as _,
 };
 
 use numcodecs::StaticCodec;
@@ -94,3 +94,17 @@ fn config_predictor() {
         .unwrap(),
     );
 }
+
+#[test]
+fn config_regions() {
+    let codec = Sz3Codec::from_config(
+        Deserialize::deserialize(json!({
+            "eb_mode": "abs",
+            "eb_abs": 1.0,
+            "regions": [{ "start": [0, 2], "stop": [4, 8], "eb_abs": 0.1 }],
+        }))
+        .unwrap(),
+    );
+
+    assert_eq!(codec.regions.len(), 1);
+}
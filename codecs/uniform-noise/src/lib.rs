@@ -35,24 +35,66 @@ use thiserror::Error;
 use wyhash::{WyHash, WyRng};
 
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
-#[serde(deny_unknown_fields)]
-/// Codec that adds `seed`ed `$\text{U}(-0.5 \cdot scale, 0.5 \cdot scale)$`
-/// uniform noise of the given `scale` during encoding and passes through the
-/// input unchanged during decoding.
+#[schemars(deny_unknown_fields)]
+/// Codec that adds `seed`ed random noise of the given `distribution` during
+/// encoding and passes through the input unchanged during decoding, unless
+/// `subtractive` dither mode is enabled.
+///
+/// By default, `$\text{U}(-0.5 \cdot scale, 0.5 \cdot scale)$` uniform noise
+/// of the given `scale` is added. Gaussian and Laplace noise are also
+/// supported.
 ///
 /// This codec first hashes the input array data and shape to then seed a
-/// pseudo-random number generator that generates the uniform noise. Therefore,
+/// pseudo-random number generator that generates the noise. Therefore,
 /// passing in the same input with the same `seed` will produce the same noise
 /// and thus the same encoded output.
+///
+/// In `subtractive` dither mode, the noise is only seeded from the `seed` and
+/// the shape of the input array, such that decoding can regenerate the same
+/// noise and subtract it again.
 pub struct UniformNoiseCodec {
-    /// Scale of the uniform noise, which is sampled from
-    /// `$\text{U}(-0.5 \cdot scale, 0.5 \cdot scale)$`
-    pub scale: f64,
+    /// Distribution of the random noise
+    #[serde(flatten)]
+    pub distribution: NoiseDistribution,
     /// Seed for the random noise generator
     pub seed: u64,
+    /// Subtractive dither mode, in which decoding regenerates and subtracts
+    /// the same noise that was added during encoding
+    #[serde(default)]
+    pub subtractive: bool,
     /// The codec's encoding format version. Do not provide this parameter explicitly.
     #[serde(default, rename = "_version")]
-    pub version: StaticCodecVersion<1, 0, 0>,
+    pub version: StaticCodecVersion<1, 1, 0>,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "distribution")]
+#[serde(deny_unknown_fields)]
+/// Distribution of the random noise
+pub enum NoiseDistribution {
+    /// Gaussian noise, which is sampled from
+    /// `$\mathcal{N}(0, \sigma^2)$`
+    #[serde(rename = "gaussian")]
+    Gaussian {
+        /// Standard deviation of the Gaussian noise
+        sigma: f64,
+    },
+    /// Laplace noise, which is sampled from `$\text{Laplace}(0, b)$`
+    #[serde(rename = "laplace")]
+    Laplace {
+        /// Scale of the Laplace noise
+        b: f64,
+    },
+    /// Uniform noise, which is sampled from
+    /// `$\text{U}(-0.5 \cdot scale, 0.5 \cdot scale)$`
+    ///
+    /// This is the default distribution, which is used if no `distribution`
+    /// is specified.
+    #[serde(untagged)]
+    Uniform {
+        /// Scale of the uniform noise
+        scale: f64,
+    },
 }
 
 impl Codec for UniformNoiseCodec {
@@ -60,14 +102,17 @@ impl Codec for UniformNoiseCodec {
 
     fn encode(&self, data: AnyCowArray) -> Result<AnyArray, Self::Error> {
         match data {
-            #[expect(clippy::cast_possible_truncation)]
-            AnyCowArray::F32(data) => Ok(AnyArray::F32(add_uniform_noise(
+            AnyCowArray::F32(data) => Ok(AnyArray::F32(add_noise(
                 data,
-                self.scale as f32,
+                self.distribution,
                 self.seed,
+                self.subtractive,
             ))),
-            AnyCowArray::F64(data) => Ok(AnyArray::F64(add_uniform_noise(
-                data, self.scale, self.seed,
+            AnyCowArray::F64(data) => Ok(AnyArray::F64(add_noise(
+                data,
+                self.distribution,
+                self.seed,
+                self.subtractive,
             ))),
             encoded => Err(UniformNoiseCodecError::UnsupportedDtype(encoded.dtype())),
         }
@@ -75,6 +120,16 @@ impl Codec for UniformNoiseCodec {
 
     fn decode(&self, encoded: AnyCowArray) -> Result<AnyArray, Self::Error> {
         match encoded {
+            AnyCowArray::F32(encoded) if self.subtractive => Ok(AnyArray::F32(subtract_noise(
+                encoded,
+                self.distribution,
+                self.seed,
+            ))),
+            AnyCowArray::F64(encoded) if self.subtractive => Ok(AnyArray::F64(subtract_noise(
+                encoded,
+                self.distribution,
+                self.seed,
+            ))),
             AnyCowArray::F32(encoded) => Ok(AnyArray::F32(encoded.into_owned())),
             AnyCowArray::F64(encoded) => Ok(AnyArray::F64(encoded.into_owned())),
             encoded => Err(UniformNoiseCodecError::UnsupportedDtype(encoded.dtype())),
@@ -90,6 +145,11 @@ impl Codec for UniformNoiseCodec {
             return Err(UniformNoiseCodecError::UnsupportedDtype(encoded.dtype()));
         }
 
+        if self.subtractive {
+            let encoded = self.decode(encoded.cow())?;
+            return Ok(decoded.assign(&encoded)?);
+        }
+
         Ok(decoded.assign(&encoded)?)
     }
 }
@@ -139,14 +199,7 @@ pub fn add_uniform_noise<T: FloatExt, S: Data<Elem = T>, D: Dimension>(
 where
     Open01: Distribution<T>,
 {
-    let mut hasher = WyHash::with_seed(seed);
-    // hashing the shape provides a prefix for the flattened data
-    data.shape().hash(&mut hasher);
-    // the data must be visited in a defined order
-    data.iter().copied().for_each(|x| x.hash_bits(&mut hasher));
-    let seed = hasher.finish();
-
-    let mut rng: WyRng = WyRng::seed_from_u64(seed);
+    let mut rng = noise_rng(&data, seed, true);
 
     let mut encoded = data.into_owned();
 
@@ -163,27 +216,269 @@ where
     encoded
 }
 
+/// Adds random noise of the given `distribution` to the input `data`.
+///
+/// If `subtractive` is `false`, this function first hashes the input and its
+/// shape to then seed a pseudo-random number generator that generates the
+/// noise. Therefore, passing in the same input with the same `seed` will
+/// produce the same noise and thus the same output.
+///
+/// If `subtractive` is `true`, only the shape of the input is hashed such
+/// that the same noise can be regenerated and removed again using
+/// [`subtract_noise`].
+#[must_use]
+pub fn add_noise<T: FloatExt, S: Data<Elem = T>, D: Dimension>(
+    data: ArrayBase<S, D>,
+    distribution: NoiseDistribution,
+    seed: u64,
+    subtractive: bool,
+) -> Array<T, D>
+where
+    Open01: Distribution<T>,
+{
+    if let (NoiseDistribution::Uniform { scale }, false) = (distribution, subtractive) {
+        return add_uniform_noise(data, T::from_f64(scale), seed);
+    }
+
+    let mut rng = noise_rng(&data, seed, !subtractive);
+
+    let mut encoded = data.into_owned();
+
+    // the data must be visited in a defined order
+    for x in &mut encoded {
+        *x = *x + sample_noise(distribution, &mut rng);
+    }
+
+    encoded
+}
+
+/// Subtracts the random noise of the given `distribution` that was added to
+/// the `encoded` data by [`add_noise`] in `subtractive` mode.
+///
+/// This function only hashes the shape of the `encoded` data to seed the
+/// pseudo-random number generator such that it regenerates the same noise.
+#[must_use]
+pub fn subtract_noise<T: FloatExt, S: Data<Elem = T>, D: Dimension>(
+    encoded: ArrayBase<S, D>,
+    distribution: NoiseDistribution,
+    seed: u64,
+) -> Array<T, D>
+where
+    Open01: Distribution<T>,
+{
+    let mut rng = noise_rng(&encoded, seed, false);
+
+    let mut decoded = encoded.into_owned();
+
+    // the data must be visited in the same order as during encoding
+    for x in &mut decoded {
+        *x = *x - sample_noise(distribution, &mut rng);
+    }
+
+    decoded
+}
+
+/// Seeds the pseudo-random number generator from the `seed`, the shape of the
+/// `data`, and optionally the `data` itself.
+fn noise_rng<T: FloatExt, S: Data<Elem = T>, D: Dimension>(
+    data: &ArrayBase<S, D>,
+    seed: u64,
+    hash_data: bool,
+) -> WyRng {
+    let mut hasher = WyHash::with_seed(seed);
+    // hashing the shape provides a prefix for the flattened data
+    data.shape().hash(&mut hasher);
+    if hash_data {
+        // the data must be visited in a defined order
+        data.iter().copied().for_each(|x| x.hash_bits(&mut hasher));
+    }
+    let seed = hasher.finish();
+
+    WyRng::seed_from_u64(seed)
+}
+
+/// Samples one value of random noise from the `distribution`.
+fn sample_noise<T: FloatExt>(distribution: NoiseDistribution, rng: &mut WyRng) -> T
+where
+    Open01: Distribution<T>,
+{
+    match distribution {
+        NoiseDistribution::Uniform { scale } => {
+            let scale = T::from_f64(scale);
+            // U(0,1)*scale - scale/2 = U(-scale/2, +scale/2)
+            Open01.sample(rng).mul_add(scale, scale * T::NEG_HALF)
+        }
+        NoiseDistribution::Gaussian { sigma } => {
+            // Box-Muller transform, using only the cosine branch such that
+            //  each value consumes exactly two samples
+            let u1: T = Open01.sample(rng);
+            let u2: T = Open01.sample(rng);
+            let z = (T::NEG_TWO * u1.ln()).sqrt() * (T::TAU * u2).cos();
+            z * T::from_f64(sigma)
+        }
+        NoiseDistribution::Laplace { b } => {
+            // inverse transform sampling from U(-0.5, +0.5)
+            let u = Open01.sample(rng) + T::NEG_HALF;
+            let z = -u.signum() * (u.abs() * T::NEG_TWO).ln_1p();
+            z * T::from_f64(b)
+        }
+    }
+}
+
 /// Floating point types
 pub trait FloatExt: Float {
     /// -0.5
     const NEG_HALF: Self;
+    /// -2.0
+    const NEG_TWO: Self;
+    /// `$\tau = 2 \pi$`
+    const TAU: Self;
 
     /// Hash the binary representation of the floating point value
     fn hash_bits<H: Hasher>(self, hasher: &mut H);
+
+    /// Converts from a [`f64`] value
+    #[must_use]
+    fn from_f64(x: f64) -> Self;
 }
 
 impl FloatExt for f32 {
     const NEG_HALF: Self = -0.5;
+    const NEG_TWO: Self = -2.0;
+    const TAU: Self = std::f32::consts::TAU;
 
     fn hash_bits<H: Hasher>(self, hasher: &mut H) {
         hasher.write_u32(self.to_bits());
     }
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn from_f64(x: f64) -> Self {
+        x as Self
+    }
 }
 
 impl FloatExt for f64 {
     const NEG_HALF: Self = -0.5;
+    const NEG_TWO: Self = -2.0;
+    const TAU: Self = std::f64::consts::TAU;
 
     fn hash_bits<H: Hasher>(self, hasher: &mut H) {
         hasher.write_u64(self.to_bits());
     }
+
+    fn from_f64(x: f64) -> Self {
+        x
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use ndarray::{Array1, ArrayView1};
+    use serde_json::json;
+
+    use super::*;
+
+    fn sample_data() -> Array1<f64> {
+        Array1::from_iter((0..10_000).map(|i| f64::from(i).mul_add(0.02, -100.0)))
+    }
+
+    #[test]
+    fn uniform_matches_add_uniform_noise() {
+        let data = sample_data();
+
+        let a = add_uniform_noise(data.view(), 0.5, 42);
+        let b = add_noise(
+            data.view(),
+            NoiseDistribution::Uniform { scale: 0.5 },
+            42,
+            false,
+        );
+
+        assert_eq!(a.mapv(f64::to_bits), b.mapv(f64::to_bits));
+    }
+
+    #[test]
+    fn noise_statistics() {
+        let data = Array1::<f64>::zeros(100_000);
+
+        for (distribution, std) in [
+            (
+                NoiseDistribution::Uniform { scale: 2.0 },
+                2.0 / 12.0_f64.sqrt(),
+            ),
+            (NoiseDistribution::Gaussian { sigma: 2.0 }, 2.0),
+            (NoiseDistribution::Laplace { b: 2.0 }, 2.0 * 2.0_f64.sqrt()),
+        ] {
+            let noise = add_noise(data.view(), distribution, 42, false);
+
+            assert!(noise.iter().all(|x| x.is_finite()));
+            let mean = noise.mean().unwrap();
+            let var = noise.mapv(|x| (x - mean) * (x - mean)).mean().unwrap();
+
+            assert!(mean.abs() < 0.05);
+            assert!((var.sqrt() - std).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn subtractive_roundtrip() -> Result<(), UniformNoiseCodecError> {
+        let data = sample_data();
+
+        for distribution in [
+            NoiseDistribution::Uniform { scale: 0.5 },
+            NoiseDistribution::Gaussian { sigma: 0.5 },
+            NoiseDistribution::Laplace { b: 0.5 },
+        ] {
+            let codec = UniformNoiseCodec {
+                distribution,
+                seed: 42,
+                subtractive: true,
+                version: StaticCodecVersion,
+            };
+
+            let encoded = codec.encode(AnyCowArray::F64(data.view().into_dyn().into()))?;
+            let AnyArray::F64(encoded) = encoded else {
+                return Err(UniformNoiseCodecError::UnsupportedDtype(encoded.dtype()));
+            };
+            assert!(
+                encoded
+                    .iter()
+                    .zip(&data)
+                    .any(|(e, d)| e.to_bits() != d.to_bits())
+            );
+
+            let decoded = codec.decode(AnyCowArray::F64(encoded.view().into()))?;
+            let AnyArray::F64(decoded) = decoded else {
+                return Err(UniformNoiseCodecError::UnsupportedDtype(decoded.dtype()));
+            };
+            let decoded: ArrayView1<f64> = decoded.view().into_dimensionality().unwrap();
+
+            for (d, o) in decoded.iter().zip(&data) {
+                assert!((d - o).abs() <= 1e-12);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn uniform_distribution_is_default() {
+        for config in [
+            json!({ "scale": 0.5, "seed": 42 }),
+            json!({ "scale": 0.5, "seed": 42, "_version": "1.0.0" }),
+        ] {
+            let codec: UniformNoiseCodec = serde_json::from_value(config).unwrap();
+            assert!(matches!(
+                codec.distribution,
+                NoiseDistribution::Uniform { scale } if scale.to_bits() == 0.5_f64.to_bits()
+            ));
+            assert!(!codec.subtractive);
+
+            assert_eq!(
+                serde_json::to_value(&codec).unwrap(),
+                json!({ "scale": 0.5, "seed": 42, "subtractive": false, "_version": "1.1.0" })
+            );
+        }
+    }
 }
@@ -0,0 +1,161 @@
+//! Sparse corrections of individual points of lossily decoded data.
+//!
+//! A correction consists of the flat index, in standard (row-major) order,
+//! and the exact value of a point. Codecs can encode a list of corrections
+//! next to their lossy encoding to guarantee a pointwise error bound.
+
+use ndarray::{ArrayBase, Data, Dimension};
+use serde::{Serialize, de::DeserializeOwned};
+use thiserror::Error;
+
+/// Sparse corrections, each consisting of the flat index and exact value of a
+/// point
+pub type Corrections<T> = Vec<(usize, T)>;
+
+/// Collects the flat index and exact value of every point in the `data`
+/// whose `decoded` value `needs_correction`.
+///
+/// The `needs_correction` predicate receives the multi-dimensional index of
+/// the point, its exact value, and its decoded value.
+pub fn collect_corrections<T: Copy, S: Data<Elem = T>, D: Dimension>(
+    data: &ArrayBase<S, D>,
+    decoded: impl IntoIterator<Item = T>,
+    mut needs_correction: impl FnMut(&[usize], T, T) -> bool,
+) -> Corrections<T> {
+    data.view()
+        .into_dyn()
+        .indexed_iter()
+        .zip(decoded)
+        .enumerate()
+        .filter_map(|(flat_index, ((index, &x), y))| {
+            needs_correction(index.slice(), x, y).then_some((flat_index, x))
+        })
+        .collect()
+}
+
+/// Appends the encoded `corrections` to the `encoded` bytes.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`CorrectionsError::EncodeFailed`] if encoding the corrections failed
+pub fn encode_corrections<T: Serialize>(
+    corrections: &[(usize, T)],
+    encoded: Vec<u8>,
+) -> Result<Vec<u8>, CorrectionsError> {
+    postcard::to_extend(corrections, encoded).map_err(|err| CorrectionsError::EncodeFailed {
+        source: CorrectionsCodingError(err),
+    })
+}
+
+/// Decodes the corrections from the start of the `encoded` bytes, and returns
+/// them together with the remaining bytes.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`CorrectionsError::DecodeFailed`] if decoding the corrections failed
+pub fn decode_corrections<T: DeserializeOwned>(
+    encoded: &[u8],
+) -> Result<(Corrections<T>, &[u8]), CorrectionsError> {
+    postcard::take_from_bytes(encoded).map_err(|err| CorrectionsError::DecodeFailed {
+        source: CorrectionsCodingError(err),
+    })
+}
+
+/// Applies the `corrections` to the flat `decoded` data.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`CorrectionsError::DecodeInvalidCorrection`] if a correction is out of
+///   bounds
+pub fn apply_corrections<T>(
+    decoded: &mut [T],
+    corrections: Corrections<T>,
+) -> Result<(), CorrectionsError> {
+    let len = decoded.len();
+
+    for (index, x) in corrections {
+        *decoded
+            .get_mut(index)
+            .ok_or(CorrectionsError::DecodeInvalidCorrection { index, len })? = x;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+/// Errors that may occur when encoding, decoding, or applying corrections
+pub enum CorrectionsError {
+    /// The corrections could not be encoded
+    #[error("failed to encode the corrections")]
+    EncodeFailed {
+        /// Opaque source error
+        source: CorrectionsCodingError,
+    },
+    /// The corrections could not be decoded
+    #[error("failed to decode the corrections")]
+    DecodeFailed {
+        /// Opaque source error
+        source: CorrectionsCodingError,
+    },
+    /// A correction was decoded for a point outside the decoded data
+    #[error("decoded a correction for the point {index} outside the decoded data of length {len}")]
+    DecodeInvalidCorrection {
+        /// The flat index of the corrected point
+        index: usize,
+        /// The length of the decoded data
+        len: usize,
+    },
+}
+
+#[derive(Debug, Error)]
+#[error(transparent)]
+/// Opaque error for when encoding or decoding the corrections fails
+pub struct CorrectionsCodingError(postcard::Error);
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use ndarray::Array;
+
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let data = Array::from_shape_fn((4, 4), |(i, j)| {
+            #[expect(clippy::cast_precision_loss)]
+            let x = (i * 4 + j) as f32;
+            x
+        });
+        let decoded = data.mapv(f32::round_ties_even);
+
+        let corrections =
+            collect_corrections(&(&data + 0.25), decoded.iter().copied(), |index, x, y| {
+                index[0] == 1 || (x - y).abs() > 0.5
+            });
+        assert_eq!(corrections.len(), 4);
+
+        let mut encoded = encode_corrections(&corrections, Vec::new()).unwrap();
+        encoded.extend_from_slice(&[1, 2, 3]);
+
+        let (corrections, remaining) = decode_corrections::<f32>(&encoded).unwrap();
+        assert_eq!(remaining, &[1, 2, 3]);
+
+        let mut decoded = decoded.into_raw_vec_and_offset().0;
+        apply_corrections(&mut decoded, corrections).unwrap();
+        assert_eq!(decoded[4..8], [4.25, 5.25, 6.25, 7.25]);
+        assert_eq!(decoded[8], 8.0);
+    }
+
+    #[test]
+    fn out_of_bounds() {
+        let mut decoded = vec![0.0_f64; 4];
+
+        assert!(matches!(
+            apply_corrections(&mut decoded, vec![(4, 1.0)]),
+            Err(CorrectionsError::DecodeInvalidCorrection { index: 4, len: 4 })
+        ));
+    }
+}
@@ -0,0 +1,231 @@
+//! Framing of a wrapped codec's encoding inside a one-dimensional byte array.
+//!
+//! A frame starts with a codec-specific header, followed by the dtype, shape,
+//! and bytes of the wrapped codec's encoding. Codecs may append further data
+//! after the frame.
+//!
+//! Since a frame is a byte array, any codec that is stacked after a framing
+//! codec must be lossless.
+
+use std::borrow::Cow;
+
+use ndarray::Array1;
+use numcodecs::{AnyArray, AnyArrayDType, AnyArrayView};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Encodes the `header`, followed by the dtype, shape, and bytes of the
+/// wrapped codec's `encoded` array, into a frame.
+///
+/// Further data can be appended to the returned frame before it is converted
+/// into an array using [`framed_array`].
+///
+/// # Errors
+///
+/// Errors with
+/// - [`FramingError::HeaderEncodeFailed`] if encoding the header failed
+pub fn encode_framed<H: Serialize>(
+    header: &H,
+    encoded: &AnyArray,
+) -> Result<Vec<u8>, FramingError> {
+    let framed = postcard::to_extend(header, Vec::new()).map_err(|err| {
+        FramingError::HeaderEncodeFailed {
+            source: FramingHeaderError(err),
+        }
+    })?;
+
+    let mut framed = postcard::to_extend(
+        &InnerEncodingHeader {
+            dtype: encoded.dtype(),
+            shape: Cow::Borrowed(encoded.shape()),
+        },
+        framed,
+    )
+    .map_err(|err| FramingError::HeaderEncodeFailed {
+        source: FramingHeaderError(err),
+    })?;
+    framed.extend_from_slice(&encoded.as_bytes());
+
+    Ok(framed)
+}
+
+/// Decodes the header and the wrapped codec's encoded array from the start of
+/// the `framed` bytes, and returns them together with any remaining bytes that
+/// were appended after the frame.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`FramingError::HeaderDecodeFailed`] if decoding the header failed
+/// - [`FramingError::CorruptedInnerEncoding`] if the wrapped codec's encoding
+///   does not fit its dtype and shape
+pub fn decode_framed<'a, H: Deserialize<'a>>(
+    framed: &'a [u8],
+) -> Result<(H, AnyArray, &'a [u8]), FramingError> {
+    let (header, framed) =
+        postcard::take_from_bytes::<H>(framed).map_err(|err| FramingError::HeaderDecodeFailed {
+            source: FramingHeaderError(err),
+        })?;
+
+    let (inner, framed) =
+        postcard::take_from_bytes::<InnerEncodingHeader>(framed).map_err(|err| {
+            FramingError::HeaderDecodeFailed {
+                source: FramingHeaderError(err),
+            }
+        })?;
+
+    let (encoded, remaining) = inner
+        .shape
+        .iter()
+        .try_fold(inner.dtype.size(), |len, s| len.checked_mul(*s))
+        .and_then(|len| framed.split_at_checked(len))
+        .ok_or(FramingError::CorruptedInnerEncoding)?;
+
+    let (encoded, ()) = AnyArray::with_zeros_bytes(inner.dtype, &inner.shape, |bytes| {
+        bytes.copy_from_slice(encoded);
+    });
+
+    Ok((header, encoded, remaining))
+}
+
+/// Wraps the `framed` bytes in a one-dimensional byte array.
+#[must_use]
+pub fn framed_array(framed: Vec<u8>) -> AnyArray {
+    AnyArray::U8(Array1::from(framed).into_dyn())
+}
+
+/// Returns the bytes of the `encoded` array, which must be a one-dimensional
+/// byte array.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`FramingError::EncodedDataNotBytes`] if the `encoded` array is not a byte
+///   array
+/// - [`FramingError::EncodedDataNotOneDimensional`] if the `encoded` array is
+///   not one-dimensional
+pub fn framed_bytes<'a>(encoded: &'a AnyArrayView) -> Result<Cow<'a, [u8]>, FramingError> {
+    if !matches!(encoded, AnyArrayView::U8(_)) {
+        return Err(FramingError::EncodedDataNotBytes {
+            dtype: encoded.dtype(),
+        });
+    }
+
+    if !matches!(encoded.shape(), [_]) {
+        return Err(FramingError::EncodedDataNotOneDimensional {
+            shape: encoded.shape().to_vec(),
+        });
+    }
+
+    Ok(encoded.as_bytes())
+}
+
+#[derive(Debug, Error)]
+/// Errors that may occur when framing or unframing a wrapped codec's encoding
+pub enum FramingError {
+    /// The frame's header could not be encoded
+    #[error("failed to encode the frame header")]
+    HeaderEncodeFailed {
+        /// Opaque source error
+        source: FramingHeaderError,
+    },
+    /// A frame can only be decoded from a one-dimensional byte array but an
+    /// array of a different dtype was received
+    #[error(
+        "can only decode a frame from a one-dimensional byte array but received an array of dtype {dtype}"
+    )]
+    EncodedDataNotBytes {
+        /// The unexpected dtype of the encoded array
+        dtype: AnyArrayDType,
+    },
+    /// A frame can only be decoded from a one-dimensional byte array but an
+    /// array of a different shape was received
+    #[error(
+        "can only decode a frame from a one-dimensional byte array but received a byte array of shape {shape:?}"
+    )]
+    EncodedDataNotOneDimensional {
+        /// The unexpected shape of the encoded array
+        shape: Vec<usize>,
+    },
+    /// The frame's header could not be decoded
+    #[error("failed to decode the frame header")]
+    HeaderDecodeFailed {
+        /// Opaque source error
+        source: FramingHeaderError,
+    },
+    /// The frame contains an encoding of the wrapped codec that does not fit
+    /// its dtype and shape
+    #[error(
+        "the frame contains an encoding of the wrapped codec that does not fit its dtype and shape"
+    )]
+    CorruptedInnerEncoding,
+}
+
+#[derive(Debug, Error)]
+#[error(transparent)]
+/// Opaque error for when encoding or decoding the frame header fails
+pub struct FramingHeaderError(postcard::Error);
+
+#[derive(Serialize, Deserialize)]
+struct InnerEncodingHeader<'a> {
+    dtype: AnyArrayDType,
+    #[serde(borrow)]
+    shape: Cow<'a, [usize]>,
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use ndarray::Array;
+
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let encoded = AnyArray::F32(
+            Array::from_shape_fn((3, 4), |(i, j)| {
+                #[expect(clippy::cast_precision_loss)]
+                let x = (i * 4 + j) as f32;
+                x
+            })
+            .into_dyn(),
+        );
+
+        let mut framed = encode_framed(&(42_u32, "header"), &encoded).unwrap();
+        framed.extend_from_slice(&[1, 2, 3]);
+
+        let framed = framed_array(framed);
+        let framed = framed.view();
+        let framed = framed_bytes(&framed).unwrap();
+
+        let ((number, text), decoded, remaining) = decode_framed::<(u32, &str)>(&framed).unwrap();
+        assert_eq!(number, 42);
+        assert_eq!(text, "header");
+        assert_eq!(decoded, encoded);
+        assert_eq!(remaining, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn truncated() {
+        let encoded = AnyArray::U16(Array::zeros((2, 2)).into_dyn());
+
+        let framed = encode_framed(&(), &encoded).unwrap();
+
+        assert!(matches!(
+            decode_framed::<()>(framed.get(..framed.len() - 1).unwrap()),
+            Err(FramingError::CorruptedInnerEncoding)
+        ));
+    }
+
+    #[test]
+    fn not_bytes() {
+        let encoded = AnyArray::F64(Array::zeros(4).into_dyn());
+
+        assert!(matches!(
+            framed_bytes(&encoded.view()),
+            Err(FramingError::EncodedDataNotBytes {
+                dtype: AnyArrayDType::F64
+            })
+        ));
+    }
+}
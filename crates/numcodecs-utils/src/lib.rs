@@ -0,0 +1,27 @@
+//! [![CI Status]][workflow] [![MSRV]][repo] [![Latest Version]][crates.io] [![Rust Doc Crate]][docs.rs] [![Rust Doc Main]][docs]
+//!
+//! [CI Status]: https://img.shields.io/github/actions/workflow/status/juntyr/numcodecs-rs/ci.yml?branch=main
+//! [workflow]: https://github.com/juntyr/numcodecs-rs/actions/workflows/ci.yml?query=branch%3Amain
+//!
+//! [MSRV]: https://img.shields.io/badge/MSRV-1.87.0-blue
+//! [repo]: https://github.com/juntyr/numcodecs-rs
+//!
+//! [Latest Version]: https://img.shields.io/crates/v/numcodecs-utils
+//! [crates.io]: https://crates.io/crates/numcodecs-utils
+//!
+//! [Rust Doc Crate]: https://img.shields.io/docsrs/numcodecs-utils
+//! [docs.rs]: https://docs.rs/numcodecs-utils/
+//!
+//! [Rust Doc Main]: https://img.shields.io/badge/docs-main-blue
+//! [docs]: https://juntyr.github.io/numcodecs-rs/numcodecs_utils
+//!
+//! Shared building blocks for compression codecs implementing the
+//! [`numcodecs`] API.
+//!
+//! [`numcodecs`]: https://numcodecs.readthedocs.io/en/stable/
+
+pub mod corrections;
+pub mod framing;
+pub mod parallel;
+pub mod regions;
+pub mod slices;
@@ -0,0 +1,116 @@
+//! Mapping over independent work items, optionally in parallel.
+//!
+//! The results are always returned in the order of the work items, such that
+//! they do not depend on the number of threads.
+
+use std::{num::NonZeroUsize, sync::Mutex};
+
+/// Applies `f` to all `items` using up to `num_threads` threads, or serially
+/// if `None`, and returns the results in item order.
+///
+/// If no additional threads can be spawned, e.g. on platforms without thread
+/// support, all items are processed on the current thread.
+///
+/// # Panics
+///
+/// Resumes the panic of any thread in which `f` panicked.
+pub fn map<I: Send, O: Send>(
+    items: Vec<I>,
+    num_threads: Option<NonZeroUsize>,
+    f: impl Fn(I) -> O + Sync,
+) -> Vec<O> {
+    let num_threads = num_threads.map_or(1, NonZeroUsize::get).min(items.len());
+
+    if num_threads <= 1 {
+        return items.into_iter().map(f).collect();
+    }
+
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let next = || queue.lock().ok()?.next();
+
+    let work = || {
+        let mut results = Vec::new();
+        while let Some((index, item)) = next() {
+            results.push((index, f(item)));
+        }
+        results
+    };
+
+    let mut results = std::thread::scope(|scope| {
+        let workers = (1..num_threads)
+            .filter_map(|_| std::thread::Builder::new().spawn_scoped(scope, work).ok())
+            .collect::<Vec<_>>();
+
+        let mut results = work();
+        for worker in workers {
+            match worker.join() {
+                Ok(worker_results) => results.extend(worker_results),
+                Err(panic) => std::panic::resume_unwind(panic),
+            }
+        }
+        results
+    });
+
+    results.sort_unstable_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Applies the fallible `f` to all `items` using up to `num_threads` threads,
+/// or serially if `None`, and returns the results in item order.
+///
+/// When processing serially, no further items are processed after the first
+/// error. When processing in parallel, all items are processed, and the error
+/// of the first failing item is returned.
+///
+/// # Errors
+///
+/// Errors with the error of the first item for which `f` failed.
+///
+/// # Panics
+///
+/// Resumes the panic of any thread in which `f` panicked.
+pub fn try_map<I: Send, O: Send, E: Send>(
+    items: Vec<I>,
+    num_threads: Option<NonZeroUsize>,
+    f: impl Fn(I) -> Result<O, E> + Sync,
+) -> Result<Vec<O>, E> {
+    if num_threads.is_none_or(|num_threads| num_threads.get() <= 1) {
+        return items.into_iter().map(f).collect();
+    }
+
+    map(items, num_threads, f).into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_in_item_order() {
+        let items = (0..100).collect::<Vec<u32>>();
+        let serial = map(items.clone(), None, |x| x * 2);
+
+        for num_threads in [1, 2, 7, 200] {
+            assert_eq!(
+                map(items.clone(), NonZeroUsize::new(num_threads), |x| x * 2),
+                serial
+            );
+        }
+    }
+
+    #[test]
+    fn first_error() {
+        let items = (0..100).collect::<Vec<u32>>();
+
+        for num_threads in [None, NonZeroUsize::new(4)] {
+            assert_eq!(
+                try_map(items.clone(), num_threads, |x| if x % 30 == 29 {
+                    Err(x)
+                } else {
+                    Ok(x)
+                }),
+                Err(29)
+            );
+        }
+    }
+}
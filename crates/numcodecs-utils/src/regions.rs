@@ -0,0 +1,204 @@
+//! Hyperrectangular regions of an array with local absolute error bounds.
+//!
+//! Codecs that support spatially varying error bounds accept a list of
+//! [`ErrorBoundRegion`]s in addition to their global error bound. Where
+//! regions overlap, the strictest bound applies.
+
+use std::borrow::Cow;
+
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+/// Hyperrectangular region of an array with a local absolute error bound
+pub struct ErrorBoundRegion {
+    /// The inclusive start index of the region along each axis
+    pub start: Vec<usize>,
+    /// The exclusive stop index of the region along each axis
+    pub stop: Vec<usize>,
+    /// The pointwise absolute error bound to preserve inside the region.
+    ///
+    /// This error bound guarantees that
+    /// `$|x - \hat{x}| \leq \epsilon_{abs}$` for all points in the region.
+    pub eb_abs: NonNegative<f64>,
+}
+
+impl ErrorBoundRegion {
+    /// Returns `true` if the region fits inside an array of the given `shape`
+    #[must_use]
+    pub fn fits(&self, shape: &[usize]) -> bool {
+        self.start.len() == shape.len()
+            && self.stop.len() == shape.len()
+            && self
+                .start
+                .iter()
+                .zip(&self.stop)
+                .zip(shape)
+                .all(|((start, stop), len)| start <= stop && stop <= len)
+    }
+
+    /// Returns `true` if the point at the multi-dimensional `index` lies
+    /// inside the region
+    #[must_use]
+    pub fn contains(&self, index: &[usize]) -> bool {
+        index
+            .iter()
+            .zip(&self.start)
+            .zip(&self.stop)
+            .all(|((i, start), stop)| (start..stop).contains(&i))
+    }
+}
+
+/// Checks that all error bound `regions` fit inside an array of the given
+/// `shape`.
+///
+/// # Errors
+///
+/// Errors with [`InvalidRegionError`] for the first region that does not fit
+pub fn check_regions_fit(
+    regions: &[ErrorBoundRegion],
+    shape: &[usize],
+) -> Result<(), InvalidRegionError> {
+    for region in regions {
+        if !region.fits(shape) {
+            return Err(InvalidRegionError {
+                start: region.start.clone(),
+                stop: region.stop.clone(),
+                shape: shape.to_vec(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the strictest absolute error bound of the `regions` that contain
+/// the point at the multi-dimensional `index`, or [`None`] if no region
+/// contains it
+#[must_use]
+pub fn local_error_bound(regions: &[ErrorBoundRegion], index: &[usize]) -> Option<f64> {
+    regions
+        .iter()
+        .filter(|region| region.contains(index))
+        .map(|region| region.eb_abs.get())
+        .reduce(f64::min)
+}
+
+#[derive(Debug, Error)]
+#[error(
+    "the error bound region from {start:?} to {stop:?} does not fit an array of shape {shape:?}"
+)]
+/// Error for when an error bound region does not fit the shape of an array
+pub struct InvalidRegionError {
+    /// The inclusive start index of the region
+    pub start: Vec<usize>,
+    /// The exclusive stop index of the region
+    pub stop: Vec<usize>,
+    /// The shape of the array
+    pub shape: Vec<usize>,
+}
+
+#[expect(clippy::derive_partial_eq_without_eq)] // floats are not Eq
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Hash)]
+/// Non-negative floating point number
+pub struct NonNegative<T>(T);
+
+impl NonNegative<f64> {
+    /// Returns the non-negative number `x`, or [`None`] if `x` is negative or
+    /// NaN
+    #[must_use]
+    pub fn new(x: f64) -> Option<Self> {
+        (x >= 0.0).then_some(Self(x))
+    }
+
+    /// Returns the non-negative number as an [`f64`]
+    #[must_use]
+    pub const fn get(self) -> f64 {
+        self.0
+    }
+}
+
+impl Serialize for NonNegative<f64> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for NonNegative<f64> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let x = f64::deserialize(deserializer)?;
+
+        Self::new(x).ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Float(x),
+                &"a non-negative value",
+            )
+        })
+    }
+}
+
+impl JsonSchema for NonNegative<f64> {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("NonNegativeF64")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed(concat!(module_path!(), "::", "NonNegative<f64>"))
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "number",
+            "minimum": 0.0
+        })
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn region(start: Vec<usize>, stop: Vec<usize>, eb_abs: f64) -> ErrorBoundRegion {
+        ErrorBoundRegion {
+            start,
+            stop,
+            eb_abs: NonNegative::new(eb_abs).unwrap(),
+        }
+    }
+
+    #[test]
+    fn fit() {
+        let regions = [region(vec![0, 2], vec![4, 8], 0.1)];
+        assert!(check_regions_fit(&regions, &[4, 8]).is_ok());
+
+        for (start, stop) in [
+            (vec![0, 0], vec![5, 1]),
+            (vec![3, 0], vec![2, 1]),
+            (vec![0], vec![1]),
+        ] {
+            assert!(check_regions_fit(&[region(start, stop, 0.1)], &[4, 8]).is_err());
+        }
+    }
+
+    #[test]
+    fn strictest_bound() {
+        let regions = [
+            region(vec![0, 0], vec![8, 8], 1e-3),
+            region(vec![4, 4], vec![16, 12], 0.0),
+        ];
+
+        assert_eq!(local_error_bound(&regions, &[1, 1]), Some(1e-3));
+        assert_eq!(local_error_bound(&regions, &[5, 5]), Some(0.0));
+        assert_eq!(local_error_bound(&regions, &[12, 4]), Some(0.0));
+        assert_eq!(local_error_bound(&regions, &[15, 15]), None);
+    }
+
+    #[test]
+    fn negative_bound() {
+        assert!(NonNegative::new(-1.0).is_none());
+        assert!(NonNegative::new(f64::NAN).is_none());
+    }
+}
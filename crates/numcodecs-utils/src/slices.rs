@@ -0,0 +1,488 @@
+//! Splitting an array into slices along its trailing axes, which are encoded
+//! independently and stored as length-prefixed byte strings.
+//!
+//! An array of shape `[.., a, b]` is split into 2D slices of shape `[a, b]`,
+//! one for every index into its leading axes. Arrays with fewer dimensions
+//! than a slice consist of a single slice. The byte offsets of the encoded
+//! slices form a slice index, which allows decoding only selected slices.
+
+use std::num::NonZeroUsize;
+
+use ndarray::{Array, ArrayBase, ArrayView, ArrayViewMut, Axis, Data, IxDyn};
+use num_traits::Zero;
+use numcodecs::{AnyArrayAssignError, AnyArrayDType, AnyArrayViewMut};
+use thiserror::Error;
+
+use crate::parallel;
+
+/// Returns the chunk size with which an array of the given `shape` is split
+/// into `N`-dimensional slices, and the shape of each slice.
+///
+/// If the array has fewer than `N` dimensions, the slice shape is padded with
+/// leading axes of length 1.
+#[must_use]
+pub fn slice_chunk_size<const N: usize>(shape: &[usize]) -> (Vec<usize>, [usize; N]) {
+    let num_leading_axes = shape.len().saturating_sub(N);
+
+    let mut chunk_size = Vec::from(shape);
+    for s in chunk_size.iter_mut().take(num_leading_axes) {
+        *s = 1;
+    }
+
+    let mut slice_shape = [1; N];
+    for (s, &len) in slice_shape.iter_mut().rev().zip(shape.iter().rev()) {
+        *s = len;
+    }
+
+    (chunk_size, slice_shape)
+}
+
+/// Returns the number of `N`-dimensional slices that an array of the given
+/// `shape` is split into, which is zero for zero-size arrays.
+#[must_use]
+pub fn num_slices<const N: usize>(shape: &[usize]) -> usize {
+    if shape.contains(&0) {
+        return 0;
+    }
+
+    shape.iter().take(shape.len().saturating_sub(N)).product()
+}
+
+/// Encodes all `N`-dimensional slices of the `data` array with
+/// `encode_slice`, using up to `num_threads` threads if provided, or serially
+/// otherwise.
+///
+/// `encode_slice` is called with each slice, in which all leading axes have
+/// length 1, and with the slice shape.
+///
+/// Returns the slice index, i.e. the byte offsets of the encoded slices, and
+/// the length-prefixed encoded slices.
+///
+/// # Errors
+///
+/// Errors with
+/// - the error of `encode_slice` if encoding a slice failed
+/// - [`SlicesError::SliceEncodeFailed`] if framing an encoded slice failed
+pub fn encode_slices<T: Sync, S: Data<Elem = T>, E: From<SlicesError> + Send, const N: usize>(
+    data: &ArrayBase<S, IxDyn>,
+    num_threads: Option<NonZeroUsize>,
+    encode_slice: impl Fn(ArrayView<T, IxDyn>, [usize; N]) -> Result<Vec<u8>, E> + Sync,
+) -> Result<(Vec<usize>, Vec<u8>), E> {
+    // zero-size arrays have no slices
+    if data.is_empty() {
+        return Ok((Vec::new(), Vec::new()));
+    }
+
+    let (chunk_size, slice_shape) = slice_chunk_size(data.shape());
+
+    let slices = data
+        .exact_chunks(chunk_size.as_slice())
+        .into_iter()
+        .collect();
+
+    let encoded_slices = parallel::try_map(slices, num_threads, |slice| {
+        encode_slice(slice, slice_shape)
+    })?;
+
+    let mut slice_offsets = Vec::with_capacity(encoded_slices.len());
+    let mut encoded = Vec::new();
+
+    for encoded_slice in encoded_slices {
+        slice_offsets.push(encoded.len());
+
+        encoded = postcard::to_extend(encoded_slice.as_slice(), encoded).map_err(|err| {
+            SlicesError::SliceEncodeFailed {
+                source: SliceFramingError(err),
+            }
+        })?;
+    }
+
+    Ok((slice_offsets, encoded))
+}
+
+/// Reconstructs the slice index of the `num_slices` length-prefixed `encoded`
+/// slices by walking over them.
+///
+/// This allows selected slices to be decoded from encodings that were created
+/// without a slice index.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`SlicesError::SliceDecodeFailed`] if decoding a slice failed
+/// - [`SlicesError::DecodeTooManySlices`] if the encoded data contains more
+///   than `num_slices` slices
+pub fn slice_offsets(encoded: &[u8], num_slices: usize) -> Result<Vec<usize>, SlicesError> {
+    let mut slice_offsets = Vec::new();
+    let mut remaining = encoded;
+
+    for _ in 0..num_slices {
+        slice_offsets.push(encoded.len() - remaining.len());
+
+        let (_encoded_slice, rest) =
+            postcard::take_from_bytes::<&[u8]>(remaining).map_err(|err| {
+                SlicesError::SliceDecodeFailed {
+                    source: SliceFramingError(err),
+                }
+            })?;
+        remaining = rest;
+    }
+
+    if !remaining.is_empty() {
+        return Err(SlicesError::DecodeTooManySlices);
+    }
+
+    Ok(slice_offsets)
+}
+
+/// Decodes all `N`-dimensional slices of the `decoded` array from the
+/// length-prefixed `encoded` slices with `decode_slice`, using up to
+/// `num_threads` threads if provided, or serially otherwise.
+///
+/// `decode_slice` is called with each encoded slice, the slice of the
+/// `decoded` array into which it is decoded, in which all leading axes have
+/// length 1, and the slice shape.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`SlicesError::SliceDecodeFailed`] if decoding a slice failed
+/// - [`SlicesError::DecodeTooManySlices`] if the encoded data contains too
+///   many slices
+/// - the error of `decode_slice` if decoding a slice failed
+pub fn decode_slices_into<T: Send, E: From<SlicesError> + Send, const N: usize>(
+    mut encoded: &[u8],
+    mut decoded: ArrayViewMut<T, IxDyn>,
+    num_threads: Option<NonZeroUsize>,
+    decode_slice: impl Fn(&[u8], ArrayViewMut<T, IxDyn>, [usize; N]) -> Result<(), E> + Sync,
+) -> Result<(), E> {
+    // zero-size arrays have no slices
+    if decoded.is_empty() {
+        if !encoded.is_empty() {
+            return Err(SlicesError::DecodeTooManySlices.into());
+        }
+        return Ok(());
+    }
+
+    let (chunk_size, slice_shape) = slice_chunk_size(decoded.shape());
+
+    let mut slices = Vec::new();
+    for slice in decoded.exact_chunks_mut(chunk_size.as_slice()) {
+        let (encoded_slice, rest) = postcard::take_from_bytes::<&[u8]>(encoded).map_err(|err| {
+            SlicesError::SliceDecodeFailed {
+                source: SliceFramingError(err),
+            }
+        })?;
+        encoded = rest;
+
+        slices.push((encoded_slice, slice));
+    }
+
+    if !encoded.is_empty() {
+        return Err(SlicesError::DecodeTooManySlices.into());
+    }
+
+    parallel::try_map(slices, num_threads, |(encoded_slice, slice)| {
+        decode_slice(encoded_slice, slice, slice_shape)
+    })?;
+
+    Ok(())
+}
+
+/// Checks that the slices at the `indices` along the first axis of an encoded
+/// array with the given `dtype` and `shape` can be decoded into the `decoded`
+/// array.
+///
+/// The `decoded` array must have the same dtype and shape as the encoded
+/// array, except that its first axis must have length `indices.len()`.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`SlicesError::SliceIndexOutOfBounds`] if any of the `indices` is out of
+///   bounds for the first axis of the encoded array
+/// - [`SlicesError::MismatchedDecodeIntoArray`] if the `decoded` array has
+///   the wrong dtype or shape
+pub fn check_decode_selected_slices_into(
+    dtype: AnyArrayDType,
+    shape: &[usize],
+    indices: &[usize],
+    decoded: &AnyArrayViewMut,
+) -> Result<(), SlicesError> {
+    let len = shape.first().copied().unwrap_or(0);
+    if let Some(&index) = indices.iter().find(|&&index| index >= len) {
+        return Err(SlicesError::SliceIndexOutOfBounds { index, len });
+    }
+
+    if decoded.dtype() != dtype {
+        return Err(SlicesError::MismatchedDecodeIntoArray {
+            source: AnyArrayAssignError::DTypeMismatch {
+                src: dtype,
+                dst: decoded.dtype(),
+            },
+        });
+    }
+
+    let mut shape = shape.to_vec();
+    if let Some(s) = shape.first_mut() {
+        *s = indices.len();
+    }
+
+    if decoded.shape() != shape {
+        return Err(SlicesError::MismatchedDecodeIntoArray {
+            source: AnyArrayAssignError::ShapeMismatch {
+                src: shape,
+                dst: decoded.shape().to_vec(),
+            },
+        });
+    }
+
+    Ok(())
+}
+
+/// Decodes only the `N`-dimensional slices at the `indices` along the first
+/// axis of an encoded array of the given `shape` into the `decoded` array.
+///
+/// The `decoded` array must have been checked with
+/// [`check_decode_selected_slices_into`]. The encoded slices are looked up in the length-prefixed `encoded` slices
+/// using the `slice_offsets` index, and decoded with `decode_slice` like in
+/// [`decode_slices_into`], using up to `num_threads` threads if provided, or
+/// serially otherwise. Arrays with at most `N` dimensions consist of a single
+/// slice, which is decoded in full before the selected indices are copied
+/// out.
+///
+/// # Errors
+///
+/// Errors with
+/// - [`SlicesError::DecodeCorruptedSliceIndex`] if the `slice_offsets` index
+///   is corrupted
+/// - [`SlicesError::SliceDecodeFailed`] if decoding a slice failed
+/// - the error of `decode_slice` if decoding a slice failed
+pub fn decode_selected_slices_into<
+    T: Clone + Zero + Send,
+    E: From<SlicesError> + Send,
+    const N: usize,
+>(
+    encoded: &[u8],
+    slice_offsets: &[usize],
+    shape: &[usize],
+    indices: &[usize],
+    mut decoded: ArrayViewMut<T, IxDyn>,
+    num_threads: Option<NonZeroUsize>,
+    decode_slice: impl Fn(&[u8], ArrayViewMut<T, IxDyn>, [usize; N]) -> Result<(), E> + Sync,
+) -> Result<(), E> {
+    if slice_offsets.len() != num_slices::<N>(shape) {
+        return Err(SlicesError::DecodeCorruptedSliceIndex.into());
+    }
+
+    // nothing to decode if no slices are selected or for zero-size arrays
+    if indices.is_empty() || decoded.is_empty() {
+        return Ok(());
+    }
+
+    let encoded_slice =
+        |slice_index: usize| {
+            let encoded_slice = slice_offsets
+                .get(slice_index)
+                .and_then(|&offset| encoded.get(offset..))
+                .ok_or(SlicesError::DecodeCorruptedSliceIndex)?;
+            let (encoded_slice, _rest) = postcard::take_from_bytes::<&[u8]>(encoded_slice)
+                .map_err(|err| SlicesError::SliceDecodeFailed {
+                    source: SliceFramingError(err),
+                })?;
+            Ok::<_, SlicesError>(encoded_slice)
+        };
+
+    if shape.len() <= N {
+        let mut slice = Array::<T, _>::zeros(shape);
+        let (_chunk_size, slice_shape) = slice_chunk_size(shape);
+        decode_slice(encoded_slice(0)?, slice.view_mut(), slice_shape)?;
+
+        for (mut decoded, &index) in decoded.outer_iter_mut().zip(indices) {
+            decoded.assign(&slice.index_axis(Axis(0), index));
+        }
+
+        return Ok(());
+    }
+
+    let slices_per_index = shape
+        .iter()
+        .take(shape.len() - N)
+        .skip(1)
+        .product::<usize>();
+
+    let (chunk_size, slice_shape) = slice_chunk_size(decoded.shape());
+
+    let slices = indices
+        .iter()
+        .flat_map(|index| (0..slices_per_index).map(move |i| (index * slices_per_index) + i))
+        .zip(decoded.exact_chunks_mut(chunk_size.as_slice()))
+        .map(|(slice_index, slice)| Ok((encoded_slice(slice_index)?, slice)))
+        .collect::<Result<Vec<_>, SlicesError>>()?;
+
+    parallel::try_map(slices, num_threads, |(encoded_slice, slice)| {
+        decode_slice(encoded_slice, slice, slice_shape)
+    })?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+/// Errors that may occur when encoding or decoding slices
+pub enum SlicesError {
+    /// An encoded slice could not be framed
+    #[error("failed to frame an encoded slice")]
+    SliceEncodeFailed {
+        /// Opaque source error
+        source: SliceFramingError,
+    },
+    /// An encoded slice could not be unframed
+    #[error("failed to unframe an encoded slice")]
+    SliceDecodeFailed {
+        /// Opaque source error
+        source: SliceFramingError,
+    },
+    /// The encoded data contains more slices than the array consists of
+    #[error("the encoded data contains an excessive number of slices")]
+    DecodeTooManySlices,
+    /// The slice index of the encoded data is corrupted
+    #[error("the slice index of the encoded data is corrupted")]
+    DecodeCorruptedSliceIndex,
+    /// A slice at an out-of-bounds index cannot be decoded
+    #[error("cannot decode the slice at index {index} of an axis of length {len}")]
+    SliceIndexOutOfBounds {
+        /// The out-of-bounds slice index
+        index: usize,
+        /// The length of the first axis of the encoded array
+        len: usize,
+    },
+    /// The selected slices cannot be decoded into the provided array
+    #[error("cannot decode the selected slices into the provided array")]
+    MismatchedDecodeIntoArray {
+        /// The source of the error
+        source: AnyArrayAssignError,
+    },
+}
+
+#[derive(Debug, Error)]
+#[error(transparent)]
+/// Opaque error for when framing or unframing an encoded slice fails
+pub struct SliceFramingError(postcard::Error);
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct TestError(SlicesError);
+
+    impl From<SlicesError> for TestError {
+        fn from(err: SlicesError) -> Self {
+            Self(err)
+        }
+    }
+
+    #[expect(clippy::needless_pass_by_value, clippy::unnecessary_wraps)] // slice encoder signature
+    fn encode_slice(slice: ArrayView<u8, IxDyn>, _: [usize; 2]) -> Result<Vec<u8>, TestError> {
+        Ok(slice.iter().copied().collect())
+    }
+
+    #[expect(clippy::unnecessary_wraps)] // slice decoder signature
+    fn decode_slice(
+        encoded_slice: &[u8],
+        mut slice: ArrayViewMut<u8, IxDyn>,
+        _: [usize; 2],
+    ) -> Result<(), TestError> {
+        for (x, y) in slice.iter_mut().zip(encoded_slice) {
+            *x = *y;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn chunk_size() {
+        assert_eq!(
+            slice_chunk_size::<3>(&[2, 3, 4, 5, 6]),
+            (vec![1, 1, 4, 5, 6], [4, 5, 6])
+        );
+        assert_eq!(slice_chunk_size::<3>(&[5, 6]), (vec![5, 6], [1, 5, 6]));
+        assert_eq!(slice_chunk_size::<2>(&[]), (vec![], [1, 1]));
+
+        assert_eq!(num_slices::<2>(&[2, 3, 4, 5]), 6);
+        assert_eq!(num_slices::<2>(&[4, 5]), 1);
+        assert_eq!(num_slices::<2>(&[2, 0, 4, 5]), 0);
+    }
+
+    #[test]
+    fn roundtrip() {
+        #[expect(clippy::cast_possible_truncation)]
+        let data = Array::from_shape_fn((4, 3, 2, 2), |(i, j, k, l)| {
+            (i * 12 + j * 4 + k * 2 + l) as u8
+        })
+        .into_dyn();
+
+        for num_threads in [None, NonZeroUsize::new(3)] {
+            let (slice_offsets, encoded) = encode_slices(&data, num_threads, encode_slice).unwrap();
+            assert_eq!(slice_offsets, self::slice_offsets(&encoded, 12).unwrap());
+
+            let mut decoded = Array::zeros(data.shape());
+            decode_slices_into(&encoded, decoded.view_mut(), num_threads, decode_slice).unwrap();
+            assert_eq!(decoded, data);
+
+            let mut selected = Array::zeros((2, 3, 2, 2)).into_dyn();
+            decode_selected_slices_into(
+                &encoded,
+                &slice_offsets,
+                data.shape(),
+                &[3, 1],
+                selected.view_mut(),
+                num_threads,
+                decode_slice,
+            )
+            .unwrap();
+            assert_eq!(selected, data.select(Axis(0), &[3, 1]));
+        }
+    }
+
+    #[test]
+    fn single_slice() {
+        let data = Array::from_shape_vec((3, 2), vec![1_u8, 2, 3, 4, 5, 6])
+            .unwrap()
+            .into_dyn();
+
+        let (slice_offsets, encoded) = encode_slices(&data, None, encode_slice).unwrap();
+        assert_eq!(slice_offsets, vec![0]);
+
+        let mut selected = Array::zeros((1, 2)).into_dyn();
+        decode_selected_slices_into(
+            &encoded,
+            &slice_offsets,
+            data.shape(),
+            &[2],
+            selected.view_mut(),
+            None,
+            decode_slice,
+        )
+        .unwrap();
+        assert_eq!(selected, data.select(Axis(0), &[2]));
+    }
+
+    #[test]
+    fn too_many_slices() {
+        let data = Array::<u8, _>::zeros((2, 2, 2)).into_dyn();
+
+        let (_slice_offsets, encoded) = encode_slices(&data, None, encode_slice).unwrap();
+
+        assert!(matches!(
+            slice_offsets(&encoded, 1),
+            Err(SlicesError::DecodeTooManySlices)
+        ));
+
+        let mut decoded = Array::zeros((1, 2, 2)).into_dyn();
+        assert!(matches!(
+            decode_slices_into(&encoded, decoded.view_mut(), None, decode_slice),
+            Err(TestError(SlicesError::DecodeTooManySlices))
+        ));
+    }
+}